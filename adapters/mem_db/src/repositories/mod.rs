@@ -1,5 +1,9 @@
+mod profile_caching;
 mod routes_read;
 
+pub use profile_caching::{
+    ProfileCachingMemDbRepo, ProfileCachingMemDbRepoParameters,
+};
 pub use routes_read::{
     RoutesFetchingMemDbRepo, RoutesFetchingMemDbRepoParameters,
 };
@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use myc_core::{
+    domain::{
+        dtos::{
+            email::Email,
+            profile::{Profile, ProfileCacheKey},
+        },
+        entities::ProfileCaching,
+    },
+    settings::{PROFILE_CACHE, PROFILE_CACHE_VERSION},
+};
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+use shaku::Component;
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The maximum number of cached emails
+///
+/// When the cache is full, the expired entries are discarded first. If the
+/// cache remains full, the email with the earliest expiration is evicted.
+///
+const PROFILE_CACHE_MAX_EMAILS: usize = 10_000;
+
+/// The maximum number of cached profiles (one per filters set) of an email
+const PROFILE_CACHE_MAX_ENTRIES_PER_EMAIL: usize = 32;
+
+#[derive(Component)]
+#[shaku(interface = ProfileCaching)]
+pub struct ProfileCachingMemDbRepo {
+    /// The time to live of the cached profiles in seconds
+    ///
+    /// Without a time to live, profiles are not cached.
+    ///
+    pub ttl_in_secs: Option<u64>,
+}
+
+fn earliest_expiration(entries: &HashMap<String, (u64, Profile)>) -> u64 {
+    entries
+        .values()
+        .map(|(expires_at, _)| *expires_at)
+        .min()
+        .unwrap_or_default()
+}
+
+fn now_in_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl ProfileCaching for ProfileCachingMemDbRepo {
+    async fn get(
+        &self,
+        key: ProfileCacheKey,
+    ) -> Result<FetchResponseKind<Profile, String>, MappedErrors> {
+        if self.ttl_in_secs.is_none() {
+            return Ok(FetchResponseKind::NotFound(None));
+        }
+
+        let email = key.email.email();
+        let digest = key.filters_digest();
+        let mut cache = PROFILE_CACHE.lock().await;
+
+        let entries = match cache.get_mut(&email) {
+            None => return Ok(FetchResponseKind::NotFound(None)),
+            Some(entries) => entries,
+        };
+
+        match entries.get(&digest).cloned() {
+            Some((expires_at, profile)) if expires_at > now_in_millis() => {
+                Ok(FetchResponseKind::Found(profile))
+            }
+            Some(_) => {
+                entries.remove(&digest);
+
+                if entries.is_empty() {
+                    cache.remove(&email);
+                }
+
+                Ok(FetchResponseKind::NotFound(None))
+            }
+            None => Ok(FetchResponseKind::NotFound(None)),
+        }
+    }
+
+    async fn version(&self) -> Result<String, MappedErrors> {
+        let _cache = PROFILE_CACHE.lock().await;
+
+        Ok(PROFILE_CACHE_VERSION.lock().await.to_string())
+    }
+
+    async fn set(
+        &self,
+        key: ProfileCacheKey,
+        profile: Profile,
+        version: String,
+    ) -> Result<(), MappedErrors> {
+        let ttl_in_secs = match self.ttl_in_secs {
+            None => return Ok(()),
+            Some(ttl_in_secs) => ttl_in_secs,
+        };
+
+        let now = now_in_millis();
+        let email = key.email.email();
+        let digest = key.filters_digest();
+        let mut cache = PROFILE_CACHE.lock().await;
+
+        //
+        // The cache was invalidated after the profile was fetched
+        //
+        if PROFILE_CACHE_VERSION.lock().await.to_string() != version {
+            return Ok(());
+        }
+
+        if !cache.contains_key(&email)
+            && cache.len() >= PROFILE_CACHE_MAX_EMAILS
+        {
+            cache.retain(|_, entries| {
+                entries.retain(|_, (expires_at, _)| *expires_at > now);
+                !entries.is_empty()
+            });
+
+            if cache.len() >= PROFILE_CACHE_MAX_EMAILS {
+                let evicted = cache
+                    .iter()
+                    .min_by_key(|(_, entries)| earliest_expiration(entries))
+                    .map(|(email, _)| email.to_owned());
+
+                if let Some(evicted) = evicted {
+                    cache.remove(&evicted);
+                }
+            }
+        }
+
+        let entries = cache.entry(email).or_default();
+
+        if !entries.contains_key(&digest)
+            && entries.len() >= PROFILE_CACHE_MAX_ENTRIES_PER_EMAIL
+        {
+            entries.retain(|_, (expires_at, _)| *expires_at > now);
+
+            if entries.len() >= PROFILE_CACHE_MAX_ENTRIES_PER_EMAIL {
+                let evicted = entries
+                    .iter()
+                    .min_by_key(|(_, (expires_at, _))| *expires_at)
+                    .map(|(digest, _)| digest.to_owned());
+
+                if let Some(evicted) = evicted {
+                    entries.remove(&evicted);
+                }
+            }
+        }
+
+        entries.insert(digest, (now + ttl_in_secs * 1000, profile));
+
+        Ok(())
+    }
+
+    async fn invalidate_email(&self, email: Email) -> Result<(), MappedErrors> {
+        let mut cache = PROFILE_CACHE.lock().await;
+
+        cache.remove(&email.email());
+        *PROFILE_CACHE_VERSION.lock().await += 1;
+
+        Ok(())
+    }
+
+    async fn invalidate_all(&self) -> Result<(), MappedErrors> {
+        let mut cache = PROFILE_CACHE.lock().await;
+
+        cache.clear();
+        *PROFILE_CACHE_VERSION.lock().await += 1;
+
+        Ok(())
+    }
+}
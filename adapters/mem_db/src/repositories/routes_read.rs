@@ -2,10 +2,10 @@ use actix_web::http::uri::PathAndQuery;
 use async_trait::async_trait;
 use myc_core::{
     domain::{
-        dtos::{route::Route, service::Service},
+        dtos::{http::HttpMethod, route::Route, service::Service},
         entities::RoutesFetching,
     },
-    settings::ROUTES,
+    settings::{ROUTES, ROUTES_INDEX},
 };
 use mycelium_base::{
     dtos::Parent,
@@ -15,7 +15,6 @@ use mycelium_base::{
 use shaku::Component;
 use tracing::{error, warn};
 use uuid::Uuid;
-use wildmatch::WildMatch;
 
 #[derive(Component)]
 #[shaku(interface = RoutesFetching)]
@@ -26,63 +25,52 @@ impl RoutesFetching for RoutesFetchingMemDbRepo {
     async fn get(
         &self,
         path: PathAndQuery,
+        method: HttpMethod,
     ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
-        let db = ROUTES.lock().await.clone();
+        let index = ROUTES_INDEX.lock().await;
 
-        if db.len() == 0 {
+        if index.is_empty() {
             return fetching_err("Routes already not initialized.".to_string())
                 .as_error();
         }
 
-        let path_string = path.to_string();
-        let path_parts = path_string.split("/").collect::<Vec<&str>>();
-        let service_name = path_parts[1];
-        let rest = path_string.replace(&format!("/{}", service_name), "");
-
-        let response = db
-            .into_iter()
-            .filter(|route| {
-                let service = match &route.service {
-                    Parent::Record(service) => service,
-                    Parent::Id(_) => {
-                        error!(
-                            "Service not found when trying to match the route with the route: {:?}", 
-                            route.id.to_owned()
-                        );
-
-                        return false;
-                    }
-                };
-
-                // Check the match between the registered route and the
-                // requested route.
-                let path_match = WildMatch::new(&route.path.as_str())
-                    .matches(rest.as_str());
+        //
+        // The first path segment is the service name. The remaining path is
+        // matched against the routes of the service.
+        //
+        let (service_name, rest) =
+            match path.path().trim_start_matches("/").split_once("/") {
+                Some((service_name, rest)) => {
+                    (service_name, format!("/{rest}"))
+                }
+                None => (path.path().trim_start_matches("/"), String::new()),
+            };
 
-                // Check the match between the registered service name and the
-                // requested service name.
-                let service_name_match =
-                    service.to_owned().name == service_name;
+        match index.find(service_name, rest.as_str(), method) {
+            None => Ok(FetchResponseKind::NotFound(None)),
+            Some(route) => Ok(FetchResponseKind::Found(route.to_owned())),
+        }
+    }
 
-                // Check the match between the service and the route.
-                path_match && service_name_match
-            })
-            .collect::<Vec<Route>>();
+    async fn get_grpc(
+        &self,
+        path: PathAndQuery,
+    ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
+        let index = ROUTES_INDEX.lock().await;
 
-        if response.len() == 0 {
-            return Ok(FetchResponseKind::NotFound(None));
+        if index.is_empty() {
+            return fetching_err("Routes already not initialized.".to_string())
+                .as_error();
         }
 
-        if response.len() > 1 {
-            return fetching_err(
-                "Multiple routes found for the specified path.".to_string(),
-            )
-            .as_error();
+        //
+        // gRPC calls are matched by the full path, since they do not include
+        // the service name.
+        //
+        match index.find_grpc(path.path()) {
+            None => Ok(FetchResponseKind::NotFound(None)),
+            Some(route) => Ok(FetchResponseKind::Found(route.to_owned())),
         }
-
-        Ok(FetchResponseKind::Found(
-            response.first().unwrap().to_owned(),
-        ))
     }
 
     async fn list_routes(
@@ -1,16 +1,21 @@
-use crate::repositories::{get_client, QueueMessage};
+use crate::{
+    repositories::{get_client, QueueMessage},
+    settings::EMAIL_QUEUE_DEPTH,
+};
 
 use myc_core::domain::entities::MessageSending;
 use mycelium_base::{
     entities::CreateResponseKind,
     utils::errors::{creation_err, MappedErrors},
 };
-use redis::{FromRedisValue, Value};
-use tracing::error;
+use redis::{Connection, FromRedisValue, Value};
+use tracing::{error, warn};
 
 /// Consumes messages from the message queue
 ///
 /// This function consumes messages from the message queue sending by smtp.
+/// Once the queue is consumed, the queues depth is updated at the
+/// `myc_email_queue_depth` metric.
 #[tracing::instrument(name = "consume_messages", skip(message_sending_repo))]
 pub async fn consume_messages(
     queue_name: String,
@@ -143,9 +148,26 @@ pub async fn consume_messages(
         }
     }
 
+    update_queues_depth(
+        &mut connection,
+        &[&queue_name, &processing_queue, &error_queue],
+    );
+
     Ok(processed_messages)
 }
 
+/// Update the depth of the queues at the metrics
+fn update_queues_depth(connection: &mut Connection, queues: &[&str]) {
+    for queue in queues {
+        match redis::cmd("LLEN").arg(*queue).query::<i64>(connection) {
+            Ok(depth) => {
+                EMAIL_QUEUE_DEPTH.with_label_values(&[*queue]).set(depth)
+            }
+            Err(err) => warn!("Failed to check the depth of {queue}: {err}"),
+        }
+    }
+}
+
 async fn process_record(
     record: Value,
     message_sending_repo: Box<&dyn MessageSending>,
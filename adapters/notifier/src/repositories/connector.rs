@@ -1,13 +1,31 @@
 use crate::models::QueueConfig;
 
+use futures::lock::Mutex as AsyncMutex;
 use lazy_static::lazy_static;
 use mycelium_base::utils::errors::MappedErrors;
-use redis::Client;
-use std::sync::Mutex;
+use redis::{aio::MultiplexedConnection, Client, ErrorKind, RedisError};
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// The time to wait for a new async connection to the queue
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The time to wait before connecting again after a failed attempt
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(5);
 
 lazy_static! {
     #[derive(Debug)]
     pub(super) static ref QUEUE_CLIENT: Mutex<Option<Client>> = Mutex::new(None);
+
+    pub(super) static ref QUEUE_ASYNC_CONNECTION: AsyncMutex<Option<MultiplexedConnection>> =
+        AsyncMutex::new(None);
+
+    /// The time of the last failed attempt to open the async connection
+    static ref QUEUE_CONNECTION_FAILED_AT: Mutex<Option<Instant>> =
+        Mutex::new(None);
 }
 
 /// Initialize the queue client from a given URL
@@ -46,3 +64,102 @@ pub(crate) async fn get_client() -> Client {
         None => panic!("Queue client is not initialized"),
     }
 }
+
+/// Get a shared async connection to the queue
+///
+/// The connection is multiplexed, then it is opened once and shared between
+/// the callers. Broken connections should be discarded with
+/// `reset_async_connection`.
+///
+/// New connections are opened without holding the shared connection lock and
+/// fail after the connection timeout. After a failed attempt, callers fail
+/// immediately during the reconnect backoff, then they could use their
+/// fallbacks instead of waiting for an unavailable queue.
+///
+pub(crate) async fn get_async_connection(
+) -> Result<MultiplexedConnection, RedisError> {
+    if let Some(connection) = QUEUE_ASYNC_CONNECTION.lock().await.as_ref() {
+        return Ok(connection.clone());
+    }
+
+    if let Some(failed_at) = *QUEUE_CONNECTION_FAILED_AT
+        .lock()
+        .expect("Could not check the queue connection state")
+    {
+        if failed_at.elapsed() < RECONNECT_BACKOFF {
+            return Err(RedisError::from((
+                ErrorKind::IoError,
+                "Queue connection unavailable",
+                format!(
+                    "the last attempt failed less than {}s ago",
+                    RECONNECT_BACKOFF.as_secs()
+                ),
+            )));
+        }
+    }
+
+    let new_connection = match tokio::time::timeout(
+        CONNECTION_TIMEOUT,
+        get_client().await.get_multiplexed_async_connection(),
+    )
+    .await
+    {
+        Ok(Ok(connection)) => connection,
+        Ok(Err(err)) => {
+            register_connection_failure(&err);
+            return Err(err);
+        }
+        Err(_) => {
+            let err = RedisError::from((
+                ErrorKind::IoError,
+                "Queue connection timed out",
+                format!(
+                    "no connection after {}s",
+                    CONNECTION_TIMEOUT.as_secs()
+                ),
+            ));
+
+            register_connection_failure(&err);
+            return Err(err);
+        }
+    };
+
+    QUEUE_CONNECTION_FAILED_AT
+        .lock()
+        .expect("Could not update the queue connection state")
+        .take();
+
+    //
+    // Concurrent callers could open connections at the same time. The first
+    // published connection is shared and the remaining ones are dropped.
+    //
+    let mut connection = QUEUE_ASYNC_CONNECTION.lock().await;
+
+    if let Some(connection) = connection.as_ref() {
+        return Ok(connection.clone());
+    }
+
+    connection.replace(new_connection.clone());
+
+    Ok(new_connection)
+}
+
+/// Start the reconnect backoff after a failed connection attempt
+fn register_connection_failure(err: &RedisError) {
+    warn!(
+        "Unable to connect to the queue: {err}. Retrying in {}s",
+        RECONNECT_BACKOFF.as_secs()
+    );
+
+    QUEUE_CONNECTION_FAILED_AT
+        .lock()
+        .expect("Could not update the queue connection state")
+        .replace(Instant::now());
+}
+
+/// Discard the shared async connection
+///
+/// A new connection is opened on the next `get_async_connection` call.
+pub(crate) async fn reset_async_connection() {
+    QUEUE_ASYNC_CONNECTION.lock().await.take();
+}
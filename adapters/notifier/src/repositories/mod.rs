@@ -1,7 +1,11 @@
 mod connector;
 mod message_sending_queue;
 mod message_sending_smtp;
+mod profile_caching_redis;
+mod rate_limit_counting_redis;
 
 pub use connector::*;
 pub use message_sending_queue::*;
 pub use message_sending_smtp::*;
+pub use profile_caching_redis::*;
+pub use rate_limit_counting_redis::*;
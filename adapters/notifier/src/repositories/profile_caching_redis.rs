@@ -0,0 +1,312 @@
+use super::{get_async_connection, reset_async_connection};
+
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use myc_core::domain::{
+    dtos::{
+        email::Email,
+        profile::{Profile, ProfileCacheKey},
+    },
+    entities::ProfileCaching,
+};
+use mycelium_base::{
+    entities::FetchResponseKind,
+    utils::errors::{creation_err, deletion_err, fetching_err, MappedErrors},
+};
+use redis::{RedisError, RedisResult, Script};
+use shaku::Component;
+
+/// The prefix of the profile cache keys
+const PROFILE_CACHE_KEY_PREFIX: &str = "myc:profile-cache";
+
+/// The key of the profile cache generation
+///
+/// Entries are stored under the current generation. Discarding all entries
+/// increments the generation, then the previous entries expire by their time
+/// to live.
+///
+const PROFILE_CACHE_GENERATION_KEY: &str = "myc:profile-cache:generation";
+
+/// The key of the profile cache version
+///
+/// The version is incremented on every invalidation. Profiles fetched before
+/// an invalidation are not cached.
+///
+const PROFILE_CACHE_VERSION_KEY: &str = "myc:profile-cache:version";
+
+lazy_static! {
+    /// Get the entry of the email and filters digest
+    ///
+    /// Keys are `<prefix>:<generation>:<email>:<digest>`.
+    ///
+    static ref GET_SCRIPT: Script = Script::new(
+        r"
+local generation = redis.call('GET', KEYS[1]) or '0'
+local index = ARGV[1] .. ':' .. generation .. ':' .. ARGV[2]
+
+return redis.call('GET', index .. ':' .. ARGV[3])
+        "
+    );
+
+    /// Set the entry of the email and filters digest
+    ///
+    /// The digests of each email are indexed at `<prefix>:<generation>:<email>`,
+    /// allowing to discard the email entries together. The entry is skipped
+    /// when the version differs from the version collected before fetching the
+    /// profile.
+    ///
+    static ref SET_SCRIPT: Script = Script::new(
+        r"
+if (redis.call('GET', KEYS[2]) or '0') ~= ARGV[6] then
+    return
+end
+
+local generation = redis.call('GET', KEYS[1]) or '0'
+local index = ARGV[1] .. ':' .. generation .. ':' .. ARGV[2]
+
+redis.call('SET', index .. ':' .. ARGV[3], ARGV[4], 'EX', ARGV[5])
+redis.call('SADD', index, ARGV[3])
+redis.call('EXPIRE', index, ARGV[5])
+        "
+    );
+
+    /// Discard the entries of an email
+    static ref INVALIDATE_EMAIL_SCRIPT: Script = Script::new(
+        r"
+local generation = redis.call('GET', KEYS[1]) or '0'
+local index = ARGV[1] .. ':' .. generation .. ':' .. ARGV[2]
+
+for _, digest in ipairs(redis.call('SMEMBERS', index)) do
+    redis.call('DEL', index .. ':' .. digest)
+end
+
+redis.call('DEL', index)
+redis.call('INCR', KEYS[2])
+        "
+    );
+}
+
+#[derive(Component)]
+#[shaku(interface = ProfileCaching)]
+pub struct ProfileCachingRedisRepository {
+    /// The time to live of the cached profiles in seconds
+    ///
+    /// Without a time to live, profiles are not cached.
+    ///
+    pub ttl_in_secs: Option<u64>,
+}
+
+/// Discard the shared connection when the error is related to it
+async fn handle_redis_error(err: &RedisError) {
+    if err.is_io_error() || err.is_connection_dropped() {
+        reset_async_connection().await;
+    }
+}
+
+#[async_trait]
+impl ProfileCaching for ProfileCachingRedisRepository {
+    #[tracing::instrument(name = "ProfileCachingRedisRepository.get", skip_all)]
+    async fn get(
+        &self,
+        key: ProfileCacheKey,
+    ) -> Result<FetchResponseKind<Profile, String>, MappedErrors> {
+        if self.ttl_in_secs.is_none() {
+            return Ok(FetchResponseKind::NotFound(None));
+        }
+
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return fetching_err(format!(
+                    "Failed to connect to the profile cache: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let res: RedisResult<Option<String>> = GET_SCRIPT
+            .key(PROFILE_CACHE_GENERATION_KEY)
+            .arg(PROFILE_CACHE_KEY_PREFIX)
+            .arg(key.email.email())
+            .arg(key.filters_digest())
+            .invoke_async(&mut connection)
+            .await;
+
+        match res {
+            Ok(None) => Ok(FetchResponseKind::NotFound(None)),
+            Ok(Some(value)) => match serde_json::from_str::<Profile>(&value) {
+                Ok(profile) => Ok(FetchResponseKind::Found(profile)),
+                Err(err) => fetching_err(format!(
+                    "Failed to parse the cached profile: {err}"
+                ))
+                .as_error(),
+            },
+            Err(err) => {
+                handle_redis_error(&err).await;
+
+                fetching_err(format!("Failed to get the cached profile: {err}"))
+                    .as_error()
+            }
+        }
+    }
+
+    #[tracing::instrument(
+        name = "ProfileCachingRedisRepository.version",
+        skip_all
+    )]
+    async fn version(&self) -> Result<String, MappedErrors> {
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return fetching_err(format!(
+                    "Failed to connect to the profile cache: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let res: RedisResult<Option<String>> = redis::cmd("GET")
+            .arg(PROFILE_CACHE_VERSION_KEY)
+            .query_async(&mut connection)
+            .await;
+
+        match res {
+            Ok(version) => Ok(version.unwrap_or_else(|| "0".to_string())),
+            Err(err) => {
+                handle_redis_error(&err).await;
+
+                fetching_err(format!(
+                    "Failed to get the profile cache version: {err}"
+                ))
+                .as_error()
+            }
+        }
+    }
+
+    #[tracing::instrument(name = "ProfileCachingRedisRepository.set", skip_all)]
+    async fn set(
+        &self,
+        key: ProfileCacheKey,
+        profile: Profile,
+        version: String,
+    ) -> Result<(), MappedErrors> {
+        let ttl_in_secs = match self.ttl_in_secs {
+            None => return Ok(()),
+            Some(ttl_in_secs) => ttl_in_secs,
+        };
+
+        let value = match serde_json::to_string(&profile) {
+            Ok(value) => value,
+            Err(err) => {
+                return creation_err(format!(
+                    "Failed to serialize the profile: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return creation_err(format!(
+                    "Failed to connect to the profile cache: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let res: RedisResult<()> = SET_SCRIPT
+            .key(PROFILE_CACHE_GENERATION_KEY)
+            .key(PROFILE_CACHE_VERSION_KEY)
+            .arg(PROFILE_CACHE_KEY_PREFIX)
+            .arg(key.email.email())
+            .arg(key.filters_digest())
+            .arg(value)
+            .arg(ttl_in_secs.max(1))
+            .arg(version)
+            .invoke_async(&mut connection)
+            .await;
+
+        if let Err(err) = res {
+            handle_redis_error(&err).await;
+
+            return creation_err(format!("Failed to cache the profile: {err}"))
+                .as_error();
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        name = "ProfileCachingRedisRepository.invalidate_email",
+        skip_all
+    )]
+    async fn invalidate_email(&self, email: Email) -> Result<(), MappedErrors> {
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return deletion_err(format!(
+                    "Failed to connect to the profile cache: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let res: RedisResult<()> = INVALIDATE_EMAIL_SCRIPT
+            .key(PROFILE_CACHE_GENERATION_KEY)
+            .key(PROFILE_CACHE_VERSION_KEY)
+            .arg(PROFILE_CACHE_KEY_PREFIX)
+            .arg(email.email())
+            .invoke_async(&mut connection)
+            .await;
+
+        if let Err(err) = res {
+            handle_redis_error(&err).await;
+
+            return deletion_err(format!(
+                "Failed to discard the cached profiles: {err}"
+            ))
+            .as_error();
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        name = "ProfileCachingRedisRepository.invalidate_all",
+        skip_all
+    )]
+    async fn invalidate_all(&self) -> Result<(), MappedErrors> {
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return deletion_err(format!(
+                    "Failed to connect to the profile cache: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let res: RedisResult<()> = redis::pipe()
+            .atomic()
+            .cmd("INCR")
+            .arg(PROFILE_CACHE_GENERATION_KEY)
+            .ignore()
+            .cmd("INCR")
+            .arg(PROFILE_CACHE_VERSION_KEY)
+            .ignore()
+            .query_async(&mut connection)
+            .await;
+
+        if let Err(err) = res {
+            handle_redis_error(&err).await;
+
+            return deletion_err(format!(
+                "Failed to discard the cached profiles: {err}"
+            ))
+            .as_error();
+        }
+
+        Ok(())
+    }
+}
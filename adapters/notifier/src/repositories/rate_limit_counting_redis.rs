@@ -0,0 +1,195 @@
+use super::{get_async_connection, reset_async_connection};
+
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use myc_core::domain::{
+    dtos::rate_limit::{
+        RateLimitAlgorithm, RateLimitDecision, RateLimitPolicy,
+    },
+    entities::RateLimitCounting,
+};
+use mycelium_base::utils::errors::{fetching_err, MappedErrors};
+use redis::{RedisResult, Script};
+use shaku::Component;
+
+/// The prefix of the rate limit counter keys
+const RATE_LIMIT_KEY_PREFIX: &str = "myc:rate-limit";
+
+lazy_static! {
+    /// Consume a token of the bucket stored at the key
+    ///
+    /// Returns the decision as `[allowed, remaining, reset, retry_after]`,
+    /// where times are in milliseconds and `retry_after` is `-1` for accepted
+    /// requests.
+    ///
+    static ref TOKEN_BUCKET_SCRIPT: Script = Script::new(
+        r"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local millis_per_token = window / math.max(limit, 1)
+
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'updated_at')
+local tokens = tonumber(state[1])
+local updated_at = tonumber(state[2])
+
+if tokens == nil or updated_at == nil then
+    tokens = limit
+else
+    tokens = math.min(
+        limit,
+        tokens + math.max(0, now - updated_at) * limit / window
+    )
+end
+
+local allowed = 0
+local retry_after = -1
+
+if tokens >= 1 then
+    allowed = 1
+    tokens = tokens - 1
+else
+    retry_after = math.ceil((1 - tokens) * millis_per_token)
+end
+
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'updated_at', now)
+redis.call('PEXPIRE', KEYS[1], 2 * window)
+
+return {
+    allowed,
+    math.floor(tokens),
+    math.ceil((limit - tokens) * millis_per_token),
+    retry_after
+}
+        "
+    );
+
+    /// Count a request of the sliding window stored at the key
+    ///
+    /// Returns the decision in the same format of the token bucket script.
+    ///
+    static ref SLIDING_WINDOW_SCRIPT: Script = Script::new(
+        r"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local window_id = math.floor(now / window)
+
+local state = redis.call('HMGET', KEYS[1], 'window_id', 'current', 'previous')
+local stored_window_id = tonumber(state[1])
+local current = tonumber(state[2]) or 0
+local previous = tonumber(state[3]) or 0
+
+if stored_window_id == nil or stored_window_id < window_id - 1 then
+    current = 0
+    previous = 0
+elseif stored_window_id == window_id - 1 then
+    previous = current
+    current = 0
+end
+
+local elapsed = (now % window) / window
+local weighted_previous = previous * (1 - elapsed)
+local reset = window - now % window
+local allowed = 0
+local retry_after = -1
+
+if weighted_previous + current + 1 <= limit then
+    allowed = 1
+    current = current + 1
+else
+    local available = limit - current - 1
+
+    if previous > 0 and available >= 0 then
+        retry_after = math.ceil(((1 - available / previous) - elapsed) * window)
+        retry_after = math.min(math.max(retry_after, 1), reset)
+    else
+        retry_after = reset
+    end
+end
+
+redis.call(
+    'HSET', KEYS[1],
+    'window_id', window_id, 'current', current, 'previous', previous
+)
+redis.call('PEXPIRE', KEYS[1], 2 * window)
+
+return {
+    allowed,
+    math.floor(math.max(0, limit - weighted_previous - current)),
+    reset,
+    retry_after
+}
+        "
+    );
+}
+
+#[derive(Component)]
+#[shaku(interface = RateLimitCounting)]
+pub struct RateLimitCountingRedisRepository {}
+
+#[async_trait]
+impl RateLimitCounting for RateLimitCountingRedisRepository {
+    #[tracing::instrument(
+        name = "RateLimitCountingRedisRepository.consume",
+        skip_all
+    )]
+    async fn consume(
+        &self,
+        key: String,
+        policy: RateLimitPolicy,
+    ) -> Result<RateLimitDecision, MappedErrors> {
+        let mut connection = match get_async_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                return fetching_err(format!(
+                    "Failed to connect to the rate limit store: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        let script = match policy.algorithm() {
+            RateLimitAlgorithm::TokenBucket => &*TOKEN_BUCKET_SCRIPT,
+            RateLimitAlgorithm::SlidingWindow => &*SLIDING_WINDOW_SCRIPT,
+        };
+
+        let res: RedisResult<Vec<i64>> = script
+            .key(format!("{RATE_LIMIT_KEY_PREFIX}:{key}"))
+            .arg(policy.limit)
+            .arg(policy.window_in_millis())
+            .invoke_async(&mut connection)
+            .await;
+
+        match res.as_deref() {
+            Ok([allowed, remaining, reset, retry_after]) => {
+                Ok(RateLimitDecision {
+                    allowed: *allowed == 1,
+                    limit: policy.limit,
+                    remaining: (*remaining).max(0) as u64,
+                    reset_in_millis: (*reset).max(0) as u64,
+                    retry_after_in_millis: match *retry_after {
+                        value if value < 0 => None,
+                        value => Some(value as u64),
+                    },
+                })
+            }
+            Ok(values) => fetching_err(format!(
+                "Unexpected rate limit store response: {values:?}"
+            ))
+            .as_error(),
+            Err(err) => {
+                if err.is_io_error() || err.is_connection_dropped() {
+                    reset_async_connection().await;
+                }
+
+                fetching_err(format!(
+                    "Failed to consume the rate limit counter: {err}"
+                ))
+                .as_error()
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
 
 use lazy_static::lazy_static;
 use myc_config::optional_config::OptionalConfig;
+use prometheus::{register_int_gauge_vec, IntGaugeVec};
 use std::{path::PathBuf, sync::Mutex};
 
 lazy_static! {
@@ -13,6 +14,19 @@ lazy_static! {
 
     #[derive(Debug)]
     pub(crate) static ref QUEUE_CONFIG: Mutex<Option<QueueConfig>> = Mutex::new(None);
+
+    /// The number of messages waiting at the email queues
+    ///
+    /// Depths are updated by the queue consumer, including the processing and
+    /// the error queues.
+    ///
+    pub(crate) static ref EMAIL_QUEUE_DEPTH: IntGaugeVec =
+        register_int_gauge_vec!(
+            "myc_email_queue_depth",
+            "The number of messages waiting at the email queues",
+            &["queue"]
+        )
+        .expect("Unable to register the email queue metrics");
 }
 
 pub async fn init_smtp_config_from_file(
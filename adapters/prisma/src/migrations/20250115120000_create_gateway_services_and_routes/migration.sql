@@ -0,0 +1,37 @@
+-- CreateTable
+CREATE TABLE "gateway_service" (
+    "id" TEXT NOT NULL,
+    "name" VARCHAR(255) NOT NULL,
+    "host" VARCHAR(255) NOT NULL,
+    "targets" JSONB,
+    "load_balancing" VARCHAR(32),
+    "health_check" JSONB,
+    "secrets" JSONB,
+    "created" TIMESTAMPTZ(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated" TIMESTAMPTZ(6),
+
+    CONSTRAINT "gateway_service_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "gateway_route" (
+    "id" TEXT NOT NULL,
+    "group" JSONB NOT NULL,
+    "methods" JSONB NOT NULL,
+    "path" VARCHAR(512) NOT NULL,
+    "protocol" VARCHAR(32) NOT NULL,
+    "allowed_sources" JSONB,
+    "secret_name" VARCHAR(255),
+    "accept_insecure_routing" BOOLEAN,
+    "created" TIMESTAMPTZ(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated" TIMESTAMPTZ(6),
+    "service_id" TEXT NOT NULL,
+
+    CONSTRAINT "gateway_route_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "gateway_service_name_key" ON "gateway_service"("name");
+
+-- AddForeignKey
+ALTER TABLE "gateway_route" ADD CONSTRAINT "gateway_route_service_id_fkey" FOREIGN KEY ("service_id") REFERENCES "gateway_service"("id") ON DELETE CASCADE ON UPDATE CASCADE;
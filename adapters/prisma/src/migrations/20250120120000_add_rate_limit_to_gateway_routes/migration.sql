@@ -0,0 +1,2 @@
+-- AlterTable
+ALTER TABLE "gateway_route" ADD COLUMN "rate_limit" JSONB;
@@ -0,0 +1,8 @@
+-- AlterTable
+ALTER TABLE "gateway_service" ADD COLUMN "timeouts" JSONB,
+ADD COLUMN "retry" JSONB;
+
+-- AlterTable
+ALTER TABLE "gateway_route" ADD COLUMN "timeouts" JSONB,
+ADD COLUMN "retry" JSONB,
+ADD COLUMN "accept_non_idempotent_retries" BOOLEAN;
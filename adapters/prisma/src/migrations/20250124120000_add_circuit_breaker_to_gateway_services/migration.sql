@@ -0,0 +1,2 @@
+-- AlterTable
+ALTER TABLE "gateway_service" ADD COLUMN "circuit_breaker" JSONB;
@@ -0,0 +1,5 @@
+-- AlterTable
+ALTER TABLE "gateway_service" ADD COLUMN "header_policy" JSONB;
+
+-- AlterTable
+ALTER TABLE "gateway_route" ADD COLUMN "header_policy" JSONB;
@@ -0,0 +1,5 @@
+-- AlterTable
+ALTER TABLE "gateway_service" ADD COLUMN "cors" JSONB;
+
+-- AlterTable
+ALTER TABLE "gateway_route" ADD COLUMN "cors" JSONB;
@@ -0,0 +1,2 @@
+-- CreateIndex
+CREATE UNIQUE INDEX "gateway_route_service_id_path_methods_key" ON "gateway_route"("service_id", "path", "methods");
@@ -17374,6 +17374,4495 @@ pub mod webhook {
         }
     }
 }
+pub mod gateway_service {
+    use super::_prisma::*;
+    use super::*;
+    pub const NAME: &str = "GatewayService";
+    pub mod id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "id";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetId(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Id(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Id(direction)
+        }
+        pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+            UniqueWhereParam::IdEquals(value).into()
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Id,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Id(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Id(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod name {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "name";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetName(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Name(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Name(direction)
+        }
+        pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+            UniqueWhereParam::NameEquals(value).into()
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Name,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Name(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Name(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod host {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "host";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetHost(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Host(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Host(direction)
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::Host(_prisma::read_filters::StringFilter::Equals(value))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Host,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Host(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Host(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod targets {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "targets";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetTargets(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Targets(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Targets(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Targets(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Targets,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Targets(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Targets(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod load_balancing {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "load_balancing";
+        pub struct Set(pub Option<String>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetLoadBalancing(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::LoadBalancing(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: Option<String>) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::LoadBalancing(direction)
+        }
+        pub fn equals(value: Option<String>) -> WhereParam {
+            WhereParam::LoadBalancing(
+                _prisma::read_filters::StringNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringNullableFilter,
+            LoadBalancing,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: Option<String>) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::LoadBalancing(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::LoadBalancing(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod health_check {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "health_check";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetHealthCheck(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::HealthCheck(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::HealthCheck(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::HealthCheck(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            HealthCheck,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::HealthCheck(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::HealthCheck(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod timeouts {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "timeouts";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetTimeouts(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Timeouts(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Timeouts(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Timeouts(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Timeouts,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Timeouts(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Timeouts(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod retry {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "retry";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetRetry(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Retry(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Retry(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Retry(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Retry,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Retry(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Retry(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod circuit_breaker {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "circuit_breaker";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetCircuitBreaker(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::CircuitBreaker(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::CircuitBreaker(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::CircuitBreaker(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            CircuitBreaker,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::CircuitBreaker(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::CircuitBreaker(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod header_policy {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "header_policy";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetHeaderPolicy(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::HeaderPolicy(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::HeaderPolicy(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::HeaderPolicy(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            HeaderPolicy,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::HeaderPolicy(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::HeaderPolicy(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod cors {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "cors";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetCors(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Cors(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Cors(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Cors(_prisma::read_filters::JsonNullableFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Cors,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Cors(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Cors(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod secrets {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "secrets";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetSecrets(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Secrets(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Secrets(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Secrets(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Secrets,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Secrets(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Secrets(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod created {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "created";
+        pub struct Set(
+            pub  ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        );
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetCreated(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Created(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Created(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::Created(_prisma::read_filters::DateTimeFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::DateTimeFilter,
+            Created,
+            {
+                fn in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> InVec;
+                fn not_in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> NotInVec;
+                fn lt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lt;
+                fn lte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lte;
+                fn gt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gt;
+                fn gte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gte;
+                fn not(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Created(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Created(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod updated {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "updated";
+        pub struct Set(
+            pub  Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        );
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetUpdated(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Updated(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Updated(direction)
+        }
+        pub fn equals(
+            value: Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ) -> WhereParam {
+            WhereParam::Updated(
+                _prisma::read_filters::DateTimeNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::DateTimeNullableFilter,
+            Updated,
+            {
+                fn in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> InVec;
+                fn not_in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> NotInVec;
+                fn lt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lt;
+                fn lte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lte;
+                fn gt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gt;
+                fn gte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gte;
+                fn not(
+                    _: Option<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Updated(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Updated(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod routes {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "routes";
+        pub struct Fetch(pub gateway_route::ManyArgs);
+        impl Fetch {
+            pub fn with(
+                mut self,
+                params: impl Into<gateway_route::WithParam>,
+            ) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+            pub fn order_by(
+                mut self,
+                param: gateway_route::OrderByParam,
+            ) -> Self {
+                self.0 = self.0.order_by(param);
+                self
+            }
+            pub fn skip(mut self, value: i64) -> Self {
+                self.0 = self.0.skip(value);
+                self
+            }
+            pub fn take(mut self, value: i64) -> Self {
+                self.0 = self.0.take(value);
+                self
+            }
+            pub fn cursor(
+                mut self,
+                value: gateway_route::UniqueWhereParam,
+            ) -> Self {
+                self.0 = self.0.cursor(value.into());
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(Fetch(v): Fetch) -> Self {
+                WithParam::Routes(v)
+            }
+        }
+        pub fn fetch(params: Vec<gateway_route::WhereParam>) -> Fetch {
+            Fetch(gateway_route::ManyArgs::new(params))
+        }
+        pub struct Connect(pub Vec<gateway_route::UniqueWhereParam>);
+        impl From<Connect> for SetParam {
+            fn from(Connect(v): Connect) -> Self {
+                Self::ConnectRoutes(v)
+            }
+        }
+        pub fn connect<T: From<Connect>>(
+            params: Vec<gateway_route::UniqueWhereParam>,
+        ) -> T {
+            Connect(params).into()
+        }
+        pub fn disconnect(
+            params: Vec<gateway_route::UniqueWhereParam>,
+        ) -> SetParam {
+            SetParam::DisconnectRoutes(params)
+        }
+        pub fn set(params: Vec<gateway_route::UniqueWhereParam>) -> SetParam {
+            SetParam::SetRoutes(params)
+        }
+        pub fn some(value: Vec<gateway_route::WhereParam>) -> WhereParam {
+            WhereParam::RoutesSome(value)
+        }
+        pub fn every(value: Vec<gateway_route::WhereParam>) -> WhereParam {
+            WhereParam::RoutesEvery(value)
+        }
+        pub fn none(value: Vec<gateway_route::WhereParam>) -> WhereParam {
+            WhereParam::RoutesNone(value)
+        }
+        pub enum Include {
+            Select(gateway_route::ManyArgs, Vec<gateway_route::SelectParam>),
+            Include(gateway_route::ManyArgs, Vec<gateway_route::IncludeParam>),
+            Fetch(gateway_route::ManyArgs),
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Routes(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let (args , selections) = match self { Self :: Select (args , selections) => (args . to_graphql () . 0 , selections . into_iter () . map (| s | s . to_selection ()) . collect ()) , Self :: Include (args , selections) => (args . to_graphql () . 0 , { let mut nested_selections = < gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections () ; nested_selections . extend (selections . into_iter () . map (| s | s . to_selection ())) ; nested_selections }) , Self :: Fetch (args) => (args . to_graphql () . 0 , < gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()) } ;
+                ::prisma_client_rust::Selection::new(
+                    NAME, None, args, selections,
+                )
+            }
+            pub fn select(
+                args: gateway_route::ManyArgs,
+                nested_selections: Vec<gateway_route::SelectParam>,
+            ) -> Self {
+                Self::Select(args, nested_selections)
+            }
+            pub fn include(
+                args: gateway_route::ManyArgs,
+                nested_selections: Vec<gateway_route::IncludeParam>,
+            ) -> Self {
+                Self::Include(args, nested_selections)
+            }
+        }
+        pub enum Select {
+            Select(gateway_route::ManyArgs, Vec<gateway_route::SelectParam>),
+            Include(gateway_route::ManyArgs, Vec<gateway_route::IncludeParam>),
+            Fetch(gateway_route::ManyArgs),
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Routes(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let (args , selections) = match self { Self :: Select (args , selections) => (args . to_graphql () . 0 , selections . into_iter () . map (| s | s . to_selection ()) . collect ()) , Self :: Include (args , selections) => (args . to_graphql () . 0 , { let mut nested_selections = vec ! [] ; nested_selections . extend (selections . into_iter () . map (| s | s . to_selection ())) ; nested_selections }) , Self :: Fetch (args) => (args . to_graphql () . 0 , < gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()) } ;
+                ::prisma_client_rust::Selection::new(
+                    NAME, None, args, selections,
+                )
+            }
+            pub fn select(
+                args: gateway_route::ManyArgs,
+                nested_selections: Vec<gateway_route::SelectParam>,
+            ) -> Self {
+                Self::Select(args, nested_selections)
+            }
+            pub fn include(
+                args: gateway_route::ManyArgs,
+                nested_selections: Vec<gateway_route::IncludeParam>,
+            ) -> Self {
+                Self::Include(args, nested_selections)
+            }
+        }
+    }
+    pub fn create(
+        name: String,
+        host: String,
+        _params: Vec<SetParam>,
+    ) -> (String, String, Vec<SetParam>) {
+        (name, host, _params)
+    }
+    pub fn create_unchecked(
+        name: String,
+        host: String,
+        _params: Vec<SetParam>,
+    ) -> (String, String, Vec<SetParam>) {
+        (name, host, _params)
+    }
+    #[macro_export]
+    macro_rules ! _select_gateway_service { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: gateway_service :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_service :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: gateway_service :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: gateway_service :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_service :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: gateway_service :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , name , host , targets , load_balancing , health_check , timeouts , retry , circuit_breaker , header_policy , cors , secrets , created , updated , routes } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: gateway_service :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: gateway_service :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: gateway_service :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: gateway_service :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "host" , "targets" , "load_balancing" , "health_check" , "timeouts" , "retry" , "circuit_breaker" , "header_policy" , "cors" , "secrets" , "created" , "updated" , "routes"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: gateway_service :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; name) => { String } ; (@ field_type ; host) => { String } ; (@ field_type ; targets) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; load_balancing) => { Option < String > } ; (@ field_type ; health_check) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; timeouts) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; retry) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; circuit_breaker) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; header_policy) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; cors) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; secrets) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; created) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; updated) => { Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > } ; (@ field_type ; routes : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < routes :: Data > } ; (@ field_type ; routes) => { Vec < crate :: prisma :: gateway_route :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "GatewayService" , available relations are "id, name, host, targets, load_balancing, health_check, timeouts, retry, circuit_breaker, header_policy, cors, secrets, created, updated, routes")) } ; (@ field_module ; routes : $ selection_mode : ident { $ ($ selections : tt) + }) => { crate :: prisma :: gateway_route :: select ! (@ definitions ; ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: id :: Select) } ; (@ selection_field_to_selection_param ; name) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: name :: Select) } ; (@ selection_field_to_selection_param ; host) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: host :: Select) } ; (@ selection_field_to_selection_param ; targets) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: targets :: Select) } ; (@ selection_field_to_selection_param ; load_balancing) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: load_balancing :: Select) } ; (@ selection_field_to_selection_param ; health_check) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: health_check :: Select) } ; (@ selection_field_to_selection_param ; timeouts) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: timeouts :: Select) } ; (@ selection_field_to_selection_param ; retry) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: retry :: Select) } ; (@ selection_field_to_selection_param ; circuit_breaker) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: circuit_breaker :: Select) } ; (@ selection_field_to_selection_param ; header_policy) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: header_policy :: Select) } ; (@ selection_field_to_selection_param ; cors) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: cors :: Select) } ; (@ selection_field_to_selection_param ; secrets) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: secrets :: Select) } ; (@ selection_field_to_selection_param ; created) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: created :: Select) } ; (@ selection_field_to_selection_param ; updated) => { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: updated :: Select) } ; (@ selection_field_to_selection_param ; routes $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: routes :: Select :: $ selection_mode (crate :: prisma :: gateway_route :: ManyArgs :: new (crate :: prisma :: gateway_route :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) $ ($ (. $ arg ($ ($ arg_params) *)) *) ? , crate :: prisma :: gateway_route :: select ! (@ selections_to_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; routes $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { Into :: < crate :: prisma :: gateway_service :: SelectParam > :: into (crate :: prisma :: gateway_service :: routes :: Select :: Fetch (crate :: prisma :: gateway_route :: ManyArgs :: new (crate :: prisma :: gateway_route :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) $ ($ (. $ arg ($ ($ arg_params) *)) *) ?) ,) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: gateway_service :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; host) => { "host" } ; (@ field_serde_name ; targets) => { "targets" } ; (@ field_serde_name ; load_balancing) => { "load_balancing" } ; (@ field_serde_name ; health_check) => { "health_check" } ; (@ field_serde_name ; timeouts) => { "timeouts" } ; (@ field_serde_name ; retry) => { "retry" } ; (@ field_serde_name ; circuit_breaker) => { "circuit_breaker" } ; (@ field_serde_name ; header_policy) => { "header_policy" } ; (@ field_serde_name ; cors) => { "cors" } ; (@ field_serde_name ; secrets) => { "secrets" } ; (@ field_serde_name ; created) => { "created" } ; (@ field_serde_name ; updated) => { "updated" } ; (@ field_serde_name ; routes) => { "routes" } ; }
+    pub use _select_gateway_service as select;
+    pub enum SelectParam {
+        Id(id::Select),
+        Name(name::Select),
+        Host(host::Select),
+        Targets(targets::Select),
+        LoadBalancing(load_balancing::Select),
+        HealthCheck(health_check::Select),
+        Timeouts(timeouts::Select),
+        Retry(retry::Select),
+        CircuitBreaker(circuit_breaker::Select),
+        HeaderPolicy(header_policy::Select),
+        Cors(cors::Select),
+        Secrets(secrets::Select),
+        Created(created::Select),
+        Updated(updated::Select),
+        Routes(routes::Select),
+    }
+    impl SelectParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::Name(data) => data.to_selection(),
+                Self::Host(data) => data.to_selection(),
+                Self::Targets(data) => data.to_selection(),
+                Self::LoadBalancing(data) => data.to_selection(),
+                Self::HealthCheck(data) => data.to_selection(),
+                Self::Timeouts(data) => data.to_selection(),
+                Self::Retry(data) => data.to_selection(),
+                Self::CircuitBreaker(data) => data.to_selection(),
+                Self::HeaderPolicy(data) => data.to_selection(),
+                Self::Cors(data) => data.to_selection(),
+                Self::Secrets(data) => data.to_selection(),
+                Self::Created(data) => data.to_selection(),
+                Self::Updated(data) => data.to_selection(),
+                Self::Routes(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _include_gateway_service { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: gateway_service :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_service :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: gateway_service :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: gateway_service :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_service :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: gateway_service :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { routes } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub name : String , pub host : String , pub targets : Option < :: prisma_client_rust :: serde_json :: Value > , pub load_balancing : Option < String > , pub health_check : Option < :: prisma_client_rust :: serde_json :: Value > , pub timeouts : Option < :: prisma_client_rust :: serde_json :: Value > , pub retry : Option < :: prisma_client_rust :: serde_json :: Value > , pub circuit_breaker : Option < :: prisma_client_rust :: serde_json :: Value > , pub header_policy : Option < :: prisma_client_rust :: serde_json :: Value > , pub cors : Option < :: prisma_client_rust :: serde_json :: Value > , pub secrets : Option < :: prisma_client_rust :: serde_json :: Value > , pub created : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub updated : Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > , $ (pub $ field : crate :: prisma :: gateway_service :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (name) , stringify ! (host) , stringify ! (targets) , stringify ! (load_balancing) , stringify ! (health_check) , stringify ! (timeouts) , stringify ! (retry) , stringify ! (circuit_breaker) , stringify ! (header_policy) , stringify ! (cors) , stringify ! (secrets) , stringify ! (created) , stringify ! (updated)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: gateway_service :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: gateway_service :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: gateway_service :: name :: NAME , & self . name) ? ; state . serialize_field (crate :: prisma :: gateway_service :: host :: NAME , & self . host) ? ; state . serialize_field (crate :: prisma :: gateway_service :: targets :: NAME , & self . targets) ? ; state . serialize_field (crate :: prisma :: gateway_service :: load_balancing :: NAME , & self . load_balancing) ? ; state . serialize_field (crate :: prisma :: gateway_service :: health_check :: NAME , & self . health_check) ? ; state . serialize_field (crate :: prisma :: gateway_service :: timeouts :: NAME , & self . timeouts) ? ; state . serialize_field (crate :: prisma :: gateway_service :: retry :: NAME , & self . retry) ? ; state . serialize_field (crate :: prisma :: gateway_service :: circuit_breaker :: NAME , & self . circuit_breaker) ? ; state . serialize_field (crate :: prisma :: gateway_service :: header_policy :: NAME , & self . header_policy) ? ; state . serialize_field (crate :: prisma :: gateway_service :: cors :: NAME , & self . cors) ? ; state . serialize_field (crate :: prisma :: gateway_service :: secrets :: NAME , & self . secrets) ? ; state . serialize_field (crate :: prisma :: gateway_service :: created :: NAME , & self . created) ? ; state . serialize_field (crate :: prisma :: gateway_service :: updated :: NAME , & self . updated) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , name , host , targets , load_balancing , health_check , timeouts , retry , circuit_breaker , header_policy , cors , secrets , created , updated } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: gateway_service :: $ field :: NAME) , + , crate :: prisma :: gateway_service :: id :: NAME , crate :: prisma :: gateway_service :: name :: NAME , crate :: prisma :: gateway_service :: host :: NAME , crate :: prisma :: gateway_service :: targets :: NAME , crate :: prisma :: gateway_service :: load_balancing :: NAME , crate :: prisma :: gateway_service :: health_check :: NAME , crate :: prisma :: gateway_service :: timeouts :: NAME , crate :: prisma :: gateway_service :: retry :: NAME , crate :: prisma :: gateway_service :: circuit_breaker :: NAME , crate :: prisma :: gateway_service :: header_policy :: NAME , crate :: prisma :: gateway_service :: cors :: NAME , crate :: prisma :: gateway_service :: secrets :: NAME , crate :: prisma :: gateway_service :: created :: NAME , crate :: prisma :: gateway_service :: updated :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: gateway_service :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: gateway_service :: id :: NAME => Ok (Field :: id) , crate :: prisma :: gateway_service :: name :: NAME => Ok (Field :: name) , crate :: prisma :: gateway_service :: host :: NAME => Ok (Field :: host) , crate :: prisma :: gateway_service :: targets :: NAME => Ok (Field :: targets) , crate :: prisma :: gateway_service :: load_balancing :: NAME => Ok (Field :: load_balancing) , crate :: prisma :: gateway_service :: health_check :: NAME => Ok (Field :: health_check) , crate :: prisma :: gateway_service :: timeouts :: NAME => Ok (Field :: timeouts) , crate :: prisma :: gateway_service :: retry :: NAME => Ok (Field :: retry) , crate :: prisma :: gateway_service :: circuit_breaker :: NAME => Ok (Field :: circuit_breaker) , crate :: prisma :: gateway_service :: header_policy :: NAME => Ok (Field :: header_policy) , crate :: prisma :: gateway_service :: cors :: NAME => Ok (Field :: cors) , crate :: prisma :: gateway_service :: secrets :: NAME => Ok (Field :: secrets) , crate :: prisma :: gateway_service :: created :: NAME => Ok (Field :: created) , crate :: prisma :: gateway_service :: updated :: NAME => Ok (Field :: updated) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut name = None ; let mut host = None ; let mut targets = None ; let mut load_balancing = None ; let mut health_check = None ; let mut timeouts = None ; let mut retry = None ; let mut circuit_breaker = None ; let mut header_policy = None ; let mut cors = None ; let mut secrets = None ; let mut created = None ; let mut updated = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: name => { if name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: name :: NAME)) ; } name = Some (map . next_value () ?) ; } Field :: host => { if host . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: host :: NAME)) ; } host = Some (map . next_value () ?) ; } Field :: targets => { if targets . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: targets :: NAME)) ; } targets = Some (map . next_value () ?) ; } Field :: load_balancing => { if load_balancing . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: load_balancing :: NAME)) ; } load_balancing = Some (map . next_value () ?) ; } Field :: health_check => { if health_check . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: health_check :: NAME)) ; } health_check = Some (map . next_value () ?) ; } Field :: timeouts => { if timeouts . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: timeouts :: NAME)) ; } timeouts = Some (map . next_value () ?) ; } Field :: retry => { if retry . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: retry :: NAME)) ; } retry = Some (map . next_value () ?) ; } Field :: circuit_breaker => { if circuit_breaker . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: circuit_breaker :: NAME)) ; } circuit_breaker = Some (map . next_value () ?) ; } Field :: header_policy => { if header_policy . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: header_policy :: NAME)) ; } header_policy = Some (map . next_value () ?) ; } Field :: cors => { if cors . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: cors :: NAME)) ; } cors = Some (map . next_value () ?) ; } Field :: secrets => { if secrets . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: secrets :: NAME)) ; } secrets = Some (map . next_value () ?) ; } Field :: created => { if created . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: created :: NAME)) ; } created = Some (map . next_value () ?) ; } Field :: updated => { if updated . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: updated :: NAME)) ; } updated = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_service :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: id :: NAME)) ? ; let name = name . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: name :: NAME)) ? ; let host = host . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: host :: NAME)) ? ; let targets = targets . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: targets :: NAME)) ? ; let load_balancing = load_balancing . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: load_balancing :: NAME)) ? ; let health_check = health_check . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: health_check :: NAME)) ? ; let timeouts = timeouts . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: timeouts :: NAME)) ? ; let retry = retry . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: retry :: NAME)) ? ; let circuit_breaker = circuit_breaker . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: circuit_breaker :: NAME)) ? ; let header_policy = header_policy . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: header_policy :: NAME)) ? ; let cors = cors . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: cors :: NAME)) ? ; let secrets = secrets . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: secrets :: NAME)) ? ; let created = created . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: created :: NAME)) ? ; let updated = updated . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_service :: updated :: NAME)) ? ; Ok (Data { id , name , host , targets , load_balancing , health_check , timeouts , retry , circuit_breaker , header_policy , cors , secrets , created , updated , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "host" , "targets" , "load_balancing" , "health_check" , "timeouts" , "retry" , "circuit_breaker" , "header_policy" , "cors" , "secrets" , "created" , "updated" , "routes"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: gateway_service :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; routes : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < routes :: Data > } ; (@ field_type ; routes) => { Vec < crate :: prisma :: gateway_route :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "GatewayService" , available relations are "routes")) } ; (@ field_module ; routes : $ selection_mode : ident { $ ($ selections : tt) + }) => { crate :: prisma :: gateway_route :: include ! (@ definitions ; ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; routes $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { Into :: < crate :: prisma :: gateway_service :: IncludeParam > :: into (crate :: prisma :: gateway_service :: routes :: Include :: $ selection_mode (crate :: prisma :: gateway_route :: ManyArgs :: new (crate :: prisma :: gateway_route :: include ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) $ ($ (. $ arg ($ ($ arg_params) *)) *) ? , crate :: prisma :: gateway_route :: select ! (@ selections_to_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; routes $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { Into :: < crate :: prisma :: gateway_service :: IncludeParam > :: into (crate :: prisma :: gateway_service :: routes :: Include :: Fetch (crate :: prisma :: gateway_route :: ManyArgs :: new (crate :: prisma :: gateway_route :: include ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) $ ($ (. $ arg ($ ($ arg_params) *)) *) ?) ,) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: gateway_service :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; host) => { "host" } ; (@ field_serde_name ; targets) => { "targets" } ; (@ field_serde_name ; load_balancing) => { "load_balancing" } ; (@ field_serde_name ; health_check) => { "health_check" } ; (@ field_serde_name ; timeouts) => { "timeouts" } ; (@ field_serde_name ; retry) => { "retry" } ; (@ field_serde_name ; circuit_breaker) => { "circuit_breaker" } ; (@ field_serde_name ; header_policy) => { "header_policy" } ; (@ field_serde_name ; cors) => { "cors" } ; (@ field_serde_name ; secrets) => { "secrets" } ; (@ field_serde_name ; created) => { "created" } ; (@ field_serde_name ; updated) => { "updated" } ; (@ field_serde_name ; routes) => { "routes" } ; }
+    pub use _include_gateway_service as include;
+    pub enum IncludeParam {
+        Id(id::Include),
+        Name(name::Include),
+        Host(host::Include),
+        Targets(targets::Include),
+        LoadBalancing(load_balancing::Include),
+        HealthCheck(health_check::Include),
+        Timeouts(timeouts::Include),
+        Retry(retry::Include),
+        CircuitBreaker(circuit_breaker::Include),
+        HeaderPolicy(header_policy::Include),
+        Cors(cors::Include),
+        Secrets(secrets::Include),
+        Created(created::Include),
+        Updated(updated::Include),
+        Routes(routes::Include),
+    }
+    impl IncludeParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::Name(data) => data.to_selection(),
+                Self::Host(data) => data.to_selection(),
+                Self::Targets(data) => data.to_selection(),
+                Self::LoadBalancing(data) => data.to_selection(),
+                Self::HealthCheck(data) => data.to_selection(),
+                Self::Timeouts(data) => data.to_selection(),
+                Self::Retry(data) => data.to_selection(),
+                Self::CircuitBreaker(data) => data.to_selection(),
+                Self::HeaderPolicy(data) => data.to_selection(),
+                Self::Cors(data) => data.to_selection(),
+                Self::Secrets(data) => data.to_selection(),
+                Self::Created(data) => data.to_selection(),
+                Self::Updated(data) => data.to_selection(),
+                Self::Routes(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _partial_unchecked_gateway_service { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: gateway_service struct $ struct_name { # [serde (rename = "id")] pub id : String , # [serde (rename = "name")] pub name : String , # [serde (rename = "host")] pub host : String , # [serde (rename = "targets")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub targets : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "load_balancing")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub load_balancing : Option < String > , # [serde (rename = "health_check")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub health_check : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "timeouts")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub timeouts : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "retry")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub retry : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "circuit_breaker")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub circuit_breaker : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "header_policy")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub header_policy : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "cors")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub cors : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "secrets")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub secrets : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "created")] pub created : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , # [serde (rename = "updated")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub updated : Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > } [$ ($ scalar_field) , +] } } ; }
+    pub use _partial_unchecked_gateway_service as partial_unchecked;
+    #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub struct Data {
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "name")]
+        pub name: String,
+        #[serde(rename = "host")]
+        pub host: String,
+        #[serde(rename = "targets")]
+        pub targets: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "load_balancing")]
+        pub load_balancing: Option<String>,
+        #[serde(rename = "health_check")]
+        pub health_check: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "timeouts")]
+        pub timeouts: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "retry")]
+        pub retry: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "circuit_breaker")]
+        pub circuit_breaker: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "header_policy")]
+        pub header_policy: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "cors")]
+        pub cors: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "secrets")]
+        pub secrets: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "created")]
+        pub created: ::prisma_client_rust::chrono::DateTime<
+            ::prisma_client_rust::chrono::FixedOffset,
+        >,
+        #[serde(rename = "updated")]
+        pub updated: Option<
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        >,
+        #[serde(rename = "routes")]
+        pub routes: Option<Vec<super::gateway_route::Data>>,
+    }
+    impl Data {
+        pub fn routes(
+            &self,
+        ) -> Result<
+            &Vec<super::gateway_route::Data>,
+            ::prisma_client_rust::RelationNotFetchedError,
+        > {
+            self.routes.as_ref().ok_or(
+                ::prisma_client_rust::RelationNotFetchedError::new(stringify!(
+                    routes
+                )),
+            )
+        }
+    }
+    #[derive(Clone)]
+    pub enum WithParam {
+        Routes(super::gateway_route::ManyArgs),
+    }
+    impl Into<::prisma_client_rust::Selection> for WithParam {
+        fn into(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Routes(args) => {
+                    let (arguments, mut nested_selections) = args.to_graphql();
+                    nested_selections . extend (< super :: gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()) ;
+                    ::prisma_client_rust::Selection::new(
+                        routes::NAME,
+                        None,
+                        arguments,
+                        nested_selections,
+                    )
+                }
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum SetParam {
+        SetId(String),
+        SetName(String),
+        SetHost(String),
+        SetTargets(Option<::prisma_client_rust::serde_json::Value>),
+        SetLoadBalancing(Option<String>),
+        SetHealthCheck(Option<::prisma_client_rust::serde_json::Value>),
+        SetTimeouts(Option<::prisma_client_rust::serde_json::Value>),
+        SetRetry(Option<::prisma_client_rust::serde_json::Value>),
+        SetCircuitBreaker(Option<::prisma_client_rust::serde_json::Value>),
+        SetHeaderPolicy(Option<::prisma_client_rust::serde_json::Value>),
+        SetCors(Option<::prisma_client_rust::serde_json::Value>),
+        SetSecrets(Option<::prisma_client_rust::serde_json::Value>),
+        SetCreated(
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ),
+        SetUpdated(
+            Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ),
+        ConnectRoutes(Vec<super::gateway_route::UniqueWhereParam>),
+        DisconnectRoutes(Vec<super::gateway_route::UniqueWhereParam>),
+        SetRoutes(Vec<super::gateway_route::UniqueWhereParam>),
+    }
+    impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+        fn from(param: SetParam) -> Self {
+            match param { SetParam :: SetId (value) => (id :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetName (value) => (name :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetHost (value) => (host :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetTargets (value) => (targets :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetLoadBalancing (value) => (load_balancing :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetHealthCheck (value) => (health_check :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetTimeouts (value) => (timeouts :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetRetry (value) => (retry :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetCircuitBreaker (value) => (circuit_breaker :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetHeaderPolicy (value) => (header_policy :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetCors (value) => (cors :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetSecrets (value) => (secrets :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetCreated (value) => (created :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) , SetParam :: SetUpdated (value) => (updated :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: DateTime (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: ConnectRoutes (where_params) => (routes :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: gateway_route :: WhereParam > :: into) . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) , SetParam :: DisconnectRoutes (where_params) => (routes :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: gateway_route :: WhereParam > :: into) . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) , SetParam :: SetRoutes (where_params) => (routes :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("set" . to_string () , :: prisma_client_rust :: PrismaValue :: List (where_params . into_iter () . map (Into :: < super :: gateway_route :: WhereParam > :: into) . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . map (| v | :: prisma_client_rust :: PrismaValue :: Object (vec ! [v])) . collect ()))])) }
+        }
+    }
+    #[derive(Clone)]
+    pub enum UncheckedSetParam {
+        Id(String),
+        Name(String),
+        Host(String),
+        Targets(Option<::prisma_client_rust::serde_json::Value>),
+        LoadBalancing(Option<String>),
+        HealthCheck(Option<::prisma_client_rust::serde_json::Value>),
+        Timeouts(Option<::prisma_client_rust::serde_json::Value>),
+        Retry(Option<::prisma_client_rust::serde_json::Value>),
+        CircuitBreaker(Option<::prisma_client_rust::serde_json::Value>),
+        HeaderPolicy(Option<::prisma_client_rust::serde_json::Value>),
+        Cors(Option<::prisma_client_rust::serde_json::Value>),
+        Secrets(Option<::prisma_client_rust::serde_json::Value>),
+        Created(
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ),
+        Updated(
+            Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ),
+    }
+    impl From<UncheckedSetParam> for SetParam {
+        fn from(param: UncheckedSetParam) -> Self {
+            match param {
+                UncheckedSetParam::Id(value) => Self::SetId(value),
+                UncheckedSetParam::Name(value) => Self::SetName(value),
+                UncheckedSetParam::Host(value) => Self::SetHost(value),
+                UncheckedSetParam::Targets(value) => Self::SetTargets(value),
+                UncheckedSetParam::LoadBalancing(value) => {
+                    Self::SetLoadBalancing(value)
+                }
+                UncheckedSetParam::HealthCheck(value) => {
+                    Self::SetHealthCheck(value)
+                }
+                UncheckedSetParam::Timeouts(value) => Self::SetTimeouts(value),
+                UncheckedSetParam::Retry(value) => Self::SetRetry(value),
+                UncheckedSetParam::CircuitBreaker(value) => {
+                    Self::SetCircuitBreaker(value)
+                }
+                UncheckedSetParam::HeaderPolicy(value) => {
+                    Self::SetHeaderPolicy(value)
+                }
+                UncheckedSetParam::Cors(value) => Self::SetCors(value),
+                UncheckedSetParam::Secrets(value) => Self::SetSecrets(value),
+                UncheckedSetParam::Created(value) => Self::SetCreated(value),
+                UncheckedSetParam::Updated(value) => Self::SetUpdated(value),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum OrderByParam {
+        Id(::prisma_client_rust::Direction),
+        Name(::prisma_client_rust::Direction),
+        Host(::prisma_client_rust::Direction),
+        Targets(::prisma_client_rust::Direction),
+        LoadBalancing(::prisma_client_rust::Direction),
+        HealthCheck(::prisma_client_rust::Direction),
+        Timeouts(::prisma_client_rust::Direction),
+        Retry(::prisma_client_rust::Direction),
+        CircuitBreaker(::prisma_client_rust::Direction),
+        HeaderPolicy(::prisma_client_rust::Direction),
+        Cors(::prisma_client_rust::Direction),
+        Secrets(::prisma_client_rust::Direction),
+        Created(::prisma_client_rust::Direction),
+        Updated(::prisma_client_rust::Direction),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self {
+                Self::Id(direction) => (
+                    id::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Name(direction) => (
+                    name::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Host(direction) => (
+                    host::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Targets(direction) => (
+                    targets::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::LoadBalancing(direction) => (
+                    load_balancing::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::HealthCheck(direction) => (
+                    health_check::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Timeouts(direction) => (
+                    timeouts::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Retry(direction) => (
+                    retry::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::CircuitBreaker(direction) => (
+                    circuit_breaker::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::HeaderPolicy(direction) => (
+                    header_policy::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Cors(direction) => (
+                    cors::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Secrets(direction) => (
+                    secrets::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Created(direction) => (
+                    created::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Updated(direction) => (
+                    updated::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum WhereParam {
+        Not(Vec<WhereParam>),
+        Or(Vec<WhereParam>),
+        And(Vec<WhereParam>),
+        Id(_prisma::read_filters::StringFilter),
+        Name(_prisma::read_filters::StringFilter),
+        Host(_prisma::read_filters::StringFilter),
+        Targets(_prisma::read_filters::JsonNullableFilter),
+        LoadBalancing(_prisma::read_filters::StringNullableFilter),
+        HealthCheck(_prisma::read_filters::JsonNullableFilter),
+        Timeouts(_prisma::read_filters::JsonNullableFilter),
+        Retry(_prisma::read_filters::JsonNullableFilter),
+        CircuitBreaker(_prisma::read_filters::JsonNullableFilter),
+        HeaderPolicy(_prisma::read_filters::JsonNullableFilter),
+        Cors(_prisma::read_filters::JsonNullableFilter),
+        Secrets(_prisma::read_filters::JsonNullableFilter),
+        Created(_prisma::read_filters::DateTimeFilter),
+        Updated(_prisma::read_filters::DateTimeNullableFilter),
+        RoutesSome(Vec<super::gateway_route::WhereParam>),
+        RoutesEvery(Vec<super::gateway_route::WhereParam>),
+        RoutesNone(Vec<super::gateway_route::WhereParam>),
+    }
+    impl ::prisma_client_rust::WhereInput for WhereParam {
+        fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+            let (name , value) = match self { Self :: Not (value) => ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (:: prisma_client_rust :: merge_fields (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . collect ())) ,) , Self :: Or (value) => ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (:: prisma_client_rust :: merge_fields (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . collect ())) ,) , Self :: Id (value) => (id :: NAME , value . into ()) , Self :: Name (value) => (name :: NAME , value . into ()) , Self :: Host (value) => (host :: NAME , value . into ()) , Self :: Targets (value) => (targets :: NAME , value . into ()) , Self :: LoadBalancing (value) => (load_balancing :: NAME , value . into ()) , Self :: HealthCheck (value) => (health_check :: NAME , value . into ()) , Self :: Timeouts (value) => (timeouts :: NAME , value . into ()) , Self :: Retry (value) => (retry :: NAME , value . into ()) , Self :: CircuitBreaker (value) => (circuit_breaker :: NAME , value . into ()) , Self :: HeaderPolicy (value) => (header_policy :: NAME , value . into ()) , Self :: Cors (value) => (cors :: NAME , value . into ()) , Self :: Secrets (value) => (secrets :: NAME , value . into ()) , Self :: Created (value) => (created :: NAME , value . into ()) , Self :: Updated (value) => (updated :: NAME , value . into ()) , Self :: RoutesSome (where_params) => (routes :: NAME , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("some" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()) ,)])) , Self :: RoutesEvery (where_params) => (routes :: NAME , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("every" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()) ,)])) , Self :: RoutesNone (where_params) => (routes :: NAME , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("none" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()) ,)])) } ;
+            ::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+        }
+    }
+    #[derive(Clone)]
+    pub enum UniqueWhereParam {
+        NameEquals(String),
+        IdEquals(String),
+    }
+    impl From<UniqueWhereParam> for WhereParam {
+        fn from(value: UniqueWhereParam) -> Self {
+            match value {
+                UniqueWhereParam::NameEquals(value) => Self::Name(
+                    _prisma::read_filters::StringFilter::Equals(value),
+                ),
+                UniqueWhereParam::IdEquals(value) => {
+                    Self::Id(_prisma::read_filters::StringFilter::Equals(value))
+                }
+            }
+        }
+    }
+    impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+        fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+            match op {
+                ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+                ::prisma_client_rust::Operator::And(value) => Self::And(value),
+                ::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub struct Types;
+    impl ::prisma_client_rust::ModelTypes for Types {
+        type Data = Data;
+        type Where = WhereParam;
+        type UncheckedSet = UncheckedSetParam;
+        type Set = SetParam;
+        type With = WithParam;
+        type OrderBy = OrderByParam;
+        type Cursor = UniqueWhereParam;
+        const MODEL: &'static str = NAME;
+        fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+            vec![
+                ::prisma_client_rust::sel(id::NAME),
+                ::prisma_client_rust::sel(name::NAME),
+                ::prisma_client_rust::sel(host::NAME),
+                ::prisma_client_rust::sel(targets::NAME),
+                ::prisma_client_rust::sel(load_balancing::NAME),
+                ::prisma_client_rust::sel(health_check::NAME),
+                ::prisma_client_rust::sel(timeouts::NAME),
+                ::prisma_client_rust::sel(retry::NAME),
+                ::prisma_client_rust::sel(circuit_breaker::NAME),
+                ::prisma_client_rust::sel(header_policy::NAME),
+                ::prisma_client_rust::sel(cors::NAME),
+                ::prisma_client_rust::sel(secrets::NAME),
+                ::prisma_client_rust::sel(created::NAME),
+                ::prisma_client_rust::sel(updated::NAME),
+            ]
+        }
+    }
+    pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+    pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+    pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+    pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+    pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+    pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+    pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+    pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+    pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+    pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+    pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+    pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+    pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+    #[derive(Clone)]
+    pub struct Actions<'a> {
+        pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+    }
+    impl<'a> Actions<'a> {
+        pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+            FindUnique::new(self.client, _where.into())
+        }
+        pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+            FindFirst::new(self.client, _where)
+        }
+        pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+            FindMany::new(self.client, _where)
+        }
+        pub fn create(
+            self,
+            name: String,
+            host: String,
+            mut _params: Vec<SetParam>,
+        ) -> Create<'a> {
+            _params.extend([name::set(name), host::set(host)]);
+            Create::new(self.client, _params)
+        }
+        pub fn create_unchecked(
+            self,
+            name: String,
+            host: String,
+            mut _params: Vec<UncheckedSetParam>,
+        ) -> Create<'a> {
+            _params.extend([name::set(name), host::set(host)]);
+            Create::new(
+                self.client,
+                _params.into_iter().map(Into::into).collect(),
+            )
+        }
+        pub fn create_many(
+            self,
+            data: Vec<(String, String, Vec<SetParam>)>,
+        ) -> CreateMany<'a> {
+            let data = data
+                .into_iter()
+                .map(|(name, host, mut _params)| {
+                    _params.extend([name::set(name), host::set(host)]);
+                    _params
+                })
+                .collect();
+            CreateMany::new(self.client, data)
+        }
+        pub fn update(
+            self,
+            _where: UniqueWhereParam,
+            _params: Vec<SetParam>,
+        ) -> Update<'a> {
+            Update::new(self.client, _where.into(), _params, vec![])
+        }
+        pub fn update_unchecked(
+            self,
+            _where: UniqueWhereParam,
+            _params: Vec<UncheckedSetParam>,
+        ) -> Update<'a> {
+            Update::new(
+                self.client,
+                _where.into(),
+                _params.into_iter().map(Into::into).collect(),
+                vec![],
+            )
+        }
+        pub fn update_many(
+            self,
+            _where: Vec<WhereParam>,
+            _params: Vec<SetParam>,
+        ) -> UpdateMany<'a> {
+            UpdateMany::new(self.client, _where, _params)
+        }
+        pub fn upsert(
+            self,
+            _where: UniqueWhereParam,
+            (name, host, mut _params): (String, String, Vec<SetParam>),
+            _update: Vec<SetParam>,
+        ) -> Upsert<'a> {
+            _params.extend([name::set(name), host::set(host)]);
+            Upsert::new(self.client, _where.into(), _params, _update)
+        }
+        pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+            Delete::new(self.client, _where.into(), vec![])
+        }
+        pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+            DeleteMany::new(self.client, _where)
+        }
+        pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+            Count::new(self.client, _where)
+        }
+    }
+}
+pub mod gateway_route {
+    use super::_prisma::*;
+    use super::*;
+    pub const NAME: &str = "GatewayRoute";
+    pub mod id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "id";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetId(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Id(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Id(direction)
+        }
+        pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+            UniqueWhereParam::IdEquals(value).into()
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Id,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Id(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Id(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod group {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "group";
+        pub struct Set(pub ::prisma_client_rust::serde_json::Value);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetGroup(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Group(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Group(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Group(_prisma::read_filters::JsonFilter::Equals(value))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonFilter,
+            Group,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Group(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Group(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod methods {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "methods";
+        pub struct Set(pub ::prisma_client_rust::serde_json::Value);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetMethods(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Methods(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Methods(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Methods(_prisma::read_filters::JsonFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonFilter,
+            Methods,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Methods(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Methods(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod path {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "path";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetPath(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Path(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Path(direction)
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::Path(_prisma::read_filters::StringFilter::Equals(value))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Path,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Path(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Path(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod protocol {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "protocol";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetProtocol(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Protocol(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Protocol(direction)
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::Protocol(_prisma::read_filters::StringFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            Protocol,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Protocol(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Protocol(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod allowed_sources {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "allowed_sources";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetAllowedSources(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::AllowedSources(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::AllowedSources(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::AllowedSources(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            AllowedSources,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::AllowedSources(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::AllowedSources(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod secret_name {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "secret_name";
+        pub struct Set(pub Option<String>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetSecretName(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SecretName(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: Option<String>) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::SecretName(direction)
+        }
+        pub fn equals(value: Option<String>) -> WhereParam {
+            WhereParam::SecretName(
+                _prisma::read_filters::StringNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringNullableFilter,
+            SecretName,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: Option<String>) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::SecretName(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::SecretName(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod accept_insecure_routing {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "accept_insecure_routing";
+        pub struct Set(pub Option<bool>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetAcceptInsecureRouting(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::AcceptInsecureRouting(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: Option<bool>) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::AcceptInsecureRouting(direction)
+        }
+        pub fn equals(value: Option<bool>) -> WhereParam {
+            WhereParam::AcceptInsecureRouting(
+                _prisma::read_filters::BoolNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::BoolNullableFilter,
+            AcceptInsecureRouting,
+            {
+                fn not(_: Option<bool>) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::AcceptInsecureRouting(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::AcceptInsecureRouting(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod rate_limit {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "rate_limit";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetRateLimit(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::RateLimit(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::RateLimit(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::RateLimit(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            RateLimit,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::RateLimit(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::RateLimit(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod timeouts {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "timeouts";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetTimeouts(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Timeouts(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Timeouts(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Timeouts(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Timeouts,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Timeouts(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Timeouts(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod retry {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "retry";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetRetry(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Retry(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Retry(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Retry(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Retry,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Retry(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Retry(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod accept_non_idempotent_retries {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "accept_non_idempotent_retries";
+        pub struct Set(pub Option<bool>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetAcceptNonIdempotentRetries(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::AcceptNonIdempotentRetries(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: Option<bool>) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::AcceptNonIdempotentRetries(direction)
+        }
+        pub fn equals(value: Option<bool>) -> WhereParam {
+            WhereParam::AcceptNonIdempotentRetries(
+                _prisma::read_filters::BoolNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::BoolNullableFilter,
+            AcceptNonIdempotentRetries,
+            {
+                fn not(_: Option<bool>) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::AcceptNonIdempotentRetries(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::AcceptNonIdempotentRetries(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod rewrite {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "rewrite";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetRewrite(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Rewrite(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Rewrite(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Rewrite(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Rewrite,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Rewrite(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Rewrite(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod header_policy {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "header_policy";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetHeaderPolicy(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::HeaderPolicy(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::HeaderPolicy(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::HeaderPolicy(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            HeaderPolicy,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::HeaderPolicy(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::HeaderPolicy(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod traffic_split {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "traffic_split";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetTrafficSplit(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::TrafficSplit(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::TrafficSplit(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::TrafficSplit(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            TrafficSplit,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::TrafficSplit(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::TrafficSplit(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod mirror {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "mirror";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetMirror(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Mirror(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Mirror(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Mirror(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Mirror,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Mirror(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Mirror(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod cors {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "cors";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetCors(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Cors(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Cors(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::Cors(_prisma::read_filters::JsonNullableFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            Cors,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Cors(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Cors(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod profile_projection {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "profile_projection";
+        pub struct Set(pub Option<::prisma_client_rust::serde_json::Value>);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetProfileProjection(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::ProfileProjection(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<::prisma_client_rust::serde_json::Value>,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::ProfileProjection(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::serde_json::Value,
+        ) -> WhereParam {
+            WhereParam::ProfileProjection(
+                _prisma::read_filters::JsonNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::JsonNullableFilter,
+            ProfileProjection,
+            {
+                fn path(_: Vec<String>) -> Path;
+                fn string_contains(_: String) -> StringContains;
+                fn string_starts_with(_: String) -> StringStartsWith;
+                fn string_ends_with(_: String) -> StringEndsWith;
+                fn array_contains(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayContains;
+                fn array_starts_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayStartsWith;
+                fn array_ends_with(
+                    _: Option<::prisma_client_rust::serde_json::Value>,
+                ) -> ArrayEndsWith;
+                fn lt(_: ::prisma_client_rust::serde_json::Value) -> Lt;
+                fn lte(_: ::prisma_client_rust::serde_json::Value) -> Lte;
+                fn gt(_: ::prisma_client_rust::serde_json::Value) -> Gt;
+                fn gte(_: ::prisma_client_rust::serde_json::Value) -> Gte;
+                fn not(_: ::prisma_client_rust::serde_json::Value) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::ProfileProjection(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::ProfileProjection(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod created {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "created";
+        pub struct Set(
+            pub  ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        );
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetCreated(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Created(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Created(direction)
+        }
+        pub fn equals(
+            value: ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ) -> WhereParam {
+            WhereParam::Created(_prisma::read_filters::DateTimeFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::DateTimeFilter,
+            Created,
+            {
+                fn in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> InVec;
+                fn not_in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> NotInVec;
+                fn lt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lt;
+                fn lte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lte;
+                fn gt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gt;
+                fn gte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gte;
+                fn not(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Created(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Created(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod updated {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "updated";
+        pub struct Set(
+            pub  Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        );
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetUpdated(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::Updated(v)
+            }
+        }
+        pub fn set<T: From<Set>>(
+            value: Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::Updated(direction)
+        }
+        pub fn equals(
+            value: Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ) -> WhereParam {
+            WhereParam::Updated(
+                _prisma::read_filters::DateTimeNullableFilter::Equals(value),
+            )
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::DateTimeNullableFilter,
+            Updated,
+            {
+                fn in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> InVec;
+                fn not_in_vec(
+                    _: Vec<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> NotInVec;
+                fn lt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lt;
+                fn lte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Lte;
+                fn gt(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gt;
+                fn gte(
+                    _: ::prisma_client_rust::chrono::DateTime<
+                        ::prisma_client_rust::chrono::FixedOffset,
+                    >,
+                ) -> Gte;
+                fn not(
+                    _: Option<
+                        ::prisma_client_rust::chrono::DateTime<
+                            ::prisma_client_rust::chrono::FixedOffset,
+                        >,
+                    >,
+                ) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Updated(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Updated(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod service_id {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "service_id";
+        pub struct Set(pub String);
+        impl From<Set> for SetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::SetServiceId(v)
+            }
+        }
+        impl From<Set> for UncheckedSetParam {
+            fn from(Set(v): Set) -> Self {
+                Self::ServiceId(v)
+            }
+        }
+        pub fn set<T: From<Set>>(value: String) -> T {
+            Set(value).into()
+        }
+        pub fn order(
+            direction: ::prisma_client_rust::Direction,
+        ) -> OrderByParam {
+            OrderByParam::ServiceId(direction)
+        }
+        pub fn equals(value: String) -> WhereParam {
+            WhereParam::ServiceId(_prisma::read_filters::StringFilter::Equals(
+                value,
+            ))
+        }
+        ::prisma_client_rust::scalar_where_param_fns!(
+            _prisma::read_filters::StringFilter,
+            ServiceId,
+            {
+                fn in_vec(_: Vec<String>) -> InVec;
+                fn not_in_vec(_: Vec<String>) -> NotInVec;
+                fn lt(_: String) -> Lt;
+                fn lte(_: String) -> Lte;
+                fn gt(_: String) -> Gt;
+                fn gte(_: String) -> Gte;
+                fn contains(_: String) -> Contains;
+                fn starts_with(_: String) -> StartsWith;
+                fn ends_with(_: String) -> EndsWith;
+                fn mode(_: super::super::QueryMode) -> Mode;
+                fn not(_: String) -> Not;
+            }
+        );
+        pub struct Include;
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::ServiceId(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+        pub struct Select;
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::ServiceId(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                ::prisma_client_rust::sel(NAME)
+            }
+        }
+    }
+    pub mod service {
+        use super::super::*;
+        use super::_prisma::*;
+        use super::{
+            OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam,
+            WhereParam, WithParam,
+        };
+        pub const NAME: &str = "service";
+        pub struct Fetch(pub gateway_service::UniqueArgs);
+        impl Fetch {
+            pub fn with(
+                mut self,
+                params: impl Into<gateway_service::WithParam>,
+            ) -> Self {
+                self.0 = self.0.with(params.into());
+                self
+            }
+        }
+        impl From<Fetch> for WithParam {
+            fn from(Fetch(v): Fetch) -> Self {
+                WithParam::Service(v)
+            }
+        }
+        pub fn fetch() -> Fetch {
+            Fetch(gateway_service::UniqueArgs::new())
+        }
+        pub struct Connect(gateway_service::UniqueWhereParam);
+        impl From<Connect> for SetParam {
+            fn from(Connect(v): Connect) -> Self {
+                Self::ConnectService(v)
+            }
+        }
+        pub fn connect<T: From<Connect>>(
+            value: gateway_service::UniqueWhereParam,
+        ) -> T {
+            Connect(value).into()
+        }
+        pub fn is(value: Vec<gateway_service::WhereParam>) -> WhereParam {
+            WhereParam::ServiceIs(value)
+        }
+        pub fn is_not(value: Vec<gateway_service::WhereParam>) -> WhereParam {
+            WhereParam::ServiceIsNot(value)
+        }
+        pub enum Include {
+            Select(Vec<gateway_service::SelectParam>),
+            Include(Vec<gateway_service::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::IncludeParam> for Include {
+            fn into(self) -> super::IncludeParam {
+                super::IncludeParam::Service(self)
+            }
+        }
+        impl Include {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let selections = match self { Self :: Select (selections) => { selections . into_iter () . map (| s | s . to_selection ()) . collect () } , Self :: Include (selections) => { let mut nested_selections = < gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections () ; nested_selections . extend (selections . into_iter () . map (| s | s . to_selection ())) ; nested_selections } , Self :: Fetch => { < gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections () } } ;
+                ::prisma_client_rust::Selection::new(
+                    "service",
+                    None,
+                    [],
+                    selections,
+                )
+            }
+            pub fn select(
+                nested_selections: Vec<gateway_service::SelectParam>,
+            ) -> Self {
+                Self::Select(nested_selections)
+            }
+            pub fn include(
+                nested_selections: Vec<gateway_service::IncludeParam>,
+            ) -> Self {
+                Self::Include(nested_selections)
+            }
+        }
+        pub enum Select {
+            Select(Vec<gateway_service::SelectParam>),
+            Include(Vec<gateway_service::IncludeParam>),
+            Fetch,
+        }
+        impl Into<super::SelectParam> for Select {
+            fn into(self) -> super::SelectParam {
+                super::SelectParam::Service(self)
+            }
+        }
+        impl Select {
+            pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+                let selections = match self { Self :: Select (selections) => { selections . into_iter () . map (| s | s . to_selection ()) . collect () } , Self :: Include (selections) => { let mut nested_selections = vec ! [] ; nested_selections . extend (selections . into_iter () . map (| s | s . to_selection ())) ; nested_selections } , Self :: Fetch => { < gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections () } } ;
+                ::prisma_client_rust::Selection::new(
+                    "service",
+                    None,
+                    [],
+                    selections,
+                )
+            }
+            pub fn select(
+                nested_selections: Vec<gateway_service::SelectParam>,
+            ) -> Self {
+                Self::Select(nested_selections)
+            }
+            pub fn include(
+                nested_selections: Vec<gateway_service::IncludeParam>,
+            ) -> Self {
+                Self::Include(nested_selections)
+            }
+        }
+    }
+    pub fn service_id_path_methods<T: From<UniqueWhereParam>>(
+        service_id: String,
+        path: String,
+        methods: ::prisma_client_rust::serde_json::Value,
+    ) -> T {
+        UniqueWhereParam::ServiceIdPathMethodsEquals(service_id, path, methods)
+            .into()
+    }
+    pub fn create(
+        group: ::prisma_client_rust::serde_json::Value,
+        methods: ::prisma_client_rust::serde_json::Value,
+        path: String,
+        protocol: String,
+        service: super::gateway_service::UniqueWhereParam,
+        _params: Vec<SetParam>,
+    ) -> (
+        ::prisma_client_rust::serde_json::Value,
+        ::prisma_client_rust::serde_json::Value,
+        String,
+        String,
+        super::gateway_service::UniqueWhereParam,
+        Vec<SetParam>,
+    ) {
+        (group, methods, path, protocol, service, _params)
+    }
+    pub fn create_unchecked(
+        group: ::prisma_client_rust::serde_json::Value,
+        methods: ::prisma_client_rust::serde_json::Value,
+        path: String,
+        protocol: String,
+        service_id: String,
+        _params: Vec<SetParam>,
+    ) -> (
+        ::prisma_client_rust::serde_json::Value,
+        ::prisma_client_rust::serde_json::Value,
+        String,
+        String,
+        String,
+        Vec<SetParam>,
+    ) {
+        (group, methods, path, protocol, service_id, _params)
+    }
+    #[macro_export]
+    macro_rules ! _select_gateway_route { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: gateway_route :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_route :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: gateway_route :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: gateway_route :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_route :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: gateway_route :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , group , methods , path , protocol , allowed_sources , secret_name , accept_insecure_routing , rate_limit , timeouts , retry , accept_non_idempotent_retries , rewrite , header_policy , traffic_split , mirror , cors , profile_projection , created , updated , service_id , service } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: gateway_route :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: gateway_route :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: gateway_route :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: gateway_route :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "group" , "methods" , "path" , "protocol" , "allowed_sources" , "secret_name" , "accept_insecure_routing" , "rate_limit" , "timeouts" , "retry" , "accept_non_idempotent_retries" , "rewrite" , "header_policy" , "traffic_split" , "mirror" , "cors" , "profile_projection" , "created" , "updated" , "service_id" , "service"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: gateway_route :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { String } ; (@ field_type ; group) => { :: prisma_client_rust :: serde_json :: Value } ; (@ field_type ; methods) => { :: prisma_client_rust :: serde_json :: Value } ; (@ field_type ; path) => { String } ; (@ field_type ; protocol) => { String } ; (@ field_type ; allowed_sources) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; secret_name) => { Option < String > } ; (@ field_type ; accept_insecure_routing) => { Option < bool > } ; (@ field_type ; rate_limit) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; timeouts) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; retry) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; accept_non_idempotent_retries) => { Option < bool > } ; (@ field_type ; rewrite) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; header_policy) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; traffic_split) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; mirror) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; cors) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; profile_projection) => { Option < :: prisma_client_rust :: serde_json :: Value > } ; (@ field_type ; created) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; updated) => { Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > } ; (@ field_type ; service_id) => { String } ; (@ field_type ; service : $ selection_mode : ident { $ ($ selections : tt) + }) => { service :: Data } ; (@ field_type ; service) => { crate :: prisma :: gateway_service :: Data } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "GatewayRoute" , available relations are "id, group, methods, path, protocol, allowed_sources, secret_name, accept_insecure_routing, rate_limit, timeouts, retry, accept_non_idempotent_retries, rewrite, header_policy, traffic_split, mirror, cors, profile_projection, created, updated, service_id, service")) } ; (@ field_module ; service : $ selection_mode : ident { $ ($ selections : tt) + }) => { crate :: prisma :: gateway_service :: select ! (@ definitions ; ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: id :: Select) } ; (@ selection_field_to_selection_param ; group) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: group :: Select) } ; (@ selection_field_to_selection_param ; methods) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: methods :: Select) } ; (@ selection_field_to_selection_param ; path) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: path :: Select) } ; (@ selection_field_to_selection_param ; protocol) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: protocol :: Select) } ; (@ selection_field_to_selection_param ; allowed_sources) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: allowed_sources :: Select) } ; (@ selection_field_to_selection_param ; secret_name) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: secret_name :: Select) } ; (@ selection_field_to_selection_param ; accept_insecure_routing) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: accept_insecure_routing :: Select) } ; (@ selection_field_to_selection_param ; rate_limit) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: rate_limit :: Select) } ; (@ selection_field_to_selection_param ; timeouts) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: timeouts :: Select) } ; (@ selection_field_to_selection_param ; retry) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: retry :: Select) } ; (@ selection_field_to_selection_param ; accept_non_idempotent_retries) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: Select) } ; (@ selection_field_to_selection_param ; rewrite) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: rewrite :: Select) } ; (@ selection_field_to_selection_param ; header_policy) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: header_policy :: Select) } ; (@ selection_field_to_selection_param ; traffic_split) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: traffic_split :: Select) } ; (@ selection_field_to_selection_param ; mirror) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: mirror :: Select) } ; (@ selection_field_to_selection_param ; cors) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: cors :: Select) } ; (@ selection_field_to_selection_param ; profile_projection) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: profile_projection :: Select) } ; (@ selection_field_to_selection_param ; created) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: created :: Select) } ; (@ selection_field_to_selection_param ; updated) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: updated :: Select) } ; (@ selection_field_to_selection_param ; service_id) => { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: service_id :: Select) } ; (@ selection_field_to_selection_param ; service $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: service :: Select :: $ selection_mode (crate :: prisma :: gateway_service :: select ! (@ selections_to_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; service $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { Into :: < crate :: prisma :: gateway_route :: SelectParam > :: into (crate :: prisma :: gateway_route :: service :: Select :: Fetch) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: gateway_route :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; group) => { "group" } ; (@ field_serde_name ; methods) => { "methods" } ; (@ field_serde_name ; path) => { "path" } ; (@ field_serde_name ; protocol) => { "protocol" } ; (@ field_serde_name ; allowed_sources) => { "allowed_sources" } ; (@ field_serde_name ; secret_name) => { "secret_name" } ; (@ field_serde_name ; accept_insecure_routing) => { "accept_insecure_routing" } ; (@ field_serde_name ; rate_limit) => { "rate_limit" } ; (@ field_serde_name ; timeouts) => { "timeouts" } ; (@ field_serde_name ; retry) => { "retry" } ; (@ field_serde_name ; accept_non_idempotent_retries) => { "accept_non_idempotent_retries" } ; (@ field_serde_name ; rewrite) => { "rewrite" } ; (@ field_serde_name ; header_policy) => { "header_policy" } ; (@ field_serde_name ; traffic_split) => { "traffic_split" } ; (@ field_serde_name ; mirror) => { "mirror" } ; (@ field_serde_name ; cors) => { "cors" } ; (@ field_serde_name ; profile_projection) => { "profile_projection" } ; (@ field_serde_name ; created) => { "created" } ; (@ field_serde_name ; updated) => { "updated" } ; (@ field_serde_name ; service_id) => { "service_id" } ; (@ field_serde_name ; service) => { "service" } ; }
+    pub use _select_gateway_route as select;
+    pub enum SelectParam {
+        Id(id::Select),
+        Group(group::Select),
+        Methods(methods::Select),
+        Path(path::Select),
+        Protocol(protocol::Select),
+        AllowedSources(allowed_sources::Select),
+        SecretName(secret_name::Select),
+        AcceptInsecureRouting(accept_insecure_routing::Select),
+        RateLimit(rate_limit::Select),
+        Timeouts(timeouts::Select),
+        Retry(retry::Select),
+        AcceptNonIdempotentRetries(accept_non_idempotent_retries::Select),
+        Rewrite(rewrite::Select),
+        HeaderPolicy(header_policy::Select),
+        TrafficSplit(traffic_split::Select),
+        Mirror(mirror::Select),
+        Cors(cors::Select),
+        ProfileProjection(profile_projection::Select),
+        Created(created::Select),
+        Updated(updated::Select),
+        ServiceId(service_id::Select),
+        Service(service::Select),
+    }
+    impl SelectParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::Group(data) => data.to_selection(),
+                Self::Methods(data) => data.to_selection(),
+                Self::Path(data) => data.to_selection(),
+                Self::Protocol(data) => data.to_selection(),
+                Self::AllowedSources(data) => data.to_selection(),
+                Self::SecretName(data) => data.to_selection(),
+                Self::AcceptInsecureRouting(data) => data.to_selection(),
+                Self::RateLimit(data) => data.to_selection(),
+                Self::Timeouts(data) => data.to_selection(),
+                Self::Retry(data) => data.to_selection(),
+                Self::AcceptNonIdempotentRetries(data) => data.to_selection(),
+                Self::Rewrite(data) => data.to_selection(),
+                Self::HeaderPolicy(data) => data.to_selection(),
+                Self::TrafficSplit(data) => data.to_selection(),
+                Self::Mirror(data) => data.to_selection(),
+                Self::Cors(data) => data.to_selection(),
+                Self::ProfileProjection(data) => data.to_selection(),
+                Self::Created(data) => data.to_selection(),
+                Self::Updated(data) => data.to_selection(),
+                Self::ServiceId(data) => data.to_selection(),
+                Self::Service(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _include_gateway_route { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: gateway_route :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_route :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: gateway_route :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: gateway_route :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: gateway_route :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: gateway_route :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: gateway_route :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { service } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : String , pub group : :: prisma_client_rust :: serde_json :: Value , pub methods : :: prisma_client_rust :: serde_json :: Value , pub path : String , pub protocol : String , pub allowed_sources : Option < :: prisma_client_rust :: serde_json :: Value > , pub secret_name : Option < String > , pub accept_insecure_routing : Option < bool > , pub rate_limit : Option < :: prisma_client_rust :: serde_json :: Value > , pub timeouts : Option < :: prisma_client_rust :: serde_json :: Value > , pub retry : Option < :: prisma_client_rust :: serde_json :: Value > , pub accept_non_idempotent_retries : Option < bool > , pub rewrite : Option < :: prisma_client_rust :: serde_json :: Value > , pub header_policy : Option < :: prisma_client_rust :: serde_json :: Value > , pub traffic_split : Option < :: prisma_client_rust :: serde_json :: Value > , pub mirror : Option < :: prisma_client_rust :: serde_json :: Value > , pub cors : Option < :: prisma_client_rust :: serde_json :: Value > , pub profile_projection : Option < :: prisma_client_rust :: serde_json :: Value > , pub created : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub updated : Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > , pub service_id : String , $ (pub $ field : crate :: prisma :: gateway_route :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (group) , stringify ! (methods) , stringify ! (path) , stringify ! (protocol) , stringify ! (allowed_sources) , stringify ! (secret_name) , stringify ! (accept_insecure_routing) , stringify ! (rate_limit) , stringify ! (timeouts) , stringify ! (retry) , stringify ! (accept_non_idempotent_retries) , stringify ! (rewrite) , stringify ! (header_policy) , stringify ! (traffic_split) , stringify ! (mirror) , stringify ! (cors) , stringify ! (profile_projection) , stringify ! (created) , stringify ! (updated) , stringify ! (service_id)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: gateway_route :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: gateway_route :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: gateway_route :: group :: NAME , & self . group) ? ; state . serialize_field (crate :: prisma :: gateway_route :: methods :: NAME , & self . methods) ? ; state . serialize_field (crate :: prisma :: gateway_route :: path :: NAME , & self . path) ? ; state . serialize_field (crate :: prisma :: gateway_route :: protocol :: NAME , & self . protocol) ? ; state . serialize_field (crate :: prisma :: gateway_route :: allowed_sources :: NAME , & self . allowed_sources) ? ; state . serialize_field (crate :: prisma :: gateway_route :: secret_name :: NAME , & self . secret_name) ? ; state . serialize_field (crate :: prisma :: gateway_route :: accept_insecure_routing :: NAME , & self . accept_insecure_routing) ? ; state . serialize_field (crate :: prisma :: gateway_route :: rate_limit :: NAME , & self . rate_limit) ? ; state . serialize_field (crate :: prisma :: gateway_route :: timeouts :: NAME , & self . timeouts) ? ; state . serialize_field (crate :: prisma :: gateway_route :: retry :: NAME , & self . retry) ? ; state . serialize_field (crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: NAME , & self . accept_non_idempotent_retries) ? ; state . serialize_field (crate :: prisma :: gateway_route :: rewrite :: NAME , & self . rewrite) ? ; state . serialize_field (crate :: prisma :: gateway_route :: header_policy :: NAME , & self . header_policy) ? ; state . serialize_field (crate :: prisma :: gateway_route :: traffic_split :: NAME , & self . traffic_split) ? ; state . serialize_field (crate :: prisma :: gateway_route :: mirror :: NAME , & self . mirror) ? ; state . serialize_field (crate :: prisma :: gateway_route :: cors :: NAME , & self . cors) ? ; state . serialize_field (crate :: prisma :: gateway_route :: profile_projection :: NAME , & self . profile_projection) ? ; state . serialize_field (crate :: prisma :: gateway_route :: created :: NAME , & self . created) ? ; state . serialize_field (crate :: prisma :: gateway_route :: updated :: NAME , & self . updated) ? ; state . serialize_field (crate :: prisma :: gateway_route :: service_id :: NAME , & self . service_id) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , group , methods , path , protocol , allowed_sources , secret_name , accept_insecure_routing , rate_limit , timeouts , retry , accept_non_idempotent_retries , rewrite , header_policy , traffic_split , mirror , cors , profile_projection , created , updated , service_id } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: gateway_route :: $ field :: NAME) , + , crate :: prisma :: gateway_route :: id :: NAME , crate :: prisma :: gateway_route :: group :: NAME , crate :: prisma :: gateway_route :: methods :: NAME , crate :: prisma :: gateway_route :: path :: NAME , crate :: prisma :: gateway_route :: protocol :: NAME , crate :: prisma :: gateway_route :: allowed_sources :: NAME , crate :: prisma :: gateway_route :: secret_name :: NAME , crate :: prisma :: gateway_route :: accept_insecure_routing :: NAME , crate :: prisma :: gateway_route :: rate_limit :: NAME , crate :: prisma :: gateway_route :: timeouts :: NAME , crate :: prisma :: gateway_route :: retry :: NAME , crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: NAME , crate :: prisma :: gateway_route :: rewrite :: NAME , crate :: prisma :: gateway_route :: header_policy :: NAME , crate :: prisma :: gateway_route :: traffic_split :: NAME , crate :: prisma :: gateway_route :: mirror :: NAME , crate :: prisma :: gateway_route :: cors :: NAME , crate :: prisma :: gateway_route :: profile_projection :: NAME , crate :: prisma :: gateway_route :: created :: NAME , crate :: prisma :: gateway_route :: updated :: NAME , crate :: prisma :: gateway_route :: service_id :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: gateway_route :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: gateway_route :: id :: NAME => Ok (Field :: id) , crate :: prisma :: gateway_route :: group :: NAME => Ok (Field :: group) , crate :: prisma :: gateway_route :: methods :: NAME => Ok (Field :: methods) , crate :: prisma :: gateway_route :: path :: NAME => Ok (Field :: path) , crate :: prisma :: gateway_route :: protocol :: NAME => Ok (Field :: protocol) , crate :: prisma :: gateway_route :: allowed_sources :: NAME => Ok (Field :: allowed_sources) , crate :: prisma :: gateway_route :: secret_name :: NAME => Ok (Field :: secret_name) , crate :: prisma :: gateway_route :: accept_insecure_routing :: NAME => Ok (Field :: accept_insecure_routing) , crate :: prisma :: gateway_route :: rate_limit :: NAME => Ok (Field :: rate_limit) , crate :: prisma :: gateway_route :: timeouts :: NAME => Ok (Field :: timeouts) , crate :: prisma :: gateway_route :: retry :: NAME => Ok (Field :: retry) , crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: NAME => Ok (Field :: accept_non_idempotent_retries) , crate :: prisma :: gateway_route :: rewrite :: NAME => Ok (Field :: rewrite) , crate :: prisma :: gateway_route :: header_policy :: NAME => Ok (Field :: header_policy) , crate :: prisma :: gateway_route :: traffic_split :: NAME => Ok (Field :: traffic_split) , crate :: prisma :: gateway_route :: mirror :: NAME => Ok (Field :: mirror) , crate :: prisma :: gateway_route :: cors :: NAME => Ok (Field :: cors) , crate :: prisma :: gateway_route :: profile_projection :: NAME => Ok (Field :: profile_projection) , crate :: prisma :: gateway_route :: created :: NAME => Ok (Field :: created) , crate :: prisma :: gateway_route :: updated :: NAME => Ok (Field :: updated) , crate :: prisma :: gateway_route :: service_id :: NAME => Ok (Field :: service_id) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut group = None ; let mut methods = None ; let mut path = None ; let mut protocol = None ; let mut allowed_sources = None ; let mut secret_name = None ; let mut accept_insecure_routing = None ; let mut rate_limit = None ; let mut timeouts = None ; let mut retry = None ; let mut accept_non_idempotent_retries = None ; let mut rewrite = None ; let mut header_policy = None ; let mut traffic_split = None ; let mut mirror = None ; let mut cors = None ; let mut profile_projection = None ; let mut created = None ; let mut updated = None ; let mut service_id = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: group => { if group . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: group :: NAME)) ; } group = Some (map . next_value () ?) ; } Field :: methods => { if methods . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: methods :: NAME)) ; } methods = Some (map . next_value () ?) ; } Field :: path => { if path . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: path :: NAME)) ; } path = Some (map . next_value () ?) ; } Field :: protocol => { if protocol . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: protocol :: NAME)) ; } protocol = Some (map . next_value () ?) ; } Field :: allowed_sources => { if allowed_sources . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: allowed_sources :: NAME)) ; } allowed_sources = Some (map . next_value () ?) ; } Field :: secret_name => { if secret_name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: secret_name :: NAME)) ; } secret_name = Some (map . next_value () ?) ; } Field :: accept_insecure_routing => { if accept_insecure_routing . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: accept_insecure_routing :: NAME)) ; } accept_insecure_routing = Some (map . next_value () ?) ; } Field :: rate_limit => { if rate_limit . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: rate_limit :: NAME)) ; } rate_limit = Some (map . next_value () ?) ; } Field :: timeouts => { if timeouts . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: timeouts :: NAME)) ; } timeouts = Some (map . next_value () ?) ; } Field :: retry => { if retry . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: retry :: NAME)) ; } retry = Some (map . next_value () ?) ; } Field :: accept_non_idempotent_retries => { if accept_non_idempotent_retries . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: NAME)) ; } accept_non_idempotent_retries = Some (map . next_value () ?) ; } Field :: rewrite => { if rewrite . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: rewrite :: NAME)) ; } rewrite = Some (map . next_value () ?) ; } Field :: header_policy => { if header_policy . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: header_policy :: NAME)) ; } header_policy = Some (map . next_value () ?) ; } Field :: traffic_split => { if traffic_split . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: traffic_split :: NAME)) ; } traffic_split = Some (map . next_value () ?) ; } Field :: mirror => { if mirror . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: mirror :: NAME)) ; } mirror = Some (map . next_value () ?) ; } Field :: cors => { if cors . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: cors :: NAME)) ; } cors = Some (map . next_value () ?) ; } Field :: profile_projection => { if profile_projection . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: profile_projection :: NAME)) ; } profile_projection = Some (map . next_value () ?) ; } Field :: created => { if created . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: created :: NAME)) ; } created = Some (map . next_value () ?) ; } Field :: updated => { if updated . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: updated :: NAME)) ; } updated = Some (map . next_value () ?) ; } Field :: service_id => { if service_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: service_id :: NAME)) ; } service_id = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: gateway_route :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: id :: NAME)) ? ; let group = group . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: group :: NAME)) ? ; let methods = methods . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: methods :: NAME)) ? ; let path = path . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: path :: NAME)) ? ; let protocol = protocol . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: protocol :: NAME)) ? ; let allowed_sources = allowed_sources . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: allowed_sources :: NAME)) ? ; let secret_name = secret_name . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: secret_name :: NAME)) ? ; let accept_insecure_routing = accept_insecure_routing . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: accept_insecure_routing :: NAME)) ? ; let rate_limit = rate_limit . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: rate_limit :: NAME)) ? ; let timeouts = timeouts . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: timeouts :: NAME)) ? ; let retry = retry . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: retry :: NAME)) ? ; let accept_non_idempotent_retries = accept_non_idempotent_retries . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: accept_non_idempotent_retries :: NAME)) ? ; let rewrite = rewrite . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: rewrite :: NAME)) ? ; let header_policy = header_policy . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: header_policy :: NAME)) ? ; let traffic_split = traffic_split . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: traffic_split :: NAME)) ? ; let mirror = mirror . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: mirror :: NAME)) ? ; let cors = cors . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: cors :: NAME)) ? ; let profile_projection = profile_projection . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: profile_projection :: NAME)) ? ; let created = created . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: created :: NAME)) ? ; let updated = updated . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: updated :: NAME)) ? ; let service_id = service_id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: gateway_route :: service_id :: NAME)) ? ; Ok (Data { id , group , methods , path , protocol , allowed_sources , secret_name , accept_insecure_routing , rate_limit , timeouts , retry , accept_non_idempotent_retries , rewrite , header_policy , traffic_split , mirror , cors , profile_projection , created , updated , service_id , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "group" , "methods" , "path" , "protocol" , "allowed_sources" , "secret_name" , "accept_insecure_routing" , "rate_limit" , "timeouts" , "retry" , "accept_non_idempotent_retries" , "rewrite" , "header_policy" , "traffic_split" , "mirror" , "cors" , "profile_projection" , "created" , "updated" , "service_id" , "service"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: gateway_route :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; service : $ selection_mode : ident { $ ($ selections : tt) + }) => { service :: Data } ; (@ field_type ; service) => { crate :: prisma :: gateway_service :: Data } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "GatewayRoute" , available relations are "service")) } ; (@ field_module ; service : $ selection_mode : ident { $ ($ selections : tt) + }) => { crate :: prisma :: gateway_service :: include ! (@ definitions ; ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; service $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { Into :: < crate :: prisma :: gateway_route :: IncludeParam > :: into (crate :: prisma :: gateway_route :: service :: Include :: $ selection_mode (crate :: prisma :: gateway_service :: select ! (@ selections_to_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; service $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { Into :: < crate :: prisma :: gateway_route :: IncludeParam > :: into (crate :: prisma :: gateway_route :: service :: Include :: Fetch) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: gateway_route :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; group) => { "group" } ; (@ field_serde_name ; methods) => { "methods" } ; (@ field_serde_name ; path) => { "path" } ; (@ field_serde_name ; protocol) => { "protocol" } ; (@ field_serde_name ; allowed_sources) => { "allowed_sources" } ; (@ field_serde_name ; secret_name) => { "secret_name" } ; (@ field_serde_name ; accept_insecure_routing) => { "accept_insecure_routing" } ; (@ field_serde_name ; rate_limit) => { "rate_limit" } ; (@ field_serde_name ; timeouts) => { "timeouts" } ; (@ field_serde_name ; retry) => { "retry" } ; (@ field_serde_name ; accept_non_idempotent_retries) => { "accept_non_idempotent_retries" } ; (@ field_serde_name ; rewrite) => { "rewrite" } ; (@ field_serde_name ; header_policy) => { "header_policy" } ; (@ field_serde_name ; traffic_split) => { "traffic_split" } ; (@ field_serde_name ; mirror) => { "mirror" } ; (@ field_serde_name ; cors) => { "cors" } ; (@ field_serde_name ; profile_projection) => { "profile_projection" } ; (@ field_serde_name ; created) => { "created" } ; (@ field_serde_name ; updated) => { "updated" } ; (@ field_serde_name ; service_id) => { "service_id" } ; (@ field_serde_name ; service) => { "service" } ; }
+    pub use _include_gateway_route as include;
+    pub enum IncludeParam {
+        Id(id::Include),
+        Group(group::Include),
+        Methods(methods::Include),
+        Path(path::Include),
+        Protocol(protocol::Include),
+        AllowedSources(allowed_sources::Include),
+        SecretName(secret_name::Include),
+        AcceptInsecureRouting(accept_insecure_routing::Include),
+        RateLimit(rate_limit::Include),
+        Timeouts(timeouts::Include),
+        Retry(retry::Include),
+        AcceptNonIdempotentRetries(accept_non_idempotent_retries::Include),
+        Rewrite(rewrite::Include),
+        HeaderPolicy(header_policy::Include),
+        TrafficSplit(traffic_split::Include),
+        Mirror(mirror::Include),
+        Cors(cors::Include),
+        ProfileProjection(profile_projection::Include),
+        Created(created::Include),
+        Updated(updated::Include),
+        ServiceId(service_id::Include),
+        Service(service::Include),
+    }
+    impl IncludeParam {
+        pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Id(data) => data.to_selection(),
+                Self::Group(data) => data.to_selection(),
+                Self::Methods(data) => data.to_selection(),
+                Self::Path(data) => data.to_selection(),
+                Self::Protocol(data) => data.to_selection(),
+                Self::AllowedSources(data) => data.to_selection(),
+                Self::SecretName(data) => data.to_selection(),
+                Self::AcceptInsecureRouting(data) => data.to_selection(),
+                Self::RateLimit(data) => data.to_selection(),
+                Self::Timeouts(data) => data.to_selection(),
+                Self::Retry(data) => data.to_selection(),
+                Self::AcceptNonIdempotentRetries(data) => data.to_selection(),
+                Self::Rewrite(data) => data.to_selection(),
+                Self::HeaderPolicy(data) => data.to_selection(),
+                Self::TrafficSplit(data) => data.to_selection(),
+                Self::Mirror(data) => data.to_selection(),
+                Self::Cors(data) => data.to_selection(),
+                Self::ProfileProjection(data) => data.to_selection(),
+                Self::Created(data) => data.to_selection(),
+                Self::Updated(data) => data.to_selection(),
+                Self::ServiceId(data) => data.to_selection(),
+                Self::Service(data) => data.to_selection(),
+            }
+        }
+    }
+    #[macro_export]
+    macro_rules ! _partial_unchecked_gateway_route { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: gateway_route struct $ struct_name { # [serde (rename = "id")] pub id : String , # [serde (rename = "group")] pub group : :: prisma_client_rust :: serde_json :: Value , # [serde (rename = "methods")] pub methods : :: prisma_client_rust :: serde_json :: Value , # [serde (rename = "path")] pub path : String , # [serde (rename = "protocol")] pub protocol : String , # [serde (rename = "allowed_sources")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub allowed_sources : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "secret_name")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub secret_name : Option < String > , # [serde (rename = "accept_insecure_routing")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub accept_insecure_routing : Option < bool > , # [serde (rename = "rate_limit")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub rate_limit : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "timeouts")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub timeouts : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "retry")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub retry : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "accept_non_idempotent_retries")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub accept_non_idempotent_retries : Option < bool > , # [serde (rename = "rewrite")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub rewrite : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "header_policy")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub header_policy : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "traffic_split")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub traffic_split : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "mirror")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub mirror : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "cors")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub cors : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "profile_projection")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub profile_projection : Option < :: prisma_client_rust :: serde_json :: Value > , # [serde (rename = "created")] pub created : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , # [serde (rename = "updated")] # [serde (default , with = "::prisma_client_rust::serde::double_option")] pub updated : Option < :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > > , # [serde (rename = "service_id")] pub service_id : String } [$ ($ scalar_field) , +] } } ; }
+    pub use _partial_unchecked_gateway_route as partial_unchecked;
+    #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+    pub struct Data {
+        #[serde(rename = "id")]
+        pub id: String,
+        #[serde(rename = "group")]
+        pub group: ::prisma_client_rust::serde_json::Value,
+        #[serde(rename = "methods")]
+        pub methods: ::prisma_client_rust::serde_json::Value,
+        #[serde(rename = "path")]
+        pub path: String,
+        #[serde(rename = "protocol")]
+        pub protocol: String,
+        #[serde(rename = "allowed_sources")]
+        pub allowed_sources: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "secret_name")]
+        pub secret_name: Option<String>,
+        #[serde(rename = "accept_insecure_routing")]
+        pub accept_insecure_routing: Option<bool>,
+        #[serde(rename = "rate_limit")]
+        pub rate_limit: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "timeouts")]
+        pub timeouts: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "retry")]
+        pub retry: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "accept_non_idempotent_retries")]
+        pub accept_non_idempotent_retries: Option<bool>,
+        #[serde(rename = "rewrite")]
+        pub rewrite: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "header_policy")]
+        pub header_policy: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "traffic_split")]
+        pub traffic_split: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "mirror")]
+        pub mirror: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "cors")]
+        pub cors: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "profile_projection")]
+        pub profile_projection: Option<::prisma_client_rust::serde_json::Value>,
+        #[serde(rename = "created")]
+        pub created: ::prisma_client_rust::chrono::DateTime<
+            ::prisma_client_rust::chrono::FixedOffset,
+        >,
+        #[serde(rename = "updated")]
+        pub updated: Option<
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        >,
+        #[serde(rename = "service_id")]
+        pub service_id: String,
+        #[serde(rename = "service")]
+        pub service: Option<Box<super::gateway_service::Data>>,
+    }
+    impl Data {
+        pub fn service(
+            &self,
+        ) -> Result<
+            &super::gateway_service::Data,
+            ::prisma_client_rust::RelationNotFetchedError,
+        > {
+            self.service
+                .as_ref()
+                .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+                    stringify!(service),
+                ))
+                .map(|v| v.as_ref())
+        }
+    }
+    #[derive(Clone)]
+    pub enum WithParam {
+        Service(super::gateway_service::UniqueArgs),
+    }
+    impl Into<::prisma_client_rust::Selection> for WithParam {
+        fn into(self) -> ::prisma_client_rust::Selection {
+            match self {
+                Self::Service(args) => {
+                    let mut selections = < super :: gateway_service :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections () ;
+                    selections.extend(
+                        args.with_params
+                            .into_iter()
+                            .map(Into::<::prisma_client_rust::Selection>::into),
+                    );
+                    ::prisma_client_rust::Selection::new(
+                        service::NAME,
+                        None,
+                        [],
+                        selections,
+                    )
+                }
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum SetParam {
+        SetId(String),
+        SetGroup(::prisma_client_rust::serde_json::Value),
+        SetMethods(::prisma_client_rust::serde_json::Value),
+        SetPath(String),
+        SetProtocol(String),
+        SetAllowedSources(Option<::prisma_client_rust::serde_json::Value>),
+        SetSecretName(Option<String>),
+        SetAcceptInsecureRouting(Option<bool>),
+        SetRateLimit(Option<::prisma_client_rust::serde_json::Value>),
+        SetTimeouts(Option<::prisma_client_rust::serde_json::Value>),
+        SetRetry(Option<::prisma_client_rust::serde_json::Value>),
+        SetAcceptNonIdempotentRetries(Option<bool>),
+        SetRewrite(Option<::prisma_client_rust::serde_json::Value>),
+        SetHeaderPolicy(Option<::prisma_client_rust::serde_json::Value>),
+        SetTrafficSplit(Option<::prisma_client_rust::serde_json::Value>),
+        SetMirror(Option<::prisma_client_rust::serde_json::Value>),
+        SetCors(Option<::prisma_client_rust::serde_json::Value>),
+        SetProfileProjection(Option<::prisma_client_rust::serde_json::Value>),
+        SetCreated(
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ),
+        SetUpdated(
+            Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ),
+        SetServiceId(String),
+        ConnectService(super::gateway_service::UniqueWhereParam),
+    }
+    impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+        fn from(param: SetParam) -> Self {
+            match param { SetParam :: SetId (value) => (id :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetGroup (value) => (group :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) , SetParam :: SetMethods (value) => (methods :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) , SetParam :: SetPath (value) => (path :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetProtocol (value) => (protocol :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetAllowedSources (value) => (allowed_sources :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetSecretName (value) => (secret_name :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: String (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetAcceptInsecureRouting (value) => (accept_insecure_routing :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Boolean (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetRateLimit (value) => (rate_limit :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetTimeouts (value) => (timeouts :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetRetry (value) => (retry :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetAcceptNonIdempotentRetries (value) => (accept_non_idempotent_retries :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Boolean (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetRewrite (value) => (rewrite :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetHeaderPolicy (value) => (header_policy :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetTrafficSplit (value) => (traffic_split :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetMirror (value) => (mirror :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetCors (value) => (cors :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetProfileProjection (value) => (profile_projection :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& value) . unwrap ())) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetCreated (value) => (created :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) , SetParam :: SetUpdated (value) => (updated :: NAME . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: DateTime (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: SetServiceId (value) => (service_id :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: ConnectService (where_param) => (service :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: gateway_service :: WhereParam > :: into) . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()))])) }
+        }
+    }
+    #[derive(Clone)]
+    pub enum UncheckedSetParam {
+        Id(String),
+        Group(::prisma_client_rust::serde_json::Value),
+        Methods(::prisma_client_rust::serde_json::Value),
+        Path(String),
+        Protocol(String),
+        AllowedSources(Option<::prisma_client_rust::serde_json::Value>),
+        SecretName(Option<String>),
+        AcceptInsecureRouting(Option<bool>),
+        RateLimit(Option<::prisma_client_rust::serde_json::Value>),
+        Timeouts(Option<::prisma_client_rust::serde_json::Value>),
+        Retry(Option<::prisma_client_rust::serde_json::Value>),
+        AcceptNonIdempotentRetries(Option<bool>),
+        Rewrite(Option<::prisma_client_rust::serde_json::Value>),
+        HeaderPolicy(Option<::prisma_client_rust::serde_json::Value>),
+        TrafficSplit(Option<::prisma_client_rust::serde_json::Value>),
+        Mirror(Option<::prisma_client_rust::serde_json::Value>),
+        Cors(Option<::prisma_client_rust::serde_json::Value>),
+        ProfileProjection(Option<::prisma_client_rust::serde_json::Value>),
+        Created(
+            ::prisma_client_rust::chrono::DateTime<
+                ::prisma_client_rust::chrono::FixedOffset,
+            >,
+        ),
+        Updated(
+            Option<
+                ::prisma_client_rust::chrono::DateTime<
+                    ::prisma_client_rust::chrono::FixedOffset,
+                >,
+            >,
+        ),
+        ServiceId(String),
+    }
+    impl From<UncheckedSetParam> for SetParam {
+        fn from(param: UncheckedSetParam) -> Self {
+            match param {
+                UncheckedSetParam::Id(value) => Self::SetId(value),
+                UncheckedSetParam::Group(value) => Self::SetGroup(value),
+                UncheckedSetParam::Methods(value) => Self::SetMethods(value),
+                UncheckedSetParam::Path(value) => Self::SetPath(value),
+                UncheckedSetParam::Protocol(value) => Self::SetProtocol(value),
+                UncheckedSetParam::AllowedSources(value) => {
+                    Self::SetAllowedSources(value)
+                }
+                UncheckedSetParam::SecretName(value) => {
+                    Self::SetSecretName(value)
+                }
+                UncheckedSetParam::AcceptInsecureRouting(value) => {
+                    Self::SetAcceptInsecureRouting(value)
+                }
+                UncheckedSetParam::RateLimit(value) => {
+                    Self::SetRateLimit(value)
+                }
+                UncheckedSetParam::Timeouts(value) => Self::SetTimeouts(value),
+                UncheckedSetParam::Retry(value) => Self::SetRetry(value),
+                UncheckedSetParam::AcceptNonIdempotentRetries(value) => {
+                    Self::SetAcceptNonIdempotentRetries(value)
+                }
+                UncheckedSetParam::Rewrite(value) => Self::SetRewrite(value),
+                UncheckedSetParam::HeaderPolicy(value) => {
+                    Self::SetHeaderPolicy(value)
+                }
+                UncheckedSetParam::TrafficSplit(value) => {
+                    Self::SetTrafficSplit(value)
+                }
+                UncheckedSetParam::Mirror(value) => Self::SetMirror(value),
+                UncheckedSetParam::Cors(value) => Self::SetCors(value),
+                UncheckedSetParam::ProfileProjection(value) => {
+                    Self::SetProfileProjection(value)
+                }
+                UncheckedSetParam::Created(value) => Self::SetCreated(value),
+                UncheckedSetParam::Updated(value) => Self::SetUpdated(value),
+                UncheckedSetParam::ServiceId(value) => {
+                    Self::SetServiceId(value)
+                }
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum OrderByParam {
+        Id(::prisma_client_rust::Direction),
+        Group(::prisma_client_rust::Direction),
+        Methods(::prisma_client_rust::Direction),
+        Path(::prisma_client_rust::Direction),
+        Protocol(::prisma_client_rust::Direction),
+        AllowedSources(::prisma_client_rust::Direction),
+        SecretName(::prisma_client_rust::Direction),
+        AcceptInsecureRouting(::prisma_client_rust::Direction),
+        RateLimit(::prisma_client_rust::Direction),
+        Timeouts(::prisma_client_rust::Direction),
+        Retry(::prisma_client_rust::Direction),
+        AcceptNonIdempotentRetries(::prisma_client_rust::Direction),
+        Rewrite(::prisma_client_rust::Direction),
+        HeaderPolicy(::prisma_client_rust::Direction),
+        TrafficSplit(::prisma_client_rust::Direction),
+        Mirror(::prisma_client_rust::Direction),
+        Cors(::prisma_client_rust::Direction),
+        ProfileProjection(::prisma_client_rust::Direction),
+        Created(::prisma_client_rust::Direction),
+        Updated(::prisma_client_rust::Direction),
+        ServiceId(::prisma_client_rust::Direction),
+    }
+    impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+        fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+            match self {
+                Self::Id(direction) => (
+                    id::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Group(direction) => (
+                    group::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Methods(direction) => (
+                    methods::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Path(direction) => (
+                    path::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Protocol(direction) => (
+                    protocol::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::AllowedSources(direction) => (
+                    allowed_sources::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::SecretName(direction) => (
+                    secret_name::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::AcceptInsecureRouting(direction) => (
+                    accept_insecure_routing::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::RateLimit(direction) => (
+                    rate_limit::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Timeouts(direction) => (
+                    timeouts::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Retry(direction) => (
+                    retry::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::AcceptNonIdempotentRetries(direction) => (
+                    accept_non_idempotent_retries::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Rewrite(direction) => (
+                    rewrite::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::HeaderPolicy(direction) => (
+                    header_policy::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::TrafficSplit(direction) => (
+                    traffic_split::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Mirror(direction) => (
+                    mirror::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Cors(direction) => (
+                    cors::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::ProfileProjection(direction) => (
+                    profile_projection::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Created(direction) => (
+                    created::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::Updated(direction) => (
+                    updated::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+                Self::ServiceId(direction) => (
+                    service_id::NAME.to_string(),
+                    ::prisma_client_rust::PrismaValue::String(
+                        direction.to_string(),
+                    ),
+                ),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub enum WhereParam {
+        Not(Vec<WhereParam>),
+        Or(Vec<WhereParam>),
+        And(Vec<WhereParam>),
+        ServiceIdPathMethodsEquals(
+            String,
+            String,
+            ::prisma_client_rust::serde_json::Value,
+        ),
+        Id(_prisma::read_filters::StringFilter),
+        Group(_prisma::read_filters::JsonFilter),
+        Methods(_prisma::read_filters::JsonFilter),
+        Path(_prisma::read_filters::StringFilter),
+        Protocol(_prisma::read_filters::StringFilter),
+        AllowedSources(_prisma::read_filters::JsonNullableFilter),
+        SecretName(_prisma::read_filters::StringNullableFilter),
+        AcceptInsecureRouting(_prisma::read_filters::BoolNullableFilter),
+        RateLimit(_prisma::read_filters::JsonNullableFilter),
+        Timeouts(_prisma::read_filters::JsonNullableFilter),
+        Retry(_prisma::read_filters::JsonNullableFilter),
+        AcceptNonIdempotentRetries(_prisma::read_filters::BoolNullableFilter),
+        Rewrite(_prisma::read_filters::JsonNullableFilter),
+        HeaderPolicy(_prisma::read_filters::JsonNullableFilter),
+        TrafficSplit(_prisma::read_filters::JsonNullableFilter),
+        Mirror(_prisma::read_filters::JsonNullableFilter),
+        Cors(_prisma::read_filters::JsonNullableFilter),
+        ProfileProjection(_prisma::read_filters::JsonNullableFilter),
+        Created(_prisma::read_filters::DateTimeFilter),
+        Updated(_prisma::read_filters::DateTimeNullableFilter),
+        ServiceId(_prisma::read_filters::StringFilter),
+        ServiceIs(Vec<super::gateway_service::WhereParam>),
+        ServiceIsNot(Vec<super::gateway_service::WhereParam>),
+    }
+    impl ::prisma_client_rust::WhereInput for WhereParam {
+        fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+            let (name , value) = match self { Self :: Not (value) => ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (:: prisma_client_rust :: merge_fields (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . collect ())) ,) , Self :: Or (value) => ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (:: prisma_client_rust :: merge_fields (value . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (Into :: into) . collect ())) ,) , Self :: ServiceIdPathMethodsEquals (service_id , path , methods) => ("service_id_path_methods" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [(service_id :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (service_id)) , (path :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: String (path)) , (methods :: NAME . to_string () , :: prisma_client_rust :: PrismaValue :: Json (:: prisma_client_rust :: serde_json :: to_string (& methods) . unwrap ()))])) , Self :: Id (value) => (id :: NAME , value . into ()) , Self :: Group (value) => (group :: NAME , value . into ()) , Self :: Methods (value) => (methods :: NAME , value . into ()) , Self :: Path (value) => (path :: NAME , value . into ()) , Self :: Protocol (value) => (protocol :: NAME , value . into ()) , Self :: AllowedSources (value) => (allowed_sources :: NAME , value . into ()) , Self :: SecretName (value) => (secret_name :: NAME , value . into ()) , Self :: AcceptInsecureRouting (value) => (accept_insecure_routing :: NAME , value . into ()) , Self :: RateLimit (value) => (rate_limit :: NAME , value . into ()) , Self :: Timeouts (value) => (timeouts :: NAME , value . into ()) , Self :: Retry (value) => (retry :: NAME , value . into ()) , Self :: AcceptNonIdempotentRetries (value) => (accept_non_idempotent_retries :: NAME , value . into ()) , Self :: Rewrite (value) => (rewrite :: NAME , value . into ()) , Self :: HeaderPolicy (value) => (header_policy :: NAME , value . into ()) , Self :: TrafficSplit (value) => (traffic_split :: NAME , value . into ()) , Self :: Mirror (value) => (mirror :: NAME , value . into ()) , Self :: Cors (value) => (cors :: NAME , value . into ()) , Self :: ProfileProjection (value) => (profile_projection :: NAME , value . into ()) , Self :: Created (value) => (created :: NAME , value . into ()) , Self :: Updated (value) => (updated :: NAME , value . into ()) , Self :: ServiceId (value) => (service_id :: NAME , value . into ()) , Self :: ServiceIs (where_params) => (service :: NAME , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()) ,)])) , Self :: ServiceIsNot (where_params) => (service :: NAME , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (where_params . into_iter () . map (:: prisma_client_rust :: WhereInput :: serialize) . map (:: prisma_client_rust :: SerializedWhereInput :: transform_equals) . collect ()) ,)])) } ;
+            ::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+        }
+    }
+    #[derive(Clone)]
+    pub enum UniqueWhereParam {
+        ServiceIdPathMethodsEquals(
+            String,
+            String,
+            ::prisma_client_rust::serde_json::Value,
+        ),
+        IdEquals(String),
+    }
+    impl From<UniqueWhereParam> for WhereParam {
+        fn from(value: UniqueWhereParam) -> Self {
+            match value {
+                UniqueWhereParam::ServiceIdPathMethodsEquals(
+                    service_id,
+                    path,
+                    methods,
+                ) => {
+                    Self::ServiceIdPathMethodsEquals(service_id, path, methods)
+                }
+                UniqueWhereParam::IdEquals(value) => {
+                    Self::Id(_prisma::read_filters::StringFilter::Equals(value))
+                }
+            }
+        }
+    }
+    impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+        fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+            match op {
+                ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+                ::prisma_client_rust::Operator::And(value) => Self::And(value),
+                ::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+            }
+        }
+    }
+    #[derive(Clone)]
+    pub struct Types;
+    impl ::prisma_client_rust::ModelTypes for Types {
+        type Data = Data;
+        type Where = WhereParam;
+        type UncheckedSet = UncheckedSetParam;
+        type Set = SetParam;
+        type With = WithParam;
+        type OrderBy = OrderByParam;
+        type Cursor = UniqueWhereParam;
+        const MODEL: &'static str = NAME;
+        fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+            vec![
+                ::prisma_client_rust::sel(id::NAME),
+                ::prisma_client_rust::sel(group::NAME),
+                ::prisma_client_rust::sel(methods::NAME),
+                ::prisma_client_rust::sel(path::NAME),
+                ::prisma_client_rust::sel(protocol::NAME),
+                ::prisma_client_rust::sel(allowed_sources::NAME),
+                ::prisma_client_rust::sel(secret_name::NAME),
+                ::prisma_client_rust::sel(accept_insecure_routing::NAME),
+                ::prisma_client_rust::sel(rate_limit::NAME),
+                ::prisma_client_rust::sel(timeouts::NAME),
+                ::prisma_client_rust::sel(retry::NAME),
+                ::prisma_client_rust::sel(accept_non_idempotent_retries::NAME),
+                ::prisma_client_rust::sel(rewrite::NAME),
+                ::prisma_client_rust::sel(header_policy::NAME),
+                ::prisma_client_rust::sel(traffic_split::NAME),
+                ::prisma_client_rust::sel(mirror::NAME),
+                ::prisma_client_rust::sel(cors::NAME),
+                ::prisma_client_rust::sel(profile_projection::NAME),
+                ::prisma_client_rust::sel(created::NAME),
+                ::prisma_client_rust::sel(updated::NAME),
+                ::prisma_client_rust::sel(service_id::NAME),
+            ]
+        }
+    }
+    pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+    pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+    pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+    pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+    pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+    pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+    pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+    pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+    pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+    pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+    pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+    pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+    pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+    #[derive(Clone)]
+    pub struct Actions<'a> {
+        pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+    }
+    impl<'a> Actions<'a> {
+        pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+            FindUnique::new(self.client, _where.into())
+        }
+        pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+            FindFirst::new(self.client, _where)
+        }
+        pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+            FindMany::new(self.client, _where)
+        }
+        pub fn create(
+            self,
+            group: ::prisma_client_rust::serde_json::Value,
+            methods: ::prisma_client_rust::serde_json::Value,
+            path: String,
+            protocol: String,
+            service: super::gateway_service::UniqueWhereParam,
+            mut _params: Vec<SetParam>,
+        ) -> Create<'a> {
+            _params.extend([
+                group::set(group),
+                methods::set(methods),
+                path::set(path),
+                protocol::set(protocol),
+                service::connect(service),
+            ]);
+            Create::new(self.client, _params)
+        }
+        pub fn create_unchecked(
+            self,
+            group: ::prisma_client_rust::serde_json::Value,
+            methods: ::prisma_client_rust::serde_json::Value,
+            path: String,
+            protocol: String,
+            service_id: String,
+            mut _params: Vec<UncheckedSetParam>,
+        ) -> Create<'a> {
+            _params.extend([
+                group::set(group),
+                methods::set(methods),
+                path::set(path),
+                protocol::set(protocol),
+                service_id::set(service_id),
+            ]);
+            Create::new(
+                self.client,
+                _params.into_iter().map(Into::into).collect(),
+            )
+        }
+        pub fn create_many(
+            self,
+            data: Vec<(
+                ::prisma_client_rust::serde_json::Value,
+                ::prisma_client_rust::serde_json::Value,
+                String,
+                String,
+                String,
+                Vec<SetParam>,
+            )>,
+        ) -> CreateMany<'a> {
+            let data = data
+                .into_iter()
+                .map(
+                    |(
+                        group,
+                        methods,
+                        path,
+                        protocol,
+                        service_id,
+                        mut _params,
+                    )| {
+                        _params.extend([
+                            group::set(group),
+                            methods::set(methods),
+                            path::set(path),
+                            protocol::set(protocol),
+                            service_id::set(service_id),
+                        ]);
+                        _params
+                    },
+                )
+                .collect();
+            CreateMany::new(self.client, data)
+        }
+        pub fn update(
+            self,
+            _where: UniqueWhereParam,
+            _params: Vec<SetParam>,
+        ) -> Update<'a> {
+            Update::new(self.client, _where.into(), _params, vec![])
+        }
+        pub fn update_unchecked(
+            self,
+            _where: UniqueWhereParam,
+            _params: Vec<UncheckedSetParam>,
+        ) -> Update<'a> {
+            Update::new(
+                self.client,
+                _where.into(),
+                _params.into_iter().map(Into::into).collect(),
+                vec![],
+            )
+        }
+        pub fn update_many(
+            self,
+            _where: Vec<WhereParam>,
+            _params: Vec<SetParam>,
+        ) -> UpdateMany<'a> {
+            UpdateMany::new(self.client, _where, _params)
+        }
+        pub fn upsert(
+            self,
+            _where: UniqueWhereParam,
+            (group, methods, path, protocol, service, mut _params): (
+                ::prisma_client_rust::serde_json::Value,
+                ::prisma_client_rust::serde_json::Value,
+                String,
+                String,
+                super::gateway_service::UniqueWhereParam,
+                Vec<SetParam>,
+            ),
+            _update: Vec<SetParam>,
+        ) -> Upsert<'a> {
+            _params.extend([
+                group::set(group),
+                methods::set(methods),
+                path::set(path),
+                protocol::set(protocol),
+                service::connect(service),
+            ]);
+            Upsert::new(self.client, _where.into(), _params, _update)
+        }
+        pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+            Delete::new(self.client, _where.into(), vec![])
+        }
+        pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+            DeleteMany::new(self.client, _where)
+        }
+        pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+            Count::new(self.client, _where)
+        }
+    }
+}
 pub mod _prisma {
     pub struct PrismaClientBuilder {
         url: Option<String>,
@@ -17498,6 +21987,12 @@ pub mod _prisma {
         pub fn webhook(&self) -> super::webhook::Actions {
             super::webhook::Actions { client: &self.0 }
         }
+        pub fn gateway_service(&self) -> super::gateway_service::Actions {
+            super::gateway_service::Actions { client: &self.0 }
+        }
+        pub fn gateway_route(&self) -> super::gateway_route::Actions {
+            super::gateway_route::Actions { client: &self.0 }
+        }
     }
     impl ::prisma_client_rust::PrismaClient for PrismaClient {
         fn internals(&self) -> &::prisma_client_rust::PrismaClientInternals {
@@ -17641,6 +22136,149 @@ pub mod _prisma {
         PartialEq,
         Eq,
     )]
+    pub enum GatewayRouteScalarFieldEnum {
+        #[serde(rename = "id")]
+        Id,
+        #[serde(rename = "group")]
+        Group,
+        #[serde(rename = "methods")]
+        Methods,
+        #[serde(rename = "path")]
+        Path,
+        #[serde(rename = "protocol")]
+        Protocol,
+        #[serde(rename = "allowed_sources")]
+        AllowedSources,
+        #[serde(rename = "secret_name")]
+        SecretName,
+        #[serde(rename = "accept_insecure_routing")]
+        AcceptInsecureRouting,
+        #[serde(rename = "rate_limit")]
+        RateLimit,
+        #[serde(rename = "timeouts")]
+        Timeouts,
+        #[serde(rename = "retry")]
+        Retry,
+        #[serde(rename = "accept_non_idempotent_retries")]
+        AcceptNonIdempotentRetries,
+        #[serde(rename = "rewrite")]
+        Rewrite,
+        #[serde(rename = "header_policy")]
+        HeaderPolicy,
+        #[serde(rename = "traffic_split")]
+        TrafficSplit,
+        #[serde(rename = "mirror")]
+        Mirror,
+        #[serde(rename = "cors")]
+        Cors,
+        #[serde(rename = "profile_projection")]
+        ProfileProjection,
+        #[serde(rename = "created")]
+        Created,
+        #[serde(rename = "updated")]
+        Updated,
+        #[serde(rename = "service_id")]
+        ServiceId,
+    }
+    impl ToString for GatewayRouteScalarFieldEnum {
+        fn to_string(&self) -> String {
+            match self {
+                Self::Id => "id".to_string(),
+                Self::Group => "group".to_string(),
+                Self::Methods => "methods".to_string(),
+                Self::Path => "path".to_string(),
+                Self::Protocol => "protocol".to_string(),
+                Self::AllowedSources => "allowed_sources".to_string(),
+                Self::SecretName => "secret_name".to_string(),
+                Self::AcceptInsecureRouting => {
+                    "accept_insecure_routing".to_string()
+                }
+                Self::RateLimit => "rate_limit".to_string(),
+                Self::Timeouts => "timeouts".to_string(),
+                Self::Retry => "retry".to_string(),
+                Self::AcceptNonIdempotentRetries => {
+                    "accept_non_idempotent_retries".to_string()
+                }
+                Self::Rewrite => "rewrite".to_string(),
+                Self::HeaderPolicy => "header_policy".to_string(),
+                Self::TrafficSplit => "traffic_split".to_string(),
+                Self::Mirror => "mirror".to_string(),
+                Self::Cors => "cors".to_string(),
+                Self::ProfileProjection => "profile_projection".to_string(),
+                Self::Created => "created".to_string(),
+                Self::Updated => "updated".to_string(),
+                Self::ServiceId => "service_id".to_string(),
+            }
+        }
+    }
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        :: serde :: Serialize,
+        :: serde :: Deserialize,
+        PartialEq,
+        Eq,
+    )]
+    pub enum GatewayServiceScalarFieldEnum {
+        #[serde(rename = "id")]
+        Id,
+        #[serde(rename = "name")]
+        Name,
+        #[serde(rename = "host")]
+        Host,
+        #[serde(rename = "targets")]
+        Targets,
+        #[serde(rename = "load_balancing")]
+        LoadBalancing,
+        #[serde(rename = "health_check")]
+        HealthCheck,
+        #[serde(rename = "timeouts")]
+        Timeouts,
+        #[serde(rename = "retry")]
+        Retry,
+        #[serde(rename = "circuit_breaker")]
+        CircuitBreaker,
+        #[serde(rename = "header_policy")]
+        HeaderPolicy,
+        #[serde(rename = "cors")]
+        Cors,
+        #[serde(rename = "secrets")]
+        Secrets,
+        #[serde(rename = "created")]
+        Created,
+        #[serde(rename = "updated")]
+        Updated,
+    }
+    impl ToString for GatewayServiceScalarFieldEnum {
+        fn to_string(&self) -> String {
+            match self {
+                Self::Id => "id".to_string(),
+                Self::Name => "name".to_string(),
+                Self::Host => "host".to_string(),
+                Self::Targets => "targets".to_string(),
+                Self::LoadBalancing => "load_balancing".to_string(),
+                Self::HealthCheck => "health_check".to_string(),
+                Self::Timeouts => "timeouts".to_string(),
+                Self::Retry => "retry".to_string(),
+                Self::CircuitBreaker => "circuit_breaker".to_string(),
+                Self::HeaderPolicy => "header_policy".to_string(),
+                Self::Cors => "cors".to_string(),
+                Self::Secrets => "secrets".to_string(),
+                Self::Created => "created".to_string(),
+                Self::Updated => "updated".to_string(),
+            }
+        }
+    }
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        :: serde :: Serialize,
+        :: serde :: Deserialize,
+        PartialEq,
+        Eq,
+    )]
     pub enum GuestRoleChildrenScalarFieldEnum {
         #[serde(rename = "parent_id")]
         ParentId,
@@ -18359,6 +22997,16 @@ pub mod _prisma {
             }
         }
         #[derive(Clone)]
+        pub enum BoolNullableFilter {
+            Equals(Option<bool>),
+            Not(Option<bool>),
+        }
+        impl Into<::prisma_client_rust::SerializedWhereValue> for BoolNullableFilter {
+            fn into(self) -> ::prisma_client_rust::SerializedWhereValue {
+                match self { Self :: Equals (value) => :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Boolean (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))]) , Self :: Not (value) => :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Boolean (value)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))]) }
+            }
+        }
+        #[derive(Clone)]
         pub enum IntFilter {
             Equals(i32),
             InVec(Vec<i32>),
@@ -21,6 +21,10 @@ mod guest_user_on_account_updating;
 mod guest_user_registration;
 mod licensed_resources_fetching;
 mod profile_fetching;
+mod routes_deletion;
+mod routes_fetching;
+mod routes_registration;
+mod routes_updating;
 mod tenant_deletion;
 mod tenant_fetching;
 mod tenant_registration;
@@ -61,6 +65,10 @@ pub use guest_user_on_account_updating::*;
 pub use guest_user_registration::*;
 pub use licensed_resources_fetching::*;
 pub use profile_fetching::*;
+pub use routes_deletion::*;
+pub use routes_fetching::*;
+pub use routes_registration::*;
+pub use routes_updating::*;
 pub use tenant_deletion::*;
 pub use tenant_fetching::*;
 pub use tenant_registration::*;
@@ -0,0 +1,115 @@
+use crate::repositories::connector::get_client;
+
+use async_trait::async_trait;
+use myc_core::domain::{
+    dtos::native_error_codes::NativeErrorCodes, entities::RoutesDeletion,
+};
+use mycelium_base::{
+    entities::DeletionResponseKind,
+    utils::errors::{deletion_err, MappedErrors},
+};
+use prisma_client_rust::{PrismaValue, Raw};
+use shaku::Component;
+use std::process::id as process_id;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = RoutesDeletion)]
+pub struct RoutesDeletionSqlDbRepository {}
+
+#[async_trait]
+impl RoutesDeletion for RoutesDeletionSqlDbRepository {
+    #[tracing::instrument(name = "delete_service", skip_all)]
+    async fn delete_service(
+        &self,
+        service_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return deletion_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Delete the service
+        //
+        // The service routes are removed by the foreign key cascade.
+        //
+        // ? -------------------------------------------------------------------
+
+        match client
+            ._execute_raw(Raw::new(
+                "DELETE FROM gateway_service WHERE id = {}",
+                vec![PrismaValue::String(service_id.to_string())],
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(DeletionResponseKind::NotDeleted(
+                service_id,
+                "Service not found".to_string(),
+            )),
+            Ok(_) => Ok(DeletionResponseKind::Deleted),
+            Err(err) => Ok(DeletionResponseKind::NotDeleted(
+                service_id,
+                err.to_string(),
+            )),
+        }
+    }
+
+    #[tracing::instrument(name = "delete_route", skip_all)]
+    async fn delete_route(
+        &self,
+        route_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return deletion_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Delete the route
+        // ? -------------------------------------------------------------------
+
+        match client
+            ._execute_raw(Raw::new(
+                "DELETE FROM gateway_route WHERE id = {}",
+                vec![PrismaValue::String(route_id.to_string())],
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(DeletionResponseKind::NotDeleted(
+                route_id,
+                "Route not found".to_string(),
+            )),
+            Ok(_) => Ok(DeletionResponseKind::Deleted),
+            Err(err) => {
+                Ok(DeletionResponseKind::NotDeleted(route_id, err.to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use crate::{prisma::PrismaClient, repositories::connector::get_client};
+
+use actix_web::http::uri::PathAndQuery;
+use async_trait::async_trait;
+use myc_core::domain::{
+    dtos::{
+        circuit_breaker::CircuitBreakerConfig,
+        cors::CorsPolicy,
+        header_policy::HeaderPolicy,
+        health_check::HealthCheckConfig,
+        http::{HttpMethod, Protocol},
+        native_error_codes::NativeErrorCodes,
+        path_rewrite::PathRewriteRule,
+        profile_projection::ProfileProjection,
+        rate_limit::RateLimitPolicy,
+        request_mirror::RequestMirror,
+        retry::RetryPolicy,
+        route::Route,
+        route_tree::RoutesIndex,
+        route_type::RouteType,
+        service::{Service, ServiceSecret},
+        timeout::UpstreamTimeouts,
+        traffic_split::TrafficSplit,
+        upstream::{LoadBalancingStrategy, UpstreamTarget},
+    },
+    entities::RoutesFetching,
+};
+use mycelium_base::{
+    dtos::Parent,
+    entities::{FetchManyResponseKind, FetchResponseKind},
+    utils::errors::{fetching_err, MappedErrors},
+};
+use prisma_client_rust::{PrismaValue, Raw};
+use serde::Deserialize;
+use serde_json::{from_value, Value};
+use shaku::Component;
+use std::{process::id as process_id, str::FromStr};
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = RoutesFetching)]
+pub struct RoutesFetchingSqlDbRepository {}
+
+#[derive(Deserialize, Debug)]
+struct ServiceRow {
+    id: String,
+    name: String,
+    host: String,
+    targets: Option<Value>,
+    load_balancing: Option<String>,
+    health_check: Option<Value>,
+    timeouts: Option<Value>,
+    retry: Option<Value>,
+    circuit_breaker: Option<Value>,
+    header_policy: Option<Value>,
+    cors: Option<Value>,
+    secrets: Option<Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RouteRow {
+    id: String,
+    service_id: String,
+    group: Value,
+    methods: Value,
+    path: String,
+    protocol: String,
+    allowed_sources: Option<Value>,
+    secret_name: Option<String>,
+    accept_insecure_routing: Option<bool>,
+    rate_limit: Option<Value>,
+    timeouts: Option<Value>,
+    retry: Option<Value>,
+    accept_non_idempotent_retries: Option<bool>,
+    rewrite: Option<Value>,
+    header_policy: Option<Value>,
+    traffic_split: Option<Value>,
+    mirror: Option<Value>,
+    cors: Option<Value>,
+    profile_projection: Option<Value>,
+}
+
+#[async_trait]
+impl RoutesFetching for RoutesFetchingSqlDbRepository {
+    #[tracing::instrument(name = "get_route", skip_all)]
+    async fn get(
+        &self,
+        path: PathAndQuery,
+        method: HttpMethod,
+    ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return fetching_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Fetch the service routes and match the path
+        //
+        // The first path segment is the service name. The routes of the
+        // service are compiled on demand, then the same matching rules of the
+        // in-memory routes are applied.
+        //
+        // ? -------------------------------------------------------------------
+
+        let (service_name, rest) =
+            match path.path().trim_start_matches("/").split_once("/") {
+                Some((service_name, rest)) => {
+                    (service_name, format!("/{rest}"))
+                }
+                None => (path.path().trim_start_matches("/"), String::new()),
+            };
+
+        let routes =
+            fetch_routes(client, None, Some(service_name.to_string())).await?;
+
+        let index = RoutesIndex::compile(&routes);
+
+        match index.find(service_name, rest.as_str(), method) {
+            None => Ok(FetchResponseKind::NotFound(None)),
+            Some(route) => Ok(FetchResponseKind::Found(route.to_owned())),
+        }
+    }
+
+    #[tracing::instrument(name = "get_grpc_route", skip_all)]
+    async fn get_grpc(
+        &self,
+        path: PathAndQuery,
+    ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return fetching_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Fetch the gRPC routes and match the path
+        //
+        // gRPC calls do not include the service name, then the gRPC routes of
+        // all services are compiled and matched by the full path.
+        //
+        // ? -------------------------------------------------------------------
+
+        let routes = fetch_routes(client, None, None)
+            .await?
+            .into_iter()
+            .filter(|route| route.protocol == Protocol::Grpc)
+            .collect::<Vec<Route>>();
+
+        let index = RoutesIndex::compile(&routes);
+
+        match index.find_grpc(path.path()) {
+            None => Ok(FetchResponseKind::NotFound(None)),
+            Some(route) => Ok(FetchResponseKind::Found(route.to_owned())),
+        }
+    }
+
+    #[tracing::instrument(name = "list_routes", skip_all)]
+    async fn list_routes(
+        &self,
+        id: Option<Uuid>,
+        name: Option<String>,
+        include_service_details: Option<bool>,
+    ) -> Result<FetchManyResponseKind<Route>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return fetching_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Fetch routes
+        // ? -------------------------------------------------------------------
+
+        let routes = fetch_routes(client, id, name)
+            .await?
+            .into_iter()
+            .map(|route| {
+                if let Some(true) = include_service_details {
+                    return route;
+                }
+
+                let mut route = route;
+
+                if let Parent::Record(service) = &route.service {
+                    if let Some(service_id) = service.id {
+                        route.service = Parent::Id(service_id);
+                    }
+                }
+
+                route
+            })
+            .collect::<Vec<Route>>();
+
+        if routes.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(routes))
+    }
+
+    #[tracing::instrument(name = "list_services", skip_all)]
+    async fn list_services(
+        &self,
+        id: Option<Uuid>,
+        name: Option<String>,
+    ) -> Result<FetchManyResponseKind<Service>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return fetching_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Fetch services
+        // ? -------------------------------------------------------------------
+
+        let services = fetch_services(client, id, name).await?;
+
+        if services.is_empty() {
+            return Ok(FetchManyResponseKind::NotFound);
+        }
+
+        Ok(FetchManyResponseKind::Found(services))
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Auxiliary functions
+// ? ---------------------------------------------------------------------------
+
+async fn fetch_services(
+    client: &PrismaClient,
+    id: Option<Uuid>,
+    name: Option<String>,
+) -> Result<Vec<Service>, MappedErrors> {
+    let mut query = vec![
+        "SELECT id, name, host, targets, load_balancing, health_check, timeouts, retry, circuit_breaker, header_policy, cors, secrets FROM gateway_service WHERE TRUE",
+    ];
+
+    let mut params = vec![];
+
+    if let Some(id) = id {
+        query.push("AND id = {}");
+        params.push(PrismaValue::String(id.to_string()));
+    }
+
+    if let Some(name) = name {
+        query.push("AND name = {}");
+        params.push(PrismaValue::String(name));
+    }
+
+    query.push("ORDER BY name");
+
+    let rows: Vec<ServiceRow> = match client
+        ._query_raw(Raw::new(query.join(" ").as_str(), params))
+        .exec()
+        .await
+    {
+        Ok(res) => res,
+        Err(err) => {
+            return fetching_err(format!("Error fetching services: {err}"))
+                .as_error()
+        }
+    };
+
+    rows.into_iter().map(parse_service_row).collect()
+}
+
+async fn fetch_routes(
+    client: &PrismaClient,
+    service_id: Option<Uuid>,
+    service_name: Option<String>,
+) -> Result<Vec<Route>, MappedErrors> {
+    let services = fetch_services(client, service_id, service_name).await?;
+
+    if services.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let rows: Vec<RouteRow> = match client
+        ._query_raw(Raw::new(
+            "
+SELECT
+    id, service_id, \"group\", methods, path, protocol, allowed_sources,
+    secret_name, accept_insecure_routing, rate_limit, timeouts, retry,
+    accept_non_idempotent_retries, rewrite, header_policy, traffic_split,
+    mirror, cors, profile_projection
+FROM gateway_route
+WHERE service_id = ANY({})
+ORDER BY created, id
+            ",
+            vec![PrismaValue::List(
+                services
+                    .iter()
+                    .filter_map(|service| service.id)
+                    .map(|id| PrismaValue::String(id.to_string()))
+                    .collect::<Vec<PrismaValue>>(),
+            )],
+        ))
+        .exec()
+        .await
+    {
+        Ok(res) => res,
+        Err(err) => {
+            return fetching_err(format!("Error fetching routes: {err}"))
+                .as_error()
+        }
+    };
+
+    let mut routes = vec![];
+
+    for row in rows {
+        let service = match services.iter().find(|service| {
+            service.id.map(|id| id.to_string())
+                == Some(row.service_id.to_owned())
+        }) {
+            Some(service) => service.to_owned(),
+            None => continue,
+        };
+
+        routes.push(parse_route_row(row, service)?);
+    }
+
+    Ok(routes)
+}
+
+fn parse_service_row(row: ServiceRow) -> Result<Service, MappedErrors> {
+    let parse_err = |field: &str, err: String| {
+        fetching_err(format!(
+            "Invalid field {field} of service {}: {err}",
+            row.name
+        ))
+        .as_error()
+    };
+
+    let id = match Uuid::from_str(&row.id) {
+        Ok(id) => id,
+        Err(err) => return parse_err("id", err.to_string()),
+    };
+
+    let targets = match row
+        .targets
+        .to_owned()
+        .map(from_value::<Vec<UpstreamTarget>>)
+    {
+        None => None,
+        Some(Ok(targets)) => Some(targets),
+        Some(Err(err)) => return parse_err("targets", err.to_string()),
+    };
+
+    let load_balancing = match row.load_balancing.to_owned().map(|strategy| {
+        from_value::<LoadBalancingStrategy>(Value::String(strategy))
+    }) {
+        None => None,
+        Some(Ok(strategy)) => Some(strategy),
+        Some(Err(err)) => return parse_err("load_balancing", err.to_string()),
+    };
+
+    let health_check = match row
+        .health_check
+        .to_owned()
+        .map(from_value::<HealthCheckConfig>)
+    {
+        None => None,
+        Some(Ok(health_check)) => Some(health_check),
+        Some(Err(err)) => return parse_err("health_check", err.to_string()),
+    };
+
+    let timeouts =
+        match row.timeouts.to_owned().map(from_value::<UpstreamTimeouts>) {
+            None => None,
+            Some(Ok(timeouts)) => Some(timeouts),
+            Some(Err(err)) => return parse_err("timeouts", err.to_string()),
+        };
+
+    let retry = match row.retry.to_owned().map(from_value::<RetryPolicy>) {
+        None => None,
+        Some(Ok(retry)) => Some(retry),
+        Some(Err(err)) => return parse_err("retry", err.to_string()),
+    };
+
+    let circuit_breaker = match row
+        .circuit_breaker
+        .to_owned()
+        .map(from_value::<CircuitBreakerConfig>)
+    {
+        None => None,
+        Some(Ok(circuit_breaker)) => Some(circuit_breaker),
+        Some(Err(err)) => return parse_err("circuit_breaker", err.to_string()),
+    };
+
+    let header_policy =
+        match row.header_policy.to_owned().map(from_value::<HeaderPolicy>) {
+            None => None,
+            Some(Ok(header_policy)) => Some(header_policy),
+            Some(Err(err)) => {
+                return parse_err("header_policy", err.to_string())
+            }
+        };
+
+    let cors = match row.cors.to_owned().map(from_value::<CorsPolicy>) {
+        None => None,
+        Some(Ok(cors)) => Some(cors),
+        Some(Err(err)) => return parse_err("cors", err.to_string()),
+    };
+
+    let secrets =
+        match row.secrets.to_owned().map(from_value::<Vec<ServiceSecret>>) {
+            None => None,
+            Some(Ok(secrets)) if secrets.is_empty() => None,
+            Some(Ok(secrets)) => Some(secrets),
+            Some(Err(err)) => return parse_err("secrets", err.to_string()),
+        };
+
+    Ok(Service::new(
+        Some(id),
+        row.name.to_owned(),
+        row.host.to_owned(),
+        targets,
+        load_balancing,
+        health_check,
+        vec![],
+        secrets,
+    )
+    .with_timeouts(timeouts)
+    .with_retry(retry)
+    .with_circuit_breaker(circuit_breaker)
+    .with_header_policy(header_policy)
+    .with_cors(cors))
+}
+
+fn parse_route_row(
+    row: RouteRow,
+    service: Service,
+) -> Result<Route, MappedErrors> {
+    let parse_err = |field: &str, err: String| {
+        fetching_err(format!(
+            "Invalid field {field} of route {}: {err}",
+            row.id
+        ))
+        .as_error()
+    };
+
+    let id = match Uuid::from_str(&row.id) {
+        Ok(id) => id,
+        Err(err) => return parse_err("id", err.to_string()),
+    };
+
+    let group = match from_value::<RouteType>(row.group.to_owned()) {
+        Ok(group) => group,
+        Err(err) => return parse_err("group", err.to_string()),
+    };
+
+    let methods = match from_value::<Vec<HttpMethod>>(row.methods.to_owned()) {
+        Ok(methods) => methods,
+        Err(err) => return parse_err("methods", err.to_string()),
+    };
+
+    let protocol =
+        match from_value::<Protocol>(Value::String(row.protocol.to_owned())) {
+            Ok(protocol) => protocol,
+            Err(err) => return parse_err("protocol", err.to_string()),
+        };
+
+    let allowed_sources = match row
+        .allowed_sources
+        .to_owned()
+        .map(from_value::<Vec<String>>)
+    {
+        None => None,
+        Some(Ok(sources)) => Some(sources),
+        Some(Err(err)) => return parse_err("allowed_sources", err.to_string()),
+    };
+
+    let rate_limit =
+        match row.rate_limit.to_owned().map(from_value::<RateLimitPolicy>) {
+            None => None,
+            Some(Ok(rate_limit)) => Some(rate_limit),
+            Some(Err(err)) => return parse_err("rate_limit", err.to_string()),
+        };
+
+    let timeouts =
+        match row.timeouts.to_owned().map(from_value::<UpstreamTimeouts>) {
+            None => None,
+            Some(Ok(timeouts)) => Some(timeouts),
+            Some(Err(err)) => return parse_err("timeouts", err.to_string()),
+        };
+
+    let retry = match row.retry.to_owned().map(from_value::<RetryPolicy>) {
+        None => None,
+        Some(Ok(retry)) => Some(retry),
+        Some(Err(err)) => return parse_err("retry", err.to_string()),
+    };
+
+    let rewrite = match row
+        .rewrite
+        .to_owned()
+        .map(from_value::<Vec<PathRewriteRule>>)
+    {
+        None => None,
+        Some(Ok(rules)) if rules.is_empty() => None,
+        Some(Ok(rules)) => Some(rules),
+        Some(Err(err)) => return parse_err("rewrite", err.to_string()),
+    };
+
+    let header_policy =
+        match row.header_policy.to_owned().map(from_value::<HeaderPolicy>) {
+            None => None,
+            Some(Ok(header_policy)) => Some(header_policy),
+            Some(Err(err)) => {
+                return parse_err("header_policy", err.to_string())
+            }
+        };
+
+    let traffic_split =
+        match row.traffic_split.to_owned().map(from_value::<TrafficSplit>) {
+            None => None,
+            Some(Ok(traffic_split)) => Some(traffic_split),
+            Some(Err(err)) => {
+                return parse_err("traffic_split", err.to_string())
+            }
+        };
+
+    let mirror = match row.mirror.to_owned().map(from_value::<RequestMirror>) {
+        None => None,
+        Some(Ok(mirror)) => Some(mirror),
+        Some(Err(err)) => return parse_err("mirror", err.to_string()),
+    };
+
+    let cors = match row.cors.to_owned().map(from_value::<CorsPolicy>) {
+        None => None,
+        Some(Ok(cors)) => Some(cors),
+        Some(Err(err)) => return parse_err("cors", err.to_string()),
+    };
+
+    let profile_projection = match row
+        .profile_projection
+        .to_owned()
+        .map(from_value::<ProfileProjection>)
+    {
+        None => None,
+        Some(Ok(projection)) => Some(projection),
+        Some(Err(err)) => {
+            return parse_err("profile_projection", err.to_string())
+        }
+    };
+
+    Ok(Route::new(
+        Some(id),
+        service,
+        group,
+        methods,
+        row.path.to_owned(),
+        protocol,
+        allowed_sources,
+        row.secret_name.to_owned(),
+        row.accept_insecure_routing,
+    )
+    .with_rate_limit(rate_limit)
+    .with_timeouts(timeouts)
+    .with_retry(retry, row.accept_non_idempotent_retries)
+    .with_rewrite(rewrite)
+    .with_header_policy(header_policy)
+    .with_traffic_split(traffic_split)
+    .with_mirror(mirror)
+    .with_cors(cors)
+    .with_profile_projection(profile_projection))
+}
@@ -0,0 +1,377 @@
+use crate::repositories::connector::get_client;
+
+use async_trait::async_trait;
+use myc_core::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes,
+        route::Route,
+        service::{Service, ServiceSecret},
+    },
+    entities::RoutesRegistration,
+};
+use mycelium_base::{
+    dtos::Parent,
+    entities::{CreateManyResponseKind, CreateResponseKind},
+    utils::errors::{creation_err, MappedErrors},
+};
+use prisma_client_rust::{PrismaValue, QueryError, Raw};
+use serde::Serialize;
+use serde_json::{to_value, Value};
+use shaku::Component;
+use std::process::id as process_id;
+use uuid::Uuid;
+
+#[derive(Component)]
+#[shaku(interface = RoutesRegistration)]
+pub struct RoutesRegistrationSqlDbRepository {}
+
+#[async_trait]
+impl RoutesRegistration for RoutesRegistrationSqlDbRepository {
+    #[tracing::instrument(name = "register_service", skip_all)]
+    async fn register_service(
+        &self,
+        service: Service,
+    ) -> Result<CreateResponseKind<Service>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return creation_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Create the service
+        //
+        // Services are unique by name. Existing services are not overwritten.
+        //
+        // ? -------------------------------------------------------------------
+
+        match client
+            ._execute_raw(Raw::new(
+                INSERT_SERVICE_SQL,
+                service_params(&service)?,
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(CreateResponseKind::NotCreated(
+                service,
+                "Service already exists".to_string(),
+            )),
+            Ok(_) => Ok(CreateResponseKind::Created(service)),
+            Err(err) => creation_err(format!(
+                "Unexpected error detected on create record: {err}"
+            ))
+            .as_error(),
+        }
+    }
+
+    #[tracing::instrument(name = "register_route", skip_all)]
+    async fn register_route(
+        &self,
+        route: Route,
+    ) -> Result<CreateResponseKind<Route>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return creation_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Create the route
+        // ? -------------------------------------------------------------------
+
+        match client
+            ._execute_raw(Raw::new(
+                INSERT_ROUTE_SQL,
+                route_insert_params(&route)?,
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(CreateResponseKind::NotCreated(
+                route,
+                "Route already exists".to_string(),
+            )),
+            Ok(_) => Ok(CreateResponseKind::Created(route)),
+            Err(err) => creation_err(format!(
+                "Unexpected error detected on create record: {err}"
+            ))
+            .as_error(),
+        }
+    }
+
+    #[tracing::instrument(name = "register_services_and_routes", skip_all)]
+    async fn register_services_and_routes(
+        &self,
+        services: Vec<Service>,
+        routes: Vec<Route>,
+    ) -> Result<CreateManyResponseKind<Route>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return creation_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Build the records before opening the transaction
+        // ? -------------------------------------------------------------------
+
+        let services_params = services
+            .iter()
+            .map(service_params)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let routes_params = routes
+            .into_iter()
+            .map(|route| Ok((route_insert_params(&route)?, route)))
+            .collect::<Result<Vec<_>, MappedErrors>>()?;
+
+        // ? -------------------------------------------------------------------
+        // ? Create the services and routes
+        //
+        // Records are created in a single transaction, then a failure leaves
+        // the tables as they were before the call. Existing records are
+        // skipped and not returned as created.
+        //
+        // ? -------------------------------------------------------------------
+
+        let response = client
+            ._transaction()
+            .run(|client| async move {
+                for params in services_params {
+                    client
+                        ._execute_raw(Raw::new(INSERT_SERVICE_SQL, params))
+                        .exec()
+                        .await?;
+                }
+
+                let mut created = vec![];
+
+                for (params, route) in routes_params {
+                    if client
+                        ._execute_raw(Raw::new(INSERT_ROUTE_SQL, params))
+                        .exec()
+                        .await?
+                        > 0
+                    {
+                        created.push(route);
+                    }
+                }
+
+                Ok::<_, QueryError>(created)
+            })
+            .await;
+
+        match response {
+            Ok(created) => Ok(CreateManyResponseKind::Created(created)),
+            Err(err) => creation_err(format!(
+                "Unexpected error detected on create records: {err}"
+            ))
+            .as_error(),
+        }
+    }
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Auxiliary functions
+//
+// Shared with the updating repository.
+// ? ---------------------------------------------------------------------------
+
+const INSERT_SERVICE_SQL: &str = "
+INSERT INTO gateway_service (
+    id, name, host, targets, load_balancing, health_check, timeouts, retry,
+    circuit_breaker, header_policy, cors, secrets
+)
+VALUES (
+    {}, {}, {}, CAST({} AS JSONB), {}, CAST({} AS JSONB), CAST({} AS JSONB),
+    CAST({} AS JSONB), CAST({} AS JSONB), CAST({} AS JSONB), CAST({} AS JSONB),
+    CAST({} AS JSONB)
+)
+ON CONFLICT DO NOTHING
+";
+
+const INSERT_ROUTE_SQL: &str = "
+INSERT INTO gateway_route (
+    id, service_id, \"group\", methods, path, protocol, allowed_sources,
+    secret_name, accept_insecure_routing, rate_limit, timeouts, retry,
+    accept_non_idempotent_retries, rewrite, header_policy, traffic_split,
+    mirror, cors, profile_projection
+)
+VALUES (
+    {}, {}, CAST({} AS JSONB), CAST({} AS JSONB), {}, {}, CAST({} AS JSONB),
+    {}, {}, CAST({} AS JSONB), CAST({} AS JSONB), CAST({} AS JSONB), {},
+    CAST({} AS JSONB), CAST({} AS JSONB), CAST({} AS JSONB), CAST({} AS JSONB),
+    CAST({} AS JSONB), CAST({} AS JSONB)
+)
+ON CONFLICT DO NOTHING
+";
+
+/// Build the service columns in the declaration order of the service table
+fn service_params(service: &Service) -> Result<Vec<PrismaValue>, MappedErrors> {
+    let service_id = match service.id {
+        Some(id) => id,
+        None => {
+            return creation_err("Service id is required").as_error();
+        }
+    };
+
+    Ok(vec![
+        PrismaValue::String(service_id.to_string()),
+        PrismaValue::String(service.name.to_owned()),
+        PrismaValue::String(service.host.to_owned()),
+        to_json_param(service.targets.as_ref()),
+        to_text_param(service.load_balancing.as_ref()),
+        to_json_param(service.health_check.as_ref()),
+        to_json_param(service.timeouts.as_ref()),
+        to_json_param(service.retry.as_ref()),
+        to_json_param(service.circuit_breaker.as_ref()),
+        to_json_param(service.header_policy.as_ref()),
+        to_json_param(service.cors.as_ref()),
+        secrets_to_json_param(service.secrets.as_ref()),
+    ])
+}
+
+/// Build the route columns of a new route
+///
+/// New routes should carry their own id and the id of the parent service.
+///
+fn route_insert_params(
+    route: &Route,
+) -> Result<Vec<PrismaValue>, MappedErrors> {
+    match (route.id, &route.service) {
+        (Some(id), Parent::Record(service)) if service.id.is_some() => {
+            Ok(route_params(id, service.id.unwrap(), route))
+        }
+        (Some(id), Parent::Id(service_id)) => {
+            Ok(route_params(id, *service_id, route))
+        }
+        _ => creation_err("Route and service ids are required").as_error(),
+    }
+}
+
+/// Build the route columns in the declaration order of the route table
+///
+/// The methods are sorted and deduplicated, then the unique constraint over
+/// the service, path and methods does not depend on the declaration order.
+///
+pub(super) fn route_params(
+    route_id: Uuid,
+    service_id: Uuid,
+    route: &Route,
+) -> Vec<PrismaValue> {
+    let mut methods = route.methods.to_owned();
+    methods.sort_by_key(|method| method.to_string());
+    methods.dedup();
+
+    vec![
+        PrismaValue::String(route_id.to_string()),
+        PrismaValue::String(service_id.to_string()),
+        to_json_param(Some(&route.group)),
+        to_json_param(Some(&methods)),
+        PrismaValue::String(route.path.to_owned()),
+        to_text_param(Some(&route.protocol)),
+        to_json_param(route.allowed_sources.as_ref()),
+        match &route.secret_name {
+            Some(name) => PrismaValue::String(name.to_owned()),
+            None => PrismaValue::Null,
+        },
+        match route.accept_insecure_routing {
+            Some(accept) => PrismaValue::Boolean(accept),
+            None => PrismaValue::Null,
+        },
+        to_json_param(route.rate_limit.as_ref()),
+        to_json_param(route.timeouts.as_ref()),
+        to_json_param(route.retry.as_ref()),
+        match route.accept_non_idempotent_retries {
+            Some(accept) => PrismaValue::Boolean(accept),
+            None => PrismaValue::Null,
+        },
+        to_json_param(route.rewrite.as_ref()),
+        to_json_param(route.header_policy.as_ref()),
+        to_json_param(route.traffic_split.as_ref()),
+        to_json_param(route.mirror.as_ref()),
+        to_json_param(route.cors.as_ref()),
+        to_json_param(route.profile_projection.as_ref()),
+    ]
+}
+
+/// Serialize a value as a JSON column
+pub(super) fn to_json_param<T: Serialize>(value: Option<&T>) -> PrismaValue {
+    match value.map(to_value) {
+        Some(Ok(value)) => PrismaValue::String(value.to_string()),
+        _ => PrismaValue::Null,
+    }
+}
+
+/// Serialize a unit enum as a text column
+pub(super) fn to_text_param<T: Serialize>(value: Option<&T>) -> PrismaValue {
+    match value.map(to_value) {
+        Some(Ok(Value::String(value))) => PrismaValue::String(value),
+        _ => PrismaValue::Null,
+    }
+}
+
+/// Serialize the service secrets as a JSON column
+///
+/// The default serialization of the service secrets redacts the secret
+/// values. Here the values are kept, since the secrets should be already
+/// encrypted by the use case layer.
+///
+pub(super) fn secrets_to_json_param(
+    secrets: Option<&Vec<ServiceSecret>>,
+) -> PrismaValue {
+    let secrets = match secrets {
+        None => return PrismaValue::Null,
+        Some(secrets) => secrets,
+    };
+
+    let values = secrets
+        .iter()
+        .filter_map(|secret| {
+            let mut value = to_value(secret.secret()).ok()?;
+
+            value.as_object_mut()?.insert(
+                "name".to_string(),
+                Value::String(secret.name().to_owned()),
+            );
+
+            Some(value)
+        })
+        .collect::<Vec<Value>>();
+
+    PrismaValue::String(Value::Array(values).to_string())
+}
@@ -0,0 +1,197 @@
+use super::routes_registration::{
+    route_params, secrets_to_json_param, to_json_param, to_text_param,
+};
+use crate::repositories::connector::get_client;
+
+use async_trait::async_trait;
+use myc_core::domain::{
+    dtos::{
+        native_error_codes::NativeErrorCodes, route::Route, service::Service,
+    },
+    entities::RoutesUpdating,
+};
+use mycelium_base::{
+    dtos::Parent,
+    entities::UpdatingResponseKind,
+    utils::errors::{updating_err, MappedErrors},
+};
+use prisma_client_rust::{PrismaValue, Raw};
+use shaku::Component;
+use std::process::id as process_id;
+
+#[derive(Component)]
+#[shaku(interface = RoutesUpdating)]
+pub struct RoutesUpdatingSqlDbRepository {}
+
+#[async_trait]
+impl RoutesUpdating for RoutesUpdatingSqlDbRepository {
+    #[tracing::instrument(name = "update_service", skip_all)]
+    async fn update_service(
+        &self,
+        service: Service,
+    ) -> Result<UpdatingResponseKind<Service>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return updating_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Update the service
+        // ? -------------------------------------------------------------------
+
+        let service_id = match service.id {
+            Some(id) => id,
+            None => {
+                return updating_err("Service id is required").as_error();
+            }
+        };
+
+        match client
+            ._execute_raw(Raw::new(
+                "
+UPDATE gateway_service
+SET
+    name = {},
+    host = {},
+    targets = CAST({} AS JSONB),
+    load_balancing = {},
+    health_check = CAST({} AS JSONB),
+    timeouts = CAST({} AS JSONB),
+    retry = CAST({} AS JSONB),
+    circuit_breaker = CAST({} AS JSONB),
+    header_policy = CAST({} AS JSONB),
+    cors = CAST({} AS JSONB),
+    secrets = CAST({} AS JSONB),
+    updated = now()
+WHERE id = {}
+                ",
+                vec![
+                    PrismaValue::String(service.name.to_owned()),
+                    PrismaValue::String(service.host.to_owned()),
+                    to_json_param(service.targets.as_ref()),
+                    to_text_param(service.load_balancing.as_ref()),
+                    to_json_param(service.health_check.as_ref()),
+                    to_json_param(service.timeouts.as_ref()),
+                    to_json_param(service.retry.as_ref()),
+                    to_json_param(service.circuit_breaker.as_ref()),
+                    to_json_param(service.header_policy.as_ref()),
+                    to_json_param(service.cors.as_ref()),
+                    secrets_to_json_param(service.secrets.as_ref()),
+                    PrismaValue::String(service_id.to_string()),
+                ],
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(UpdatingResponseKind::NotUpdated(
+                service,
+                "Service not found".to_string(),
+            )),
+            Ok(_) => Ok(UpdatingResponseKind::Updated(service)),
+            Err(err) => updating_err(format!(
+                "Unexpected error detected on update record: {err}"
+            ))
+            .as_error(),
+        }
+    }
+
+    #[tracing::instrument(name = "update_route", skip_all)]
+    async fn update_route(
+        &self,
+        route: Route,
+    ) -> Result<UpdatingResponseKind<Route>, MappedErrors> {
+        // ? -------------------------------------------------------------------
+        // ? Try to build the prisma client
+        // ? -------------------------------------------------------------------
+
+        let tmp_client = get_client().await;
+
+        let client = match tmp_client.get(&process_id()) {
+            None => {
+                return updating_err(String::from(
+                    "Prisma Client error. Could not fetch client.",
+                ))
+                .with_code(NativeErrorCodes::MYC00001)
+                .as_error()
+            }
+            Some(res) => res,
+        };
+
+        // ? -------------------------------------------------------------------
+        // ? Update the route
+        //
+        // The route parameters follow the table declaration order. The route
+        // id is used as the filter.
+        //
+        // ? -------------------------------------------------------------------
+
+        let (route_id, service_id) = match (route.id, &route.service) {
+            (Some(id), Parent::Record(service)) if service.id.is_some() => {
+                (id, service.id.unwrap())
+            }
+            (Some(id), Parent::Id(service_id)) => (id, *service_id),
+            _ => {
+                return updating_err("Route and service ids are required")
+                    .as_error();
+            }
+        };
+
+        let mut params = route_params(route_id, service_id, &route);
+        let route_id_param = params.remove(0);
+        params.push(route_id_param);
+
+        match client
+            ._execute_raw(Raw::new(
+                "
+UPDATE gateway_route
+SET
+    service_id = {},
+    \"group\" = CAST({} AS JSONB),
+    methods = CAST({} AS JSONB),
+    path = {},
+    protocol = {},
+    allowed_sources = CAST({} AS JSONB),
+    secret_name = {},
+    accept_insecure_routing = {},
+    rate_limit = CAST({} AS JSONB),
+    timeouts = CAST({} AS JSONB),
+    retry = CAST({} AS JSONB),
+    accept_non_idempotent_retries = {},
+    rewrite = CAST({} AS JSONB),
+    header_policy = CAST({} AS JSONB),
+    traffic_split = CAST({} AS JSONB),
+    mirror = CAST({} AS JSONB),
+    cors = CAST({} AS JSONB),
+    profile_projection = CAST({} AS JSONB),
+    updated = now()
+WHERE id = {}
+                ",
+                params,
+            ))
+            .exec()
+            .await
+        {
+            Ok(0) => Ok(UpdatingResponseKind::NotUpdated(
+                route,
+                "Route not found".to_string(),
+            )),
+            Ok(_) => Ok(UpdatingResponseKind::Updated(route)),
+            Err(err) => updating_err(format!(
+                "Unexpected error detected on update record: {err}"
+            ))
+            .as_error(),
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use crate::{get_vault_config, optional_config::OptionalConfig};
+use crate::{
+    get_vault_config, optional_config::OptionalConfig,
+    settings::SECRET_RESOLUTION_DURATION,
+};
 
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Debug, str::FromStr};
+use std::{collections::HashMap, fmt::Debug, str::FromStr, time::Instant};
 use utoipa::ToSchema;
 
 /// A secret resolver
@@ -103,7 +106,38 @@ impl<T: FromStr + Debug + Clone> SecretResolver<T> {
         }
     }
 
+    /// Resolve the value, including the ones stored in the vault
+    ///
+    /// The resolution time is recorded at the
+    /// `myc_secret_resolution_duration_seconds` metric.
+    ///
     pub async fn async_get_or_error(&self) -> Result<T, MappedErrors> {
+        let started_at = Instant::now();
+        let result = self.resolve().await;
+
+        SECRET_RESOLUTION_DURATION
+            .with_label_values(&[
+                self.source_name(),
+                match result.is_ok() {
+                    true => "ok",
+                    false => "error",
+                },
+            ])
+            .observe(started_at.elapsed().as_secs_f64());
+
+        result
+    }
+
+    /// The name of the secret source, used as metric label
+    fn source_name(&self) -> &'static str {
+        match self {
+            SecretResolver::Value(_) => "value",
+            SecretResolver::Env(_) => "env",
+            SecretResolver::Vault { .. } => "vault",
+        }
+    }
+
+    async fn resolve(&self) -> Result<T, MappedErrors> {
         match self {
             //
             // Return the value directly
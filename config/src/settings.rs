@@ -1,11 +1,22 @@
 use crate::{optional_config::OptionalConfig, VaultConfig};
 
 use lazy_static::lazy_static;
+use prometheus::{register_histogram_vec, HistogramVec};
 use std::{path::PathBuf, sync::Mutex};
 
 lazy_static! {
     #[derive(Debug)]
     pub(crate) static ref VAULT_CONFIG: Mutex<Option<OptionalConfig<VaultConfig>>> = Mutex::new(None);
+
+    /// The time to resolve secrets, labelled by the secret source and the
+    /// resolution outcome
+    pub(crate) static ref SECRET_RESOLUTION_DURATION: HistogramVec =
+        register_histogram_vec!(
+            "myc_secret_resolution_duration_seconds",
+            "The time to resolve the configuration secrets",
+            &["source", "outcome"]
+        )
+        .expect("Unable to register the secret resolution metrics");
 }
 
 pub async fn init_vault_config_from_file(
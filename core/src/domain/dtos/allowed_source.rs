@@ -0,0 +1,332 @@
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::IpAddr,
+    str::FromStr,
+};
+use tokio::net::lookup_host;
+use tracing::warn;
+
+/// The addresses of the allowed source hostnames, indexed by the hostname
+pub type ResolvedHostnames = HashMap<String, Vec<IpAddr>>;
+
+/// A source allowed to reach a route
+///
+/// Allowed sources are declared as plain strings at the `allowedSources` field
+/// of the routes file. Each entry could be a single IP address, a CIDR range or
+/// a hostname.
+///
+/// # Example
+///
+/// ```yaml
+/// allowedSources:
+/// - 192.168.1.10
+/// - 10.0.0.0/8
+/// - internal.example.com
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllowedSource {
+    /// A single IP address (v4 or v6)
+    Ip(IpAddr),
+
+    /// A CIDR range
+    ///
+    /// The network address and the prefix length of the range.
+    ///
+    Cidr { network: IpAddr, prefix: u8 },
+
+    /// A hostname
+    ///
+    /// Hostnames are resolved when the routes are loaded or reloaded, and
+    /// periodically refreshed. The request source should match any of the
+    /// resolved addresses.
+    ///
+    Hostname(String),
+}
+
+impl FromStr for AllowedSource {
+    type Err = MappedErrors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = s.trim();
+
+        if source.is_empty() {
+            return dto_err("Allowed source should not be empty").as_error();
+        }
+
+        //
+        // CIDR ranges
+        //
+        if let Some((network, prefix)) = source.split_once("/") {
+            let network = match IpAddr::from_str(network) {
+                Ok(network) => network,
+                Err(err) => {
+                    return dto_err(format!(
+                        "Invalid CIDR network ({source}): {err}"
+                    ))
+                    .as_error()
+                }
+            };
+
+            let prefix = match prefix.parse::<u8>() {
+                Ok(prefix) => prefix,
+                Err(err) => {
+                    return dto_err(format!(
+                        "Invalid CIDR prefix ({source}): {err}"
+                    ))
+                    .as_error()
+                }
+            };
+
+            let max_prefix = match network {
+                IpAddr::V4(_) => 32,
+                IpAddr::V6(_) => 128,
+            };
+
+            if prefix > max_prefix {
+                return dto_err(format!(
+                    "Invalid CIDR prefix ({source}): should be lower than or equal to {max_prefix}"
+                ))
+                .as_error();
+            }
+
+            return Ok(Self::Cidr { network, prefix });
+        }
+
+        //
+        // Single IP addresses
+        //
+        if let Ok(ip) = IpAddr::from_str(source) {
+            return Ok(Self::Ip(ip));
+        }
+
+        //
+        // Hostnames
+        //
+        let is_valid_hostname = source.len() <= 253
+            && source.split(".").all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && !label.starts_with("-")
+                    && !label.ends_with("-")
+                    && label
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+
+        if !is_valid_hostname {
+            return dto_err(format!(
+                "Invalid allowed source (expected IP, CIDR or hostname): {source}"
+            ))
+            .as_error();
+        }
+
+        Ok(Self::Hostname(source.to_lowercase()))
+    }
+}
+
+impl Display for AllowedSource {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            AllowedSource::Ip(ip) => write!(f, "{ip}"),
+            AllowedSource::Cidr { network, prefix } => {
+                write!(f, "{network}/{prefix}")
+            }
+            AllowedSource::Hostname(hostname) => write!(f, "{hostname}"),
+        }
+    }
+}
+
+impl AllowedSource {
+    /// Resolve the addresses of a hostname source
+    ///
+    /// Returns an empty list for IP and CIDR sources or if the hostname could
+    /// not be resolved.
+    ///
+    pub async fn resolve(&self) -> Vec<IpAddr> {
+        let hostname = match self {
+            AllowedSource::Hostname(hostname) => hostname,
+            _ => return vec![],
+        };
+
+        match lookup_host((hostname.as_str(), 0)).await {
+            Ok(addresses) => addresses
+                .map(|address| address.ip().to_canonical())
+                .collect(),
+            Err(err) => {
+                warn!("Unable to resolve allowed source {hostname}: {err}");
+
+                vec![]
+            }
+        }
+    }
+
+    /// Check if the source address matches the allowed source
+    ///
+    /// Hostnames are matched against the addresses resolved during the routes
+    /// loading. IPv4-mapped IPv6 addresses are compared as their IPv4
+    /// equivalent.
+    ///
+    pub fn matches(
+        &self,
+        source: IpAddr,
+        resolved_hostnames: &ResolvedHostnames,
+    ) -> bool {
+        match self {
+            AllowedSource::Hostname(hostname) => {
+                let source = source.to_canonical();
+
+                resolved_hostnames
+                    .get(hostname)
+                    .map(|addresses| addresses.contains(&source))
+                    .unwrap_or(false)
+            }
+            _ => self.contains_address(source),
+        }
+    }
+
+    /// Check if the source address matches the allowed source without
+    /// resolving hostnames
+    ///
+    /// Only IP addresses and CIDR ranges are matched. Hostnames never match.
+    ///
+    pub fn contains_address(&self, source: IpAddr) -> bool {
+        let source = source.to_canonical();
+
+        match self {
+            AllowedSource::Ip(ip) => ip.to_canonical() == source,
+            AllowedSource::Cidr { network, prefix } => {
+                cidr_contains(network.to_canonical(), *prefix, source)
+            }
+            AllowedSource::Hostname(_) => false,
+        }
+    }
+}
+
+fn cidr_contains(network: IpAddr, prefix: u8, source: IpAddr) -> bool {
+    match (network, source) {
+        (IpAddr::V4(network), IpAddr::V4(source)) => {
+            let mask = match prefix {
+                0 => 0,
+                _ => u32::MAX << (32 - prefix as u32),
+            };
+
+            u32::from(network) & mask == u32::from(source) & mask
+        }
+        (IpAddr::V6(network), IpAddr::V6(source)) => {
+            let mask = match prefix {
+                0 => 0,
+                _ => u128::MAX << (128 - prefix as u32),
+            };
+
+            u128::from(network) & mask == u128::from(source) & mask
+        }
+        _ => false,
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_allowed_sources_works() {
+        assert_eq!(
+            AllowedSource::from_str("192.168.1.10").unwrap(),
+            AllowedSource::Ip(IpAddr::from_str("192.168.1.10").unwrap())
+        );
+
+        assert_eq!(
+            AllowedSource::from_str("10.0.0.0/8").unwrap(),
+            AllowedSource::Cidr {
+                network: IpAddr::from_str("10.0.0.0").unwrap(),
+                prefix: 8
+            }
+        );
+
+        assert_eq!(
+            AllowedSource::from_str("fd00::/8").unwrap(),
+            AllowedSource::Cidr {
+                network: IpAddr::from_str("fd00::").unwrap(),
+                prefix: 8
+            }
+        );
+
+        assert_eq!(
+            AllowedSource::from_str("Internal.Example.com").unwrap(),
+            AllowedSource::Hostname("internal.example.com".to_string())
+        );
+
+        assert!(AllowedSource::from_str("").is_err());
+        assert!(AllowedSource::from_str("10.0.0.0/33").is_err());
+        assert!(AllowedSource::from_str("10.0.0/8").is_err());
+        assert!(AllowedSource::from_str("invalid_host!").is_err());
+    }
+
+    #[test]
+    fn test_allowed_sources_matches_works() {
+        let resolved = ResolvedHostnames::from([(
+            "internal.example.com".to_string(),
+            vec![IpAddr::from_str("10.1.2.3").unwrap()],
+        )]);
+
+        let ip = AllowedSource::from_str("192.168.1.10").unwrap();
+        assert!(
+            ip.matches(IpAddr::from_str("192.168.1.10").unwrap(), &resolved)
+        );
+        assert!(
+            !ip.matches(IpAddr::from_str("192.168.1.11").unwrap(), &resolved)
+        );
+
+        let cidr = AllowedSource::from_str("10.0.0.0/8").unwrap();
+        assert!(
+            cidr.matches(IpAddr::from_str("10.20.30.40").unwrap(), &resolved)
+        );
+        assert!(!cidr.matches(IpAddr::from_str("11.0.0.1").unwrap(), &resolved));
+        assert!(cidr
+            .matches(IpAddr::from_str("::ffff:10.1.1.1").unwrap(), &resolved));
+
+        let any = AllowedSource::from_str("0.0.0.0/0").unwrap();
+        assert!(any.matches(IpAddr::from_str("8.8.8.8").unwrap(), &resolved));
+
+        let cidr_v6 = AllowedSource::from_str("fd00::/8").unwrap();
+        assert!(
+            cidr_v6.matches(IpAddr::from_str("fd12::1").unwrap(), &resolved)
+        );
+        assert!(
+            !cidr_v6.matches(IpAddr::from_str("fe80::1").unwrap(), &resolved)
+        );
+        assert!(
+            !cidr_v6.matches(IpAddr::from_str("10.0.0.1").unwrap(), &resolved)
+        );
+
+        let hostname = AllowedSource::from_str("internal.example.com").unwrap();
+        assert!(
+            hostname.matches(IpAddr::from_str("10.1.2.3").unwrap(), &resolved)
+        );
+        assert!(hostname
+            .matches(IpAddr::from_str("::ffff:10.1.2.3").unwrap(), &resolved));
+        assert!(
+            !hostname.matches(IpAddr::from_str("10.1.2.4").unwrap(), &resolved)
+        );
+
+        let unresolved = AllowedSource::from_str("other.example.com").unwrap();
+        assert!(!unresolved
+            .matches(IpAddr::from_str("10.1.2.3").unwrap(), &resolved));
+    }
+
+    #[tokio::test]
+    async fn test_allowed_sources_resolve_skips_addresses() {
+        let ip = AllowedSource::from_str("192.168.1.10").unwrap();
+        assert!(ip.resolve().await.is_empty());
+
+        let cidr = AllowedSource::from_str("10.0.0.0/8").unwrap();
+        assert!(cidr.resolve().await.is_empty());
+    }
+}
@@ -0,0 +1,426 @@
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+use uuid::Uuid;
+
+/// The default number of requests needed to evaluate the failure rate
+pub const DEFAULT_CIRCUIT_BREAKER_MINIMUM_REQUESTS: u32 = 10;
+
+/// The default size of the window used to count the requests in seconds
+pub const DEFAULT_CIRCUIT_BREAKER_WINDOW: u64 = 60;
+
+/// The default number of trial requests accepted by half-open circuits
+pub const DEFAULT_CIRCUIT_BREAKER_HALF_OPEN_REQUESTS: u32 = 1;
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerConfig {
+    /// The failure rate which opens the circuit
+    ///
+    /// The rate is expressed as a percentage of the requests sent to the
+    /// service during the window.
+    ///
+    pub failure_rate_threshold: u8,
+
+    /// The time the circuit remains open before accepting trial requests
+    pub cool_down_in_secs: u64,
+
+    /// The number of requests needed to evaluate the failure rate
+    ///
+    /// Default to `10`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_requests: Option<u32>,
+
+    /// The size of the window used to count the requests in seconds
+    ///
+    /// Default to `60`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_in_secs: Option<u64>,
+
+    /// The number of trial requests accepted while the circuit is half-open
+    ///
+    /// The circuit is closed when all trial requests succeed. Default to `1`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub half_open_requests: Option<u32>,
+}
+
+impl CircuitBreakerConfig {
+    pub fn cool_down(&self) -> Duration {
+        Duration::seconds(self.cool_down_in_secs as i64)
+    }
+
+    pub fn minimum_requests(&self) -> u32 {
+        self.minimum_requests
+            .unwrap_or(DEFAULT_CIRCUIT_BREAKER_MINIMUM_REQUESTS)
+            .max(1)
+    }
+
+    pub fn window_in_secs(&self) -> u64 {
+        self.window_in_secs
+            .unwrap_or(DEFAULT_CIRCUIT_BREAKER_WINDOW)
+            .max(1)
+    }
+
+    pub fn half_open_requests(&self) -> u32 {
+        self.half_open_requests
+            .unwrap_or(DEFAULT_CIRCUIT_BREAKER_HALF_OPEN_REQUESTS)
+            .max(1)
+    }
+
+    /// Check if the configuration values are in the accepted ranges
+    pub fn is_valid(&self) -> bool {
+        (1..=100).contains(&self.failure_rate_threshold)
+            && self.cool_down_in_secs > 0
+            && self.minimum_requests != Some(0)
+            && self.window_in_secs != Some(0)
+            && self.half_open_requests != Some(0)
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum CircuitState {
+    /// Requests are sent to the service
+    Closed,
+
+    /// Requests are rejected without reaching the service
+    Open,
+
+    /// A limited number of trial requests are sent to the service
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// The numeric value of the state exported as metric
+    pub fn as_metric_value(&self) -> u64 {
+        match self {
+            CircuitState::Closed => 0,
+            CircuitState::HalfOpen => 1,
+            CircuitState::Open => 2,
+        }
+    }
+}
+
+/// The circuit breaker state of a downstream service
+///
+/// The circuit opens when the failure rate of the requests sent to the service
+/// during the window reaches the configured threshold. After the cool-down
+/// period it becomes half-open and accepts trial requests, closing again when
+/// they succeed or reopening on the first failure.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreaker {
+    /// The service id
+    pub service_id: Option<Uuid>,
+
+    /// The service name
+    pub service_name: String,
+
+    /// The current circuit state
+    pub state: CircuitState,
+
+    /// The number of requests sent during the current window
+    pub requests: u32,
+
+    /// The number of failed requests sent during the current window
+    pub failures: u32,
+
+    /// The start date of the current window
+    pub window_started_at: DateTime<Local>,
+
+    /// The date when the circuit was last opened
+    pub opened_at: Option<DateTime<Local>>,
+
+    /// The reason of the last failed request
+    pub last_failure: Option<String>,
+
+    /// The number of trial requests accepted while half-open
+    #[serde(skip)]
+    half_open_requests: u32,
+
+    /// The number of succeeded trial requests while half-open
+    #[serde(skip)]
+    half_open_successes: u32,
+}
+
+impl CircuitBreaker {
+    pub fn new(
+        service_id: Option<Uuid>,
+        service_name: String,
+        now: DateTime<Local>,
+    ) -> Self {
+        Self {
+            service_id,
+            service_name,
+            state: CircuitState::Closed,
+            requests: 0,
+            failures: 0,
+            window_started_at: now,
+            opened_at: None,
+            last_failure: None,
+            half_open_requests: 0,
+            half_open_successes: 0,
+        }
+    }
+
+    /// Try to accept a request to the service
+    ///
+    /// Open circuits become half-open after the cool-down period. Returns the
+    /// time in seconds until the circuit accepts requests again if the request
+    /// should be rejected.
+    ///
+    pub fn try_acquire(
+        &mut self,
+        config: &CircuitBreakerConfig,
+        now: DateTime<Local>,
+    ) -> Result<(), u64> {
+        if self.state == CircuitState::Open {
+            let reopens_at = self.opened_at.unwrap_or(now) + config.cool_down();
+
+            if now < reopens_at {
+                return Err((reopens_at - now).num_seconds().max(1) as u64);
+            }
+
+            self.state = CircuitState::HalfOpen;
+            self.half_open_requests = 0;
+            self.half_open_successes = 0;
+        }
+
+        match self.state {
+            CircuitState::HalfOpen => {
+                //
+                // Trial requests without a registered result for another
+                // cool-down period are considered lost, then new trials are
+                // accepted.
+                //
+                let trials_expire_at =
+                    self.opened_at.unwrap_or(now) + config.cool_down() * 2;
+
+                if self.half_open_requests >= config.half_open_requests() {
+                    if now < trials_expire_at {
+                        return Err(1);
+                    }
+
+                    self.opened_at = Some(now - config.cool_down());
+                    self.half_open_requests = 0;
+                    self.half_open_successes = 0;
+                }
+
+                self.half_open_requests += 1;
+            }
+            _ => self.refresh_window(config, now),
+        }
+
+        Ok(())
+    }
+
+    /// Register a request succeeded by the service
+    pub fn register_success(
+        &mut self,
+        config: &CircuitBreakerConfig,
+        now: DateTime<Local>,
+    ) {
+        match self.state {
+            CircuitState::HalfOpen => {
+                self.half_open_successes += 1;
+
+                if self.half_open_successes >= config.half_open_requests() {
+                    self.close(now);
+                }
+            }
+            CircuitState::Closed => {
+                self.refresh_window(config, now);
+                self.requests = self.requests.saturating_add(1);
+            }
+            CircuitState::Open => (),
+        }
+    }
+
+    /// Register a request failed by the service
+    pub fn register_failure(
+        &mut self,
+        reason: String,
+        config: &CircuitBreakerConfig,
+        now: DateTime<Local>,
+    ) {
+        self.last_failure = Some(reason);
+
+        match self.state {
+            CircuitState::HalfOpen => self.open(now),
+            CircuitState::Closed => {
+                self.refresh_window(config, now);
+                self.requests = self.requests.saturating_add(1);
+                self.failures = self.failures.saturating_add(1);
+
+                if self.requests >= config.minimum_requests()
+                    && self.failures as u64 * 100
+                        >= config.failure_rate_threshold as u64
+                            * self.requests as u64
+                {
+                    self.open(now);
+                }
+            }
+            CircuitState::Open => (),
+        }
+    }
+
+    /// Release a request accepted without a registered result
+    ///
+    /// Requests which never reached the service, like the ones failed by the
+    /// gateway itself, are not counted. Half-open circuits accept a new trial
+    /// request in place of the released one.
+    ///
+    pub fn release(&mut self) {
+        if self.state == CircuitState::HalfOpen {
+            self.half_open_requests = self.half_open_requests.saturating_sub(1);
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state == CircuitState::Open
+    }
+
+    fn refresh_window(
+        &mut self,
+        config: &CircuitBreakerConfig,
+        now: DateTime<Local>,
+    ) {
+        if now - self.window_started_at
+            >= Duration::seconds(config.window_in_secs() as i64)
+        {
+            self.requests = 0;
+            self.failures = 0;
+            self.window_started_at = now;
+        }
+    }
+
+    fn open(&mut self, now: DateTime<Local>) {
+        self.state = CircuitState::Open;
+        self.opened_at = Some(now);
+        self.half_open_requests = 0;
+        self.half_open_successes = 0;
+    }
+
+    fn close(&mut self, now: DateTime<Local>) {
+        self.state = CircuitState::Closed;
+        self.requests = 0;
+        self.failures = 0;
+        self.window_started_at = now;
+        self.half_open_requests = 0;
+        self.half_open_successes = 0;
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_rate_threshold: 50,
+            cool_down_in_secs: 10,
+            minimum_requests: Some(4),
+            window_in_secs: Some(60),
+            half_open_requests: None,
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_transitions_works() {
+        let config = build_config();
+        let now = Local::now();
+        let mut breaker = CircuitBreaker::new(None, "service".to_string(), now);
+
+        //
+        // The failure rate is evaluated only after the minimum requests
+        //
+        for _ in 0..3 {
+            assert!(breaker.try_acquire(&config, now).is_ok());
+            breaker.register_failure("status 503".to_string(), &config, now);
+        }
+        assert_eq!(breaker.state, CircuitState::Closed);
+
+        assert!(breaker.try_acquire(&config, now).is_ok());
+        breaker.register_failure("status 503".to_string(), &config, now);
+        assert!(breaker.is_open());
+        assert_eq!(breaker.try_acquire(&config, now), Err(10));
+
+        //
+        // After the cool-down a single trial request is accepted
+        //
+        let later = now + Duration::seconds(10);
+        assert!(breaker.try_acquire(&config, later).is_ok());
+        assert_eq!(breaker.state, CircuitState::HalfOpen);
+        assert!(breaker.try_acquire(&config, later).is_err());
+
+        breaker.register_failure("timeout".to_string(), &config, later);
+        assert!(breaker.is_open());
+
+        let later = later + Duration::seconds(10);
+        assert!(breaker.try_acquire(&config, later).is_ok());
+        breaker.register_success(&config, later);
+        assert_eq!(breaker.state, CircuitState::Closed);
+        assert_eq!(breaker.requests, 0);
+    }
+
+    #[test]
+    fn test_released_trials_are_accepted_again() {
+        let config = build_config();
+        let now = Local::now();
+        let mut breaker = CircuitBreaker::new(None, "service".to_string(), now);
+
+        for _ in 0..4 {
+            breaker.register_failure("status 503".to_string(), &config, now);
+        }
+        assert!(breaker.is_open());
+
+        let later = now + Duration::seconds(10);
+        assert!(breaker.try_acquire(&config, later).is_ok());
+        assert!(breaker.try_acquire(&config, later).is_err());
+
+        breaker.release();
+        assert!(breaker.try_acquire(&config, later).is_ok());
+        assert_eq!(breaker.state, CircuitState::HalfOpen);
+    }
+
+    #[test]
+    fn test_circuit_breaker_window_resets_counters() {
+        let config = build_config();
+        let now = Local::now();
+        let mut breaker = CircuitBreaker::new(None, "service".to_string(), now);
+
+        for _ in 0..3 {
+            breaker.register_failure("status 502".to_string(), &config, now);
+        }
+
+        let later = now + Duration::seconds(60);
+        breaker.register_failure("status 502".to_string(), &config, later);
+        breaker.register_success(&config, later);
+
+        assert_eq!(breaker.state, CircuitState::Closed);
+        assert_eq!(breaker.requests, 2);
+        assert_eq!(breaker.failures, 1);
+    }
+}
@@ -0,0 +1,415 @@
+use super::http::HttpMethod;
+
+use actix_web::http::{
+    header::{
+        HeaderMap, HeaderName, HeaderValue, ACCESS_CONTROL_ALLOW_CREDENTIALS,
+        ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS,
+        ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_HEADERS,
+        ACCESS_CONTROL_REQUEST_METHOD, VARY,
+    },
+    Method,
+};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{ToResponse, ToSchema};
+
+/// The default time in seconds to cache the preflight responses
+pub const DEFAULT_CORS_MAX_AGE: u64 = 3600;
+
+/// The origin value allowing any origin
+const ANY_ORIGIN: &str = "*";
+
+/// The CORS policy of a route
+///
+/// Policies declared by the route replace the ones declared by the service.
+/// Routes without policies use the global CORS settings.
+///
+/// # Example
+///
+/// ```yaml
+/// cors:
+///   allowedOrigins:
+///   - https://app.example.com
+///   allowedMethods:
+///   - GET
+///   - POST
+///   allowedHeaders:
+///   - Authorization
+///   - Content-Type
+///   exposeHeaders:
+///   - X-Request-Id
+///   allowCredentials: true
+///   maxAgeInSecs: 600
+/// ```
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CorsPolicy {
+    /// The allowed origins
+    ///
+    /// The `*` origin allows any origin.
+    ///
+    pub allowed_origins: Vec<String>,
+
+    /// The allowed methods
+    ///
+    /// If empty, any method is allowed.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_methods: Option<Vec<HttpMethod>>,
+
+    /// The allowed request headers
+    ///
+    /// If empty, any header is allowed.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_headers: Option<Vec<String>>,
+
+    /// The response headers exposed to the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expose_headers: Option<Vec<String>>,
+
+    /// If the client could send credentials
+    ///
+    /// Default to `false`. Credentials are not accepted together with the `*`
+    /// origin.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_credentials: Option<bool>,
+
+    /// The time in seconds to cache the preflight responses
+    ///
+    /// Default to `3600`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_in_secs: Option<u64>,
+}
+
+impl CorsPolicy {
+    pub fn allow_credentials(&self) -> bool {
+        self.allow_credentials.unwrap_or(false)
+    }
+
+    pub fn max_age_in_secs(&self) -> u64 {
+        self.max_age_in_secs.unwrap_or(DEFAULT_CORS_MAX_AGE)
+    }
+
+    /// Check if the origins and the header names are valid
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        if self.allow_credentials()
+            && self
+                .allowed_origins
+                .iter()
+                .any(|origin| origin == ANY_ORIGIN)
+        {
+            return dto_err(
+                "Invalid CORS policy: credentials are not allowed for any origin",
+            )
+            .as_error();
+        }
+
+        for name in self
+            .allowed_headers
+            .iter()
+            .flatten()
+            .chain(self.expose_headers.iter().flatten())
+        {
+            if let Err(err) = HeaderName::from_str(name) {
+                return dto_err(format!(
+                    "Invalid CORS policy: invalid header name {name}: {err}"
+                ))
+                .as_error();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if the origin is allowed
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|allowed| allowed == ANY_ORIGIN || allowed == origin)
+    }
+
+    /// Build the response headers of a preflight request
+    ///
+    /// Returns `None` if the origin, the requested method or the requested
+    /// headers are not allowed.
+    ///
+    pub fn preflight_headers(
+        &self,
+        origin: &str,
+        request_headers: &HeaderMap,
+    ) -> Option<HeaderMap> {
+        if !self.allows_origin(origin) {
+            return None;
+        }
+
+        let method = request_headers
+            .get(ACCESS_CONTROL_REQUEST_METHOD)
+            .and_then(|value| Method::from_bytes(value.as_bytes()).ok())?;
+
+        if let Some(methods) = &self.allowed_methods {
+            let method = HttpMethod::from_reqwest_method(method.to_owned());
+
+            if !methods.contains(&method) && !methods.contains(&HttpMethod::All)
+            {
+                return None;
+            }
+        }
+
+        let requested_headers = request_headers
+            .get_all(ACCESS_CONTROL_REQUEST_HEADERS)
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<String>>();
+
+        if let Some(allowed_headers) = &self.allowed_headers {
+            if !requested_headers.iter().all(|name| {
+                allowed_headers
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(name))
+            }) {
+                return None;
+            }
+        }
+
+        let mut headers = HeaderMap::new();
+
+        self.insert_origin_headers(origin, &mut headers);
+
+        let methods = match &self.allowed_methods {
+            None => method.to_string(),
+            Some(methods) => methods
+                .iter()
+                .filter(|method| {
+                    ![HttpMethod::All, HttpMethod::None].contains(method)
+                })
+                .map(|method| method.to_string())
+                .chain(match methods.contains(&HttpMethod::All) {
+                    true => Some(method.to_string()),
+                    false => None,
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        insert_value(&mut headers, ACCESS_CONTROL_ALLOW_METHODS, &methods);
+
+        let allowed_headers = match &self.allowed_headers {
+            None => requested_headers.join(", "),
+            Some(allowed_headers) => allowed_headers.join(", "),
+        };
+
+        if !allowed_headers.is_empty() {
+            insert_value(
+                &mut headers,
+                ACCESS_CONTROL_ALLOW_HEADERS,
+                &allowed_headers,
+            );
+        }
+
+        insert_value(
+            &mut headers,
+            ACCESS_CONTROL_MAX_AGE,
+            &self.max_age_in_secs().to_string(),
+        );
+
+        Some(headers)
+    }
+
+    /// Insert the CORS headers into the response of an actual request
+    ///
+    /// The CORS headers sent by the upstream are replaced. Responses to
+    /// origins not allowed have them removed.
+    ///
+    pub fn apply_response_headers(
+        &self,
+        origin: &str,
+        headers: &mut HeaderMap,
+    ) {
+        if !self.allows_origin(origin) {
+            for name in [
+                ACCESS_CONTROL_ALLOW_ORIGIN,
+                ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                ACCESS_CONTROL_EXPOSE_HEADERS,
+            ] {
+                headers.remove(name);
+            }
+
+            return;
+        }
+
+        self.insert_origin_headers(origin, headers);
+
+        match &self.expose_headers {
+            Some(expose_headers) if !expose_headers.is_empty() => {
+                insert_value(
+                    headers,
+                    ACCESS_CONTROL_EXPOSE_HEADERS,
+                    &expose_headers.join(", "),
+                );
+            }
+            _ => {
+                headers.remove(ACCESS_CONTROL_EXPOSE_HEADERS);
+            }
+        }
+    }
+
+    /// Insert the allowed origin and the credentials headers
+    ///
+    /// The `*` origin is answered as is when credentials are not allowed.
+    /// Otherwise, the request origin is echoed and the response varies by
+    /// origin.
+    ///
+    fn insert_origin_headers(&self, origin: &str, headers: &mut HeaderMap) {
+        let any_origin = !self.allow_credentials()
+            && self
+                .allowed_origins
+                .iter()
+                .any(|origin| origin == ANY_ORIGIN);
+
+        match any_origin {
+            true => insert_value(headers, ACCESS_CONTROL_ALLOW_ORIGIN, "*"),
+            false => {
+                insert_value(headers, ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+                headers.append(VARY, HeaderValue::from_static("Origin"));
+            }
+        }
+
+        match self.allow_credentials() {
+            true => {
+                insert_value(headers, ACCESS_CONTROL_ALLOW_CREDENTIALS, "true")
+            }
+            false => {
+                headers.remove(ACCESS_CONTROL_ALLOW_CREDENTIALS);
+            }
+        }
+    }
+}
+
+/// Insert a header value, ignoring values which are not valid header values
+fn insert_value(headers: &mut HeaderMap, name: HeaderName, value: &str) {
+    if let Ok(value) = HeaderValue::from_str(value) {
+        headers.insert(name, value);
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_policy() -> CorsPolicy {
+        CorsPolicy {
+            allowed_origins: vec!["https://app.example.com".to_string()],
+            allowed_methods: Some(vec![HttpMethod::Get, HttpMethod::Post]),
+            allowed_headers: Some(vec!["Content-Type".to_string()]),
+            expose_headers: Some(vec!["X-Request-Id".to_string()]),
+            allow_credentials: Some(true),
+            max_age_in_secs: None,
+        }
+    }
+
+    fn build_preflight(
+        method: &'static str,
+        headers: &'static str,
+    ) -> HeaderMap {
+        let mut request_headers = HeaderMap::new();
+
+        request_headers.insert(
+            ACCESS_CONTROL_REQUEST_METHOD,
+            HeaderValue::from_static(method),
+        );
+        request_headers.insert(
+            ACCESS_CONTROL_REQUEST_HEADERS,
+            HeaderValue::from_static(headers),
+        );
+
+        request_headers
+    }
+
+    #[test]
+    fn test_preflight_headers_work() {
+        let policy = build_policy();
+
+        let headers = policy
+            .preflight_headers(
+                "https://app.example.com",
+                &build_preflight("POST", "content-type"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://app.example.com"
+        );
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_ALLOW_METHODS).unwrap(),
+            "GET, POST"
+        );
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_ALLOW_CREDENTIALS).unwrap(),
+            "true"
+        );
+        assert_eq!(headers.get(ACCESS_CONTROL_MAX_AGE).unwrap(), "3600");
+    }
+
+    #[test]
+    fn test_preflight_rejects_disallowed_requests() {
+        let policy = build_policy();
+
+        assert!(policy
+            .preflight_headers(
+                "https://evil.example.com",
+                &build_preflight("POST", "content-type"),
+            )
+            .is_none());
+
+        assert!(policy
+            .preflight_headers(
+                "https://app.example.com",
+                &build_preflight("DELETE", "content-type"),
+            )
+            .is_none());
+
+        assert!(policy
+            .preflight_headers(
+                "https://app.example.com",
+                &build_preflight("POST", "x-admin-token"),
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_any_origin_policies_work() {
+        let mut policy = build_policy();
+
+        policy.allowed_origins = vec!["*".to_string()];
+        assert!(policy.validate().is_err());
+
+        policy.allow_credentials = None;
+        assert!(policy.validate().is_ok());
+
+        let mut headers = HeaderMap::new();
+        policy
+            .apply_response_headers("https://widget.example.org", &mut headers);
+
+        assert_eq!(headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(), "*");
+        assert_eq!(
+            headers.get(ACCESS_CONTROL_EXPOSE_HEADERS).unwrap(),
+            "X-Request-Id"
+        );
+        assert!(headers.get(VARY).is_none());
+    }
+}
@@ -0,0 +1,397 @@
+use super::allowed_source::AllowedSource;
+
+use actix_web::http::header::{
+    HeaderMap, FORWARDED, X_FORWARDED_FOR, X_FORWARDED_HOST, X_FORWARDED_PROTO,
+};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::Deserialize;
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    str::FromStr,
+};
+
+/// The proxies trusted to declare the forwarding chain of the requests
+///
+/// Trusted proxies are declared as IP addresses or CIDR ranges at the
+/// `trustedProxies` field of the api settings. Forwarding headers sent by
+/// other peers are discarded.
+///
+/// # Example
+///
+/// ```yaml
+/// trustedProxies:
+/// - 10.0.0.0/8
+/// - 192.168.1.10
+/// ```
+///
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "Vec<String>")]
+pub struct TrustedProxies(Vec<AllowedSource>);
+
+impl TryFrom<Vec<String>> for TrustedProxies {
+    type Error = MappedErrors;
+
+    fn try_from(sources: Vec<String>) -> Result<Self, Self::Error> {
+        let mut proxies = vec![];
+
+        for source in sources {
+            match AllowedSource::from_str(&source)? {
+                AllowedSource::Hostname(hostname) => return dto_err(format!(
+                    "Invalid trusted proxy (expected IP or CIDR): {hostname}"
+                ))
+                .as_error(),
+                proxy => proxies.push(proxy),
+            }
+        }
+
+        Ok(Self(proxies))
+    }
+}
+
+impl TrustedProxies {
+    /// Check if the address belongs to a trusted proxy
+    pub fn contains(&self, address: IpAddr) -> bool {
+        self.0.iter().any(|proxy| proxy.contains_address(address))
+    }
+}
+
+/// The forwarding chain of a request
+///
+/// The chain is built from the `Forwarded` header or, when absent, from the
+/// `X-Forwarded-*` headers sent by trusted peers. The direct peer is appended
+/// to the chain, then the real client address is the closest address to the
+/// gateway not belonging to a trusted proxy.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardedChain {
+    /// The real client address
+    pub client_ip: Option<IpAddr>,
+
+    /// The protocol used by the client to reach the first proxy
+    pub proto: String,
+
+    /// The host requested by the client to the first proxy
+    pub host: Option<String>,
+
+    /// The chain nodes, from the client to the direct peer
+    nodes: Vec<String>,
+
+    /// The `Forwarded` elements, from the client to the direct peer
+    elements: Vec<String>,
+}
+
+impl ForwardedChain {
+    /// Build the forwarding chain of a request
+    ///
+    /// The `proto` and `host` arguments are the protocol and host of the
+    /// request received by the gateway.
+    ///
+    pub fn new(
+        headers: &HeaderMap,
+        peer: Option<IpAddr>,
+        proto: &str,
+        host: Option<String>,
+        trusted_proxies: &TrustedProxies,
+    ) -> Self {
+        let mut nodes = vec![];
+        let mut elements = vec![];
+        let mut forwarded_proto = None;
+        let mut forwarded_host = None;
+
+        //
+        // Forwarding headers are accepted only from trusted peers, otherwise
+        // clients could spoof their addresses.
+        //
+        if peer.is_some_and(|peer| trusted_proxies.contains(peer)) {
+            elements = header_list(headers, FORWARDED.as_str());
+
+            if elements.is_empty() {
+                nodes = header_list(headers, X_FORWARDED_FOR.as_str());
+
+                elements = nodes
+                    .iter()
+                    .map(|node| format!("for={}", format_node(node)))
+                    .collect();
+            } else {
+                nodes = elements
+                    .iter()
+                    .map(|element| {
+                        let node = forwarded_param(element, "for")
+                            .unwrap_or("unknown".to_string());
+
+                        match parse_node(&node) {
+                            Some(ip) => ip.to_string(),
+                            None => node,
+                        }
+                    })
+                    .collect();
+
+                forwarded_proto = elements
+                    .iter()
+                    .find_map(|element| forwarded_param(element, "proto"));
+
+                forwarded_host = elements
+                    .iter()
+                    .find_map(|element| forwarded_param(element, "host"));
+            }
+
+            forwarded_proto = forwarded_proto.or(header_list(
+                headers,
+                X_FORWARDED_PROTO.as_str(),
+            )
+            .into_iter()
+            .next());
+
+            forwarded_host = forwarded_host.or(header_list(
+                headers,
+                X_FORWARDED_HOST.as_str(),
+            )
+            .into_iter()
+            .next());
+        }
+
+        //
+        // Walk the chain from the direct peer while the nodes belong to
+        // trusted proxies. Unknown or obfuscated nodes stop the walk.
+        //
+        let mut client_ip = peer;
+
+        for node in nodes.iter().rev() {
+            match client_ip {
+                Some(ip) if trusted_proxies.contains(ip) => (),
+                _ => break,
+            }
+
+            match parse_node(node) {
+                Some(ip) => client_ip = Some(ip),
+                None => break,
+            }
+        }
+
+        if let Some(peer) = peer {
+            nodes.push(peer.to_string());
+
+            let mut element = format!("for={}", format_node(&peer.to_string()));
+
+            if let Some(host) = &host {
+                element.push_str(&format!(";host={}", format_value(host)));
+            }
+
+            element.push_str(&format!(";proto={}", format_value(proto)));
+            elements.push(element);
+        }
+
+        Self {
+            client_ip,
+            proto: forwarded_proto.unwrap_or(proto.to_string()),
+            host: forwarded_host.or(host),
+            nodes,
+            elements,
+        }
+    }
+
+    /// The `X-Forwarded-For` header value sent to the upstream
+    pub fn x_forwarded_for(&self) -> Option<String> {
+        match self.nodes.is_empty() {
+            true => None,
+            false => Some(self.nodes.join(", ")),
+        }
+    }
+
+    /// The `Forwarded` header value sent to the upstream
+    pub fn forwarded(&self) -> Option<String> {
+        match self.elements.is_empty() {
+            true => None,
+            false => Some(self.elements.join(", ")),
+        }
+    }
+}
+
+/// Collect the comma separated values of all occurrences of a header
+fn header_list(headers: &HeaderMap, name: &str) -> Vec<String> {
+    headers
+        .get_all(name)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Get a parameter of a `Forwarded` element
+fn forwarded_param(element: &str, name: &str) -> Option<String> {
+    element.split(';').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+
+        match key.trim().eq_ignore_ascii_case(name) {
+            true => Some(
+                value
+                    .trim()
+                    .trim_matches('"')
+                    .replace("\\\"", "\"")
+                    .replace("\\\\", "\\"),
+            ),
+            false => None,
+        }
+    })
+}
+
+/// Parse the address of a chain node
+///
+/// Nodes could include a port, and IPv6 addresses could be enclosed in
+/// brackets.
+///
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split(']').next()?.parse().ok();
+    }
+
+    if let Ok(ip) = node.parse::<IpAddr>() {
+        return Some(ip);
+    }
+
+    node.rsplit_once(':')
+        .and_then(|(ip, _)| ip.parse::<Ipv4Addr>().ok())
+        .map(IpAddr::V4)
+}
+
+/// Format a chain node as a `Forwarded` node
+///
+/// Nodes which are not IP addresses are declared as `unknown`.
+///
+fn format_node(node: &str) -> String {
+    match parse_node(node) {
+        Some(IpAddr::V4(ip)) => ip.to_string(),
+        Some(IpAddr::V6(ip)) => format!("\"[{ip}]\""),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Format a `Forwarded` parameter value, quoting it if needed
+fn format_value(value: &str) -> String {
+    let is_token = !value.is_empty()
+        && value.chars().all(|c| {
+            c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+        });
+
+    match is_token {
+        true => value.to_string(),
+        false => {
+            format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::header::HeaderValue;
+
+    fn build_proxies() -> TrustedProxies {
+        TrustedProxies::try_from(vec![
+            "10.0.0.0/8".to_string(),
+            "192.168.1.10".to_string(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_untrusted_peer_headers_are_discarded() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            X_FORWARDED_FOR,
+            HeaderValue::from_static("1.1.1.1, 10.0.0.5"),
+        );
+
+        let chain = ForwardedChain::new(
+            &headers,
+            Some(IpAddr::from_str("8.8.8.8").unwrap()),
+            "http",
+            Some("gateway.local".to_string()),
+            &build_proxies(),
+        );
+
+        assert_eq!(chain.client_ip, IpAddr::from_str("8.8.8.8").ok());
+        assert_eq!(chain.x_forwarded_for().unwrap(), "8.8.8.8");
+        assert_eq!(
+            chain.forwarded().unwrap(),
+            "for=8.8.8.8;host=gateway.local;proto=http"
+        );
+    }
+
+    #[test]
+    fn test_trusted_x_forwarded_chain_works() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            X_FORWARDED_FOR,
+            HeaderValue::from_static("203.0.113.7, 1.1.1.1"),
+        );
+        headers.append(X_FORWARDED_FOR, HeaderValue::from_static("10.1.1.1"));
+        headers.insert(X_FORWARDED_PROTO, HeaderValue::from_static("https"));
+        headers.insert(
+            X_FORWARDED_HOST,
+            HeaderValue::from_static("api.example.com"),
+        );
+
+        let chain = ForwardedChain::new(
+            &headers,
+            Some(IpAddr::from_str("192.168.1.10").unwrap()),
+            "http",
+            Some("gateway.local:8080".to_string()),
+            &build_proxies(),
+        );
+
+        assert_eq!(chain.client_ip, IpAddr::from_str("1.1.1.1").ok());
+        assert_eq!(chain.proto, "https");
+        assert_eq!(chain.host.as_deref(), Some("api.example.com"));
+        assert_eq!(
+            chain.x_forwarded_for().unwrap(),
+            "203.0.113.7, 1.1.1.1, 10.1.1.1, 192.168.1.10"
+        );
+        assert_eq!(
+            chain.forwarded().unwrap(),
+            "for=203.0.113.7, for=1.1.1.1, for=10.1.1.1, for=192.168.1.10;host=\"gateway.local:8080\";proto=http"
+        );
+    }
+
+    #[test]
+    fn test_trusted_forwarded_chain_works() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            FORWARDED,
+            HeaderValue::from_static(
+                "for=\"[2001:db8::1]:4711\";proto=https;host=api.example.com, for=10.0.0.2",
+            ),
+        );
+
+        let chain = ForwardedChain::new(
+            &headers,
+            Some(IpAddr::from_str("10.0.0.3").unwrap()),
+            "http",
+            None,
+            &build_proxies(),
+        );
+
+        assert_eq!(chain.client_ip, IpAddr::from_str("2001:db8::1").ok());
+        assert_eq!(chain.proto, "https");
+        assert_eq!(chain.host.as_deref(), Some("api.example.com"));
+        assert_eq!(
+            chain.x_forwarded_for().unwrap(),
+            "2001:db8::1, 10.0.0.2, 10.0.0.3"
+        );
+    }
+
+    #[test]
+    fn test_trusted_proxies_reject_hostnames() {
+        assert!(TrustedProxies::try_from(
+            vec!["proxy.example.com".to_string()]
+        )
+        .is_err());
+    }
+}
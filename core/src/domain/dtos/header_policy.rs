@@ -0,0 +1,342 @@
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{ToResponse, ToSchema};
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderValueRule {
+    /// The header name
+    pub name: String,
+
+    /// The header value
+    pub value: String,
+}
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderRenameRule {
+    /// The current header name
+    pub from: String,
+
+    /// The new header name
+    pub to: String,
+}
+
+/// The headers passed through the gateway
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum HeaderPassThrough {
+    /// Only the listed headers are passed through
+    Allow(Vec<String>),
+
+    /// All headers except the listed ones are passed through
+    Deny(Vec<String>),
+}
+
+/// The transformations applied to the headers of a message
+///
+/// Transformations are applied in the following order: the pass-through list,
+/// the removals, the renames, the set and the append rules. Header names are
+/// case-insensitive.
+///
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderTransformation {
+    /// The headers passed through the gateway
+    ///
+    /// If empty, all headers are passed through.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass_through: Option<HeaderPassThrough>,
+
+    /// The headers to remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<Vec<String>>,
+
+    /// The headers to rename
+    ///
+    /// All values of the current header are moved to the new one.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename: Option<Vec<HeaderRenameRule>>,
+
+    /// The headers to set, replacing the current values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set: Option<Vec<HeaderValueRule>>,
+
+    /// The headers to append, keeping the current values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub append: Option<Vec<HeaderValueRule>>,
+}
+
+impl HeaderTransformation {
+    /// Check if the header names and values are valid
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        let pass_through = match &self.pass_through {
+            None => vec![],
+            Some(HeaderPassThrough::Allow(names))
+            | Some(HeaderPassThrough::Deny(names)) => names.to_owned(),
+        };
+
+        let names = pass_through
+            .iter()
+            .chain(self.remove.iter().flatten())
+            .chain(
+                self.rename
+                    .iter()
+                    .flatten()
+                    .flat_map(|rule| [&rule.from, &rule.to]),
+            )
+            .chain(
+                self.set
+                    .iter()
+                    .chain(self.append.iter())
+                    .flatten()
+                    .map(|rule| &rule.name),
+            );
+
+        for name in names {
+            parse_name(name)?;
+        }
+
+        for rule in self.set.iter().chain(self.append.iter()).flatten() {
+            parse_value(&rule.value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply the transformations to a headers map
+    pub fn apply(&self, headers: &mut HeaderMap) -> Result<(), MappedErrors> {
+        //
+        // Filter the passed through headers
+        //
+        if let Some(pass_through) = &self.pass_through {
+            let (names, allow) = match pass_through {
+                HeaderPassThrough::Allow(names) => (names, true),
+                HeaderPassThrough::Deny(names) => (names, false),
+            };
+
+            let names = names
+                .iter()
+                .map(|name| parse_name(name))
+                .collect::<Result<Vec<HeaderName>, MappedErrors>>()?;
+
+            let current_names =
+                headers.keys().cloned().collect::<Vec<HeaderName>>();
+
+            for name in current_names {
+                if names.contains(&name) != allow {
+                    headers.remove(name);
+                }
+            }
+        }
+
+        //
+        // Remove headers
+        //
+        for name in self.remove.iter().flatten() {
+            headers.remove(parse_name(name)?);
+        }
+
+        //
+        // Rename headers
+        //
+        for rule in self.rename.iter().flatten() {
+            let to = parse_name(&rule.to)?;
+
+            let values = headers
+                .remove(parse_name(&rule.from)?)
+                .collect::<Vec<HeaderValue>>();
+
+            for value in values {
+                headers.append(to.to_owned(), value);
+            }
+        }
+
+        //
+        // Set and append headers
+        //
+        for rule in self.set.iter().flatten() {
+            headers.insert(parse_name(&rule.name)?, parse_value(&rule.value)?);
+        }
+
+        for rule in self.append.iter().flatten() {
+            headers.append(parse_name(&rule.name)?, parse_value(&rule.value)?);
+        }
+
+        Ok(())
+    }
+}
+
+/// The header transformations of the gateway messages
+///
+/// Policies declared by a service are applied before the ones declared by its
+/// routes.
+///
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderPolicy {
+    /// The transformations applied to the requests sent to the upstream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<HeaderTransformation>,
+
+    /// The transformations applied to the responses sent to the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<HeaderTransformation>,
+}
+
+impl HeaderPolicy {
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        for transformation in self.request.iter().chain(self.response.iter()) {
+            transformation.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_name(name: &str) -> Result<HeaderName, MappedErrors> {
+    match HeaderName::from_str(name.trim()) {
+        Ok(name) => Ok(name),
+        Err(err) => {
+            dto_err(format!("Invalid header name {name}: {err}")).as_error()
+        }
+    }
+}
+
+fn parse_value(value: &str) -> Result<HeaderValue, MappedErrors> {
+    match HeaderValue::from_str(value) {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            dto_err(format!("Invalid header value {value}: {err}")).as_error()
+        }
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::header::{CACHE_CONTROL, CONTENT_TYPE, SET_COOKIE};
+
+    fn build_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("a=1"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("b=2"));
+        headers.insert(
+            HeaderName::from_static("x-internal"),
+            HeaderValue::from_static("secret"),
+        );
+
+        headers
+    }
+
+    #[test]
+    fn test_header_transformation_apply_works() {
+        let mut headers = build_headers();
+
+        HeaderTransformation {
+            pass_through: Some(HeaderPassThrough::Deny(vec![
+                "X-Internal".to_string()
+            ])),
+            remove: Some(vec!["cache-control".to_string()]),
+            rename: Some(vec![HeaderRenameRule {
+                from: "set-cookie".to_string(),
+                to: "x-upstream-cookie".to_string(),
+            }]),
+            set: Some(vec![HeaderValueRule {
+                name: "content-type".to_string(),
+                value: "application/json".to_string(),
+            }]),
+            append: Some(vec![HeaderValueRule {
+                name: "x-upstream-cookie".to_string(),
+                value: "c=3".to_string(),
+            }]),
+        }
+        .apply(&mut headers)
+        .unwrap();
+
+        assert!(!headers.contains_key("x-internal"));
+        assert!(!headers.contains_key(CACHE_CONTROL));
+        assert!(!headers.contains_key(SET_COOKIE));
+        assert_eq!(headers.get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(headers.get_all("x-upstream-cookie").count(), 3);
+    }
+
+    #[test]
+    fn test_header_pass_through_allow_list_works() {
+        let mut headers = build_headers();
+
+        HeaderTransformation {
+            pass_through: Some(HeaderPassThrough::Allow(vec![
+                "Content-Type".to_string(),
+                "set-cookie".to_string(),
+            ])),
+            ..Default::default()
+        }
+        .apply(&mut headers)
+        .unwrap();
+
+        assert_eq!(headers.len(), 3);
+        assert!(headers.contains_key(CONTENT_TYPE));
+        assert!(!headers.contains_key(CACHE_CONTROL));
+    }
+
+    #[test]
+    fn test_header_transformation_validate_works() {
+        assert!(HeaderTransformation {
+            set: Some(vec![HeaderValueRule {
+                name: "invalid header".to_string(),
+                value: "value".to_string(),
+            }]),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+
+        assert!(HeaderTransformation {
+            append: Some(vec![HeaderValueRule {
+                name: "x-valid".to_string(),
+                value: "invalid\nvalue".to_string(),
+            }]),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -1,11 +1,224 @@
+use super::http::Protocol;
+
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
+use uuid::Uuid;
+
+/// The default health check request timeout in seconds
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: u64 = 5;
+
+/// The default number of consecutive successes to consider a service healthy
+pub const DEFAULT_HEALTHY_THRESHOLD: u32 = 2;
+
+/// The default number of consecutive failures to consider a service unhealthy
+pub const DEFAULT_UNHEALTHY_THRESHOLD: u32 = 3;
 
 #[derive(
     Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
 )]
 #[serde(rename_all = "camelCase")]
 pub struct HealthCheckConfig {
+    /// The health check path
+    ///
+    /// The path is requested at the service host on each health check cycle.
+    ///
     pub path: String,
+
+    /// The status codes considered healthy
     pub health_response_codes: Vec<i32>,
+
+    /// The protocol used to reach the health check path
+    ///
+    /// Default to `http`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
+
+    /// The health check request timeout in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_in_secs: Option<u64>,
+
+    /// The consecutive successes needed to mark the service as healthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_threshold: Option<u32>,
+
+    /// The consecutive failures needed to mark the service as unhealthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unhealthy_threshold: Option<u32>,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthStatus {
+    /// The service responded the latest health checks as expected
+    Healthy,
+
+    /// The service failed the latest health checks
+    Unhealthy,
+
+    /// The service was not checked enough times to determine the status
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthCheckFailure {
+    /// The failure date
+    pub checked_at: DateTime<Local>,
+
+    /// The failure reason
+    pub reason: String,
+}
+
+/// The health state of a downstream service target
+///
+/// The state is updated by the gateway health checker for each upstream target
+/// of the service. Status changes only occur after the configured number of
+/// consecutive successes or failures.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealth {
+    /// The service id
+    pub service_id: Option<Uuid>,
+
+    /// The service name
+    pub service_name: String,
+
+    /// The upstream target host
+    pub host: String,
+
+    /// The current health status
+    pub status: HealthStatus,
+
+    /// The last health check date
+    pub last_checked_at: Option<DateTime<Local>>,
+
+    /// The last health check failure
+    pub last_failure: Option<HealthCheckFailure>,
+
+    /// The number of consecutive successful checks
+    pub consecutive_successes: u32,
+
+    /// The number of consecutive failed checks
+    pub consecutive_failures: u32,
+}
+
+impl ServiceHealth {
+    pub fn new(
+        service_id: Option<Uuid>,
+        service_name: String,
+        host: String,
+    ) -> Self {
+        Self {
+            service_id,
+            service_name,
+            host,
+            status: HealthStatus::Unknown,
+            last_checked_at: None,
+            last_failure: None,
+            consecutive_successes: 0,
+            consecutive_failures: 0,
+        }
+    }
+
+    /// Register a successful health check
+    ///
+    /// The service is marked as healthy after reaching the healthy threshold.
+    pub fn register_success(&mut self, healthy_threshold: u32) {
+        self.last_checked_at = Some(Local::now());
+        self.consecutive_failures = 0;
+        self.consecutive_successes =
+            self.consecutive_successes.saturating_add(1);
+
+        if self.consecutive_successes >= healthy_threshold.max(1) {
+            self.status = HealthStatus::Healthy;
+        }
+    }
+
+    /// Register a failed health check
+    ///
+    /// The service is marked as unhealthy after reaching the unhealthy
+    /// threshold.
+    pub fn register_failure(
+        &mut self,
+        reason: String,
+        unhealthy_threshold: u32,
+    ) {
+        let now = Local::now();
+
+        self.last_checked_at = Some(now);
+        self.last_failure = Some(HealthCheckFailure {
+            checked_at: now,
+            reason,
+        });
+        self.consecutive_successes = 0;
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+
+        if self.consecutive_failures >= unhealthy_threshold.max(1) {
+            self.status = HealthStatus::Unhealthy;
+        }
+    }
+
+    pub fn is_unhealthy(&self) -> bool {
+        self.status == HealthStatus::Unhealthy
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_status_transitions_works() {
+        let mut health = ServiceHealth::new(
+            None,
+            "service".to_string(),
+            "localhost:8080".to_string(),
+        );
+        assert_eq!(health.status, HealthStatus::Unknown);
+
+        health.register_success(2);
+        assert_eq!(health.status, HealthStatus::Unknown);
+
+        health.register_success(2);
+        assert_eq!(health.status, HealthStatus::Healthy);
+
+        health.register_failure("timeout".to_string(), 3);
+        health.register_failure("timeout".to_string(), 3);
+        assert_eq!(health.status, HealthStatus::Healthy);
+
+        health.register_failure("status 500".to_string(), 3);
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert_eq!(
+            health.last_failure.as_ref().unwrap().reason,
+            "status 500".to_string()
+        );
+
+        health.register_success(2);
+        assert!(health.is_unhealthy());
+
+        health.register_success(2);
+        assert_eq!(health.status, HealthStatus::Healthy);
+        assert!(health.last_failure.is_some());
+    }
 }
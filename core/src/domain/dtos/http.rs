@@ -48,6 +48,22 @@ impl HttpMethod {
             _ => HttpMethod::None,
         }
     }
+
+    /// Check if the method is idempotent.
+    ///
+    /// Idempotent requests could be sent several times with the same effect
+    /// of a single request, then they could be retried safely.
+    pub fn is_idempotent(&self) -> bool {
+        matches!(
+            self,
+            HttpMethod::Get
+                | HttpMethod::Head
+                | HttpMethod::Put
+                | HttpMethod::Delete
+                | HttpMethod::Options
+                | HttpMethod::Trace
+        )
+    }
 }
 
 impl Display for HttpMethod {
@@ -104,7 +120,30 @@ impl FromStr for HttpMethod {
 pub enum Protocol {
     Http,
     Https,
+
+    /// gRPC over HTTP/2 without TLS (h2c)
+    ///
+    /// Routes are served by the gateway gRPC listener and matched by the full
+    /// gRPC path (`/<package>.<Service>/<Method>`). HTTP/2 frames and trailers
+    /// are relayed between the client and the upstream. Also used by the
+    /// telemetry exporters.
+    ///
+    /// Calls are sent to the first healthy target of the service, without
+    /// failover to the remaining targets. Routes declaring retries, rewrite
+    /// rules or request mirrors are rejected when the routes are loaded, and
+    /// the retry policies of their services are ignored.
+    ///
     Grpc,
+
+    /// WebSocket connections
+    ///
+    /// Requests are upgraded to a WebSocket connection with the upstream after
+    /// the route authorization, then frames are relayed in both directions.
+    ///
+    Ws,
+
+    /// WebSocket connections over TLS
+    Wss,
 }
 
 impl Display for Protocol {
@@ -113,6 +152,15 @@ impl Display for Protocol {
             Protocol::Http => write!(f, "http"),
             Protocol::Https => write!(f, "https"),
             Protocol::Grpc => write!(f, "grpc"),
+            Protocol::Ws => write!(f, "ws"),
+            Protocol::Wss => write!(f, "wss"),
         }
     }
 }
+
+impl Protocol {
+    /// Check if the protocol relays WebSocket connections
+    pub fn is_websocket(&self) -> bool {
+        matches!(self, Protocol::Ws | Protocol::Wss)
+    }
+}
@@ -62,36 +62,110 @@ pub enum HttpSecret {
         ///
         token: String,
     },
-    //
-    // TODO: Implement client certificate authentication
-    //
-    //#[serde(rename_all = "camelCase")]
-    //ClientCertificate {
-    //    /// The certificate
-    //    ///
-    //    /// The certificate is the client certificate in PEM format.
-    //    ///
-    //    cert_pem: String,
-    //
-    //    /// The private key
-    //    ///
-    //    /// The private key is the client private key in PEM format.
-    //    ///
-    //    key_pem: String,
-    //
-    //    /// The certificate version
-    //    ///
-    //    /// The certificate version is the version of the certificate.
-    //    ///
-    //    cert_version: Option<String>,
-    //},
-    //
+
+    /// Client certificate
+    ///
+    /// The gateway authenticates at the downstream service using mutual TLS.
+    /// Routes using this secret should use the HTTPS protocol.
+    ///
+    #[serde(rename_all = "camelCase")]
+    ClientCertificate {
+        /// The certificate
+        ///
+        /// The client certificate in PEM format. Intermediate certificates
+        /// could be appended after the client certificate.
+        ///
+        cert_pem: String,
+
+        /// The private key
+        ///
+        /// The client private key in PEM format. PKCS#8 (`BEGIN PRIVATE
+        /// KEY`), PKCS#1 (`BEGIN RSA PRIVATE KEY`) and SEC1 (`BEGIN EC
+        /// PRIVATE KEY`) keys are accepted.
+        ///
+        key_pem: String,
+
+        /// The CA bundle
+        ///
+        /// If present, the certificates in PEM format are trusted, in addition
+        /// to the system ones, to verify the downstream service certificate.
+        ///
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ca_bundle_pem: Option<String>,
+    },
+
+    /// HTTP Basic authentication
+    ///
+    /// The credentials are passed as a `Basic` authorization header.
+    ///
+    #[serde(rename_all = "camelCase")]
+    BasicAuth {
+        /// The user name
+        username: String,
+
+        /// The user password
+        password: String,
+    },
+
+    /// Custom header
+    ///
+    /// The token is passed as is in an arbitrary header, like API keys sent
+    /// through the `X-Api-Key` header.
+    ///
+    #[serde(rename_all = "camelCase")]
+    CustomHeader {
+        /// The header name
+        name: String,
+
+        /// The header value
+        token: String,
+    },
+
+    /// OAuth2 client credentials
+    ///
+    /// A bearer token is requested to the token endpoint using the client
+    /// credentials grant, then passed as an authorization header. Tokens are
+    /// cached until they expire.
+    ///
+    #[serde(rename = "oauth2ClientCredentials", rename_all = "camelCase")]
+    OAuth2ClientCredentials {
+        /// The token endpoint url
+        token_url: String,
+
+        /// The client id
+        client_id: String,
+
+        /// The client secret
+        client_secret: String,
+
+        /// The requested scopes, separated by spaces
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scope: Option<String>,
+
+        /// The requested audience
+        ///
+        /// Required by some providers to issue tokens for a specific API.
+        ///
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        audience: Option<String>,
+    },
 }
 
 pub fn default_authorization_key() -> Option<String> {
     Some("Authorization".to_string())
 }
 
+/// Encode the credentials of a HTTP Basic authorization header
+///
+/// The returned value includes the `Basic` prefix.
+///
+pub fn encode_basic_credentials(username: &str, password: &str) -> String {
+    format!(
+        "Basic {}",
+        general_purpose::STANDARD.encode(format!("{username}:{password}"))
+    )
+}
+
 impl HttpSecret {
     #[tracing::instrument(name = "encrypt_me", skip_all)]
     pub(crate) async fn encrypt_me(
@@ -140,12 +214,7 @@ impl HttpSecret {
         //
         // Prepare secret data to encrypt
         //
-        let mut in_out = (match self {
-            Self::AuthorizationHeader { token, .. } => token,
-            Self::QueryParameter { token, .. } => token,
-        })
-        .as_bytes()
-        .to_vec();
+        let mut in_out = self.sensitive_value().as_bytes().to_vec();
 
         //
         // Encrypt in-place and append the authentication tag
@@ -170,19 +239,7 @@ impl HttpSecret {
         //
         // Return encrypted TOTP instance
         //
-        let self_encrypted = match self {
-            Self::AuthorizationHeader { name, prefix, .. } => {
-                Self::AuthorizationHeader {
-                    token: encrypted_string.to_owned(),
-                    name: name.to_owned(),
-                    prefix: prefix.to_owned(),
-                }
-            }
-            Self::QueryParameter { name, .. } => Self::QueryParameter {
-                token: encrypted_string.to_owned(),
-                name: name.to_owned(),
-            },
-        };
+        let self_encrypted = self.with_sensitive_value(encrypted_string);
 
         Ok(self_encrypted)
     }
@@ -224,10 +281,7 @@ impl HttpSecret {
         //
         // Extract and decode the encrypted secret
         //
-        let secret = match self {
-            Self::AuthorizationHeader { token, .. } => token,
-            Self::QueryParameter { token, .. } => token,
-        };
+        let secret = self.sensitive_value();
 
         let encrypted = match general_purpose::STANDARD.decode(secret) {
             Ok(encrypted) => encrypted,
@@ -290,35 +344,46 @@ impl HttpSecret {
             }
         };
 
-        let self_decrypted = match self {
-            Self::AuthorizationHeader { name, prefix, .. } => {
-                Self::AuthorizationHeader {
-                    token: decrypted_secret.to_owned(),
-                    name: name.to_owned(),
-                    prefix: prefix.to_owned(),
-                }
-            }
-            Self::QueryParameter { name, .. } => Self::QueryParameter {
-                token: decrypted_secret,
-                name: name.to_owned(),
-            },
-        };
+        let self_decrypted = self.with_sensitive_value(decrypted_secret);
 
         Ok(self_decrypted)
     }
 
     #[tracing::instrument(name = "redact_token", skip_all)]
     pub(crate) fn redact_token(&mut self) {
-        let redacted_word = "REDACTED".to_string();
+        *self = self.with_sensitive_value("REDACTED".to_string());
+    }
 
+    /// The value protected by encryption and redacted on serialization
+    fn sensitive_value(&self) -> &str {
         match self {
-            Self::AuthorizationHeader { token, .. } => {
-                *token = redacted_word;
+            Self::AuthorizationHeader { token, .. } => token,
+            Self::QueryParameter { token, .. } => token,
+            Self::ClientCertificate { key_pem, .. } => key_pem,
+            Self::BasicAuth { password, .. } => password,
+            Self::CustomHeader { token, .. } => token,
+            Self::OAuth2ClientCredentials { client_secret, .. } => {
+                client_secret
             }
-            Self::QueryParameter { token, .. } => {
-                *token = redacted_word;
+        }
+    }
+
+    /// Build a copy of the secret replacing the sensitive value
+    fn with_sensitive_value(&self, value: String) -> Self {
+        let mut secret = self.to_owned();
+
+        match &mut secret {
+            Self::AuthorizationHeader { token, .. } => *token = value,
+            Self::QueryParameter { token, .. } => *token = value,
+            Self::ClientCertificate { key_pem, .. } => *key_pem = value,
+            Self::BasicAuth { password, .. } => *password = value,
+            Self::CustomHeader { token, .. } => *token = value,
+            Self::OAuth2ClientCredentials { client_secret, .. } => {
+                *client_secret = value
             }
         }
+
+        secret
     }
 }
 
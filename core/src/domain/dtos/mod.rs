@@ -1,21 +1,35 @@
 pub mod account;
 pub mod account_type;
+pub mod allowed_source;
+pub mod circuit_breaker;
+pub mod cors;
 pub mod email;
 pub mod error_code;
+pub mod forwarded;
 pub mod guest_role;
 pub mod guest_user;
+pub mod header_policy;
 pub mod health_check;
 pub mod http;
 pub mod http_secret;
 pub mod message;
 pub mod native_error_codes;
+pub mod path_rewrite;
 pub mod profile;
+pub mod profile_projection;
+pub mod rate_limit;
 pub mod related_accounts;
+pub mod request_mirror;
+pub mod retry;
 pub mod route;
+pub mod route_tree;
 pub mod route_type;
 pub mod service;
 pub mod tag;
 pub mod tenant;
+pub mod timeout;
 pub mod token;
+pub mod traffic_split;
+pub mod upstream;
 pub mod user;
 pub mod webhook;
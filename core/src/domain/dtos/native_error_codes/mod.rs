@@ -225,6 +225,24 @@ pub enum NativeErrorCodes {
     /// is_native: true
     ///
     MYC00023,
+
+    ///
+    /// code: "MYC00024",
+    /// message: "Invalid routes configuration",
+    /// details: "Indicates that the routes could not be loaded or are invalid. The previous routes remain active.",
+    /// is_internal: false,
+    /// is_native: true
+    ///
+    MYC00024,
+
+    ///
+    /// code: "MYC00025",
+    /// message: "Gateway service or route not found",
+    /// details: "Indicates that the gateway service or route was not found.",
+    /// is_internal: false,
+    /// is_native: true
+    ///
+    MYC00025,
 }
 
 impl NativeErrorCodes {
@@ -275,6 +293,8 @@ impl NativeErrorCodes {
             Self::MYC00021 => "MYC00021",
             Self::MYC00022 => "MYC00022",
             Self::MYC00023 => "MYC00023",
+            Self::MYC00024 => "MYC00024",
+            Self::MYC00025 => "MYC00025",
         }
     }
 
@@ -0,0 +1,241 @@
+use lazy_static::lazy_static;
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::RwLock};
+use utoipa::{ToResponse, ToSchema};
+
+lazy_static! {
+    /// Match the capture group references of a replacement template
+    static ref GROUP_REFERENCE: Regex =
+        Regex::new(r"\$(?:\{(\w+)\}|(\w+))").unwrap();
+
+    /// The compiled rewrite patterns, indexed by the pattern source
+    static ref COMPILED_PATTERNS: RwLock<HashMap<String, Regex>> =
+        RwLock::new(HashMap::new());
+}
+
+/// A rule used to rewrite the path of the requests sent to the upstream
+///
+/// Rules are applied in the declaration order, each one receiving the path
+/// produced by the previous one.
+///
+/// # Example
+///
+/// ```yaml
+/// rewrite:
+/// - stripPrefix: /v1
+/// - addPrefix: /api
+/// - regex:
+///     pattern: ^/api/users/(\d+)$
+///     replacement: /api/accounts/$1
+/// ```
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PathRewriteRule {
+    /// Remove a leading prefix from the path
+    ///
+    /// The prefix is only removed if it matches whole path segments. Paths
+    /// without the prefix are not changed.
+    ///
+    StripPrefix(String),
+
+    /// Prepend a prefix to the path
+    AddPrefix(String),
+
+    /// Rewrite the path matching a regular expression
+    ///
+    /// The replacement template could reference the pattern capture groups by
+    /// index (`$1`) or name (`${name}`). Paths not matching the pattern are
+    /// not changed.
+    ///
+    #[serde(rename_all = "camelCase")]
+    Regex {
+        pattern: String,
+        replacement: String,
+    },
+}
+
+impl PathRewriteRule {
+    /// Check if the rule is valid
+    ///
+    /// Prefixes should start with a slash, patterns should compile and
+    /// replacement templates should reference only existing capture groups.
+    ///
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        match self {
+            PathRewriteRule::StripPrefix(prefix)
+            | PathRewriteRule::AddPrefix(prefix) => {
+                if !prefix.starts_with('/') {
+                    return dto_err(format!(
+                        "Invalid rewrite prefix {prefix}: prefixes should start with a slash"
+                    ))
+                    .as_error();
+                }
+            }
+            PathRewriteRule::Regex {
+                pattern,
+                replacement,
+            } => {
+                let regex = compile_pattern(pattern)?;
+
+                let group_names =
+                    regex.capture_names().flatten().collect::<Vec<&str>>();
+
+                for reference in GROUP_REFERENCE.captures_iter(replacement) {
+                    let group = match reference.get(1).or(reference.get(2)) {
+                        Some(group) => group.as_str(),
+                        None => continue,
+                    };
+
+                    let exists = match group.parse::<usize>() {
+                        Ok(index) => index < regex.captures_len(),
+                        Err(_) => group_names.contains(&group),
+                    };
+
+                    if !exists {
+                        return dto_err(format!(
+                            "Invalid rewrite replacement {replacement}: group {group} not found at pattern {pattern}"
+                        ))
+                        .as_error();
+                    }
+                }
+
+                if !replacement.starts_with('/') {
+                    return dto_err(format!(
+                        "Invalid rewrite replacement {replacement}: replacements should start with a slash"
+                    ))
+                    .as_error();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply the rule to a path
+    pub fn apply(&self, path: &str) -> Result<String, MappedErrors> {
+        match self {
+            PathRewriteRule::StripPrefix(prefix) => {
+                let prefix = prefix.trim_end_matches('/');
+
+                Ok(match path.strip_prefix(prefix) {
+                    Some("") => "/".to_string(),
+                    Some(rest)
+                        if rest.starts_with('/') || prefix.is_empty() =>
+                    {
+                        rest.to_string()
+                    }
+                    _ => path.to_string(),
+                })
+            }
+            PathRewriteRule::AddPrefix(prefix) => Ok(format!(
+                "{}/{}",
+                prefix.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            )),
+            PathRewriteRule::Regex {
+                pattern,
+                replacement,
+            } => Ok(compile_pattern(pattern)?
+                .replace(path, replacement.as_str())
+                .to_string()),
+        }
+    }
+}
+
+/// Compile a rewrite pattern
+///
+/// Compiled patterns are cached, avoiding to compile them on each request.
+///
+fn compile_pattern(pattern: &str) -> Result<Regex, MappedErrors> {
+    if let Ok(patterns) = COMPILED_PATTERNS.read() {
+        if let Some(regex) = patterns.get(pattern) {
+            return Ok(regex.to_owned());
+        }
+    }
+
+    let regex = match Regex::new(pattern) {
+        Ok(regex) => regex,
+        Err(err) => {
+            return dto_err(format!("Invalid rewrite pattern {pattern}: {err}"))
+                .as_error()
+        }
+    };
+
+    if let Ok(mut patterns) = COMPILED_PATTERNS.write() {
+        patterns.insert(pattern.to_string(), regex.to_owned());
+    }
+
+    Ok(regex)
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_prefix_matches_whole_segments() {
+        let rule = PathRewriteRule::StripPrefix("/v1/".to_string());
+
+        assert_eq!(rule.apply("/v1/users").unwrap(), "/users");
+        assert_eq!(rule.apply("/v1").unwrap(), "/");
+        assert_eq!(rule.apply("/v10/users").unwrap(), "/v10/users");
+        assert_eq!(rule.apply("/users/v1").unwrap(), "/users/v1");
+    }
+
+    #[test]
+    fn test_add_prefix_works() {
+        let rule = PathRewriteRule::AddPrefix("/api/".to_string());
+
+        assert_eq!(rule.apply("/users").unwrap(), "/api/users");
+        assert_eq!(rule.apply("/").unwrap(), "/api/");
+    }
+
+    #[test]
+    fn test_regex_rewrite_works() {
+        let rule = PathRewriteRule::Regex {
+            pattern: r"^/users/(?P<id>\d+)/(\w+)$".to_string(),
+            replacement: "/accounts/${id}/$2".to_string(),
+        };
+
+        assert!(rule.validate().is_ok());
+        assert_eq!(rule.apply("/users/42/tags").unwrap(), "/accounts/42/tags");
+        assert_eq!(rule.apply("/users/me/tags").unwrap(), "/users/me/tags");
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        assert!(PathRewriteRule::StripPrefix("v1".to_string())
+            .validate()
+            .is_err());
+
+        assert!(PathRewriteRule::Regex {
+            pattern: r"^/users/(\d+$".to_string(),
+            replacement: "/accounts/$1".to_string(),
+        }
+        .validate()
+        .is_err());
+
+        assert!(PathRewriteRule::Regex {
+            pattern: r"^/users/(\d+)$".to_string(),
+            replacement: "/accounts/$2".to_string(),
+        }
+        .validate()
+        .is_err());
+
+        assert!(PathRewriteRule::Regex {
+            pattern: r"^/users/(\d+)$".to_string(),
+            replacement: "/accounts/${id}".to_string(),
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -1,9 +1,11 @@
 mod licensed_resources;
 mod owner;
+mod profile_cache_key;
 mod tenant_ownerships;
 
 pub use licensed_resources::{LicensedResource, LicensedResources};
 pub use owner::Owner;
+pub use profile_cache_key::ProfileCacheKey;
 pub use tenant_ownerships::{TenantOwnership, TenantsOwnership};
 
 use super::{
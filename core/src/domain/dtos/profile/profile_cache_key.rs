@@ -0,0 +1,101 @@
+use crate::domain::dtos::{email::Email, route_type::PermissionedRoles};
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// The key of a cached profile
+///
+/// Profiles are cached by email and by the filters used to fetch the licensed
+/// resources, then each combination of filters has its own entry. Entries of
+/// an email could be discarded together.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileCacheKey {
+    pub email: Email,
+    pub was_verified: Option<bool>,
+    pub tenant: Option<Uuid>,
+    pub roles: Option<Vec<String>>,
+    pub permissioned_roles: Option<PermissionedRoles>,
+}
+
+impl ProfileCacheKey {
+    /// A stable digest of the filters
+    ///
+    /// Roles are sorted before hashing, then filters declaring the same roles
+    /// in different orders share the same entry.
+    ///
+    pub fn filters_digest(&self) -> String {
+        let roles = self.roles.to_owned().map(|mut roles| {
+            roles.sort();
+            roles
+        });
+
+        let permissioned_roles =
+            self.permissioned_roles.to_owned().map(|roles| {
+                let mut roles = roles
+                    .into_iter()
+                    .map(|(role, permission)| (role, permission.to_i32()))
+                    .collect::<Vec<(String, i32)>>();
+
+                roles.sort();
+                roles
+            });
+
+        let filters = format!(
+            "{was_verified:?}|{tenant:?}|{roles:?}|{permissioned_roles:?}",
+            was_verified = self.was_verified,
+            tenant = self.tenant,
+        );
+
+        format!("{:x}", Sha256::digest(filters.as_bytes()))
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::guest_role::Permission;
+
+    fn build_key(roles: Vec<&str>) -> ProfileCacheKey {
+        ProfileCacheKey {
+            email: Email::from_string("user@example.com".to_string()).unwrap(),
+            was_verified: None,
+            tenant: None,
+            roles: Some(
+                roles.into_iter().map(|role| role.to_string()).collect(),
+            ),
+            permissioned_roles: None,
+        }
+    }
+
+    #[test]
+    fn test_filters_digest_ignores_roles_order() {
+        assert_eq!(
+            build_key(vec!["reader", "writer"]).filters_digest(),
+            build_key(vec!["writer", "reader"]).filters_digest()
+        );
+    }
+
+    #[test]
+    fn test_filters_digest_distinguishes_filters() {
+        let key = build_key(vec!["reader"]);
+
+        let mut with_tenant = key.to_owned();
+        with_tenant.tenant = Some(Uuid::new_v4());
+
+        let mut with_permission = key.to_owned();
+        with_permission.permissioned_roles =
+            Some(vec![("reader".to_string(), Permission::Write)]);
+
+        assert_ne!(key.filters_digest(), with_tenant.filters_digest());
+        assert_ne!(key.filters_digest(), with_permission.filters_digest());
+        assert_ne!(
+            key.filters_digest(),
+            build_key(vec!["writer"]).filters_digest()
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use super::profile::Profile;
+
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+/// The default size limit of the injected profile header in bytes
+///
+/// Upstream proxies commonly reject headers larger than 8 KiB.
+///
+pub const DEFAULT_PROFILE_MAX_SIZE: usize = 8 * 1024;
+
+/// A part of the profile injected into the route requests
+///
+/// The account flags, like `isManager` and `accountIsActive`, are always
+/// injected.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ProfileField {
+    /// The account owners
+    Owners,
+
+    /// The licensed resources
+    ///
+    /// Routes protected by roles receive only the licensed resources of their
+    /// roles.
+    ///
+    LicensedResources,
+
+    /// The tenants owned by the account
+    TenantsOwnership,
+}
+
+/// The encoding of the injected profile
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ProfileEncoding {
+    /// The profile is sent as JSON
+    #[default]
+    Json,
+
+    /// The profile is compressed with gzip and encoded as base64url
+    Gzip,
+}
+
+/// The profile injected into the route requests
+///
+/// Users with many guest memberships produce large profiles. Routes could
+/// declare the profile parts they need, compress the profile and limit the
+/// profile header size. Requests whose profile exceeds the size limit are
+/// rejected with a bad gateway response instead of forwarded.
+///
+/// # Example
+///
+/// ```yaml
+/// profileProjection:
+///   fields:
+///     - owners
+///     - licensedResources
+///   encoding: gzip
+///   maxSizeInBytes: 4096
+/// ```
+///
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileProjection {
+    /// The profile parts injected besides the account flags
+    ///
+    /// An empty list injects the account flags only. Default to all parts.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<ProfileField>>,
+
+    /// The encoding of the injected profile
+    ///
+    /// Default to `json`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<ProfileEncoding>,
+
+    /// The size limit of the profile header in bytes
+    ///
+    /// The limit applies to the signed profile. Default to `8192`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size_in_bytes: Option<usize>,
+}
+
+impl ProfileProjection {
+    pub fn encoding(&self) -> ProfileEncoding {
+        self.encoding.to_owned().unwrap_or_default()
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size_in_bytes.unwrap_or(DEFAULT_PROFILE_MAX_SIZE)
+    }
+
+    /// Check if a profile part is injected
+    pub fn includes(&self, field: &ProfileField) -> bool {
+        match &self.fields {
+            None => true,
+            Some(fields) => fields.contains(field),
+        }
+    }
+
+    /// Check if the fields and the size limit are valid
+    ///
+    /// Fields should not be repeated and the size limit should be greater
+    /// than zero.
+    ///
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        let has_repeated_fields = self.fields.as_ref().is_some_and(|fields| {
+            fields
+                .iter()
+                .enumerate()
+                .any(|(index, field)| fields[..index].contains(field))
+        });
+
+        if has_repeated_fields || self.max_size_in_bytes == Some(0) {
+            return dto_err(
+                "Invalid profile projection: fields should not be repeated and the size limit should be greater than zero",
+            )
+            .as_error();
+        }
+
+        Ok(())
+    }
+
+    /// Remove the profile parts not injected into the route requests
+    ///
+    /// Licensed resources are limited to the route roles, if any.
+    ///
+    pub fn project(
+        &self,
+        profile: Profile,
+        roles: Option<Vec<String>>,
+    ) -> Profile {
+        let mut profile = match roles {
+            Some(roles) if self.includes(&ProfileField::LicensedResources) => {
+                profile.with_roles(roles)
+            }
+            _ => profile,
+        };
+
+        if !self.includes(&ProfileField::Owners) {
+            profile.owners = vec![];
+        }
+
+        if !self.includes(&ProfileField::LicensedResources) {
+            profile.licensed_resources = None;
+        }
+
+        if !self.includes(&ProfileField::TenantsOwnership) {
+            profile.tenants_ownership = None;
+        }
+
+        profile
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{
+        guest_role::Permission,
+        profile::{
+            LicensedResource, LicensedResources, Owner, TenantOwnership,
+            TenantsOwnership,
+        },
+    };
+
+    use chrono::Local;
+    use uuid::Uuid;
+
+    fn build_license(role: &str) -> LicensedResource {
+        LicensedResource {
+            acc_id: Uuid::new_v4(),
+            sys_acc: false,
+            tenant_id: Uuid::new_v4(),
+            acc_name: "account".to_string(),
+            role: role.to_string(),
+            perm: Permission::Read,
+            verified: true,
+        }
+    }
+
+    fn build_profile() -> Profile {
+        Profile::new(
+            vec![Owner {
+                id: Uuid::new_v4(),
+                email: "user@example.com".to_string(),
+                first_name: None,
+                last_name: None,
+                username: None,
+                is_principal: true,
+            }],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            None,
+            Some(LicensedResources::Records(vec![
+                build_license("reader"),
+                build_license("writer"),
+            ])),
+            Some(TenantsOwnership::Records(vec![TenantOwnership {
+                tenant: Uuid::new_v4(),
+                since: Local::now(),
+            }])),
+        )
+    }
+
+    #[test]
+    fn test_default_projection_keeps_the_profile() {
+        let profile = build_profile();
+
+        assert_eq!(
+            ProfileProjection::default().project(profile.to_owned(), None),
+            profile
+        );
+    }
+
+    #[test]
+    fn test_flags_only_projection_removes_the_profile_parts() {
+        let projection = ProfileProjection {
+            fields: Some(vec![]),
+            ..Default::default()
+        };
+
+        let profile = projection.project(build_profile(), None);
+
+        assert!(profile.owners.is_empty());
+        assert!(profile.licensed_resources.is_none());
+        assert!(profile.tenants_ownership.is_none());
+        assert!(profile.account_is_active);
+    }
+
+    #[test]
+    fn test_projection_limits_licensed_resources_to_route_roles() {
+        let projection = ProfileProjection {
+            fields: Some(vec![ProfileField::LicensedResources]),
+            ..Default::default()
+        };
+
+        let profile = projection
+            .project(build_profile(), Some(vec!["reader".to_string()]));
+
+        let licenses = profile.licensed_resources.unwrap().to_licenses_vector();
+
+        assert_eq!(licenses.len(), 1);
+        assert_eq!(licenses[0].role, "reader");
+        assert!(profile.owners.is_empty());
+        assert!(profile.tenants_ownership.is_none());
+    }
+
+    #[test]
+    fn test_invalid_projections_are_rejected() {
+        assert!(ProfileProjection::default().validate().is_ok());
+
+        assert!(ProfileProjection {
+            fields: Some(vec![ProfileField::Owners, ProfileField::Owners]),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+
+        assert!(ProfileProjection {
+            max_size_in_bytes: Some(0),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
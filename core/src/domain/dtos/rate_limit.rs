@@ -0,0 +1,393 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+/// The algorithm used to evaluate a rate limit policy
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum RateLimitAlgorithm {
+    /// Requests consume tokens of a bucket refilled at a constant rate
+    ///
+    /// Bursts up to the bucket capacity are accepted.
+    ///
+    #[default]
+    TokenBucket,
+
+    /// Requests are counted over a window sliding with the time
+    ///
+    /// The count of the previous window is weighted by the fraction of it
+    /// still covered by the sliding window.
+    ///
+    SlidingWindow,
+}
+
+/// The client attribute used to count the requests
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum RateLimitKey {
+    /// The IP address of the client
+    #[default]
+    ClientIp,
+
+    /// The id of the authenticated account
+    ///
+    /// Requests without an authenticated profile are counted by the client IP.
+    ///
+    AccountId,
+
+    /// The signature of the connection string sent by the client
+    ///
+    /// Requests without a connection string are counted by the client IP.
+    ///
+    ConnectionString,
+}
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitPolicy {
+    /// The algorithm used to evaluate the policy
+    ///
+    /// Default to `tokenBucket`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<RateLimitAlgorithm>,
+
+    /// The client attribute used to count the requests
+    ///
+    /// Default to `clientIp`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<RateLimitKey>,
+
+    /// The number of requests accepted by window
+    ///
+    /// For the token bucket algorithm it is also the bucket capacity.
+    ///
+    pub limit: u64,
+
+    /// The window size in seconds
+    pub window_in_secs: u64,
+}
+
+impl RateLimitPolicy {
+    pub fn algorithm(&self) -> RateLimitAlgorithm {
+        self.algorithm.unwrap_or_default()
+    }
+
+    pub fn key(&self) -> RateLimitKey {
+        self.key.unwrap_or_default()
+    }
+
+    pub fn window_in_millis(&self) -> u64 {
+        self.window_in_secs.max(1) * 1000
+    }
+
+    /// Build the value of the `RateLimit-Policy` header
+    pub fn to_header_value(&self) -> String {
+        format!("{};w={}", self.limit, self.window_in_secs)
+    }
+
+    /// Consume a request from the in-process state
+    ///
+    /// The state is reset if it was built by another algorithm. The same rules
+    /// are applied by the distributed counters.
+    ///
+    pub fn consume(
+        &self,
+        state: &mut Option<RateLimitState>,
+        now_in_millis: u64,
+    ) -> RateLimitDecision {
+        let limit = self.limit as f64;
+        let window = self.window_in_millis();
+
+        match (self.algorithm(), state.to_owned()) {
+            (
+                RateLimitAlgorithm::TokenBucket,
+                Some(RateLimitState::TokenBucket { tokens, updated_at }),
+            ) => {
+                let elapsed = now_in_millis.saturating_sub(updated_at) as f64;
+                let tokens =
+                    (tokens + elapsed * limit / window as f64).min(limit);
+
+                self.consume_token(state, tokens, now_in_millis)
+            }
+            (RateLimitAlgorithm::TokenBucket, _) => {
+                self.consume_token(state, limit, now_in_millis)
+            }
+            (
+                RateLimitAlgorithm::SlidingWindow,
+                Some(RateLimitState::SlidingWindow {
+                    window_id,
+                    current,
+                    previous,
+                }),
+            ) => {
+                let now_window_id = now_in_millis / window;
+
+                let (current, previous) = match now_window_id {
+                    id if id == window_id => (current, previous),
+                    id if id == window_id + 1 => (0, current),
+                    _ => (0, 0),
+                };
+
+                self.consume_window(state, current, previous, now_in_millis)
+            }
+            (RateLimitAlgorithm::SlidingWindow, _) => {
+                self.consume_window(state, 0, 0, now_in_millis)
+            }
+        }
+    }
+
+    fn consume_token(
+        &self,
+        state: &mut Option<RateLimitState>,
+        tokens: f64,
+        now_in_millis: u64,
+    ) -> RateLimitDecision {
+        let limit = self.limit as f64;
+        let millis_per_token = self.window_in_millis() as f64 / limit.max(1.0);
+
+        let (allowed, tokens) = match tokens >= 1.0 {
+            true => (true, tokens - 1.0),
+            false => (false, tokens),
+        };
+
+        *state = Some(RateLimitState::TokenBucket {
+            tokens,
+            updated_at: now_in_millis,
+        });
+
+        RateLimitDecision {
+            allowed,
+            limit: self.limit,
+            remaining: tokens.floor() as u64,
+            reset_in_millis: ((limit - tokens) * millis_per_token).ceil()
+                as u64,
+            retry_after_in_millis: match allowed {
+                true => None,
+                false => {
+                    Some(((1.0 - tokens) * millis_per_token).ceil() as u64)
+                }
+            },
+        }
+    }
+
+    fn consume_window(
+        &self,
+        state: &mut Option<RateLimitState>,
+        current: u64,
+        previous: u64,
+        now_in_millis: u64,
+    ) -> RateLimitDecision {
+        let limit = self.limit as f64;
+        let window = self.window_in_millis();
+        let elapsed = (now_in_millis % window) as f64 / window as f64;
+        let weighted_previous = previous as f64 * (1.0 - elapsed);
+
+        let allowed = weighted_previous + current as f64 + 1.0 <= limit;
+
+        let current = match allowed {
+            true => current + 1,
+            false => current,
+        };
+
+        *state = Some(RateLimitState::SlidingWindow {
+            window_id: now_in_millis / window,
+            current,
+            previous,
+        });
+
+        let reset_in_millis = window - now_in_millis % window;
+
+        RateLimitDecision {
+            allowed,
+            limit: self.limit,
+            remaining: (limit - weighted_previous - current as f64)
+                .max(0.0)
+                .floor() as u64,
+            reset_in_millis,
+            retry_after_in_millis: match allowed {
+                true => None,
+                //
+                // The request fits when the weight of the previous window
+                // decreases enough. Otherwise, it fits only at the next
+                // window.
+                //
+                false => {
+                    let available = limit - current as f64 - 1.0;
+
+                    match previous > 0 && available >= 0.0 {
+                        true => {
+                            let target = 1.0 - available / previous as f64;
+
+                            Some(
+                                (((target - elapsed) * window as f64).ceil()
+                                    as u64)
+                                    .clamp(1, reset_in_millis),
+                            )
+                        }
+                        false => Some(reset_in_millis),
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// The in-process state of a rate limit counter
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitState {
+    TokenBucket {
+        tokens: f64,
+        updated_at: u64,
+    },
+    SlidingWindow {
+        window_id: u64,
+        current: u64,
+        previous: u64,
+    },
+}
+
+/// The result of a rate limit evaluation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// If the request is accepted
+    pub allowed: bool,
+
+    /// The number of requests accepted by window
+    pub limit: u64,
+
+    /// The number of requests still accepted in the current window
+    pub remaining: u64,
+
+    /// The time until the quota is fully restored
+    pub reset_in_millis: u64,
+
+    /// The time until the next request is accepted
+    ///
+    /// Only set for rejected requests.
+    ///
+    pub retry_after_in_millis: Option<u64>,
+}
+
+impl RateLimitDecision {
+    pub fn reset_in_secs(&self) -> u64 {
+        self.reset_in_millis.div_ceil(1000)
+    }
+
+    pub fn retry_after_in_secs(&self) -> Option<u64> {
+        self.retry_after_in_millis
+            .map(|millis| millis.div_ceil(1000).max(1))
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_policy(algorithm: RateLimitAlgorithm) -> RateLimitPolicy {
+        RateLimitPolicy {
+            algorithm: Some(algorithm),
+            key: None,
+            limit: 3,
+            window_in_secs: 3,
+        }
+    }
+
+    #[test]
+    fn test_token_bucket_consume_works() {
+        let policy = build_policy(RateLimitAlgorithm::TokenBucket);
+        let mut state = None;
+
+        for remaining in [2, 1, 0] {
+            let decision = policy.consume(&mut state, 0);
+
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, remaining);
+        }
+
+        let rejected = policy.consume(&mut state, 0);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.retry_after_in_millis, Some(1000));
+        assert_eq!(rejected.retry_after_in_secs(), Some(1));
+
+        //
+        // A token is refilled each second
+        //
+        let refilled = policy.consume(&mut state, 1000);
+        assert!(refilled.allowed);
+        assert_eq!(refilled.remaining, 0);
+        assert_eq!(refilled.reset_in_secs(), 3);
+    }
+
+    #[test]
+    fn test_sliding_window_consume_works() {
+        let policy = build_policy(RateLimitAlgorithm::SlidingWindow);
+        let mut state = None;
+
+        for _ in 0..3 {
+            assert!(policy.consume(&mut state, 500).allowed);
+        }
+
+        let rejected = policy.consume(&mut state, 2000);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining, 0);
+        assert_eq!(rejected.retry_after_in_millis, Some(1000));
+
+        //
+        // At the middle of the next window the previous requests are weighted
+        // by one half.
+        //
+        let decision = policy.consume(&mut state, 4500);
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 0);
+        assert!(!policy.consume(&mut state, 4500).allowed);
+
+        //
+        // Windows older than the previous one are discarded
+        //
+        let decision = policy.consume(&mut state, 12000);
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 2);
+    }
+
+    #[test]
+    fn test_consume_resets_state_of_other_algorithm() {
+        let mut state = None;
+
+        build_policy(RateLimitAlgorithm::SlidingWindow).consume(&mut state, 0);
+
+        let decision = build_policy(RateLimitAlgorithm::TokenBucket)
+            .consume(&mut state, 0);
+
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 2);
+    }
+}
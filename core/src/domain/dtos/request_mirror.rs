@@ -0,0 +1,111 @@
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+/// The default size limit of the mirrored request bodies in bytes
+pub const DEFAULT_MIRROR_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// The mirroring of the route requests to a secondary service
+///
+/// Mirrored requests are sent after the route authorization, including the
+/// injected profile. The route secrets are never sent to the mirror service.
+/// Mirror responses are discarded, then they never affect the client response.
+///
+/// # Example
+///
+/// ```yaml
+/// mirror:
+///   service: accounts-v2
+///   percentage: 10
+///   maxBodySizeInBytes: 65536
+/// ```
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestMirror {
+    /// The mirror service name
+    pub service: String,
+
+    /// The percentage of the requests sent to the mirror
+    pub percentage: u8,
+
+    /// The size limit of the mirrored request bodies in bytes
+    ///
+    /// Request bodies should be buffered before being sent to the mirror.
+    /// Requests with larger bodies are not mirrored. Default to `1048576`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_body_size_in_bytes: Option<usize>,
+}
+
+impl RequestMirror {
+    pub fn max_body_size(&self) -> usize {
+        self.max_body_size_in_bytes
+            .unwrap_or(DEFAULT_MIRROR_MAX_BODY_SIZE)
+    }
+
+    /// Check if the service and the sampling values are valid
+    ///
+    /// The percentage should be between 1 and 100 and the body size limit
+    /// should be greater than zero.
+    ///
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        if self.service.trim().is_empty()
+            || !(1..=100).contains(&self.percentage)
+            || self.max_body_size_in_bytes == Some(0)
+        {
+            return dto_err(
+                "Invalid request mirror: the service should not be empty, the percentage should be between 1 and 100 and the body size limit should be greater than zero",
+            )
+            .as_error();
+        }
+
+        Ok(())
+    }
+
+    /// Sample a request to be mirrored
+    pub fn sample(&self) -> bool {
+        rand::thread_rng().gen_range(0..100) < self.percentage
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_mirror(percentage: u8) -> RequestMirror {
+        RequestMirror {
+            service: "accounts-v2".to_string(),
+            percentage,
+            max_body_size_in_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_sampling_follows_percentage() {
+        assert!((0..100).all(|_| build_mirror(100).sample()));
+
+        let mirrored = (0..1000).filter(|_| build_mirror(10).sample()).count();
+
+        assert!(mirrored > 30 && mirrored < 200);
+    }
+
+    #[test]
+    fn test_invalid_mirrors_are_rejected() {
+        assert!(build_mirror(10).validate().is_ok());
+        assert!(build_mirror(0).validate().is_err());
+        assert!(build_mirror(101).validate().is_err());
+
+        let mut mirror = build_mirror(10);
+        mirror.max_body_size_in_bytes = Some(0);
+
+        assert!(mirror.validate().is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use utoipa::{ToResponse, ToSchema};
+
+/// The status codes which trigger a retry by default
+pub const DEFAULT_RETRY_STATUS_CODES: [u16; 3] = [502, 503, 504];
+
+/// The strategy used to compute the delay between retries
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum BackoffStrategy {
+    /// The same delay is used before each retry
+    Fixed,
+
+    /// The delay is doubled after each retry
+    #[default]
+    Exponential,
+}
+
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryBackoff {
+    /// The strategy used to compute the delay
+    ///
+    /// Default to `exponential`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<BackoffStrategy>,
+
+    /// The delay before the first retry in milliseconds
+    pub delay_in_millis: u64,
+
+    /// The maximum delay between retries in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay_in_millis: Option<u64>,
+}
+
+/// The retry policy of the requests sent to the upstream targets
+///
+/// Only requests with idempotent methods are retried, unless the route
+/// explicitly accepts non-idempotent retries. Policies declared by a route
+/// override the ones declared by its service.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one
+    pub max_attempts: u32,
+
+    /// The delay between attempts
+    ///
+    /// If empty, retries are sent immediately.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff: Option<RetryBackoff>,
+
+    /// The upstream response status codes which trigger a retry
+    ///
+    /// Default to `502`, `503` and `504`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on_status_codes: Option<Vec<u16>>,
+
+    /// If connection errors trigger a retry
+    ///
+    /// Default to `true`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on_connect_errors: Option<bool>,
+}
+
+impl RetryPolicy {
+    /// Check if an upstream response status should be retried
+    pub fn retry_on_status(&self, status: u16) -> bool {
+        match &self.retry_on_status_codes {
+            Some(codes) => codes.contains(&status),
+            None => DEFAULT_RETRY_STATUS_CODES.contains(&status),
+        }
+    }
+
+    /// Check if connection errors should be retried
+    pub fn retry_on_connect_errors(&self) -> bool {
+        self.retry_on_connect_errors.unwrap_or(true)
+    }
+
+    /// Compute the delay before a retry
+    ///
+    /// The `retry` argument is the number of the retry, starting from one.
+    ///
+    pub fn delay_before_retry(&self, retry: u32) -> Duration {
+        let backoff = match &self.backoff {
+            None => return Duration::ZERO,
+            Some(backoff) => backoff,
+        };
+
+        let delay = match backoff.strategy.unwrap_or_default() {
+            BackoffStrategy::Fixed => backoff.delay_in_millis,
+            BackoffStrategy::Exponential => backoff
+                .delay_in_millis
+                .saturating_mul(2u64.saturating_pow(retry.saturating_sub(1))),
+        };
+
+        Duration::from_millis(match backoff.max_delay_in_millis {
+            Some(max_delay) => delay.min(max_delay),
+            None => delay,
+        })
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_policy(backoff: Option<RetryBackoff>) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            backoff,
+            retry_on_status_codes: None,
+            retry_on_connect_errors: None,
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_defaults_works() {
+        let policy = build_policy(None);
+
+        assert!(policy.retry_on_status(503));
+        assert!(!policy.retry_on_status(500));
+        assert!(policy.retry_on_connect_errors());
+        assert_eq!(policy.delay_before_retry(1), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_works() {
+        let exponential = build_policy(Some(RetryBackoff {
+            strategy: None,
+            delay_in_millis: 100,
+            max_delay_in_millis: Some(300),
+        }));
+
+        assert_eq!(
+            exponential.delay_before_retry(1),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            exponential.delay_before_retry(2),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            exponential.delay_before_retry(3),
+            Duration::from_millis(300)
+        );
+
+        let fixed = build_policy(Some(RetryBackoff {
+            strategy: Some(BackoffStrategy::Fixed),
+            delay_in_millis: 100,
+            max_delay_in_millis: None,
+        }));
+
+        assert_eq!(fixed.delay_before_retry(3), Duration::from_millis(100));
+    }
+}
@@ -1,16 +1,28 @@
 use super::{
+    allowed_source::{AllowedSource, ResolvedHostnames},
+    cors::CorsPolicy,
+    header_policy::{HeaderPolicy, HeaderTransformation},
     http::{HttpMethod, Protocol},
     http_secret::HttpSecret,
+    path_rewrite::PathRewriteRule,
+    profile_projection::ProfileProjection,
+    rate_limit::RateLimitPolicy,
+    request_mirror::RequestMirror,
+    retry::RetryPolicy,
     route_type::RouteType,
     service::Service,
+    timeout::{UpstreamTimeouts, DEFAULT_IDLE_TIMEOUT},
+    traffic_split::TrafficSplit,
+    upstream::UpstreamTarget,
 };
 
-use actix_web::http::{uri::PathAndQuery, Uri};
+use actix_web::http::{header::HeaderMap, uri::PathAndQuery, Uri};
 use mycelium_base::{
     dtos::Parent,
     utils::errors::{dto_err, execution_err, MappedErrors},
 };
 use serde::{Deserialize, Serialize};
+use std::{net::IpAddr, str::FromStr};
 use utoipa::{ToResponse, ToSchema};
 use uuid::Uuid;
 
@@ -37,10 +49,22 @@ pub struct Route {
     /// The route protocol
     pub protocol: Protocol,
 
-    /// The route is active
+    /// The sources allowed to reach the route
+    ///
+    /// Each source could be an IP address, a CIDR range or a hostname. If
+    /// empty, requests from any source are accepted.
+    ///
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_sources: Option<Vec<String>>,
 
+    /// The allowed sources parsed when the route is compiled
+    ///
+    /// An empty list accepts requests from any source. If empty, the sources
+    /// are parsed while checking the request source.
+    ///
+    #[serde(skip)]
+    compiled_allowed_sources: Option<Vec<AllowedSource>>,
+
     /// The route secret name if it exists
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secret_name: Option<String>,
@@ -52,6 +76,78 @@ pub struct Route {
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept_insecure_routing: Option<bool>,
+
+    /// The route rate limit policy
+    ///
+    /// If empty, the route requests are not limited.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitPolicy>,
+
+    /// The timeouts of the requests sent to the route
+    ///
+    /// Override the service timeouts.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<UpstreamTimeouts>,
+
+    /// The retry policy of the requests sent to the route
+    ///
+    /// Override the service retry policy.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+
+    /// Accept retries of requests with non-idempotent methods
+    ///
+    /// Requests with methods like POST and PATCH could produce duplicated
+    /// effects if retried. Then, they are retried only if the route explicitly
+    /// accepts it.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_non_idempotent_retries: Option<bool>,
+
+    /// The rules used to rewrite the upstream path
+    ///
+    /// Rules are applied after removing the service name from the request
+    /// path. If empty, the remaining path is sent as is.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<Vec<PathRewriteRule>>,
+
+    /// The route header transformations
+    ///
+    /// Applied after the service header transformations.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_policy: Option<HeaderPolicy>,
+
+    /// The route traffic split between backend services
+    ///
+    /// Backends replace the upstream targets of the route service. The
+    /// remaining route and service settings are kept.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_split: Option<TrafficSplit>,
+
+    /// The mirroring of the route requests to a secondary service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror: Option<RequestMirror>,
+
+    /// The route CORS policy
+    ///
+    /// Replaces the CORS policy of the route service.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors: Option<CorsPolicy>,
+
+    /// The profile injected into the route requests
+    ///
+    /// If empty, the full profile is injected as JSON, limited to the default
+    /// profile size.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_projection: Option<ProfileProjection>,
 }
 
 impl Route {
@@ -91,8 +187,161 @@ impl Route {
             path: downstream_url,
             protocol,
             allowed_sources,
+            compiled_allowed_sources: None,
             secret_name,
             accept_insecure_routing: route_without_tls,
+            rate_limit: None,
+            timeouts: None,
+            retry: None,
+            accept_non_idempotent_retries: None,
+            rewrite: None,
+            header_policy: None,
+            traffic_split: None,
+            mirror: None,
+            cors: None,
+            profile_projection: None,
+        }
+    }
+
+    /// Set the route rate limit policy.
+    pub fn with_rate_limit(
+        mut self,
+        rate_limit: Option<RateLimitPolicy>,
+    ) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Set the route upstream timeouts.
+    pub fn with_timeouts(mut self, timeouts: Option<UpstreamTimeouts>) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Set the route retry policy.
+    pub fn with_retry(
+        mut self,
+        retry: Option<RetryPolicy>,
+        accept_non_idempotent_retries: Option<bool>,
+    ) -> Self {
+        self.retry = retry;
+        self.accept_non_idempotent_retries = accept_non_idempotent_retries;
+        self
+    }
+
+    /// Set the route path rewrite rules.
+    pub fn with_rewrite(
+        mut self,
+        rewrite: Option<Vec<PathRewriteRule>>,
+    ) -> Self {
+        self.rewrite = rewrite;
+        self
+    }
+
+    /// Set the route header transformations.
+    pub fn with_header_policy(
+        mut self,
+        header_policy: Option<HeaderPolicy>,
+    ) -> Self {
+        self.header_policy = header_policy;
+        self
+    }
+
+    /// Set the route traffic split between backend services.
+    pub fn with_traffic_split(
+        mut self,
+        traffic_split: Option<TrafficSplit>,
+    ) -> Self {
+        self.traffic_split = traffic_split;
+        self
+    }
+
+    /// Set the mirroring of the route requests.
+    pub fn with_mirror(mut self, mirror: Option<RequestMirror>) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Set the route CORS policy.
+    pub fn with_cors(mut self, cors: Option<CorsPolicy>) -> Self {
+        self.cors = cors;
+        self
+    }
+
+    /// Set the profile injected into the route requests.
+    pub fn with_profile_projection(
+        mut self,
+        profile_projection: Option<ProfileProjection>,
+    ) -> Self {
+        self.profile_projection = profile_projection;
+        self
+    }
+
+    /// Get the connect timeout of the route in seconds.
+    ///
+    /// The route timeout overrides the service one.
+    pub fn connect_timeout_in_secs(&self) -> Option<u64> {
+        self.timeouts
+            .as_ref()
+            .and_then(|timeouts| timeouts.connect_timeout_in_secs)
+            .or(match &self.service {
+                Parent::Record(service) => service
+                    .timeouts
+                    .as_ref()
+                    .and_then(|timeouts| timeouts.connect_timeout_in_secs),
+                Parent::Id(_) => None,
+            })
+    }
+
+    /// Get the response timeout of the route in seconds.
+    ///
+    /// The route timeout overrides the service one.
+    pub fn response_timeout_in_secs(&self) -> Option<u64> {
+        self.timeouts
+            .as_ref()
+            .and_then(|timeouts| timeouts.response_timeout_in_secs)
+            .or(match &self.service {
+                Parent::Record(service) => service
+                    .timeouts
+                    .as_ref()
+                    .and_then(|timeouts| timeouts.response_timeout_in_secs),
+                Parent::Id(_) => None,
+            })
+    }
+
+    /// Get the idle timeout of the route WebSocket connections in seconds.
+    ///
+    /// The route timeout overrides the service one.
+    pub fn idle_timeout_in_secs(&self) -> u64 {
+        self.timeouts
+            .as_ref()
+            .and_then(|timeouts| timeouts.idle_timeout_in_secs)
+            .or(match &self.service {
+                Parent::Record(service) => service
+                    .timeouts
+                    .as_ref()
+                    .and_then(|timeouts| timeouts.idle_timeout_in_secs),
+                Parent::Id(_) => None,
+            })
+            .unwrap_or(DEFAULT_IDLE_TIMEOUT)
+    }
+
+    /// Get the retry policy of a request method.
+    ///
+    /// The route policy overrides the service one. Returns `None` for
+    /// non-idempotent methods, unless the route accepts non-idempotent
+    /// retries.
+    pub fn retry_policy(&self, method: HttpMethod) -> Option<RetryPolicy> {
+        if !method.is_idempotent()
+            && !self.accept_non_idempotent_retries.unwrap_or(false)
+        {
+            return None;
+        }
+
+        match (&self.retry, &self.service) {
+            (Some(retry), _) => Some(retry.to_owned()),
+            (None, Parent::Record(service)) => service.retry.to_owned(),
+            (None, Parent::Id(_)) => None,
         }
     }
 
@@ -112,6 +361,206 @@ impl Route {
         }
     }
 
+    /// Parse the allowed sources of the route.
+    ///
+    /// Returns `None` if the route does not restrict the request sources.
+    pub fn parsed_allowed_sources(
+        &self,
+    ) -> Result<Option<Vec<AllowedSource>>, MappedErrors> {
+        let sources = match &self.allowed_sources {
+            None => return Ok(None),
+            Some(sources) if sources.is_empty() => return Ok(None),
+            Some(sources) => sources,
+        };
+
+        let mut parsed_sources = vec![];
+
+        for source in sources {
+            parsed_sources.push(AllowedSource::from_str(source)?);
+        }
+
+        Ok(Some(parsed_sources))
+    }
+
+    /// Parse the allowed sources once, when the route is compiled.
+    ///
+    /// Invalid sources are kept unparsed, then they are reported while
+    /// checking the request source.
+    pub fn compile_allowed_sources(&mut self) {
+        self.compiled_allowed_sources = self
+            .parsed_allowed_sources()
+            .ok()
+            .map(|sources| sources.unwrap_or_default());
+    }
+
+    /// Check if the request source is allowed.
+    ///
+    /// Returns `true` if the route has no source restrictions or if the source
+    /// matches at least one of the allowed sources. Requests without a known
+    /// source address are rejected by restricted routes. Hostname sources are
+    /// matched against the addresses resolved during the routes loading.
+    pub fn allow_source(
+        &self,
+        source: Option<IpAddr>,
+        resolved_hostnames: &ResolvedHostnames,
+    ) -> Result<bool, MappedErrors> {
+        let parsed_sources;
+
+        let allowed_sources = match &self.compiled_allowed_sources {
+            Some(sources) => sources,
+            None => {
+                parsed_sources =
+                    self.parsed_allowed_sources()?.unwrap_or_default();
+
+                &parsed_sources
+            }
+        };
+
+        if allowed_sources.is_empty() {
+            return Ok(true);
+        }
+
+        let source = match source {
+            None => return Ok(false),
+            Some(source) => source,
+        };
+
+        for allowed_source in allowed_sources {
+            if allowed_source.matches(source, resolved_hostnames) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Validate the route path rewrite rules.
+    pub fn validate_rewrite(&self) -> Result<(), MappedErrors> {
+        for rule in self.rewrite.iter().flatten() {
+            rule.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate the service and route header transformations.
+    pub fn validate_header_policy(&self) -> Result<(), MappedErrors> {
+        if let Parent::Record(service) = &self.service {
+            if let Some(header_policy) = &service.header_policy {
+                header_policy.validate()?;
+            }
+        }
+
+        if let Some(header_policy) = &self.header_policy {
+            header_policy.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the CORS policy of the route.
+    ///
+    /// The route policy replaces the service one. Returns `None` if both are
+    /// empty.
+    pub fn cors_policy(&self) -> Option<&CorsPolicy> {
+        match (&self.cors, &self.service) {
+            (Some(cors), _) => Some(cors),
+            (None, Parent::Record(service)) => service.cors.as_ref(),
+            (None, Parent::Id(_)) => None,
+        }
+    }
+
+    /// Validate the service and route CORS policies.
+    pub fn validate_cors(&self) -> Result<(), MappedErrors> {
+        if let Parent::Record(service) = &self.service {
+            if let Some(cors) = &service.cors {
+                cors.validate()?;
+            }
+        }
+
+        if let Some(cors) = &self.cors {
+            cors.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply the header transformations of the requests sent to the upstream.
+    ///
+    /// The service transformations are applied before the route ones.
+    pub fn apply_request_header_policy(
+        &self,
+        headers: &mut HeaderMap,
+    ) -> Result<(), MappedErrors> {
+        for transformation in
+            self.header_transformations(|policy| policy.request.as_ref())
+        {
+            transformation.apply(headers)?;
+        }
+
+        Ok(())
+    }
+
+    /// Apply the header transformations of the responses sent to the client.
+    ///
+    /// The service transformations are applied before the route ones.
+    pub fn apply_response_header_policy(
+        &self,
+        headers: &mut HeaderMap,
+    ) -> Result<(), MappedErrors> {
+        for transformation in
+            self.header_transformations(|policy| policy.response.as_ref())
+        {
+            transformation.apply(headers)?;
+        }
+
+        Ok(())
+    }
+
+    fn header_transformations<'a>(
+        &'a self,
+        select: impl Fn(&'a HeaderPolicy) -> Option<&'a HeaderTransformation>,
+    ) -> Vec<&'a HeaderTransformation> {
+        let service_policy = match &self.service {
+            Parent::Record(service) => service.header_policy.as_ref(),
+            Parent::Id(_) => None,
+        };
+
+        service_policy
+            .into_iter()
+            .chain(self.header_policy.as_ref())
+            .filter_map(select)
+            .collect()
+    }
+
+    /// Build the path of the request sent to the upstream.
+    ///
+    /// The `path` argument is the request path without the gateway scope. Only
+    /// the leading service name segment is removed, then the rewrite rules are
+    /// applied in the declaration order.
+    pub fn build_upstream_path(
+        &self,
+        path: &str,
+    ) -> Result<String, MappedErrors> {
+        let service = match self.service {
+            Parent::Record(ref service) => service,
+            Parent::Id(_) => return execution_err(
+                "Unexpected error on build upstream path: service not found",
+            )
+            .as_error(),
+        };
+
+        let mut upstream_path =
+            PathRewriteRule::StripPrefix(format!("/{}", service.name))
+                .apply(path)?;
+
+        for rule in self.rewrite.iter().flatten() {
+            upstream_path = rule.apply(&upstream_path)?;
+        }
+
+        Ok(upstream_path)
+    }
+
     /// Build a actix_web::http::Uri from itself.
     pub async fn build_uri(&self) -> Result<Uri, MappedErrors> {
         let service = match self.service {
@@ -125,7 +574,17 @@ impl Route {
         };
 
         let host = service.to_owned().host;
-        let path_parts = host.split("/").collect::<Vec<&str>>();
+
+        self.build_target_uri(&UpstreamTarget::new(host, None))
+            .await
+    }
+
+    /// Build a actix_web::http::Uri pointing to an upstream target.
+    pub async fn build_target_uri(
+        &self,
+        target: &UpstreamTarget,
+    ) -> Result<Uri, MappedErrors> {
+        let path_parts = target.host.split("/").collect::<Vec<&str>>();
         let domain = path_parts[0];
 
         match Uri::builder()
@@ -233,3 +692,101 @@ impl Route {
         Ok(None)
     }
 }
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_route(rewrite: Option<Vec<PathRewriteRule>>) -> Route {
+        Route::new(
+            None,
+            Service::new(
+                None,
+                "users".to_string(),
+                "localhost:8080".to_string(),
+                None,
+                None,
+                None,
+                vec![],
+                None,
+            ),
+            RouteType::Public,
+            vec![HttpMethod::All],
+            "/users*".to_string(),
+            Protocol::Http,
+            None,
+            None,
+            None,
+        )
+        .with_rewrite(rewrite)
+    }
+
+    #[test]
+    fn test_build_upstream_path_strips_only_leading_service_name() {
+        let route = build_route(None);
+
+        assert_eq!(
+            route.build_upstream_path("/users/users/42/users").unwrap(),
+            "/users/42/users"
+        );
+        assert_eq!(
+            route
+                .build_upstream_path("/users/profile/users-list")
+                .unwrap(),
+            "/profile/users-list"
+        );
+        assert_eq!(route.build_upstream_path("/users").unwrap(), "/");
+        assert_eq!(
+            route.build_upstream_path("/users-v2/items").unwrap(),
+            "/users-v2/items"
+        );
+    }
+
+    #[test]
+    fn test_build_upstream_path_applies_rules_in_order() {
+        let route = build_route(Some(vec![
+            PathRewriteRule::StripPrefix("/v1".to_string()),
+            PathRewriteRule::AddPrefix("/internal/users".to_string()),
+            PathRewriteRule::Regex {
+                pattern: r"^/internal/users/(\d+)$".to_string(),
+                replacement: "/internal/accounts/$1".to_string(),
+            },
+        ]));
+
+        assert!(route.validate_rewrite().is_ok());
+        assert_eq!(
+            route.build_upstream_path("/users/v1/42").unwrap(),
+            "/internal/accounts/42"
+        );
+        assert_eq!(
+            route.build_upstream_path("/users/v1/users/42").unwrap(),
+            "/internal/users/users/42"
+        );
+    }
+
+    #[test]
+    fn test_compiled_allowed_sources_works() {
+        let mut route = build_route(None);
+        let resolved = ResolvedHostnames::new();
+        let source = IpAddr::from_str("10.1.2.3").ok();
+
+        route.compile_allowed_sources();
+        assert!(route.allow_source(source, &resolved).unwrap());
+
+        route.allowed_sources = Some(vec!["10.0.0.0/8".to_string()]);
+        route.compile_allowed_sources();
+        assert!(route.allow_source(source, &resolved).unwrap());
+        assert!(!route.allow_source(None, &resolved).unwrap());
+        assert!(!route
+            .allow_source(IpAddr::from_str("11.0.0.1").ok(), &resolved)
+            .unwrap());
+
+        route.allowed_sources = Some(vec!["invalid_host!".to_string()]);
+        route.compile_allowed_sources();
+        assert!(route.allow_source(source, &resolved).is_err());
+    }
+}
@@ -0,0 +1,408 @@
+use super::{
+    http::{HttpMethod, Protocol},
+    route::Route,
+};
+
+use mycelium_base::dtos::Parent;
+use std::{cmp::Reverse, collections::HashMap};
+use tracing::error;
+use wildmatch::WildMatch;
+
+/// The compiled routes of the gateway
+///
+/// Routes are compiled once at load time into a prefix tree for each service.
+/// The lookup cost depends on the length of the requested path instead of the
+/// number of registered routes. The allowed sources of the routes are parsed
+/// during the compilation.
+///
+/// gRPC clients call fixed paths (`/<package>.<Service>/<Method>`), without
+/// the gateway service name. Then, gRPC routes are compiled into a single tree
+/// shared by all services, matched by the full request path.
+///
+#[derive(Debug, Clone, Default)]
+pub struct RoutesIndex {
+    services: HashMap<String, RouteTree>,
+    grpc: RouteTree,
+}
+
+impl RoutesIndex {
+    /// Compile the routes into a prefix tree per service
+    pub fn compile(routes: &[Route]) -> Self {
+        let mut services = HashMap::<String, RouteTree>::new();
+        let mut grpc = RouteTree::default();
+
+        for (order, route) in routes.iter().enumerate() {
+            let service_name = match &route.service {
+                Parent::Record(service) => service.name.to_owned(),
+                Parent::Id(_) => {
+                    error!(
+                        "Service not found when trying to compile the route: {:?}",
+                        route.id.to_owned()
+                    );
+
+                    continue;
+                }
+            };
+
+            let mut route = route.to_owned();
+            route.compile_allowed_sources();
+
+            if route.protocol == Protocol::Grpc {
+                grpc.insert(route, order);
+                continue;
+            }
+
+            services
+                .entry(service_name)
+                .or_default()
+                .insert(route, order);
+        }
+
+        Self { services, grpc }
+    }
+
+    /// Check if the index has no compiled routes
+    pub fn is_empty(&self) -> bool {
+        self.services.is_empty() && self.grpc.is_empty()
+    }
+
+    /// Find the gRPC route which best matches the path
+    ///
+    /// gRPC calls are always sent with the POST method. See [`RouteTree::find`]
+    /// for details about the matching rules.
+    ///
+    pub fn find_grpc(&self, path: &str) -> Option<&Route> {
+        self.grpc.find(path, HttpMethod::Post)
+    }
+
+    /// Find the route of a service which best matches the path and method
+    ///
+    /// See [`RouteTree::find`] for details about the matching rules.
+    ///
+    pub fn find(
+        &self,
+        service_name: &str,
+        path: &str,
+        method: HttpMethod,
+    ) -> Option<&Route> {
+        self.services.get(service_name)?.find(path, method)
+    }
+}
+
+/// A prefix tree of the routes of a single service
+///
+/// Each route is stored at the node reached by the literal prefix of its path
+/// pattern (the characters before the first wildcard).
+///
+#[derive(Debug, Clone, Default)]
+pub struct RouteTree {
+    children: HashMap<char, RouteTree>,
+    routes: Vec<CompiledRoute>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRoute {
+    route: Route,
+
+    /// The pattern matcher, or `None` for literal paths
+    matcher: Option<WildMatch>,
+
+    /// The number of literal characters of the pattern
+    literal_chars: usize,
+
+    /// The declaration order of the route
+    order: usize,
+}
+
+impl CompiledRoute {
+    fn matches_path(&self, path: &str) -> bool {
+        match &self.matcher {
+            None => self.route.path == path,
+            Some(matcher) => matcher.matches(path),
+        }
+    }
+
+    fn matches_method(&self, method: HttpMethod) -> bool {
+        let methods = &self.route.methods;
+
+        !methods.contains(&HttpMethod::None)
+            && (methods.contains(&HttpMethod::All) || methods.contains(&method))
+    }
+}
+
+impl RouteTree {
+    fn is_empty(&self) -> bool {
+        self.routes.is_empty() && self.children.is_empty()
+    }
+
+    fn insert(&mut self, route: Route, order: usize) {
+        let literal_prefix = route
+            .path
+            .split(|c: char| c == '*' || c == '?')
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+
+        let is_literal = literal_prefix.len() == route.path.len();
+
+        let compiled = CompiledRoute {
+            matcher: match is_literal {
+                true => None,
+                false => Some(WildMatch::new(&route.path)),
+            },
+            literal_chars: route
+                .path
+                .chars()
+                .filter(|c| *c != '*' && *c != '?')
+                .count(),
+            route,
+            order,
+        };
+
+        let node = literal_prefix
+            .chars()
+            .fold(self, |node, c| node.children.entry(c).or_default());
+
+        node.routes.push(compiled);
+
+        //
+        // Literal paths come first, followed by the patterns with more literal
+        // characters. The declaration order resolves the remaining ties.
+        //
+        node.routes.sort_by_key(|compiled| {
+            (
+                compiled.matcher.is_some(),
+                Reverse(compiled.literal_chars),
+                compiled.order,
+            )
+        });
+    }
+
+    /// Find the route which best matches the path and method
+    ///
+    /// Literal paths beat wildcard patterns and longer literal prefixes beat
+    /// shorter ones. Routes accepting the request method are preferred. If no
+    /// route accepts the method, the most specific route matching the path is
+    /// returned, allowing the caller to reject the method explicitly.
+    ///
+    pub fn find(&self, path: &str, method: HttpMethod) -> Option<&Route> {
+        let mut visited = vec![self];
+        let mut node = self;
+
+        for c in path.chars() {
+            match node.children.get(&c) {
+                Some(child) => {
+                    node = child;
+                    visited.push(child);
+                }
+                None => break,
+            }
+        }
+
+        let mut path_match: Option<&Route> = None;
+
+        for node in visited.into_iter().rev() {
+            for compiled in node.routes.iter() {
+                if !compiled.matches_path(path) {
+                    continue;
+                }
+
+                if compiled.matches_method(method) {
+                    return Some(&compiled.route);
+                }
+
+                if path_match.is_none() {
+                    path_match = Some(&compiled.route);
+                }
+            }
+        }
+
+        path_match
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dtos::{route_type::RouteType, service::Service};
+
+    fn build_routes(paths: Vec<(&str, Vec<HttpMethod>)>) -> Vec<Route> {
+        build_protocol_routes(paths, Protocol::Http)
+    }
+
+    fn build_protocol_routes(
+        paths: Vec<(&str, Vec<HttpMethod>)>,
+        protocol: Protocol,
+    ) -> Vec<Route> {
+        let service = Service::new(
+            None,
+            "service".to_string(),
+            "localhost:8080".to_string(),
+            None,
+            None,
+            None,
+            vec![],
+            None,
+        );
+
+        paths
+            .into_iter()
+            .map(|(path, methods)| {
+                Route::new(
+                    None,
+                    service.to_owned(),
+                    RouteType::Public,
+                    methods,
+                    path.to_string(),
+                    protocol,
+                    None,
+                    None,
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn find_path(
+        index: &RoutesIndex,
+        path: &str,
+        method: HttpMethod,
+    ) -> Option<String> {
+        index
+            .find("service", path, method)
+            .map(|route| route.path.to_owned())
+    }
+
+    #[test]
+    fn test_routes_index_specificity_works() {
+        let index = RoutesIndex::compile(&build_routes(vec![
+            ("/protected*", vec![HttpMethod::Get]),
+            ("/protected/roles*", vec![HttpMethod::Get]),
+            ("/protected/roles/health", vec![HttpMethod::Get]),
+            ("/*", vec![HttpMethod::All]),
+        ]));
+
+        assert_eq!(
+            find_path(&index, "/protected/accounts", HttpMethod::Get),
+            Some("/protected*".to_string())
+        );
+
+        assert_eq!(
+            find_path(&index, "/protected/roles/1", HttpMethod::Get),
+            Some("/protected/roles*".to_string())
+        );
+
+        assert_eq!(
+            find_path(&index, "/protected/roles/health", HttpMethod::Get),
+            Some("/protected/roles/health".to_string())
+        );
+
+        assert_eq!(
+            find_path(&index, "/public", HttpMethod::Get),
+            Some("/*".to_string())
+        );
+
+        assert_eq!(
+            index.find("other-service", "/public", HttpMethod::Get),
+            None
+        );
+    }
+
+    #[test]
+    fn test_routes_index_method_matching_works() {
+        let index = RoutesIndex::compile(&build_routes(vec![
+            ("/accounts*", vec![HttpMethod::Get]),
+            ("/accounts*", vec![HttpMethod::Post, HttpMethod::Put]),
+            ("/accounts/*/tags", vec![HttpMethod::Delete]),
+        ]));
+
+        let get = index
+            .find("service", "/accounts/1", HttpMethod::Get)
+            .unwrap();
+        assert_eq!(get.methods, vec![HttpMethod::Get]);
+
+        let post = index
+            .find("service", "/accounts/1", HttpMethod::Post)
+            .unwrap();
+        assert_eq!(post.methods, vec![HttpMethod::Post, HttpMethod::Put]);
+
+        //
+        // The most specific path match is returned when no route accepts the
+        // method.
+        //
+        let patch = index
+            .find("service", "/accounts/1/tags", HttpMethod::Patch)
+            .unwrap();
+        assert_eq!(patch.path, "/accounts/*/tags".to_string());
+
+        let get_tags = index
+            .find("service", "/accounts/1/tags", HttpMethod::Get)
+            .unwrap();
+        assert_eq!(get_tags.path, "/accounts*".to_string());
+    }
+
+    #[test]
+    fn test_routes_index_prefers_first_declared_route() {
+        let mut routes = build_routes(vec![
+            ("/protected*", vec![HttpMethod::Get]),
+            ("/protected*", vec![HttpMethod::Get]),
+        ]);
+
+        routes[0].group = RouteType::Protected;
+
+        let index = RoutesIndex::compile(&routes);
+
+        let route = index
+            .find("service", "/protected/accounts", HttpMethod::Get)
+            .unwrap();
+
+        assert!(matches!(route.group, RouteType::Protected));
+    }
+
+    #[test]
+    fn test_routes_index_grpc_matching_works() {
+        let mut routes = build_protocol_routes(
+            vec![
+                ("/users.v1.UserService/*", vec![HttpMethod::Post]),
+                ("/users.v1.UserService/GetUser", vec![HttpMethod::Post]),
+            ],
+            Protocol::Grpc,
+        );
+
+        routes.extend(build_routes(vec![("/*", vec![HttpMethod::All])]));
+
+        let index = RoutesIndex::compile(&routes);
+
+        assert_eq!(
+            index
+                .find_grpc("/users.v1.UserService/GetUser")
+                .map(|route| route.path.to_owned()),
+            Some("/users.v1.UserService/GetUser".to_string())
+        );
+
+        assert_eq!(
+            index
+                .find_grpc("/users.v1.UserService/ListUsers")
+                .map(|route| route.path.to_owned()),
+            Some("/users.v1.UserService/*".to_string())
+        );
+
+        assert!(index.find_grpc("/orders.v1.OrderService/Get").is_none());
+
+        //
+        // gRPC routes are not reached through the service routes.
+        //
+        assert_eq!(
+            find_path(
+                &index,
+                "/users.v1.UserService/GetUser",
+                HttpMethod::Post
+            ),
+            Some("/*".to_string())
+        );
+    }
+}
@@ -45,3 +45,36 @@ pub enum RouteType {
         permissioned_roles: Vec<(String, Permission)>,
     },
 }
+
+impl RouteType {
+    /// The route type name, without the declared roles
+    pub fn name(&self) -> &'static str {
+        match self {
+            RouteType::Public => "public",
+            RouteType::Protected => "protected",
+            RouteType::ProtectedByRoles { .. } => "protectedByRoles",
+            RouteType::ProtectedByPermissionedRoles { .. } => {
+                "protectedByPermissionedRoles"
+            }
+            RouteType::ProtectedByServiceTokenWithRole { .. } => {
+                "protectedByServiceTokenWithRole"
+            }
+            RouteType::ProtectedByServiceTokenWithPermissionedRoles {
+                ..
+            } => "protectedByServiceTokenWithPermissionedRoles",
+        }
+    }
+
+    /// Check if the user profile is injected into the downstream requests
+    ///
+    /// Routes protected by service tokens inject the connection string instead.
+    ///
+    pub fn injects_profile(&self) -> bool {
+        matches!(
+            self,
+            RouteType::Protected
+                | RouteType::ProtectedByRoles { .. }
+                | RouteType::ProtectedByPermissionedRoles { .. }
+        )
+    }
+}
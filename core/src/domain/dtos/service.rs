@@ -1,9 +1,18 @@
 use super::{
-    health_check::HealthCheckConfig, http_secret::HttpSecret, route::Route,
+    circuit_breaker::CircuitBreakerConfig,
+    cors::CorsPolicy,
+    header_policy::HeaderPolicy,
+    health_check::HealthCheckConfig,
+    http_secret::HttpSecret,
+    retry::RetryPolicy,
+    route::Route,
+    timeout::UpstreamTimeouts,
+    upstream::{LoadBalancingStrategy, UpstreamTarget},
 };
+use crate::models::AccountLifeCycle;
 
 use myc_config::secret_resolver::SecretResolver;
-use mycelium_base::dtos::UntaggedChildren;
+use mycelium_base::{dtos::UntaggedChildren, utils::errors::MappedErrors};
 use serde::{ser::SerializeStruct, Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -17,12 +26,52 @@ pub struct ServiceSecret {
 }
 
 impl ServiceSecret {
-    pub(crate) fn new(
-        name: String,
-        secret: SecretResolver<HttpSecret>,
-    ) -> Self {
+    pub fn new(name: String, secret: SecretResolver<HttpSecret>) -> Self {
         Self { name, secret }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn secret(&self) -> &SecretResolver<HttpSecret> {
+        &self.secret
+    }
+
+    /// Encrypt the secret value
+    ///
+    /// Only secrets declared by value are encrypted. Environment and vault
+    /// secrets store a reference to the value and are kept as is.
+    ///
+    pub(crate) async fn encrypt_me(
+        &self,
+        config: AccountLifeCycle,
+    ) -> Result<Self, MappedErrors> {
+        match &self.secret {
+            SecretResolver::Value(secret) => Ok(Self::new(
+                self.name.to_owned(),
+                SecretResolver::Value(secret.encrypt_me(config).await?),
+            )),
+            _ => Ok(self.to_owned()),
+        }
+    }
+
+    /// Decrypt the secret value
+    ///
+    /// The reverse of [`ServiceSecret::encrypt_me`].
+    ///
+    pub(crate) async fn decrypt_me(
+        &self,
+        config: AccountLifeCycle,
+    ) -> Result<Self, MappedErrors> {
+        match &self.secret {
+            SecretResolver::Value(secret) => Ok(Self::new(
+                self.name.to_owned(),
+                SecretResolver::Value(secret.decrypt_me(config).await?),
+            )),
+            _ => Ok(self.to_owned()),
+        }
+    }
 }
 
 impl Serialize for ServiceSecret {
@@ -69,12 +118,55 @@ pub struct Service {
     pub name: String,
 
     /// The service host
+    ///
+    /// The host is used as the single upstream target when no targets are
+    /// declared.
+    ///
     pub host: String,
 
+    /// The service upstream targets
+    ///
+    /// When declared, requests are distributed between the targets instead of
+    /// being sent to the service host.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<UpstreamTarget>>,
+
+    /// The strategy used to distribute requests between upstream targets
+    ///
+    /// Default to `roundRobin`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_balancing: Option<LoadBalancingStrategy>,
+
     /// The service health check configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub health_check: Option<HealthCheckConfig>,
 
+    /// The timeouts of the requests sent to the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<UpstreamTimeouts>,
+
+    /// The retry policy of the requests sent to the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+
+    /// The service circuit breaker configuration
+    ///
+    /// When declared, requests are rejected with a service unavailable
+    /// response while the circuit is open.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+
+    /// The header transformations shared by the service routes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_policy: Option<HeaderPolicy>,
+
+    /// The CORS policy shared by the service routes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors: Option<CorsPolicy>,
+
     /// The service routes
     pub routes: UntaggedChildren<Route, Uuid>,
 
@@ -84,10 +176,12 @@ pub struct Service {
 }
 
 impl Service {
-    pub(crate) fn new(
+    pub fn new(
         id: Option<Uuid>,
         name: String,
         host: String,
+        targets: Option<Vec<UpstreamTarget>>,
+        load_balancing: Option<LoadBalancingStrategy>,
         health_check: Option<HealthCheckConfig>,
         routes: Vec<Route>,
         secrets: Option<Vec<ServiceSecret>>,
@@ -101,9 +195,95 @@ impl Service {
             },
             name,
             host,
+            targets,
+            load_balancing,
             health_check,
+            timeouts: None,
+            retry: None,
+            circuit_breaker: None,
+            header_policy: None,
+            cors: None,
             routes: UntaggedChildren::Records(routes),
             secrets,
         }
     }
+
+    /// Set the service upstream timeouts.
+    pub fn with_timeouts(mut self, timeouts: Option<UpstreamTimeouts>) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Set the service retry policy.
+    pub fn with_retry(mut self, retry: Option<RetryPolicy>) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the service circuit breaker configuration.
+    pub fn with_circuit_breaker(
+        mut self,
+        circuit_breaker: Option<CircuitBreakerConfig>,
+    ) -> Self {
+        self.circuit_breaker = circuit_breaker;
+        self
+    }
+
+    /// Set the service header transformations.
+    pub fn with_header_policy(
+        mut self,
+        header_policy: Option<HeaderPolicy>,
+    ) -> Self {
+        self.header_policy = header_policy;
+        self
+    }
+
+    /// Set the CORS policy shared by the service routes.
+    pub fn with_cors(mut self, cors: Option<CorsPolicy>) -> Self {
+        self.cors = cors;
+        self
+    }
+
+    /// Check if the service timeouts, retry policy and circuit breaker are
+    /// valid
+    ///
+    /// Timeouts and the maximum number of attempts should be greater than
+    /// zero. The circuit breaker failure rate should be a percentage.
+    ///
+    pub fn has_valid_upstream_policies(&self) -> bool {
+        let valid_timeouts = match &self.timeouts {
+            None => true,
+            Some(timeouts) => {
+                timeouts.connect_timeout_in_secs != Some(0)
+                    && timeouts.response_timeout_in_secs != Some(0)
+                    && timeouts.idle_timeout_in_secs != Some(0)
+            }
+        };
+
+        let valid_retry = match &self.retry {
+            None => true,
+            Some(retry) => retry.max_attempts > 0,
+        };
+
+        let valid_circuit_breaker = match &self.circuit_breaker {
+            None => true,
+            Some(circuit_breaker) => circuit_breaker.is_valid(),
+        };
+
+        valid_timeouts && valid_retry && valid_circuit_breaker
+    }
+
+    /// List the service upstream targets
+    ///
+    /// Returns the declared targets or the service host as a single target.
+    ///
+    pub fn upstream_targets(&self) -> Vec<UpstreamTarget> {
+        match &self.targets {
+            Some(targets) if !targets.is_empty() => targets.to_owned(),
+            _ => vec![UpstreamTarget::new(
+                self.host.split("/").next().unwrap_or_default().to_string(),
+                None,
+            )],
+        }
+    }
 }
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+/// The default time a WebSocket connection could remain idle in seconds
+pub const DEFAULT_IDLE_TIMEOUT: u64 = 300;
+
+/// The timeouts of the requests sent to the upstream targets
+///
+/// Timeouts declared by a route override the ones declared by its service.
+/// The gateway timeout is used when no response timeout is declared.
+///
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpstreamTimeouts {
+    /// The time to establish the connection with the target in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_in_secs: Option<u64>,
+
+    /// The time to receive the target response in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_timeout_in_secs: Option<u64>,
+
+    /// The time a WebSocket connection could remain without frames in seconds
+    ///
+    /// Idle connections are closed on both sides. Default to `300`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_in_secs: Option<u64>,
+}
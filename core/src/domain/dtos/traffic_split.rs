@@ -0,0 +1,331 @@
+use super::header_policy::HeaderValueRule;
+
+use actix_web::http::header::HeaderMap;
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+/// A backend service receiving a share of the route traffic
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedBackend {
+    /// The backend service name
+    pub service: String,
+
+    /// The backend weight
+    ///
+    /// The share of the traffic sent to the backend is its weight divided by
+    /// the sum of the weights of all backends.
+    ///
+    pub weight: u32,
+}
+
+/// The request attribute used to keep a client at the same backend
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum StickyKey {
+    /// The id of the authenticated account
+    ///
+    /// Requests without an authenticated profile are assigned randomly.
+    ///
+    AccountId,
+
+    /// The value of a request header
+    ///
+    /// Requests without the header are assigned randomly.
+    ///
+    Header(String),
+}
+
+/// A rule sending matching requests to a specific backend
+///
+/// Rules match requests from profiles with the role or including the header
+/// value. Rules declaring both only match requests satisfying both.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendOverride {
+    /// The backend service name
+    pub service: String,
+
+    /// The role of the injected profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    /// The request header name and value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<HeaderValueRule>,
+}
+
+impl BackendOverride {
+    fn matches(&self, roles: &[String], headers: &HeaderMap) -> bool {
+        let role_match = match &self.role {
+            None => true,
+            Some(role) => roles.contains(role),
+        };
+
+        let header_match = match &self.header {
+            None => true,
+            Some(rule) => headers
+                .get_all(rule.name.as_str())
+                .into_iter()
+                .any(|value| value.as_bytes() == rule.value.as_bytes()),
+        };
+
+        role_match && header_match
+    }
+}
+
+/// The traffic split of a route between backend services
+///
+/// Backends are selected in the following order: the first matching
+/// override, the sticky assignment and the weighted random choice.
+///
+/// # Example
+///
+/// ```yaml
+/// trafficSplit:
+///   backends:
+///   - service: accounts
+///     weight: 95
+///   - service: accounts-v2
+///     weight: 5
+///   sticky:
+///     header: x-session-id
+///   overrides:
+///   - service: accounts-v2
+///     role: beta-testers
+/// ```
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TrafficSplit {
+    /// The weighted backends
+    pub backends: Vec<WeightedBackend>,
+
+    /// The request attribute used to keep a client at the same backend
+    ///
+    /// If empty, each request is assigned randomly.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky: Option<StickyKey>,
+
+    /// The rules sending matching requests to a specific backend
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<BackendOverride>>,
+}
+
+impl TrafficSplit {
+    /// Check if the backends and the overrides are valid
+    ///
+    /// The sum of the backend weights should be greater than zero and the
+    /// overrides should declare a role or a header.
+    ///
+    pub fn validate(&self) -> Result<(), MappedErrors> {
+        if self.total_weight() == 0 {
+            return dto_err(
+                "Invalid traffic split: the sum of the backend weights should be greater than zero",
+            )
+            .as_error();
+        }
+
+        if self
+            .backends
+            .iter()
+            .any(|backend| backend.service.is_empty())
+            || self.overrides.iter().flatten().any(|rule| {
+                rule.service.is_empty()
+                    || (rule.role.is_none() && rule.header.is_none())
+            })
+        {
+            return dto_err(
+                "Invalid traffic split: backends should declare a service and overrides should declare a role or a header",
+            )
+            .as_error();
+        }
+
+        Ok(())
+    }
+
+    /// Select the backend service of a request
+    ///
+    /// The `sticky_value` argument is the value of the sticky key attribute,
+    /// if present in the request. The `roles` are the roles of the injected
+    /// profile.
+    ///
+    pub fn select(
+        &self,
+        sticky_value: Option<&str>,
+        roles: &[String],
+        headers: &HeaderMap,
+    ) -> Option<&str> {
+        if let Some(rule) = self
+            .overrides
+            .iter()
+            .flatten()
+            .find(|rule| rule.matches(roles, headers))
+        {
+            return Some(rule.service.as_str());
+        }
+
+        let total_weight = self.total_weight();
+
+        if total_weight == 0 {
+            return None;
+        }
+
+        let point = match sticky_value {
+            Some(value) => stable_hash(value) % total_weight,
+            None => rand::thread_rng().gen_range(0..total_weight),
+        };
+
+        let mut cumulative_weight = 0;
+
+        self.backends
+            .iter()
+            .find(|backend| {
+                cumulative_weight += backend.weight as u64;
+                point < cumulative_weight
+            })
+            .map(|backend| backend.service.as_str())
+    }
+
+    /// The names of the backend services, including the override ones
+    pub fn services(&self) -> impl Iterator<Item = &str> {
+        self.backends
+            .iter()
+            .map(|backend| backend.service.as_str())
+            .chain(
+                self.overrides
+                    .iter()
+                    .flatten()
+                    .map(|rule| rule.service.as_str()),
+            )
+    }
+
+    fn total_weight(&self) -> u64 {
+        self.backends
+            .iter()
+            .map(|backend| backend.weight as u64)
+            .sum()
+    }
+}
+
+/// Hash a sticky value
+///
+/// The FNV-1a hash is used since the result should not change between gateway
+/// instances and versions.
+///
+fn stable_hash(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    fn build_split() -> TrafficSplit {
+        TrafficSplit {
+            backends: vec![
+                WeightedBackend {
+                    service: "accounts".to_string(),
+                    weight: 95,
+                },
+                WeightedBackend {
+                    service: "accounts-v2".to_string(),
+                    weight: 5,
+                },
+            ],
+            sticky: Some(StickyKey::AccountId),
+            overrides: Some(vec![BackendOverride {
+                service: "accounts-v2".to_string(),
+                role: None,
+                header: Some(HeaderValueRule {
+                    name: "x-canary".to_string(),
+                    value: "true".to_string(),
+                }),
+            }]),
+        }
+    }
+
+    #[test]
+    fn test_sticky_selection_is_stable() {
+        let split = build_split();
+        let headers = HeaderMap::new();
+
+        let first = split.select(Some("account-1"), &[], &headers);
+
+        for _ in 0..10 {
+            assert_eq!(split.select(Some("account-1"), &[], &headers), first);
+        }
+    }
+
+    #[test]
+    fn test_selection_follows_weights() {
+        let split = build_split();
+        let headers = HeaderMap::new();
+
+        let canary_count = (0..1000)
+            .filter(|index| {
+                split.select(Some(&format!("account-{index}")), &[], &headers)
+                    == Some("accounts-v2")
+            })
+            .count();
+
+        assert!(canary_count > 10 && canary_count < 100);
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let split = build_split();
+        let mut headers = HeaderMap::new();
+
+        headers.insert(
+            HeaderName::from_static("x-canary"),
+            HeaderValue::from_static("true"),
+        );
+
+        for index in 0..20 {
+            assert_eq!(
+                split.select(Some(&format!("account-{index}")), &[], &headers),
+                Some("accounts-v2")
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_splits_are_rejected() {
+        let mut split = build_split();
+
+        split
+            .backends
+            .iter_mut()
+            .for_each(|backend| backend.weight = 0);
+        assert!(split.validate().is_err());
+
+        let mut split = build_split();
+
+        split.overrides = Some(vec![BackendOverride {
+            service: "accounts-v2".to_string(),
+            role: None,
+            header: None,
+        }]);
+        assert!(split.validate().is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::{ToResponse, ToSchema};
+
+/// The default weight of an upstream target
+pub const DEFAULT_UPSTREAM_WEIGHT: u32 = 1;
+
+/// An upstream target of a service
+///
+/// Services running multiple replicas could declare each replica as a target.
+/// The gateway distributes the requests between the targets following the
+/// service load balancing strategy.
+///
+#[derive(
+    Debug, Clone, Deserialize, Serialize, ToSchema, ToResponse, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpstreamTarget {
+    /// The target host
+    ///
+    /// The host should include the port if it is not the protocol default
+    /// (e.g. `localhost:8080`).
+    ///
+    pub host: String,
+
+    /// The target weight
+    ///
+    /// Only used by the `weighted` strategy. Default to `1`. Targets with
+    /// weight `0` are only used as failover.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
+
+impl UpstreamTarget {
+    pub fn new(host: String, weight: Option<u32>) -> Self {
+        Self { host, weight }
+    }
+
+    pub fn weight(&self) -> u32 {
+        self.weight.unwrap_or(DEFAULT_UPSTREAM_WEIGHT)
+    }
+}
+
+/// The strategy used to distribute requests between upstream targets
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    ToResponse,
+    PartialEq,
+    Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum LoadBalancingStrategy {
+    /// Targets are used one after the other
+    #[default]
+    RoundRobin,
+
+    /// Targets are used proportionally to their weights
+    Weighted,
+
+    /// The target with the lowest number of in-flight requests is used
+    LeastInFlight,
+}
+
+/// The load balancing state of a service
+///
+/// The balancer tracks the number of requests already distributed and the
+/// number of in-flight requests of each target host.
+///
+#[derive(Debug, Clone, Default)]
+pub struct UpstreamBalancer {
+    counter: u64,
+    in_flight: HashMap<String, u64>,
+}
+
+impl UpstreamBalancer {
+    /// Order the targets by preference
+    ///
+    /// The first element is the target chosen by the strategy. The remaining
+    /// targets follow it and should be used as failover, in order.
+    ///
+    pub fn next_targets(
+        &mut self,
+        strategy: LoadBalancingStrategy,
+        targets: Vec<UpstreamTarget>,
+    ) -> Vec<UpstreamTarget> {
+        if targets.len() < 2 {
+            return targets;
+        }
+
+        let counter = self.counter;
+        self.counter = self.counter.wrapping_add(1);
+
+        let selected = match strategy {
+            LoadBalancingStrategy::RoundRobin => {
+                (counter % targets.len() as u64) as usize
+            }
+            LoadBalancingStrategy::Weighted => {
+                let total_weight = targets
+                    .iter()
+                    .map(|target| target.weight() as u64)
+                    .sum::<u64>();
+
+                if total_weight == 0 {
+                    (counter % targets.len() as u64) as usize
+                } else {
+                    let mut position = counter % total_weight;
+
+                    targets
+                        .iter()
+                        .position(|target| {
+                            let weight = target.weight() as u64;
+
+                            if position < weight {
+                                return true;
+                            }
+
+                            position -= weight;
+                            false
+                        })
+                        .unwrap_or_default()
+                }
+            }
+            LoadBalancingStrategy::LeastInFlight => {
+                //
+                // Ties are resolved by rotating the starting target, avoiding
+                // to overload the first one when the gateway is idle.
+                //
+                let offset = (counter % targets.len() as u64) as usize;
+
+                (0..targets.len())
+                    .map(|index| (index + offset) % targets.len())
+                    .min_by_key(|index| self.in_flight(&targets[*index].host))
+                    .unwrap_or_default()
+            }
+        };
+
+        let mut ordered = targets;
+        ordered.rotate_left(selected);
+        ordered
+    }
+
+    /// The number of in-flight requests of a target host
+    pub fn in_flight(&self, host: &str) -> u64 {
+        self.in_flight.get(host).copied().unwrap_or_default()
+    }
+
+    /// Register a request sent to a target host
+    pub fn start_request(&mut self, host: &str) {
+        *self.in_flight.entry(host.to_owned()).or_default() += 1;
+    }
+
+    /// Register a request completed by a target host
+    pub fn finish_request(&mut self, host: &str) {
+        if let Some(in_flight) = self.in_flight.get_mut(host) {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> Vec<UpstreamTarget> {
+        vec![
+            UpstreamTarget::new("replica-a:8080".to_string(), Some(3)),
+            UpstreamTarget::new("replica-b:8080".to_string(), None),
+        ]
+    }
+
+    fn first_host(targets: Vec<UpstreamTarget>) -> String {
+        targets.first().unwrap().host.to_owned()
+    }
+
+    #[test]
+    fn test_round_robin_balancing_works() {
+        let mut balancer = UpstreamBalancer::default();
+        let strategy = LoadBalancingStrategy::RoundRobin;
+
+        let ordered = balancer.next_targets(strategy, targets());
+        assert_eq!(ordered[0].host, "replica-a:8080");
+        assert_eq!(ordered[1].host, "replica-b:8080");
+
+        let ordered = balancer.next_targets(strategy, targets());
+        assert_eq!(ordered[0].host, "replica-b:8080");
+        assert_eq!(ordered[1].host, "replica-a:8080");
+    }
+
+    #[test]
+    fn test_weighted_balancing_works() {
+        let mut balancer = UpstreamBalancer::default();
+        let strategy = LoadBalancingStrategy::Weighted;
+
+        let hosts = (0..8)
+            .map(|_| first_host(balancer.next_targets(strategy, targets())))
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            hosts
+                .iter()
+                .filter(|host| *host == "replica-a:8080")
+                .count(),
+            6
+        );
+        assert_eq!(
+            hosts
+                .iter()
+                .filter(|host| *host == "replica-b:8080")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_least_in_flight_balancing_works() {
+        let mut balancer = UpstreamBalancer::default();
+        let strategy = LoadBalancingStrategy::LeastInFlight;
+
+        balancer.start_request("replica-a:8080");
+        balancer.start_request("replica-a:8080");
+        balancer.start_request("replica-b:8080");
+
+        for _ in 0..4 {
+            assert_eq!(
+                first_host(balancer.next_targets(strategy, targets())),
+                "replica-b:8080"
+            );
+        }
+
+        balancer.finish_request("replica-a:8080");
+        balancer.finish_request("replica-a:8080");
+        assert_eq!(balancer.in_flight("replica-a:8080"), 0);
+
+        assert_eq!(
+            first_host(balancer.next_targets(strategy, targets())),
+            "replica-a:8080"
+        );
+    }
+}
@@ -6,6 +6,7 @@ mod guest_user;
 mod licensed_resource;
 mod message;
 mod profile;
+mod rate_limit;
 mod route;
 mod tenant;
 mod tenant_tag;
@@ -21,6 +22,7 @@ pub use guest_user::*;
 pub use licensed_resource::*;
 pub use message::*;
 pub use profile::*;
+pub use rate_limit::*;
 pub use route::*;
 pub use tenant::*;
 pub use tenant_tag::*;
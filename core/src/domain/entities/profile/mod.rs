@@ -1,3 +1,5 @@
+mod profile_caching;
 mod profile_fetching;
 
+pub use profile_caching::*;
 pub use profile_fetching::*;
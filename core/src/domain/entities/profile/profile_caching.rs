@@ -0,0 +1,46 @@
+use crate::domain::dtos::{
+    email::Email,
+    profile::{Profile, ProfileCacheKey},
+};
+
+use async_trait::async_trait;
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+use shaku::Interface;
+
+#[async_trait]
+pub trait ProfileCaching: Interface + Send + Sync {
+    /// Get a cached profile
+    ///
+    /// Expired entries are never returned.
+    ///
+    async fn get(
+        &self,
+        key: ProfileCacheKey,
+    ) -> Result<FetchResponseKind<Profile, String>, MappedErrors>;
+
+    /// Get the current version of the cache
+    ///
+    /// The version changes on every invalidation. It should be collected
+    /// before fetching the profile to be cached.
+    ///
+    async fn version(&self) -> Result<String, MappedErrors>;
+
+    /// Cache a profile
+    ///
+    /// The time to live of the entries is defined by the cache store. The
+    /// profile is not cached when the cache version differs from the version
+    /// collected before fetching it, since the profile could be outdated.
+    ///
+    async fn set(
+        &self,
+        key: ProfileCacheKey,
+        profile: Profile,
+        version: String,
+    ) -> Result<(), MappedErrors>;
+
+    /// Discard the cached profiles of an email, for any filter
+    async fn invalidate_email(&self, email: Email) -> Result<(), MappedErrors>;
+
+    /// Discard all cached profiles
+    async fn invalidate_all(&self) -> Result<(), MappedErrors>;
+}
@@ -0,0 +1,3 @@
+mod rate_limit_counting;
+
+pub use rate_limit_counting::RateLimitCounting;
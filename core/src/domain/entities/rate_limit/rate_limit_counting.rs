@@ -0,0 +1,19 @@
+use crate::domain::dtos::rate_limit::{RateLimitDecision, RateLimitPolicy};
+
+use async_trait::async_trait;
+use mycelium_base::utils::errors::MappedErrors;
+use shaku::Interface;
+
+#[async_trait]
+pub trait RateLimitCounting: Interface + Send + Sync {
+    /// Consume a request of the counter identified by the key
+    ///
+    /// Counters are shared between the gateway replicas, then the policy
+    /// should be evaluated atomically by the counters store.
+    ///
+    async fn consume(
+        &self,
+        key: String,
+        policy: RateLimitPolicy,
+    ) -> Result<RateLimitDecision, MappedErrors>;
+}
@@ -1,3 +1,9 @@
+mod route_deletion;
 mod route_read;
+mod route_registration;
+mod route_updating;
 
+pub use route_deletion::RoutesDeletion;
 pub use route_read::RoutesFetching;
+pub use route_registration::RoutesRegistration;
+pub use route_updating::RoutesUpdating;
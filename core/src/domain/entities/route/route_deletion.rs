@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait RoutesDeletion: Interface + Send + Sync {
+    /// Delete a service and all of its routes
+    async fn delete_service(
+        &self,
+        service_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+
+    async fn delete_route(
+        &self,
+        route_id: Uuid,
+    ) -> Result<DeletionResponseKind<Uuid>, MappedErrors>;
+}
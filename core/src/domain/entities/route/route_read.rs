@@ -1,3 +1,4 @@
+use crate::domain::dtos::http::HttpMethod;
 use crate::domain::dtos::route::Route;
 use crate::domain::dtos::service::Service;
 
@@ -17,6 +18,12 @@ pub trait RoutesFetching: Interface + Send + Sync {
     async fn get(
         &self,
         path: PathAndQuery,
+        method: HttpMethod,
+    ) -> Result<FetchResponseKind<Route, String>, MappedErrors>;
+
+    async fn get_grpc(
+        &self,
+        path: PathAndQuery,
     ) -> Result<FetchResponseKind<Route, String>, MappedErrors>;
 
     async fn list_routes(
@@ -0,0 +1,32 @@
+use crate::domain::dtos::{route::Route, service::Service};
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::{CreateManyResponseKind, CreateResponseKind},
+    utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[async_trait]
+pub trait RoutesRegistration: Interface + Send + Sync {
+    async fn register_service(
+        &self,
+        service: Service,
+    ) -> Result<CreateResponseKind<Service>, MappedErrors>;
+
+    async fn register_route(
+        &self,
+        route: Route,
+    ) -> Result<CreateResponseKind<Route>, MappedErrors>;
+
+    /// Register services and routes in a single transaction
+    ///
+    /// Existing records are skipped. Only the routes created by the call are
+    /// returned.
+    ///
+    async fn register_services_and_routes(
+        &self,
+        services: Vec<Service>,
+        routes: Vec<Route>,
+    ) -> Result<CreateManyResponseKind<Route>, MappedErrors>;
+}
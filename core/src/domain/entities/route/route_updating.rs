@@ -0,0 +1,20 @@
+use crate::domain::dtos::{route::Route, service::Service};
+
+use async_trait::async_trait;
+use mycelium_base::{
+    entities::UpdatingResponseKind, utils::errors::MappedErrors,
+};
+use shaku::Interface;
+
+#[async_trait]
+pub trait RoutesUpdating: Interface + Send + Sync {
+    async fn update_service(
+        &self,
+        service: Service,
+    ) -> Result<UpdatingResponseKind<Service>, MappedErrors>;
+
+    async fn update_route(
+        &self,
+        route: Route,
+    ) -> Result<UpdatingResponseKind<Route>, MappedErrors>;
+}
@@ -1,5 +1,7 @@
 mod derive_key_from_uuid;
+mod trace_context;
 mod try_as_uuid;
 
 pub(crate) use derive_key_from_uuid::*;
+pub use trace_context::*;
 pub use try_as_uuid::*;
@@ -0,0 +1,21 @@
+use opentelemetry::global;
+use std::collections::HashMap;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Build the W3C trace context headers of the current span
+///
+/// The `traceparent` and `tracestate` headers are built by the global
+/// propagator, allowing downstream services to connect their spans to the
+/// current trace. Without the telemetry exporter the current span has no trace
+/// context, then no headers are returned.
+///
+pub fn trace_context_headers() -> HashMap<String, String> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut headers)
+    });
+
+    headers
+}
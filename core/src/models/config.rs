@@ -1,4 +1,4 @@
-use super::account_life_cycle::AccountLifeCycle;
+use super::{account_life_cycle::AccountLifeCycle, ProfileCacheConfig};
 
 use myc_config::load_config_from_file;
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
@@ -9,6 +9,12 @@ use std::path::PathBuf;
 #[serde(rename_all = "camelCase")]
 pub struct CoreConfig {
     pub account_life_cycle: AccountLifeCycle,
+
+    /// The profile cache settings
+    ///
+    /// If empty, profiles are fetched from the database on each request.
+    ///
+    pub profile_cache: Option<ProfileCacheConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1,5 +1,7 @@
 mod account_life_cycle;
 mod config;
+mod profile_cache;
 
 pub use account_life_cycle::AccountLifeCycle;
 pub use config::CoreConfig;
+pub use profile_cache::{ProfileCacheBackend, ProfileCacheConfig};
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+/// The store of the cached profiles
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProfileCacheBackend {
+    /// Profiles are cached in the process memory
+    ///
+    /// Each replica keeps its own entries, then invalidations reach only the
+    /// replica performing the change. Other replicas keep their entries until
+    /// they expire.
+    ///
+    Memory,
+
+    /// Profiles are cached at the queue Redis instance
+    ///
+    /// Entries and invalidations are shared between the replicas.
+    ///
+    Redis,
+}
+
+/// The profile cache settings
+///
+/// Profiles fetched by email are cached to avoid querying the database on each
+/// request. Entries are discarded when guests, accounts, tenant ownerships or
+/// users status change.
+///
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileCacheConfig {
+    pub backend: ProfileCacheBackend,
+
+    /// The time to live of the cached profiles in seconds
+    pub ttl_in_secs: u64,
+}
@@ -1,11 +1,21 @@
 use crate::{
-    domain::dtos::route::Route,
-    use_cases::gateway::routes::load_config_from_yaml,
+    domain::dtos::{
+        allowed_source::ResolvedHostnames, circuit_breaker::CircuitBreaker,
+        health_check::ServiceHealth, profile::Profile,
+        rate_limit::RateLimitState, route::Route, route_tree::RoutesIndex,
+        upstream::UpstreamBalancer,
+    },
+    use_cases::gateway::{
+        routes::{load_config_from_yaml, resolve_allowed_hostnames},
+        services::OAuth2AccessToken,
+    },
 };
 
+use arc_swap::ArcSwap;
 use futures::lock::Mutex;
 use lazy_static::lazy_static;
-use std::env::var_os;
+use prometheus::{register_int_counter_vec, IntCounterVec};
+use std::{collections::HashMap, env::var_os, sync::Arc};
 use tera::Tera;
 
 // ? ---------------------------------------------------------------------------
@@ -26,6 +36,23 @@ pub const DEFAULT_TOTP_DOMAIN: &str = "Mycelium";
 
 lazy_static! {
     pub static ref ROUTES: Mutex<Vec<Route>> = Mutex::new(vec![]);
+
+    /// The routes compiled into a prefix tree per service
+    ///
+    /// The index is rebuilt whenever the routes are loaded and is used to
+    /// match the gateway requests.
+    ///
+    pub static ref ROUTES_INDEX: Mutex<RoutesIndex> =
+        Mutex::new(RoutesIndex::default());
+
+    /// The addresses of the hostnames used as route allowed sources
+    ///
+    /// Hostnames are resolved whenever the routes are loaded and periodically
+    /// refreshed, then the gateway requests are matched without DNS lookups.
+    /// The addresses are replaced at once, without locking the requests.
+    ///
+    pub static ref RESOLVED_HOSTNAMES: ArcSwap<ResolvedHostnames> =
+        ArcSwap::from_pointee(ResolvedHostnames::new());
 }
 
 pub async fn init_in_memory_routes(routes_file: Option<String>) {
@@ -56,7 +83,111 @@ pub async fn init_in_memory_routes(routes_file: Option<String>) {
         Ok(res) => res,
     };
 
-    ROUTES.lock().await.extend(db);
+    let resolved_hostnames = resolve_allowed_hostnames(&db).await;
+
+    let mut routes = ROUTES.lock().await;
+    routes.extend(db);
+
+    *ROUTES_INDEX.lock().await = RoutesIndex::compile(&routes);
+    RESOLVED_HOSTNAMES.store(Arc::new(resolved_hostnames));
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Services health
+//
+// The health state of each downstream service target, indexed by the service
+// name and the target host. The state is updated by the gateway health checker.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref SERVICES_HEALTH: Mutex<HashMap<(String, String), ServiceHealth>> =
+        Mutex::new(HashMap::new());
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Circuit breakers
+//
+// The circuit breaker state of each downstream service, indexed by the service
+// name. The state is updated by the gateway router after each upstream request.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref CIRCUIT_BREAKERS: Mutex<HashMap<String, CircuitBreaker>> =
+        Mutex::new(HashMap::new());
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Upstream balancers
+//
+// The load balancing state of each downstream service, indexed by the service
+// name.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref UPSTREAM_BALANCERS: Mutex<HashMap<String, UpstreamBalancer>> =
+        Mutex::new(HashMap::new());
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Rate limit counters
+//
+// The in-process rate limit counters, indexed by the counter key. Counters are
+// used only when the distributed counters store is unavailable. Each entry
+// includes the time when it could be discarded.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref RATE_LIMIT_STATES: Mutex<HashMap<String, (u64, Option<RateLimitState>)>> =
+        Mutex::new(HashMap::new());
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Profile cache
+//
+// The in-process cached profiles, indexed by the email and by the digest of the
+// profile filters. Each entry includes the time when it expires.
+//
+// The version is incremented on every invalidation, discarding the profiles
+// fetched before it. The version is only locked while the cache is locked.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref PROFILE_CACHE: Mutex<
+        HashMap<String, HashMap<String, (u64, Profile)>>,
+    > = Mutex::new(HashMap::new());
+
+    pub static ref PROFILE_CACHE_VERSION: Mutex<u64> = Mutex::new(0);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? OAuth2 tokens
+//
+// The access tokens issued to the OAuth2 client credentials secrets, indexed by
+// a hash of the credentials. Each entry has its own lock, held while the token
+// is refreshed. Failed refreshes are kept for a few seconds, failing the
+// requests without reaching the token endpoint.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref OAUTH2_TOKENS: Mutex<HashMap<u64, Arc<Mutex<Option<OAuth2AccessToken>>>>> =
+        Mutex::new(HashMap::new());
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Webhook metrics
+//
+// The webhook dispatches, labelled by the trigger and the outcome. Metrics are
+// registered at the default Prometheus registry and exposed by the API port.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    pub static ref WEBHOOK_DISPATCHES: IntCounterVec =
+        register_int_counter_vec!(
+            "myc_webhook_dispatches_total",
+            "The webhook dispatches by trigger and outcome",
+            &["trigger", "outcome"]
+        )
+        .expect("Unable to register the webhook metrics");
 }
 
 // ? ---------------------------------------------------------------------------
@@ -0,0 +1,66 @@
+use crate::{
+    domain::{
+        dtos::{rate_limit::RateLimitDecision, route::Route},
+        entities::RateLimitCounting,
+    },
+    settings::RATE_LIMIT_STATES,
+};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+/// The number of in-process counters kept before discarding the expired ones
+const IN_PROCESS_COUNTERS_PRUNE_THRESHOLD: usize = 10_000;
+
+/// Check the rate limit of a route request
+///
+/// Returns `None` if the route has no rate limit policy. Counters are kept by
+/// the rate limit counting repository and shared between the gateway replicas.
+/// If the repository is unavailable, the counters are kept in the process
+/// memory and the limits are enforced by each replica.
+///
+#[tracing::instrument(
+    name = "check_rate_limit",
+    skip(route, rate_limit_counting_repo)
+)]
+pub async fn check_rate_limit(
+    route: &Route,
+    client_key: String,
+    rate_limit_counting_repo: Box<&dyn RateLimitCounting>,
+) -> Option<RateLimitDecision> {
+    let policy = route.rate_limit.to_owned()?;
+
+    let key = format!(
+        "{route}:{client_key}",
+        route = route
+            .id
+            .map(|id| id.to_string())
+            .unwrap_or(route.path.to_owned())
+    );
+
+    match rate_limit_counting_repo
+        .consume(key.to_owned(), policy.to_owned())
+        .await
+    {
+        Ok(decision) => return Some(decision),
+        Err(err) => {
+            warn!("Using in-process rate limit counters: {err}")
+        }
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default();
+
+    let mut states = RATE_LIMIT_STATES.lock().await;
+
+    if states.len() >= IN_PROCESS_COUNTERS_PRUNE_THRESHOLD {
+        states.retain(|_, (expires_at, _)| *expires_at > now);
+    }
+
+    let (expires_at, state) = states.entry(key).or_default();
+    *expires_at = now + 2 * policy.window_in_millis();
+
+    Some(policy.consume(state, now))
+}
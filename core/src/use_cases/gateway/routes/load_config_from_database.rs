@@ -0,0 +1,120 @@
+use super::validate_routes;
+use crate::{
+    domain::{
+        dtos::{route::Route, service::ServiceSecret},
+        entities::RoutesFetching,
+    },
+    models::AccountLifeCycle,
+};
+
+use myc_config::secret_resolver::SecretResolver;
+use mycelium_base::{
+    dtos::Parent,
+    entities::FetchManyResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use std::collections::HashMap;
+use tracing::{error, info};
+
+/// Load configuration from the database
+///
+/// Value secrets are stored encrypted and are decrypted here. Environment and
+/// vault secrets are resolved, like the secrets of the routes file. The
+/// resulting routes pass through the same validation of the routes file.
+///
+#[tracing::instrument(name = "load_config_from_database", skip_all)]
+pub async fn load_config_from_database(
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Result<Vec<Route>, MappedErrors> {
+    let stored_routes = match routes_fetching_repo
+        .list_routes(None, None, Some(true))
+        .await?
+    {
+        FetchManyResponseKind::Found(routes) => routes,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Resolve the secrets of each service
+    //
+    // Services are shared between routes. Secrets are resolved once per
+    // service.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let mut resolved_secrets =
+        HashMap::<String, Option<Vec<ServiceSecret>>>::new();
+
+    let mut db = Vec::<Route>::new();
+
+    for mut route in stored_routes.into_iter() {
+        let mut service = match route.service {
+            Parent::Record(service) => service,
+            Parent::Id(id) => {
+                error!("Service not found for route: {:?}", route.id);
+
+                return use_case_err(format!(
+                    "Service {id} not found for route {:?}",
+                    route.id
+                ))
+                .as_error();
+            }
+        };
+
+        if !resolved_secrets.contains_key(&service.name) {
+            let secrets = match service.secrets.to_owned() {
+                None => None,
+                Some(secrets) => {
+                    let mut parsed_secrets = vec![];
+
+                    for secret in secrets {
+                        let decrypted =
+                            secret.decrypt_me(config.to_owned()).await?;
+
+                        let parsed_value = match decrypted
+                            .secret()
+                            .async_get_or_error()
+                            .await
+                        {
+                            Ok(res) => res,
+                            Err(err) => {
+                                error!("Error on check secrets: {err}");
+
+                                return use_case_err(format!(
+                                    "Error on check secrets of service {}: {err}",
+                                    service.name
+                                ))
+                                .as_error();
+                            }
+                        };
+
+                        parsed_secrets.push(ServiceSecret::new(
+                            decrypted.name().to_owned(),
+                            SecretResolver::Value(parsed_value),
+                        ));
+                    }
+
+                    Some(parsed_secrets)
+                }
+            };
+
+            resolved_secrets.insert(service.name.to_owned(), secrets);
+        }
+
+        service.secrets = resolved_secrets
+            .get(&service.name)
+            .cloned()
+            .unwrap_or_default();
+
+        route.service = Parent::Record(service);
+        db.push(route);
+    }
+
+    validate_routes(&db)?;
+
+    info!("Database routes successfully loaded: {} routes", db.len());
+
+    Ok(db)
+}
@@ -1,12 +1,23 @@
+use super::validate_routes;
 use crate::domain::dtos::{
+    circuit_breaker::CircuitBreakerConfig,
+    cors::CorsPolicy,
+    header_policy::HeaderPolicy,
     health_check::HealthCheckConfig,
     http::{HttpMethod, Protocol},
+    path_rewrite::PathRewriteRule,
+    profile_projection::ProfileProjection,
+    rate_limit::RateLimitPolicy,
+    request_mirror::RequestMirror,
+    retry::RetryPolicy,
     route::Route,
     route_type::RouteType,
     service::{Service, ServiceSecret},
+    timeout::UpstreamTimeouts,
+    traffic_split::TrafficSplit,
+    upstream::{LoadBalancingStrategy, UpstreamTarget},
 };
 
-use futures::executor::block_on;
 use myc_config::secret_resolver::SecretResolver;
 use mycelium_base::utils::errors::{use_case_err, MappedErrors};
 use serde::{Deserialize, Serialize};
@@ -27,7 +38,14 @@ struct TempServiceDTO {
     pub id: Option<Uuid>,
     pub name: String,
     pub host: String,
+    pub targets: Option<Vec<UpstreamTarget>>,
+    pub load_balancing: Option<LoadBalancingStrategy>,
     pub health_check: Option<HealthCheckConfig>,
+    pub timeouts: Option<UpstreamTimeouts>,
+    pub retry: Option<RetryPolicy>,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    pub header_policy: Option<HeaderPolicy>,
+    pub cors: Option<CorsPolicy>,
     pub routes: Vec<TempRouteDTO>,
     pub secrets: Option<Vec<ServiceSecret>>,
 }
@@ -43,133 +61,183 @@ struct TempRouteDTO {
     pub allowed_sources: Option<Vec<String>>,
     pub secret_name: Option<String>,
     pub accept_insecure_routing: Option<bool>,
+    pub rate_limit: Option<RateLimitPolicy>,
+    pub timeouts: Option<UpstreamTimeouts>,
+    pub retry: Option<RetryPolicy>,
+    pub accept_non_idempotent_retries: Option<bool>,
+    pub rewrite: Option<Vec<PathRewriteRule>>,
+    pub header_policy: Option<HeaderPolicy>,
+    pub traffic_split: Option<TrafficSplit>,
+    pub mirror: Option<RequestMirror>,
+    pub cors: Option<CorsPolicy>,
+    pub profile_projection: Option<ProfileProjection>,
 }
 
 /// Load configuration from YAML file
 ///
 /// This function will load the configuration from a JSON file and return a
-/// vector of routes.
+/// vector of routes. Invalid files result in an error instead of a partially
+/// loaded vector.
 ///
 #[tracing::instrument(name = "load_config_from_yaml")]
 pub async fn load_config_from_yaml(
     source_file_path: String,
 ) -> Result<Vec<Route>, MappedErrors> {
-    let temp_services = t_read(source_file_path)
-        .await
-        .map(|data| {
-            match serde_yaml::from_str::<TempMainConfigDTO>(
-                match from_utf8(&data) {
-                    Err(err) => {
-                        error!("Invalid UTF-8 sequence: {err}");
-                        return use_case_err(format!(
-                            "Invalid UTF-8 sequence: {err}"
-                        ))
-                        .as_error();
-                    }
-                    Ok(res) => res,
-                },
-            ) {
-                Err(err) => {
-                    error!("Invalid UTF-8 sequence: {err}");
-                    return use_case_err(format!(
-                        "Invalid UTF-8 sequence: {err}"
-                    ))
-                    .as_error();
-                }
-                Ok(res) => Ok(res),
-            }
-        })
-        .unwrap();
-
-    let db = temp_services?.services.iter().fold(
-        Vec::<Route>::new(),
-        |mut init, tmp_service| {
-            let secrets = if let Some(secrets) = tmp_service.to_owned().secrets
-            {
-                let secrets =
-                    secrets.into_iter().collect::<Vec<ServiceSecret>>();
-
-                match secrets.is_empty() {
-                    true => None,
-                    false => Some(secrets),
-                }
-            } else {
-                None
-            };
-
-            // Check if secrets is valid
-            let parsed_secrets: Option<Vec<ServiceSecret>> = match secrets {
-                Some(secrets) => {
-                    let mut parsed_secrets = vec![];
+    build_routes_from_yaml(source_file_path, true).await
+}
 
-                    for secret in secrets {
-                        let secret_value =
-                            block_on(secret.secret.async_get_or_error());
+/// Load configuration from YAML file without resolving the secrets
+///
+/// Environment and vault secrets are checked but kept as references, then the
+/// routes can be stored without copying the secret values.
+///
+#[tracing::instrument(name = "load_unresolved_config_from_yaml")]
+pub(crate) async fn load_unresolved_config_from_yaml(
+    source_file_path: String,
+) -> Result<Vec<Route>, MappedErrors> {
+    build_routes_from_yaml(source_file_path, false).await
+}
 
-                        let parsed_value = match secret_value {
+async fn build_routes_from_yaml(
+    source_file_path: String,
+    resolve_secrets: bool,
+) -> Result<Vec<Route>, MappedErrors> {
+    let data = match t_read(source_file_path.to_owned()).await {
+        Err(err) => {
+            error!("Unable to read routes file {source_file_path}: {err}");
+            return use_case_err(format!(
+                "Unable to read routes file {source_file_path}: {err}"
+            ))
+            .as_error();
+        }
+        Ok(res) => res,
+    };
+
+    let temp_services = match serde_yaml::from_str::<TempMainConfigDTO>(
+        match from_utf8(&data) {
+            Err(err) => {
+                error!("Invalid UTF-8 sequence: {err}");
+                return use_case_err(format!("Invalid UTF-8 sequence: {err}"))
+                    .as_error();
+            }
+            Ok(res) => res,
+        },
+    ) {
+        Err(err) => {
+            error!("Invalid routes file: {err}");
+            return use_case_err(format!("Invalid routes file: {err}"))
+                .as_error();
+        }
+        Ok(res) => res,
+    };
+
+    let mut db = Vec::<Route>::new();
+
+    for tmp_service in temp_services.services.into_iter() {
+        let secrets = if let Some(secrets) = tmp_service.to_owned().secrets {
+            let secrets = secrets.into_iter().collect::<Vec<ServiceSecret>>();
+
+            match secrets.is_empty() {
+                true => None,
+                false => Some(secrets),
+            }
+        } else {
+            None
+        };
+
+        // Check if secrets is valid
+        let parsed_secrets: Option<Vec<ServiceSecret>> = match secrets {
+            Some(secrets) => {
+                let mut parsed_secrets = vec![];
+
+                for secret in secrets {
+                    let parsed_value =
+                        match secret.secret.async_get_or_error().await {
                             Ok(res) => res,
                             Err(err) => {
-                                panic!("Error on check secrets: {err}");
+                                error!("Error on check secrets: {err}");
+                                return use_case_err(format!(
+                                "Error on check secrets of service {}: {err}",
+                                tmp_service.name
+                            ))
+                                .as_error();
                             }
                         };
 
-                        parsed_secrets.push(ServiceSecret::new(
+                    parsed_secrets.push(match resolve_secrets {
+                        true => ServiceSecret::new(
                             secret.name,
                             SecretResolver::Value(parsed_value),
-                        ));
-                    }
+                        ),
+                        false => secret,
+                    });
+                }
 
-                    Some(parsed_secrets)
+                Some(parsed_secrets)
+            }
+            None => None,
+        };
+
+        let service = Service::new(
+            tmp_service.id,
+            tmp_service.name.to_owned(),
+            tmp_service.host.to_owned(),
+            tmp_service.targets.to_owned(),
+            tmp_service.load_balancing.to_owned(),
+            tmp_service.health_check.to_owned(),
+            vec![],
+            parsed_secrets.to_owned(),
+        )
+        .with_timeouts(tmp_service.timeouts.to_owned())
+        .with_retry(tmp_service.retry.to_owned())
+        .with_circuit_breaker(tmp_service.circuit_breaker.to_owned())
+        .with_header_policy(tmp_service.header_policy.to_owned())
+        .with_cors(tmp_service.cors.to_owned());
+
+        for r in tmp_service.routes.into_iter() {
+            if let Some(secret_name) = r.secret_name.to_owned() {
+                if let Some(secrets) = parsed_secrets.to_owned() {
+                    if !secrets
+                        .iter()
+                        .map(|i| i.name.to_owned())
+                        .collect::<Vec<String>>()
+                        .contains(&secret_name)
+                    {
+                        error!("Secret not found: {secret_name}");
+                        return use_case_err(format!(
+                            "Secret not found: {secret_name}"
+                        ))
+                        .as_error();
+                    }
                 }
-                None => None,
-            };
-
-            let service = Service::new(
-                tmp_service.id,
-                tmp_service.name.to_owned(),
-                tmp_service.host.to_owned(),
-                tmp_service.health_check.to_owned(),
-                vec![],
-                parsed_secrets.to_owned(),
-            );
+            }
 
-            init.append(
-                &mut tmp_service
-                    .to_owned()
-                    .routes
-                    .into_iter()
-                    .map(|r| {
-                        if let Some(secret_name) = r.secret_name.to_owned() {
-                            if let Some(secrets) = parsed_secrets.to_owned() {
-                                if !secrets
-                                    .iter()
-                                    .map(|i| i.name.to_owned())
-                                    .collect::<Vec<String>>()
-                                    .contains(&secret_name)
-                                {
-                                    panic!("Secret not found: {secret_name}");
-                                }
-                            }
-                        }
-
-                        Route::new(
-                            r.id,
-                            service.to_owned(),
-                            r.group,
-                            r.methods,
-                            r.path,
-                            r.protocol,
-                            r.allowed_sources,
-                            r.secret_name,
-                            r.accept_insecure_routing,
-                        )
-                    })
-                    .collect::<Vec<Route>>(),
+            db.push(
+                Route::new(
+                    r.id,
+                    service.to_owned(),
+                    r.group,
+                    r.methods,
+                    r.path,
+                    r.protocol,
+                    r.allowed_sources,
+                    r.secret_name,
+                    r.accept_insecure_routing,
+                )
+                .with_rate_limit(r.rate_limit)
+                .with_timeouts(r.timeouts)
+                .with_retry(r.retry, r.accept_non_idempotent_retries)
+                .with_rewrite(r.rewrite)
+                .with_header_policy(r.header_policy)
+                .with_traffic_split(r.traffic_split)
+                .with_mirror(r.mirror)
+                .with_cors(r.cors)
+                .with_profile_projection(r.profile_projection),
             );
+        }
+    }
 
-            init
-        },
-    );
+    validate_routes(&db)?;
 
     info!(
         "Database successfully loaded:\n
@@ -1,4 +1,7 @@
-use crate::domain::{dtos::route::Route, entities::RoutesFetching};
+use crate::domain::{
+    dtos::{http::HttpMethod, route::Route},
+    entities::RoutesFetching,
+};
 
 use actix_web::http::uri::PathAndQuery;
 use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
@@ -7,17 +10,19 @@ use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
 ///
 /// This function should be called by the main middleware router function. It
 /// will try to match the address to a route and return the route if found.
+/// The most specific route accepting the method is preferred.
 #[tracing::instrument(
     name = "match_forward_address",
     skip(routes_fetching_repo)
 )]
 pub async fn match_forward_address(
     path: PathAndQuery,
+    method: HttpMethod,
     routes_fetching_repo: Box<&dyn RoutesFetching>,
 ) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Try to fetch routes from database
     // ? -----------------------------------------------------------------------
 
-    routes_fetching_repo.get(path.to_owned()).await
+    routes_fetching_repo.get(path.to_owned(), method).await
 }
@@ -0,0 +1,17 @@
+use crate::domain::{dtos::route::Route, entities::RoutesFetching};
+
+use actix_web::http::uri::PathAndQuery;
+use mycelium_base::{entities::FetchResponseKind, utils::errors::MappedErrors};
+
+/// Matches the gRPC call path to a route
+///
+/// This function should be called by the gRPC listener. gRPC calls are matched
+/// by the full path (`/<package>.<Service>/<Method>`) against the gRPC routes
+/// of all services. The most specific route is preferred.
+#[tracing::instrument(name = "match_grpc_address", skip(routes_fetching_repo))]
+pub async fn match_grpc_address(
+    path: PathAndQuery,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Result<FetchResponseKind<Route, String>, MappedErrors> {
+    routes_fetching_repo.get_grpc(path).await
+}
@@ -1,5 +1,23 @@
+mod check_rate_limit;
+mod load_config_from_database;
 mod load_config_from_yaml;
 mod match_forward_address;
+mod match_grpc_address;
+mod refresh_allowed_hostnames;
+mod reload_config_from_database;
+mod reload_config_from_yaml;
+mod replace_in_memory_routes;
+mod seed_routes_from_yaml;
+mod validate_routes;
 
+pub use check_rate_limit::*;
+pub use load_config_from_database::*;
 pub use load_config_from_yaml::*;
 pub use match_forward_address::*;
+pub use match_grpc_address::*;
+pub use refresh_allowed_hostnames::*;
+pub use reload_config_from_database::*;
+pub use reload_config_from_yaml::*;
+pub(crate) use replace_in_memory_routes::*;
+pub use seed_routes_from_yaml::*;
+pub(crate) use validate_routes::*;
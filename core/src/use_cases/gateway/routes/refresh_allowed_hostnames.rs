@@ -0,0 +1,19 @@
+use super::resolve_allowed_hostnames;
+use crate::settings::{RESOLVED_HOSTNAMES, ROUTES};
+
+use std::sync::Arc;
+
+/// Resolve again the hostnames used as allowed sources of the routes
+///
+/// Requests are matched against the previous addresses until the resolution
+/// finishes. The new addresses are discarded if the routes were reloaded in
+/// the meantime, since the reload resolves the hostnames of the new routes.
+///
+#[tracing::instrument(name = "refresh_allowed_hostnames", skip_all)]
+pub async fn refresh_allowed_hostnames() {
+    let current = RESOLVED_HOSTNAMES.load_full();
+    let routes = ROUTES.lock().await.to_owned();
+    let resolved_hostnames = resolve_allowed_hostnames(&routes).await;
+
+    RESOLVED_HOSTNAMES.compare_and_swap(&current, Arc::new(resolved_hostnames));
+}
@@ -0,0 +1,52 @@
+use super::{load_config_from_database, replace_in_memory_routes};
+use crate::{
+    domain::{
+        dtos::native_error_codes::NativeErrorCodes, entities::RoutesFetching,
+    },
+    models::AccountLifeCycle,
+};
+
+use mycelium_base::utils::errors::{use_case_err, MappedErrors};
+use tracing::{error, info};
+
+/// Reload the routes from the database
+///
+/// Works like the routes file reload. Case the stored routes are invalid, the
+/// current routes remain active and an error is returned.
+///
+/// Returns the number of loaded routes.
+///
+#[tracing::instrument(name = "reload_config_from_database", skip_all)]
+pub async fn reload_config_from_database(
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Result<usize, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Load and validate the new routes
+    // ? -----------------------------------------------------------------------
+
+    let db = match load_config_from_database(config, routes_fetching_repo).await
+    {
+        Err(err) => {
+            error!("Routes not reloaded. Keeping the current routes: {err}");
+
+            return use_case_err(format!(
+                "Invalid stored routes. The current routes remain active: {err}"
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+        Ok(res) => res,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Swap the in-memory routes
+    // ? -----------------------------------------------------------------------
+
+    let routes_count = replace_in_memory_routes(db).await;
+
+    info!("Routes successfully reloaded: {routes_count} routes");
+
+    Ok(routes_count)
+}
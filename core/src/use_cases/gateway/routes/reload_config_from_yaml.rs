@@ -0,0 +1,47 @@
+use super::{load_config_from_yaml, replace_in_memory_routes};
+use crate::domain::dtos::native_error_codes::NativeErrorCodes;
+
+use mycelium_base::utils::errors::{use_case_err, MappedErrors};
+use tracing::{error, info};
+
+/// Reload the routes from the YAML file
+///
+/// The file is fully loaded and validated before replacing the in-memory
+/// routes. Case the file is invalid, the current routes remain active and an
+/// error is returned. Requests already in progress are not affected, since
+/// they own a copy of the matched route.
+///
+/// Returns the number of loaded routes.
+///
+#[tracing::instrument(name = "reload_config_from_yaml")]
+pub async fn reload_config_from_yaml(
+    source_file_path: String,
+) -> Result<usize, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Load and validate the new routes
+    // ? -----------------------------------------------------------------------
+
+    let db = match load_config_from_yaml(source_file_path.to_owned()).await {
+        Err(err) => {
+            error!("Routes not reloaded. Keeping the current routes: {err}");
+
+            return use_case_err(format!(
+                "Invalid routes file {source_file_path}. The current routes remain active: {err}"
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+        Ok(res) => res,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Swap the in-memory routes
+    // ? -----------------------------------------------------------------------
+
+    let routes_count = replace_in_memory_routes(db).await;
+
+    info!("Routes successfully reloaded: {routes_count} routes");
+
+    Ok(routes_count)
+}
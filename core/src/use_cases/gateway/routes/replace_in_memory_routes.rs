@@ -0,0 +1,95 @@
+use crate::{
+    domain::dtos::{
+        allowed_source::{AllowedSource, ResolvedHostnames},
+        route::Route,
+        route_tree::RoutesIndex,
+    },
+    settings::{
+        CIRCUIT_BREAKERS, RESOLVED_HOSTNAMES, ROUTES, ROUTES_INDEX,
+        SERVICES_HEALTH,
+    },
+};
+
+use mycelium_base::dtos::Parent;
+use std::{collections::HashSet, sync::Arc};
+
+/// Replace the in-memory routes
+///
+/// The routes are compiled and the allowed source hostnames resolved before
+/// taking the locks. The locks are held during the swap, then readers never
+/// observe the routes and the index out of sync.
+/// The health and circuit breaker states of services which no longer exist
+/// are removed.
+///
+/// Returns the number of loaded routes.
+///
+pub(crate) async fn replace_in_memory_routes(db: Vec<Route>) -> usize {
+    let index = RoutesIndex::compile(&db);
+    let services = collect_service_names(&db);
+    let resolved_hostnames = resolve_allowed_hostnames(&db).await;
+    let routes_count = db.len();
+
+    let mut routes = ROUTES.lock().await;
+    let mut routes_index = ROUTES_INDEX.lock().await;
+
+    *routes = db;
+    *routes_index = index;
+    RESOLVED_HOSTNAMES.store(Arc::new(resolved_hostnames));
+
+    drop(routes_index);
+    drop(routes);
+
+    SERVICES_HEALTH
+        .lock()
+        .await
+        .retain(|(service_name, _), _| services.contains(service_name));
+
+    CIRCUIT_BREAKERS
+        .lock()
+        .await
+        .retain(|service_name, _| services.contains(service_name));
+
+    routes_count
+}
+
+/// Resolve the hostnames used as allowed sources of the routes
+///
+/// Invalid sources are ignored here since they are rejected by the routes
+/// validation. Hostnames that could not be resolved map to an empty list and
+/// never match a request source.
+///
+pub(crate) async fn resolve_allowed_hostnames(
+    routes: &[Route],
+) -> ResolvedHostnames {
+    let mut resolved_hostnames = ResolvedHostnames::new();
+
+    for route in routes {
+        let sources = match route.parsed_allowed_sources() {
+            Ok(Some(sources)) => sources,
+            _ => continue,
+        };
+
+        for source in sources {
+            if let AllowedSource::Hostname(hostname) = &source {
+                if resolved_hostnames.contains_key(hostname) {
+                    continue;
+                }
+
+                resolved_hostnames
+                    .insert(hostname.to_owned(), source.resolve().await);
+            }
+        }
+    }
+
+    resolved_hostnames
+}
+
+fn collect_service_names(routes: &[Route]) -> HashSet<String> {
+    routes
+        .iter()
+        .filter_map(|route| match &route.service {
+            Parent::Record(service) => Some(service.name.to_owned()),
+            Parent::Id(_) => None,
+        })
+        .collect()
+}
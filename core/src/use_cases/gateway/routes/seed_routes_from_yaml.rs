@@ -0,0 +1,105 @@
+use super::load_unresolved_config_from_yaml;
+use crate::{
+    domain::{
+        dtos::route::Route,
+        entities::{RoutesFetching, RoutesRegistration},
+    },
+    models::AccountLifeCycle,
+};
+
+use mycelium_base::{
+    dtos::Parent,
+    entities::{CreateManyResponseKind, FetchManyResponseKind},
+    utils::errors::MappedErrors,
+};
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+/// Seed the database with the routes of the YAML file
+///
+/// The routes file is used as seed only when the database contains no
+/// services, then changes performed through the API are never overwritten.
+/// Value secrets are encrypted before storage. Environment and vault secrets
+/// are stored as references and resolved when the routes are loaded.
+///
+/// Services and routes are registered in a single transaction. Returns the
+/// number of created routes.
+///
+#[tracing::instrument(name = "seed_routes_from_yaml", skip_all)]
+pub async fn seed_routes_from_yaml(
+    source_file_path: String,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_registration_repo: Box<&dyn RoutesRegistration>,
+) -> Result<usize, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the database was already seeded
+    // ? -----------------------------------------------------------------------
+
+    if let FetchManyResponseKind::Found(_)
+    | FetchManyResponseKind::FoundPaginated(_) =
+        routes_fetching_repo.list_services(None, None).await?
+    {
+        info!("Database already contains services. Skipping routes seed");
+        return Ok(0);
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Register services and routes
+    // ? -----------------------------------------------------------------------
+
+    let db: Vec<Route> =
+        load_unresolved_config_from_yaml(source_file_path).await?;
+    let mut registered_services = HashSet::<String>::new();
+    let mut services = vec![];
+
+    for route in db.iter() {
+        let service = match &route.service {
+            Parent::Record(service) => service,
+            Parent::Id(_) => continue,
+        };
+
+        if registered_services.insert(service.name.to_owned()) {
+            let mut encrypted_service = service.to_owned();
+
+            if let Some(secrets) = &service.secrets {
+                let mut encrypted_secrets = vec![];
+
+                for secret in secrets {
+                    encrypted_secrets
+                        .push(secret.encrypt_me(config.to_owned()).await?);
+                }
+
+                encrypted_service.secrets = Some(encrypted_secrets);
+            }
+
+            services.push(encrypted_service);
+        }
+    }
+
+    //
+    // Services and routes are registered in a single transaction, then a
+    // failed seed leaves the database empty and is retried at the next start.
+    //
+    let created = match routes_registration_repo
+        .register_services_and_routes(services, db.to_owned())
+        .await?
+    {
+        CreateManyResponseKind::Created(routes) => routes,
+        CreateManyResponseKind::NotCreated(_, msg) => {
+            warn!("Routes not seeded: {msg}");
+            vec![]
+        }
+    };
+
+    if created.len() < db.len() {
+        warn!(
+            "{} routes already exist and were not seeded",
+            db.len() - created.len()
+        );
+    }
+
+    info!("Database seeded with {} routes", created.len());
+
+    Ok(created.len())
+}
@@ -0,0 +1,321 @@
+use crate::domain::dtos::{
+    http::{HttpMethod, Protocol},
+    route::Route,
+};
+
+use mycelium_base::{
+    dtos::Parent,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use tracing::{error, warn};
+
+/// Validate a set of routes
+///
+/// Check the allowed sources, the gRPC routes settings, the path rewrite rules,
+/// the header policies, the CORS policies, the traffic splits, the request
+/// mirrors, the profile projections, the rate limit policies, the upstream
+/// timeouts and retries, the circuit breakers and the upstream targets. The
+/// same validation is applied to routes loaded from the routes file and to
+/// routes managed through the API.
+///
+/// Routes of the same service sharing the same path and methods are accepted.
+/// The match prefers the first declared route, then the shadowed routes are
+/// only reported.
+///
+pub(crate) fn validate_routes(db: &[Route]) -> Result<(), MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Check if the allowed sources of each route are valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Err(err) = route.parsed_allowed_sources() {
+            error!("Invalid allowed sources on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid allowed sources on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the gRPC routes declare only the supported settings
+    //
+    // gRPC calls are always sent with the POST method. They are sent to the
+    // upstream using the original call path and their bodies are streamed,
+    // then they could not be rewritten, mirrored or retried.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter().filter(|route| route.protocol == Protocol::Grpc) {
+        if !route.path.starts_with('/')
+            || !route.methods.iter().any(|method| {
+                [HttpMethod::Post, HttpMethod::All].contains(method)
+            })
+            || route.rewrite.is_some()
+            || route.mirror.is_some()
+            || route.retry.is_some()
+        {
+            error!("Invalid gRPC route {}", route.path);
+
+            return use_case_err(format!(
+                "Invalid gRPC route {}: the path should start with a slash, the methods should include POST and rewrite rules, request mirrors and retries are not supported",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the path rewrite rules of each route are valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Err(err) = route.validate_rewrite() {
+            error!("Invalid rewrite rules on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid rewrite rules on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the header policies of each route are valid
+    //
+    // Both the route and the service declarations are checked.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Err(err) = route.validate_header_policy() {
+            error!("Invalid header policy on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid header policy on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the CORS policies of each route are valid
+    //
+    // Both the route and the service declarations are checked.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Err(err) = route.validate_cors() {
+            error!("Invalid CORS policy on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid CORS policy on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the traffic split of each route is valid
+    //
+    // Backend services are resolved on each request, since routes managed
+    // through the API are validated together with the routes of the same
+    // service only.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Some(Err(err)) =
+            route.traffic_split.as_ref().map(|split| split.validate())
+        {
+            error!("Invalid traffic split on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid traffic split on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the request mirror of each route is valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Some(Err(err)) =
+            route.mirror.as_ref().map(|mirror| mirror.validate())
+        {
+            error!("Invalid request mirror on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid request mirror on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the profile projection of each route is valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Some(Err(err)) = route
+            .profile_projection
+            .as_ref()
+            .map(|projection| projection.validate())
+        {
+            error!("Invalid profile projection on route {}: {err}", route.path);
+
+            return use_case_err(format!(
+                "Invalid profile projection on route {}: {err}",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the rate limit policy of each route is valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Some(rate_limit) = &route.rate_limit {
+            if rate_limit.limit == 0 || rate_limit.window_in_secs == 0 {
+                error!("Invalid rate limit on route {}", route.path);
+
+                return use_case_err(format!(
+                    "Invalid rate limit on route {}: limit and window should be greater than zero",
+                    route.path
+                ))
+                .as_error();
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the upstream timeouts and retries of each route are valid
+    //
+    // Both the route and the service declarations are checked.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        let (service_timeouts, service_retry) = match &route.service {
+            Parent::Record(service) => (&service.timeouts, &service.retry),
+            Parent::Id(_) => (&None, &None),
+        };
+
+        let invalid_timeouts = [&route.timeouts, service_timeouts]
+            .into_iter()
+            .flatten()
+            .any(|timeouts| {
+                timeouts.connect_timeout_in_secs == Some(0)
+                    || timeouts.response_timeout_in_secs == Some(0)
+                    || timeouts.idle_timeout_in_secs == Some(0)
+            });
+
+        let invalid_retry = [&route.retry, service_retry]
+            .into_iter()
+            .flatten()
+            .any(|retry| retry.max_attempts == 0);
+
+        if invalid_timeouts || invalid_retry {
+            error!("Invalid timeouts or retries on route {}", route.path);
+
+            return use_case_err(format!(
+                "Invalid timeouts or retries on route {}: timeouts and attempts should be greater than zero",
+                route.path
+            ))
+            .as_error();
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the circuit breaker of each service is valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Parent::Record(service) = &route.service {
+            if let Some(circuit_breaker) = &service.circuit_breaker {
+                if !circuit_breaker.is_valid() {
+                    error!(
+                        "Invalid circuit breaker on service {}",
+                        service.name
+                    );
+
+                    return use_case_err(format!(
+                        "Invalid circuit breaker on service {}: the failure rate threshold should be between 1 and 100 and the remaining values greater than zero",
+                        service.name
+                    ))
+                    .as_error();
+                }
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Report the shadowed routes
+    //
+    // Routes of the same service sharing the same path pattern and methods are
+    // matched by declaration order, then the later ones are never reached.
+    //
+    // ? -----------------------------------------------------------------------
+
+    for (index, route) in db.iter().enumerate() {
+        for other in db.iter().skip(index + 1) {
+            let same_service = match (&route.service, &other.service) {
+                (Parent::Record(a), Parent::Record(b)) => a.name == b.name,
+                _ => false,
+            };
+
+            if !same_service || route.path != other.path {
+                continue;
+            }
+
+            let overlapping_methods = route.methods.iter().any(|method| {
+                *method == HttpMethod::All
+                    || other.methods.contains(&HttpMethod::All)
+                    || other.methods.contains(method)
+            });
+
+            if overlapping_methods {
+                warn!(
+                    "Routes with path {} share methods. Requests are matched by the first declared route",
+                    route.path
+                );
+            }
+        }
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the upstream targets of each service are valid
+    // ? -----------------------------------------------------------------------
+
+    for route in db.iter() {
+        if let Parent::Record(ref service) = route.service {
+            if service
+                .upstream_targets()
+                .iter()
+                .any(|target| target.host.trim().is_empty())
+            {
+                error!("Invalid upstream target on service {}", service.name);
+
+                return use_case_err(format!(
+                    "Invalid upstream target on service {}: empty host",
+                    service.name
+                ))
+                .as_error();
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use crate::{
+    domain::{
+        dtos::{
+            health_check::{
+                HealthCheckConfig, ServiceHealth, DEFAULT_HEALTHY_THRESHOLD,
+                DEFAULT_HEALTH_CHECK_TIMEOUT, DEFAULT_UNHEALTHY_THRESHOLD,
+            },
+            http::Protocol,
+            upstream::UpstreamTarget,
+        },
+        entities::RoutesFetching,
+    },
+    settings::SERVICES_HEALTH,
+};
+
+use futures::future::join_all;
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use reqwest::Client;
+use std::time::Duration;
+use tracing::{trace, warn};
+
+/// Check the health of the downstream services
+///
+/// This function should be called periodically by the gateway. It requests the
+/// health check path of each upstream target of the services with a health
+/// check configuration and updates the targets health state.
+///
+#[tracing::instrument(name = "check_services_health", skip_all)]
+pub async fn check_services_health(
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Result<(), MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Collect services with health check configurations
+    // ? -----------------------------------------------------------------------
+
+    let services = match routes_fetching_repo.list_services(None, None).await? {
+        FetchManyResponseKind::Found(services) => services,
+        FetchManyResponseKind::FoundPaginated(services) => services.records,
+        FetchManyResponseKind::NotFound => return Ok(()),
+    };
+
+    let client = Client::new();
+
+    let checks = services
+        .into_iter()
+        .filter_map(|service| match service.health_check.to_owned() {
+            Some(config) => Some((service, config)),
+            None => None,
+        })
+        .flat_map(|(service, config)| {
+            service.upstream_targets().into_iter().map(move |target| {
+                (service.to_owned(), config.to_owned(), target)
+            })
+        })
+        .map(|(service, config, target)| {
+            let client = client.to_owned();
+
+            async move {
+                let result =
+                    check_target_health(&client, &target, &config).await;
+
+                (service, config, target, result)
+            }
+        });
+
+    let results = join_all(checks).await;
+
+    // ? -----------------------------------------------------------------------
+    // ? Update the services health state
+    // ? -----------------------------------------------------------------------
+
+    let mut services_health = SERVICES_HEALTH.lock().await;
+
+    for (service, config, target, result) in results {
+        let health = services_health
+            .entry((service.name.to_owned(), target.host.to_owned()))
+            .or_insert(ServiceHealth::new(
+                service.id,
+                service.name.to_owned(),
+                target.host.to_owned(),
+            ));
+
+        let previous_status = health.status;
+
+        match result {
+            Ok(_) => health.register_success(
+                config
+                    .healthy_threshold
+                    .unwrap_or(DEFAULT_HEALTHY_THRESHOLD),
+            ),
+            Err(reason) => {
+                trace!(
+                    "Health check failed for {} ({}): {reason}",
+                    service.name,
+                    target.host
+                );
+
+                health.register_failure(
+                    reason,
+                    config
+                        .unhealthy_threshold
+                        .unwrap_or(DEFAULT_UNHEALTHY_THRESHOLD),
+                )
+            }
+        };
+
+        if previous_status != health.status {
+            warn!(
+                "Service {} ({}) health status changed from {:?} to {:?}",
+                service.name, target.host, previous_status, health.status
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Request the health check path of a single upstream target
+///
+/// Returns the failure reason if the target is not healthy.
+async fn check_target_health(
+    client: &Client,
+    target: &UpstreamTarget,
+    config: &HealthCheckConfig,
+) -> Result<(), String> {
+    let protocol = match config.protocol {
+        Some(Protocol::Https) => Protocol::Https,
+        _ => Protocol::Http,
+    };
+
+    let host = target.host.split("/").next().unwrap_or_default();
+    let url = format!("{protocol}://{host}{path}", path = config.path);
+
+    let response = match client
+        .get(url.as_str())
+        .timeout(Duration::from_secs(
+            config
+                .timeout_in_secs
+                .unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT),
+        ))
+        .send()
+        .await
+    {
+        Err(err) => return Err(format!("Unable to reach {url}: {err}")),
+        Ok(res) => res,
+    };
+
+    let status = response.status().as_u16() as i32;
+
+    if !config.health_response_codes.contains(&status) {
+        return Err(format!("Unexpected status code from {url}: {status}"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use crate::settings::OAUTH2_TOKENS;
+
+use chrono::{DateTime, Duration, Local};
+use futures::lock::Mutex;
+use lazy_static::lazy_static;
+use mycelium_base::utils::errors::{execution_err, MappedErrors};
+use reqwest::Client;
+use serde::Deserialize;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::Arc,
+    time::Duration as StdDuration,
+};
+use tracing::{trace, warn};
+
+/// The lifetime in seconds of tokens issued without the `expires_in` field
+const DEFAULT_TOKEN_LIFETIME: i64 = 60;
+
+/// The time in seconds before the expiration when tokens are refreshed
+const TOKEN_EXPIRATION_MARGIN: i64 = 30;
+
+/// The time in seconds to wait for the token endpoint connection
+const TOKEN_CONNECT_TIMEOUT: u64 = 5;
+
+/// The time in seconds to wait for the token endpoint response
+const TOKEN_REQUEST_TIMEOUT: u64 = 10;
+
+/// The time in seconds to keep a failed token request in cache
+///
+/// Requests using the same credentials fail immediately during this period,
+/// instead of waiting for an unavailable token endpoint.
+///
+const FAILED_TOKEN_LIFETIME: i64 = 5;
+
+lazy_static! {
+    /// The client used to request the access tokens
+    ///
+    /// The client is shared by all credentials, reusing the connections to
+    /// the token endpoints.
+    ///
+    static ref TOKEN_CLIENT: Client = Client::builder()
+        .connect_timeout(StdDuration::from_secs(TOKEN_CONNECT_TIMEOUT))
+        .timeout(StdDuration::from_secs(TOKEN_REQUEST_TIMEOUT))
+        .build()
+        .expect("Unable to build the OAuth2 token client");
+}
+
+/// The result of the last access token request of a set of credentials
+#[derive(Debug, Clone)]
+pub enum OAuth2AccessToken {
+    /// A token issued by the token endpoint
+    Issued {
+        access_token: String,
+        expires_at: DateTime<Local>,
+    },
+
+    /// A failed token request
+    Failed {
+        reason: String,
+        retry_at: DateTime<Local>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+}
+
+/// Fetch an access token using the OAuth2 client credentials grant
+///
+/// Tokens are cached until they expire. Each set of credentials has its own
+/// lock, then concurrent requests wait for a single refresh instead of
+/// requesting several tokens to the token endpoint. Failed refreshes are
+/// cached for a few seconds, then requests fail immediately while the token
+/// endpoint is unavailable.
+///
+#[tracing::instrument(name = "fetch_oauth2_token", skip_all)]
+pub async fn fetch_oauth2_token(
+    token_url: &str,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+    audience: Option<&str>,
+) -> Result<String, MappedErrors> {
+    let mut hasher = DefaultHasher::new();
+    (token_url, client_id, client_secret, scope, audience).hash(&mut hasher);
+
+    let entry = OAUTH2_TOKENS
+        .lock()
+        .await
+        .entry(hasher.finish())
+        .or_insert_with(|| Arc::new(Mutex::new(None)))
+        .to_owned();
+
+    let mut cached_token = entry.lock().await;
+
+    match cached_token.as_ref() {
+        Some(OAuth2AccessToken::Issued {
+            access_token,
+            expires_at,
+        }) if *expires_at > Local::now() => {
+            return Ok(access_token.to_owned());
+        }
+        Some(OAuth2AccessToken::Failed { reason, retry_at })
+            if *retry_at > Local::now() =>
+        {
+            return execution_err(format!(
+                "Unable to request access token: {reason}"
+            ))
+            .as_error();
+        }
+        _ => (),
+    }
+
+    trace!("Requesting access token to {token_url}");
+
+    match request_token(token_url, client_id, client_secret, scope, audience)
+        .await
+    {
+        Ok(token) => {
+            *cached_token = Some(OAuth2AccessToken::Issued {
+                access_token: token.access_token.to_owned(),
+                expires_at: Local::now()
+                    + Duration::seconds(token_lifetime(token.expires_in)),
+            });
+
+            Ok(token.access_token)
+        }
+        Err(reason) => {
+            warn!("Error on request access token to {token_url}: {reason}");
+
+            *cached_token = Some(OAuth2AccessToken::Failed {
+                reason: reason.to_owned(),
+                retry_at: Local::now()
+                    + Duration::seconds(FAILED_TOKEN_LIFETIME),
+            });
+
+            execution_err(format!("Unable to request access token: {reason}"))
+                .as_error()
+        }
+    }
+}
+
+/// Request an access token to the token endpoint
+///
+/// Returns the failure reason of rejected requests.
+///
+async fn request_token(
+    token_url: &str,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+    audience: Option<&str>,
+) -> Result<TokenResponse, String> {
+    let mut params = vec![
+        ("grant_type", "client_credentials"),
+        ("client_id", client_id),
+        ("client_secret", client_secret),
+    ];
+
+    if let Some(scope) = scope {
+        params.push(("scope", scope));
+    }
+
+    if let Some(audience) = audience {
+        params.push(("audience", audience));
+    }
+
+    let response = match TOKEN_CLIENT.post(token_url).form(&params).send().await
+    {
+        Ok(response) => response,
+        Err(err) => return Err(format!("{err}")),
+    };
+
+    if !response.status().is_success() {
+        return Err(format!("unexpected status code {}", response.status()));
+    }
+
+    match response.json::<TokenResponse>().await {
+        Ok(token) => Ok(token),
+        Err(err) => Err(format!("invalid access token response: {err}")),
+    }
+}
+
+/// The time in seconds to keep a token in cache
+///
+/// Tokens are refreshed before expiring. Short-lived tokens are kept for half
+/// of their lifetime.
+///
+fn token_lifetime(expires_in: Option<i64>) -> i64 {
+    let expires_in = expires_in.unwrap_or(DEFAULT_TOKEN_LIFETIME).max(0);
+
+    match expires_in > 2 * TOKEN_EXPIRATION_MARGIN {
+        true => expires_in - TOKEN_EXPIRATION_MARGIN,
+        false => expires_in / 2,
+    }
+}
+
+// ? --------------------------------------------------------------------------
+// ? TESTS
+// ? --------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_lifetime_works() {
+        assert_eq!(token_lifetime(Some(3600)), 3570);
+        assert_eq!(token_lifetime(Some(40)), 20);
+        assert_eq!(token_lifetime(Some(-10)), 0);
+        assert_eq!(token_lifetime(None), 30);
+    }
+}
@@ -0,0 +1,11 @@
+mod check_services_health;
+mod fetch_oauth2_token;
+mod select_upstream_targets;
+mod track_circuit_breakers;
+mod track_upstream_requests;
+
+pub use check_services_health::*;
+pub use fetch_oauth2_token::*;
+pub use select_upstream_targets::*;
+pub use track_circuit_breakers::*;
+pub use track_upstream_requests::*;
@@ -0,0 +1,49 @@
+use crate::{
+    domain::dtos::{service::Service, upstream::UpstreamTarget},
+    settings::{SERVICES_HEALTH, UPSTREAM_BALANCERS},
+};
+
+use tracing::trace;
+
+/// Select the upstream targets of a service
+///
+/// Returns the service targets ordered by preference, following the service
+/// load balancing strategy. The first target should receive the request and
+/// the remaining ones should be used as failover, in order. Targets marked as
+/// unhealthy by the health checker are skipped.
+///
+/// An empty vector is returned if all targets are unhealthy.
+///
+#[tracing::instrument(
+    name = "select_upstream_targets",
+    fields(service = %service.name),
+    skip_all
+)]
+pub async fn select_upstream_targets(service: &Service) -> Vec<UpstreamTarget> {
+    let services_health = SERVICES_HEALTH.lock().await;
+
+    let targets = service
+        .upstream_targets()
+        .into_iter()
+        .filter(|target| {
+            match services_health
+                .get(&(service.name.to_owned(), target.host.to_owned()))
+            {
+                Some(health) if health.is_unhealthy() => {
+                    trace!("Skipping unhealthy target {}", target.host);
+                    false
+                }
+                _ => true,
+            }
+        })
+        .collect::<Vec<UpstreamTarget>>();
+
+    drop(services_health);
+
+    UPSTREAM_BALANCERS
+        .lock()
+        .await
+        .entry(service.name.to_owned())
+        .or_default()
+        .next_targets(service.load_balancing.unwrap_or_default(), targets)
+}
@@ -0,0 +1,152 @@
+use crate::{
+    domain::dtos::{
+        circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+        service::Service,
+    },
+    settings::CIRCUIT_BREAKERS,
+};
+
+use chrono::Local;
+use tracing::warn;
+
+/// A request accepted by the service circuit
+///
+/// The result of the request should be registered with
+/// `register_circuit_breaker_result`. Permits dropped without a result, like
+/// on gateway errors before reaching the service and on client disconnections,
+/// release the accepted request without counting it. Then half-open circuits
+/// accept a new trial request immediately.
+///
+#[must_use = "the request result should be registered to the circuit"]
+pub struct CircuitBreakerPermit {
+    service_name: String,
+
+    /// The circuit configuration of requests waiting for a result
+    config: Option<CircuitBreakerConfig>,
+}
+
+impl Drop for CircuitBreakerPermit {
+    fn drop(&mut self) {
+        if self.config.take().is_none() {
+            return;
+        }
+
+        //
+        // The circuit breakers lock is rarely contended, then the request is
+        // usually released immediately. Otherwise, it is released in
+        // background.
+        //
+        if let Some(mut circuit_breakers) = CIRCUIT_BREAKERS.try_lock() {
+            if let Some(breaker) = circuit_breakers.get_mut(&self.service_name)
+            {
+                breaker.release();
+            }
+
+            return;
+        }
+
+        let service_name = std::mem::take(&mut self.service_name);
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Some(breaker) =
+                        CIRCUIT_BREAKERS.lock().await.get_mut(&service_name)
+                    {
+                        breaker.release();
+                    }
+                });
+            }
+            Err(_) => {
+                warn!("Unable to release the circuit of {service_name}")
+            }
+        }
+    }
+}
+
+/// Try to accept a request to the service circuit
+///
+/// Services without a circuit breaker configuration always accept requests.
+/// Returns the time in seconds until the circuit accepts requests again if the
+/// request should be rejected.
+///
+pub async fn acquire_circuit_breaker(
+    service: &Service,
+) -> Result<CircuitBreakerPermit, u64> {
+    let config = match &service.circuit_breaker {
+        None => {
+            return Ok(CircuitBreakerPermit {
+                service_name: service.name.to_owned(),
+                config: None,
+            })
+        }
+        Some(config) => config,
+    };
+
+    let now = Local::now();
+    let mut circuit_breakers = CIRCUIT_BREAKERS.lock().await;
+
+    let breaker = circuit_breakers.entry(service.name.to_owned()).or_insert(
+        CircuitBreaker::new(service.id, service.name.to_owned(), now),
+    );
+
+    let previous_state = breaker.state;
+    let result = breaker.try_acquire(config, now);
+
+    if previous_state != breaker.state {
+        warn!(
+            "Service {} circuit changed from {:?} to {:?}",
+            service.name, previous_state, breaker.state
+        );
+    }
+
+    result.map(|_| CircuitBreakerPermit {
+        service_name: service.name.to_owned(),
+        config: Some(config.to_owned()),
+    })
+}
+
+/// Register the result of a request sent to the service
+///
+/// The `failure` argument contains the failure reason of failed requests.
+///
+pub async fn register_circuit_breaker_result(
+    mut permit: CircuitBreakerPermit,
+    failure: Option<String>,
+) {
+    let config = match permit.config.take() {
+        None => return,
+        Some(config) => config,
+    };
+
+    let now = Local::now();
+    let mut circuit_breakers = CIRCUIT_BREAKERS.lock().await;
+
+    let breaker = match circuit_breakers.get_mut(&permit.service_name) {
+        None => return,
+        Some(breaker) => breaker,
+    };
+
+    let previous_state = breaker.state;
+
+    match failure {
+        None => breaker.register_success(&config, now),
+        Some(reason) => breaker.register_failure(reason, &config, now),
+    };
+
+    if previous_state != breaker.state {
+        warn!(
+            "Service {} circuit changed from {:?} to {:?}",
+            permit.service_name, previous_state, breaker.state
+        );
+    }
+}
+
+/// Collect the circuit breakers of all services
+///
+/// It is used by the metrics collectors, which export the circuit state of
+/// each service.
+///
+pub async fn collect_circuit_breakers() -> Vec<CircuitBreaker> {
+    CIRCUIT_BREAKERS.lock().await.values().cloned().collect()
+}
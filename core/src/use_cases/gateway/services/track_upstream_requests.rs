@@ -0,0 +1,74 @@
+use crate::settings::UPSTREAM_BALANCERS;
+
+use tracing::warn;
+
+/// A request in flight to an upstream target
+///
+/// The request is registered as finished when the guard is dropped, including
+/// when the request future is dropped before completing, like on client
+/// disconnections and timeouts.
+///
+#[must_use = "the upstream request is finished when the guard is dropped"]
+pub struct UpstreamRequestGuard {
+    service_name: String,
+    host: String,
+}
+
+impl Drop for UpstreamRequestGuard {
+    fn drop(&mut self) {
+        //
+        // The balancers lock is rarely contended, then the request is usually
+        // finished immediately. Otherwise, it is finished in background.
+        //
+        if let Some(mut balancers) = UPSTREAM_BALANCERS.try_lock() {
+            if let Some(balancer) = balancers.get_mut(&self.service_name) {
+                balancer.finish_request(&self.host);
+            }
+
+            return;
+        }
+
+        let service_name = std::mem::take(&mut self.service_name);
+        let host = std::mem::take(&mut self.host);
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Some(balancer) =
+                        UPSTREAM_BALANCERS.lock().await.get_mut(&service_name)
+                    {
+                        balancer.finish_request(&host);
+                    }
+                });
+            }
+            Err(_) => {
+                warn!(
+                    "Unable to finish the request to {host} of {service_name}"
+                )
+            }
+        }
+    }
+}
+
+/// Register a request sent to an upstream target
+///
+/// The in-flight requests counter is used by the `leastInFlight` load
+/// balancing strategy. The request is finished when the returned guard is
+/// dropped.
+///
+pub async fn start_upstream_request(
+    service_name: &str,
+    host: &str,
+) -> UpstreamRequestGuard {
+    UPSTREAM_BALANCERS
+        .lock()
+        .await
+        .entry(service_name.to_owned())
+        .or_default()
+        .start_request(host);
+
+    UpstreamRequestGuard {
+        service_name: service_name.to_owned(),
+        host: host.to_owned(),
+    }
+}
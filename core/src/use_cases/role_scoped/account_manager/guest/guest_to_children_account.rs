@@ -8,11 +8,11 @@ use crate::{
         },
         entities::{
             AccountFetching, GuestRoleFetching, GuestUserRegistration,
-            MessageSending,
+            MessageSending, ProfileCaching,
         },
     },
     models::AccountLifeCycle,
-    use_cases::support::send_email_notification,
+    use_cases::support::{invalidate_cached_profiles, send_email_notification},
 };
 
 use futures::future;
@@ -41,6 +41,7 @@ pub async fn guest_to_children_account(
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
     message_sending_repo: Box<&dyn MessageSending>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<GetOrCreateResponseKind<GuestUser>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -184,6 +185,13 @@ pub async fn guest_to_children_account(
         }
     };
 
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(Some(email.to_owned()), profile_caching_repo)
+        .await;
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
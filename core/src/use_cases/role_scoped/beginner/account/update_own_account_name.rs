@@ -1,6 +1,9 @@
-use crate::domain::{
-    dtos::{account::Account, profile::Profile},
-    entities::AccountUpdating,
+use crate::{
+    domain::{
+        dtos::{account::Account, profile::Profile},
+        entities::{AccountUpdating, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -16,12 +19,21 @@ pub async fn update_own_account_name(
     profile: Profile,
     name: String,
     account_updating_repo: Box<&dyn AccountUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Update and persist account name
     // ? -----------------------------------------------------------------------
 
-    account_updating_repo
+    let response = account_updating_repo
         .update_own_account_name(profile.acc_id, name)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,9 +1,12 @@
-use crate::domain::{
-    dtos::{
-        guest_role::Permission, guest_user::GuestUser,
-        native_error_codes::NativeErrorCodes, profile::Profile,
+use crate::{
+    domain::{
+        dtos::{
+            email::Email, guest_role::Permission, guest_user::GuestUser,
+            native_error_codes::NativeErrorCodes, profile::Profile,
+        },
+        entities::{GuestUserOnAccountUpdating, ProfileCaching},
     },
-    entities::GuestUserOnAccountUpdating,
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -24,11 +27,14 @@ pub async fn accept_invitation(
     role_name: String,
     permission: Permission,
     guest_user_on_account_repo: Box<&dyn GuestUserOnAccountUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<GuestUser>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile licenses has the guest_user_id
     // ? -----------------------------------------------------------------------
 
+    let owners = profile.owners.to_owned();
+
     let licensed_resources = (match profile.licensed_resources {
         None => {
             return use_case_err("Profile does not have an account id")
@@ -72,7 +78,21 @@ pub async fn accept_invitation(
     // ? Accept invitation
     // ? -----------------------------------------------------------------------
 
-    guest_user_on_account_repo
+    let response = guest_user_on_account_repo
         .accept_invitation(role_name, account_id, permission)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    for owner in owners {
+        invalidate_cached_profiles(
+            Email::from_string(owner.email).ok(),
+            profile_caching_repo.to_owned(),
+        )
+        .await;
+    }
+
+    Ok(response)
 }
@@ -1,9 +1,14 @@
-use crate::domain::{
-    dtos::{
-        email::Email, native_error_codes::NativeErrorCodes,
-        token::EmailConfirmationTokenMeta, user::User,
+use crate::{
+    domain::{
+        dtos::{
+            email::Email, native_error_codes::NativeErrorCodes,
+            token::EmailConfirmationTokenMeta, user::User,
+        },
+        entities::{
+            ProfileCaching, TokenInvalidation, UserFetching, UserUpdating,
+        },
     },
-    entities::{TokenInvalidation, UserFetching, UserUpdating},
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -18,6 +23,7 @@ pub async fn check_token_and_activate_user(
     user_fetching_repo: Box<&dyn UserFetching>,
     user_updating_repo: Box<&dyn UserUpdating>,
     token_invalidation_repo: Box<&dyn TokenInvalidation>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<User, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Fetch user from email
@@ -91,6 +97,14 @@ pub async fn check_token_and_activate_user(
             .with_exp_true()
             .as_error()
         }
-        UpdatingResponseKind::Updated(user) => Ok(user),
+        UpdatingResponseKind::Updated(user) => {
+            invalidate_cached_profiles(
+                Some(user.email.to_owned()),
+                profile_caching_repo,
+            )
+            .await;
+
+            Ok(user)
+        }
     }
 }
@@ -0,0 +1,58 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::profile::Profile,
+        entities::{RoutesDeletion, RoutesFetching},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::reload_config_from_database,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Delete a route of a downstream service
+///
+/// This function is restricted to the GatewayManager users.
+///
+#[tracing::instrument(
+    name = "delete_route",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, config, routes_fetching_repo, routes_deletion_repo)
+)]
+pub async fn delete_route(
+    profile: Profile,
+    route_id: Uuid,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_deletion_repo: Box<&dyn RoutesDeletion>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Delete the route
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_deletion_repo.delete_route(route_id).await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Route deleted but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
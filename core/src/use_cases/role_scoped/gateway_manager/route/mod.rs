@@ -1,3 +1,11 @@
+mod delete_route;
 mod list_routes;
+mod register_route;
+mod reload_routes;
+mod update_route;
 
+pub use delete_route::*;
 pub use list_routes::*;
+pub use register_route::*;
+pub use reload_routes::*;
+pub use update_route::*;
@@ -0,0 +1,209 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            http::{HttpMethod, Protocol},
+            native_error_codes::NativeErrorCodes,
+            path_rewrite::PathRewriteRule,
+            profile::Profile,
+            profile_projection::ProfileProjection,
+            rate_limit::RateLimitPolicy,
+            request_mirror::RequestMirror,
+            retry::RetryPolicy,
+            route::Route,
+            route_type::RouteType,
+            timeout::UpstreamTimeouts,
+            traffic_split::TrafficSplit,
+        },
+        entities::{RoutesFetching, RoutesRegistration},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::{
+        reload_config_from_database, validate_routes,
+    },
+};
+
+use mycelium_base::{
+    entities::{CreateResponseKind, FetchManyResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Register a route of a downstream service
+///
+/// This function is restricted to the GatewayManager users. The new route is
+/// validated together with the current routes of the service. Registered
+/// routes receive a random id, then updating their path or methods does not
+/// conflict with routes registered later.
+///
+#[tracing::instrument(
+    name = "register_route",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, config, routes_fetching_repo, routes_registration_repo)
+)]
+pub async fn register_route(
+    profile: Profile,
+    service_id: Uuid,
+    group: RouteType,
+    methods: Vec<HttpMethod>,
+    path: String,
+    protocol: Protocol,
+    allowed_sources: Option<Vec<String>>,
+    secret_name: Option<String>,
+    accept_insecure_routing: Option<bool>,
+    rate_limit: Option<RateLimitPolicy>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    accept_non_idempotent_retries: Option<bool>,
+    rewrite: Option<Vec<PathRewriteRule>>,
+    header_policy: Option<HeaderPolicy>,
+    traffic_split: Option<TrafficSplit>,
+    mirror: Option<RequestMirror>,
+    cors: Option<CorsPolicy>,
+    profile_projection: Option<ProfileProjection>,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_registration_repo: Box<&dyn RoutesRegistration>,
+) -> Result<CreateResponseKind<Route>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Fetch the route service
+    // ? ----------------------------------------------------------------------
+
+    let services = match routes_fetching_repo
+        .list_services(Some(service_id), None)
+        .await?
+    {
+        FetchManyResponseKind::Found(services) => services,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    let service = match services.into_iter().next() {
+        Some(service) => service,
+        None => {
+            return use_case_err(format!("Service {service_id} not found"))
+                .with_code(NativeErrorCodes::MYC00025)
+                .with_exp_true()
+                .as_error()
+        }
+    };
+
+    // ? ----------------------------------------------------------------------
+    // ? Build and validate the route
+    // ? ----------------------------------------------------------------------
+
+    if let Some(secret_name) = &secret_name {
+        if !service
+            .secrets
+            .iter()
+            .flatten()
+            .any(|secret| secret.name() == secret_name)
+        {
+            return use_case_err(format!("Secret not found: {secret_name}"))
+                .with_code(NativeErrorCodes::MYC00024)
+                .with_exp_true()
+                .as_error();
+        }
+    }
+
+    let route = Route::new(
+        Some(Uuid::new_v4()),
+        service,
+        group,
+        methods,
+        path,
+        protocol,
+        allowed_sources,
+        secret_name,
+        accept_insecure_routing,
+    )
+    .with_rate_limit(rate_limit)
+    .with_timeouts(timeouts)
+    .with_retry(retry, accept_non_idempotent_retries)
+    .with_rewrite(rewrite)
+    .with_header_policy(header_policy)
+    .with_traffic_split(traffic_split)
+    .with_mirror(mirror)
+    .with_cors(cors)
+    .with_profile_projection(profile_projection);
+
+    for backend in route
+        .traffic_split
+        .iter()
+        .flat_map(|split| split.services())
+    {
+        if let FetchManyResponseKind::NotFound = routes_fetching_repo
+            .list_services(None, Some(backend.to_string()))
+            .await?
+        {
+            return use_case_err(format!(
+                "Traffic split backend not found: {backend}"
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+    }
+
+    if let Some(mirror) = &route.mirror {
+        if let FetchManyResponseKind::NotFound = routes_fetching_repo
+            .list_services(None, Some(mirror.service.to_owned()))
+            .await?
+        {
+            return use_case_err(format!(
+                "Mirror service not found: {}",
+                mirror.service
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+    }
+
+    let mut service_routes = match routes_fetching_repo
+        .list_routes(Some(service_id), None, Some(true))
+        .await?
+    {
+        FetchManyResponseKind::Found(routes) => routes,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    service_routes.push(route.to_owned());
+
+    if let Err(err) = validate_routes(&service_routes) {
+        return use_case_err(format!("Invalid route: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Register the route
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_registration_repo.register_route(route).await?;
+
+    if let CreateResponseKind::Created(_) = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Route registered but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,74 @@
+use crate::{
+    domain::{
+        actors::SystemActor, dtos::profile::Profile, entities::RoutesFetching,
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::{
+        reload_config_from_database, reload_config_from_yaml,
+    },
+};
+
+use mycelium_base::utils::errors::MappedErrors;
+
+/// Reload routes from the routes file
+///
+/// This function is restricted to the GatewayManager users. The current routes
+/// remain active if the routes file is invalid.
+///
+#[tracing::instrument(
+    name = "reload_routes",
+    fields(profile_id = %profile.acc_id),
+    skip(profile)
+)]
+pub async fn reload_routes(
+    profile: Profile,
+    source_file_path: String,
+) -> Result<usize, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Reload the routes
+    // ? ----------------------------------------------------------------------
+
+    reload_config_from_yaml(source_file_path).await
+}
+
+/// Reload routes from the database
+///
+/// This function is restricted to the GatewayManager users. The current routes
+/// remain active if the stored routes are invalid.
+///
+#[tracing::instrument(
+    name = "reload_routes_from_database",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, config, routes_fetching_repo)
+)]
+pub async fn reload_routes_from_database(
+    profile: Profile,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Result<usize, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Reload the routes
+    // ? ----------------------------------------------------------------------
+
+    reload_config_from_database(config, routes_fetching_repo).await
+}
@@ -0,0 +1,307 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            http::{HttpMethod, Protocol},
+            native_error_codes::NativeErrorCodes,
+            path_rewrite::PathRewriteRule,
+            profile::Profile,
+            profile_projection::ProfileProjection,
+            rate_limit::RateLimitPolicy,
+            request_mirror::RequestMirror,
+            retry::RetryPolicy,
+            route::Route,
+            route_type::RouteType,
+            timeout::UpstreamTimeouts,
+            traffic_split::TrafficSplit,
+        },
+        entities::{RoutesFetching, RoutesUpdating},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::{
+        reload_config_from_database, validate_routes,
+    },
+};
+
+use mycelium_base::{
+    dtos::Parent,
+    entities::{FetchManyResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Update a route of a downstream service
+///
+/// This function is restricted to the GatewayManager users. Only the informed
+/// fields are updated. An empty list of allowed sources removes the source
+/// restrictions of the route, an empty list of rewrite rules removes the path
+/// rewriting, an empty header policy removes the route header
+/// transformations, a traffic split without backends removes the traffic
+/// splitting and a mirror without service removes the request mirroring. An
+/// informed CORS policy or profile projection replaces the current one.
+///
+#[tracing::instrument(
+    name = "update_route",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, config, routes_fetching_repo, routes_updating_repo)
+)]
+pub async fn update_route(
+    profile: Profile,
+    route_id: Uuid,
+    group: Option<RouteType>,
+    methods: Option<Vec<HttpMethod>>,
+    path: Option<String>,
+    protocol: Option<Protocol>,
+    allowed_sources: Option<Vec<String>>,
+    secret_name: Option<String>,
+    accept_insecure_routing: Option<bool>,
+    rate_limit: Option<RateLimitPolicy>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    accept_non_idempotent_retries: Option<bool>,
+    rewrite: Option<Vec<PathRewriteRule>>,
+    header_policy: Option<HeaderPolicy>,
+    traffic_split: Option<TrafficSplit>,
+    mirror: Option<RequestMirror>,
+    cors: Option<CorsPolicy>,
+    profile_projection: Option<ProfileProjection>,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_updating_repo: Box<&dyn RoutesUpdating>,
+) -> Result<UpdatingResponseKind<Route>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Fetch the current route
+    // ? ----------------------------------------------------------------------
+
+    let routes = match routes_fetching_repo
+        .list_routes(None, None, Some(true))
+        .await?
+    {
+        FetchManyResponseKind::Found(routes) => routes,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    let mut route = match routes.iter().find(|route| route.id == Some(route_id))
+    {
+        Some(route) => route.to_owned(),
+        None => {
+            return use_case_err(format!("Route {route_id} not found"))
+                .with_code(NativeErrorCodes::MYC00025)
+                .with_exp_true()
+                .as_error()
+        }
+    };
+
+    // ? ----------------------------------------------------------------------
+    // ? Apply the changes
+    // ? ----------------------------------------------------------------------
+
+    if let Some(group) = group {
+        route.group = group;
+    }
+
+    if let Some(methods) = methods {
+        route.methods = methods;
+    }
+
+    if let Some(path) = path {
+        route.path = path;
+    }
+
+    if let Some(protocol) = protocol {
+        route.protocol = protocol;
+    }
+
+    if let Some(allowed_sources) = allowed_sources {
+        route.allowed_sources = match allowed_sources.is_empty() {
+            true => None,
+            false => Some(allowed_sources),
+        };
+    }
+
+    if secret_name.is_some() {
+        route.secret_name = secret_name;
+    }
+
+    if accept_insecure_routing.is_some() {
+        route.accept_insecure_routing = accept_insecure_routing;
+    }
+
+    if rate_limit.is_some() {
+        route.rate_limit = rate_limit;
+    }
+
+    if timeouts.is_some() {
+        route.timeouts = timeouts;
+    }
+
+    if retry.is_some() {
+        route.retry = retry;
+    }
+
+    if accept_non_idempotent_retries.is_some() {
+        route.accept_non_idempotent_retries = accept_non_idempotent_retries;
+    }
+
+    if let Some(rewrite) = rewrite {
+        route.rewrite = match rewrite.is_empty() {
+            true => None,
+            false => Some(rewrite),
+        };
+    }
+
+    if let Some(header_policy) = header_policy {
+        route.header_policy = match header_policy == HeaderPolicy::default() {
+            true => None,
+            false => Some(header_policy),
+        };
+    }
+
+    if let Some(traffic_split) = traffic_split {
+        route.traffic_split = match traffic_split.backends.is_empty() {
+            true => None,
+            false => Some(traffic_split),
+        };
+    }
+
+    if let Some(mirror) = mirror {
+        route.mirror = match mirror.service.is_empty() {
+            true => None,
+            false => Some(mirror),
+        };
+    }
+
+    if cors.is_some() {
+        route.cors = cors;
+    }
+
+    if profile_projection.is_some() {
+        route.profile_projection = profile_projection;
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Validate the route together with the other service routes
+    // ? ----------------------------------------------------------------------
+
+    let service_id = match &route.service {
+        Parent::Record(service) => service.id,
+        Parent::Id(id) => Some(*id),
+    };
+
+    if let (Some(secret_name), Parent::Record(service)) =
+        (&route.secret_name, &route.service)
+    {
+        if !service
+            .secrets
+            .iter()
+            .flatten()
+            .any(|secret| secret.name() == secret_name)
+        {
+            return use_case_err(format!("Secret not found: {secret_name}"))
+                .with_code(NativeErrorCodes::MYC00024)
+                .with_exp_true()
+                .as_error();
+        }
+    }
+
+    for backend in route
+        .traffic_split
+        .iter()
+        .flat_map(|split| split.services())
+    {
+        if let FetchManyResponseKind::NotFound = routes_fetching_repo
+            .list_services(None, Some(backend.to_string()))
+            .await?
+        {
+            return use_case_err(format!(
+                "Traffic split backend not found: {backend}"
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+    }
+
+    if let Some(mirror) = &route.mirror {
+        if let FetchManyResponseKind::NotFound = routes_fetching_repo
+            .list_services(None, Some(mirror.service.to_owned()))
+            .await?
+        {
+            return use_case_err(format!(
+                "Mirror service not found: {}",
+                mirror.service
+            ))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+        }
+    }
+
+    let mut service_routes = routes
+        .into_iter()
+        .filter(|other| other.id != Some(route_id))
+        .filter(|other| match &other.service {
+            Parent::Record(service) => service.id == service_id,
+            Parent::Id(id) => Some(*id) == service_id,
+        })
+        .collect::<Vec<Route>>();
+
+    if service_routes.iter().any(|other| {
+        other.path == route.path
+            && other
+                .methods
+                .iter()
+                .all(|method| route.methods.contains(method))
+            && route
+                .methods
+                .iter()
+                .all(|method| other.methods.contains(method))
+    }) {
+        return use_case_err(format!(
+            "Route already exists: {} {:?}",
+            route.path, route.methods
+        ))
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    service_routes.push(route.to_owned());
+
+    if let Err(err) = validate_routes(&service_routes) {
+        return use_case_err(format!("Invalid route: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Update the route
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_updating_repo.update_route(route).await?;
+
+    if let UpdatingResponseKind::Updated(_) = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Route updated but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
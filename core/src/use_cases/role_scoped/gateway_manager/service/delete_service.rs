@@ -0,0 +1,59 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::profile::Profile,
+        entities::{RoutesDeletion, RoutesFetching},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::reload_config_from_database,
+};
+
+use mycelium_base::{
+    entities::DeletionResponseKind, utils::errors::MappedErrors,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Delete a downstream service
+///
+/// This function is restricted to the GatewayManager users. The service routes
+/// are deleted together with the service.
+///
+#[tracing::instrument(
+    name = "delete_service",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, config, routes_fetching_repo, routes_deletion_repo)
+)]
+pub async fn delete_service(
+    profile: Profile,
+    service_id: Uuid,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_deletion_repo: Box<&dyn RoutesDeletion>,
+) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Delete the service
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_deletion_repo.delete_service(service_id).await?;
+
+    if let DeletionResponseKind::Deleted = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Service deleted but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,66 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{circuit_breaker::CircuitBreaker, profile::Profile},
+    },
+    settings::CIRCUIT_BREAKERS,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the circuit breaker state of the downstream services
+///
+/// This function is restricted to the GatewayManager users. Only services with
+/// a circuit breaker configuration and at least one request received are
+/// listed.
+///
+#[tracing::instrument(
+    name = "list_circuit_breakers",
+    fields(profile_id = %profile.acc_id),
+    skip(profile)
+)]
+pub async fn list_circuit_breakers(
+    profile: Profile,
+    id: Option<Uuid>,
+    name: Option<String>,
+) -> Result<FetchManyResponseKind<CircuitBreaker>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Collect the circuit breakers
+    // ? ----------------------------------------------------------------------
+
+    let mut circuit_breakers = CIRCUIT_BREAKERS
+        .lock()
+        .await
+        .values()
+        .filter(|breaker| match id {
+            Some(id) => breaker.service_id == Some(id),
+            None => true,
+        })
+        .filter(|breaker| match &name {
+            Some(name) => breaker.service_name == *name,
+            None => true,
+        })
+        .cloned()
+        .collect::<Vec<CircuitBreaker>>();
+
+    if circuit_breakers.is_empty() {
+        return Ok(FetchManyResponseKind::NotFound);
+    }
+
+    circuit_breakers.sort_by(|a, b| a.service_name.cmp(&b.service_name));
+
+    Ok(FetchManyResponseKind::Found(circuit_breakers))
+}
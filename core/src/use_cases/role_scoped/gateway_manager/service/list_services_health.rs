@@ -0,0 +1,68 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{health_check::ServiceHealth, profile::Profile},
+    },
+    settings::SERVICES_HEALTH,
+};
+
+use mycelium_base::{
+    entities::FetchManyResponseKind, utils::errors::MappedErrors,
+};
+use uuid::Uuid;
+
+/// List the health state of the downstream services
+///
+/// This function is restricted to the GatewayManager users. Only services with
+/// a health check configuration and at least one health check cycle executed
+/// are listed. Each upstream target of a service is listed separately.
+///
+#[tracing::instrument(
+    name = "list_services_health",
+    fields(profile_id = %profile.acc_id),
+    skip(profile)
+)]
+pub async fn list_services_health(
+    profile: Profile,
+    id: Option<Uuid>,
+    name: Option<String>,
+) -> Result<FetchManyResponseKind<ServiceHealth>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_read_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Collect the services health
+    // ? ----------------------------------------------------------------------
+
+    let mut services_health = SERVICES_HEALTH
+        .lock()
+        .await
+        .values()
+        .filter(|health| match id {
+            Some(id) => health.service_id == Some(id),
+            None => true,
+        })
+        .filter(|health| match &name {
+            Some(name) => health.service_name == *name,
+            None => true,
+        })
+        .cloned()
+        .collect::<Vec<ServiceHealth>>();
+
+    if services_health.is_empty() {
+        return Ok(FetchManyResponseKind::NotFound);
+    }
+
+    services_health.sort_by(|a, b| {
+        (&a.service_name, &a.host).cmp(&(&b.service_name, &b.host))
+    });
+
+    Ok(FetchManyResponseKind::Found(services_health))
+}
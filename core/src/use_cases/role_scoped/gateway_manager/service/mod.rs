@@ -1,3 +1,13 @@
+mod delete_service;
+mod list_circuit_breakers;
 mod list_services;
+mod list_services_health;
+mod register_service;
+mod update_service;
 
+pub use delete_service::*;
+pub use list_circuit_breakers::*;
 pub use list_services::*;
+pub use list_services_health::*;
+pub use register_service::*;
+pub use update_service::*;
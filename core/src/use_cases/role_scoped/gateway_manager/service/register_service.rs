@@ -0,0 +1,156 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            circuit_breaker::CircuitBreakerConfig,
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            health_check::HealthCheckConfig,
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            retry::RetryPolicy,
+            service::{Service, ServiceSecret},
+            timeout::UpstreamTimeouts,
+            upstream::{LoadBalancingStrategy, UpstreamTarget},
+        },
+        entities::{RoutesFetching, RoutesRegistration},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::reload_config_from_database,
+};
+
+use mycelium_base::{
+    entities::CreateResponseKind,
+    utils::errors::{use_case_err, MappedErrors},
+};
+use tracing::warn;
+
+/// Register a downstream service
+///
+/// This function is restricted to the GatewayManager users. Value secrets are
+/// encrypted before storage and the in-memory routes are reloaded after the
+/// registration.
+///
+#[tracing::instrument(
+    name = "register_service",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, secrets, config, routes_fetching_repo, routes_registration_repo)
+)]
+pub async fn register_service(
+    profile: Profile,
+    name: String,
+    host: String,
+    targets: Option<Vec<UpstreamTarget>>,
+    load_balancing: Option<LoadBalancingStrategy>,
+    health_check: Option<HealthCheckConfig>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    header_policy: Option<HeaderPolicy>,
+    cors: Option<CorsPolicy>,
+    secrets: Option<Vec<ServiceSecret>>,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_registration_repo: Box<&dyn RoutesRegistration>,
+) -> Result<CreateResponseKind<Service>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Build and validate the service
+    // ? ----------------------------------------------------------------------
+
+    let encrypted_secrets = match secrets {
+        None => None,
+        Some(secrets) => {
+            let mut encrypted_secrets = vec![];
+
+            for secret in secrets {
+                encrypted_secrets
+                    .push(secret.encrypt_me(config.to_owned()).await?);
+            }
+
+            Some(encrypted_secrets)
+        }
+    };
+
+    let service = Service::new(
+        None,
+        name,
+        host,
+        targets,
+        load_balancing,
+        health_check,
+        vec![],
+        encrypted_secrets,
+    )
+    .with_timeouts(timeouts)
+    .with_retry(retry)
+    .with_circuit_breaker(circuit_breaker)
+    .with_header_policy(header_policy)
+    .with_cors(cors);
+
+    if service.name.trim().is_empty()
+        || service
+            .upstream_targets()
+            .iter()
+            .any(|target| target.host.trim().is_empty())
+    {
+        return use_case_err(
+            "Service name and upstream hosts should not be empty",
+        )
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    if !service.has_valid_upstream_policies() {
+        return use_case_err(
+            "Service timeouts, retry attempts and circuit breaker cool-down should be greater than zero and the failure rate threshold should be a percentage",
+        )
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    if let Some(Err(err)) = service
+        .header_policy
+        .as_ref()
+        .map(|policy| policy.validate())
+    {
+        return use_case_err(format!("Invalid service header policy: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    if let Some(Err(err)) = service.cors.as_ref().map(|cors| cors.validate()) {
+        return use_case_err(format!("Invalid service CORS policy: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Register the service
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_registration_repo.register_service(service).await?;
+
+    if let CreateResponseKind::Created(_) = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Service registered but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
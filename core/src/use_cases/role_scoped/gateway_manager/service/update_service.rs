@@ -0,0 +1,239 @@
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            circuit_breaker::CircuitBreakerConfig,
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            health_check::HealthCheckConfig,
+            native_error_codes::NativeErrorCodes,
+            profile::Profile,
+            retry::RetryPolicy,
+            service::{Service, ServiceSecret},
+            timeout::UpstreamTimeouts,
+            upstream::{LoadBalancingStrategy, UpstreamTarget},
+        },
+        entities::{RoutesFetching, RoutesUpdating},
+    },
+    models::AccountLifeCycle,
+    use_cases::gateway::routes::reload_config_from_database,
+};
+
+use mycelium_base::{
+    entities::{FetchManyResponseKind, UpdatingResponseKind},
+    utils::errors::{use_case_err, MappedErrors},
+};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Update a downstream service
+///
+/// This function is restricted to the GatewayManager users. Only the informed
+/// fields are updated. Informed secrets replace the current ones and should
+/// still include the secrets referenced by the service routes. An empty header
+/// policy removes the service header transformations. An informed CORS policy
+/// replaces the current one.
+///
+#[tracing::instrument(
+    name = "update_service",
+    fields(profile_id = %profile.acc_id),
+    skip(profile, secrets, config, routes_fetching_repo, routes_updating_repo)
+)]
+pub async fn update_service(
+    profile: Profile,
+    service_id: Uuid,
+    name: Option<String>,
+    host: Option<String>,
+    targets: Option<Vec<UpstreamTarget>>,
+    load_balancing: Option<LoadBalancingStrategy>,
+    health_check: Option<HealthCheckConfig>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    header_policy: Option<HeaderPolicy>,
+    cors: Option<CorsPolicy>,
+    secrets: Option<Vec<ServiceSecret>>,
+    config: AccountLifeCycle,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+    routes_updating_repo: Box<&dyn RoutesUpdating>,
+) -> Result<UpdatingResponseKind<Service>, MappedErrors> {
+    // ? ----------------------------------------------------------------------
+    // ? Check if the current account has sufficient privileges
+    // ? ----------------------------------------------------------------------
+
+    profile
+        .with_system_accounts_access()
+        .with_write_access()
+        .with_roles(vec![SystemActor::GatewayManager])
+        .get_ids_or_error()?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Fetch the current service
+    // ? ----------------------------------------------------------------------
+
+    let services = match routes_fetching_repo
+        .list_services(Some(service_id), None)
+        .await?
+    {
+        FetchManyResponseKind::Found(services) => services,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    let mut service = match services.into_iter().next() {
+        Some(service) => service,
+        None => {
+            return use_case_err(format!("Service {service_id} not found"))
+                .with_code(NativeErrorCodes::MYC00025)
+                .with_exp_true()
+                .as_error()
+        }
+    };
+
+    // ? ----------------------------------------------------------------------
+    // ? Apply the changes
+    // ? ----------------------------------------------------------------------
+
+    if let Some(name) = name {
+        service.name = name;
+    }
+
+    if let Some(host) = host {
+        service.host = host;
+    }
+
+    if targets.is_some() {
+        service.targets = targets;
+    }
+
+    if load_balancing.is_some() {
+        service.load_balancing = load_balancing;
+    }
+
+    if health_check.is_some() {
+        service.health_check = health_check;
+    }
+
+    if timeouts.is_some() {
+        service.timeouts = timeouts;
+    }
+
+    if retry.is_some() {
+        service.retry = retry;
+    }
+
+    if circuit_breaker.is_some() {
+        service.circuit_breaker = circuit_breaker;
+    }
+
+    if let Some(header_policy) = header_policy {
+        service.header_policy = match header_policy == HeaderPolicy::default() {
+            true => None,
+            false => Some(header_policy),
+        };
+    }
+
+    if cors.is_some() {
+        service.cors = cors;
+    }
+
+    if let Some(secrets) = secrets {
+        let mut encrypted_secrets = vec![];
+
+        for secret in secrets {
+            encrypted_secrets.push(secret.encrypt_me(config.to_owned()).await?);
+        }
+
+        service.secrets = Some(encrypted_secrets);
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Validate the service
+    // ? ----------------------------------------------------------------------
+
+    if service.name.trim().is_empty()
+        || service
+            .upstream_targets()
+            .iter()
+            .any(|target| target.host.trim().is_empty())
+    {
+        return use_case_err(
+            "Service name and upstream hosts should not be empty",
+        )
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    if !service.has_valid_upstream_policies() {
+        return use_case_err(
+            "Service timeouts, retry attempts and circuit breaker cool-down should be greater than zero and the failure rate threshold should be a percentage",
+        )
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    if let Some(Err(err)) = service
+        .header_policy
+        .as_ref()
+        .map(|policy| policy.validate())
+    {
+        return use_case_err(format!("Invalid service header policy: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    if let Some(Err(err)) = service.cors.as_ref().map(|cors| cors.validate()) {
+        return use_case_err(format!("Invalid service CORS policy: {err}"))
+            .with_code(NativeErrorCodes::MYC00024)
+            .with_exp_true()
+            .as_error();
+    }
+
+    let routes = match routes_fetching_repo
+        .list_routes(Some(service_id), None, None)
+        .await?
+    {
+        FetchManyResponseKind::Found(routes) => routes,
+        FetchManyResponseKind::FoundPaginated(paginated) => paginated.records,
+        FetchManyResponseKind::NotFound => vec![],
+    };
+
+    let secret_names = service
+        .secrets
+        .iter()
+        .flatten()
+        .map(|secret| secret.name().to_owned())
+        .collect::<Vec<String>>();
+
+    if let Some(secret_name) = routes
+        .iter()
+        .filter_map(|route| route.secret_name.to_owned())
+        .find(|secret_name| !secret_names.contains(secret_name))
+    {
+        return use_case_err(format!(
+            "Secret {secret_name} is still referenced by the service routes"
+        ))
+        .with_code(NativeErrorCodes::MYC00024)
+        .with_exp_true()
+        .as_error();
+    }
+
+    // ? ----------------------------------------------------------------------
+    // ? Update the service
+    // ? ----------------------------------------------------------------------
+
+    let response = routes_updating_repo.update_service(service).await?;
+
+    if let UpdatingResponseKind::Updated(_) = response {
+        if let Err(err) =
+            reload_config_from_database(config, routes_fetching_repo).await
+        {
+            warn!("Service updated but routes not reloaded: {err}");
+        }
+    }
+
+    Ok(response)
+}
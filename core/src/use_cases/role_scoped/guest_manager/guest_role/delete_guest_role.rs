@@ -1,5 +1,10 @@
-use crate::domain::{
-    actors::SystemActor, dtos::profile::Profile, entities::GuestRoleDeletion,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::profile::Profile,
+        entities::{GuestRoleDeletion, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -14,6 +19,7 @@ pub async fn delete_guest_role(
     profile: Profile,
     guest_role_id: Uuid,
     role_deletion_repo: Box<&dyn GuestRoleDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
     // ? ----------------------------------------------------------------------
     // ? Check user permissions
@@ -31,5 +37,13 @@ pub async fn delete_guest_role(
     // ? Perform the deletion operation
     // ? ----------------------------------------------------------------------
 
-    role_deletion_repo.delete(guest_role_id).await
+    let response = role_deletion_repo.delete(guest_role_id).await?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? ----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
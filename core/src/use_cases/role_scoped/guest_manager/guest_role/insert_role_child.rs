@@ -1,10 +1,13 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{
-        guest_role::GuestRole, native_error_codes::NativeErrorCodes,
-        profile::Profile,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::GuestRole, native_error_codes::NativeErrorCodes,
+            profile::Profile,
+        },
+        entities::{GuestRoleFetching, GuestRoleUpdating, ProfileCaching},
     },
-    entities::{GuestRoleFetching, GuestRoleUpdating},
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use futures::future;
@@ -21,6 +24,7 @@ pub async fn insert_role_child(
     child_id: Uuid,
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Option<GuestRole>>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges to create role
@@ -90,7 +94,15 @@ pub async fn insert_role_child(
     // ? Persist UserRole
     // ? -----------------------------------------------------------------------
 
-    guest_role_updating_repo
+    let response = guest_role_updating_repo
         .insert_role_child(guest_role_id, child_id)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
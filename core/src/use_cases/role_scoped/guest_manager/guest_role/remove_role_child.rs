@@ -1,7 +1,10 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{guest_role::GuestRole, profile::Profile},
-    entities::GuestRoleUpdating,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{guest_role::GuestRole, profile::Profile},
+        entities::{GuestRoleUpdating, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -15,6 +18,7 @@ pub async fn remove_role_child(
     guest_role_id: Uuid,
     child_id: Uuid,
     guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Option<GuestRole>>, MappedErrors> {
     // ? ----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges to create role
@@ -30,7 +34,15 @@ pub async fn remove_role_child(
     // ? Persist UserRole
     // ? ----------------------------------------------------------------------
 
-    guest_role_updating_repo
+    let response = guest_role_updating_repo
         .remove_role_child(guest_role_id, child_id)
-        .await
+        .await?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? ----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
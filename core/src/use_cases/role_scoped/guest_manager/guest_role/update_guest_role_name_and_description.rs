@@ -1,7 +1,10 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{guest_role::GuestRole, profile::Profile},
-    entities::{GuestRoleFetching, GuestRoleUpdating},
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{guest_role::GuestRole, profile::Profile},
+        entities::{GuestRoleFetching, GuestRoleUpdating, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -23,6 +26,7 @@ pub async fn update_guest_role_name_and_description(
     guest_role_id: Uuid,
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<GuestRole>, MappedErrors> {
     // ? ----------------------------------------------------------------------
     // ? Check the profile permissions
@@ -68,5 +72,13 @@ pub async fn update_guest_role_name_and_description(
     // ? Perform the updating operation
     // ? ----------------------------------------------------------------------
 
-    guest_role_updating_repo.update(user_role).await
+    let response = guest_role_updating_repo.update(user_role).await?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? ----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,10 +1,13 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{
-        guest_role::{GuestRole, Permission},
-        profile::Profile,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            guest_role::{GuestRole, Permission},
+            profile::Profile,
+        },
+        entities::{GuestRoleFetching, GuestRoleUpdating, ProfileCaching},
     },
-    entities::{GuestRoleFetching, GuestRoleUpdating},
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -22,6 +25,7 @@ pub async fn update_guest_role_permission(
     permission: Permission,
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     guest_role_updating_repo: Box<&dyn GuestRoleUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<GuestRole>, MappedErrors> {
     // ? ----------------------------------------------------------------------
     // ? Check the profile permissions
@@ -61,5 +65,13 @@ pub async fn update_guest_role_permission(
     // ? Perform the updating operation
     // ? ----------------------------------------------------------------------
 
-    guest_role_updating_repo.update(user_role).await
+    let response = guest_role_updating_repo.update(user_role).await?;
+
+    // ? ----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? ----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
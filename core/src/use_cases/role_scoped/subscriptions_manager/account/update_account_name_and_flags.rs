@@ -1,7 +1,10 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{account::Account, profile::Profile},
-    entities::{AccountFetching, AccountUpdating},
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{account::Account, profile::Profile},
+        entities::{AccountFetching, AccountUpdating, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -27,6 +30,7 @@ pub async fn update_account_name_and_flags(
     is_default: Option<bool>,
     account_fetching_repo: Box<&dyn AccountFetching>,
     account_updating_repo: Box<&dyn AccountUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -86,5 +90,13 @@ pub async fn update_account_name_and_flags(
     // ? Return a positive response
     // ? -----------------------------------------------------------------------
 
-    account_updating_repo.update(account).await
+    let response = account_updating_repo.update(account).await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,10 +1,13 @@
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{
-        native_error_codes::NativeErrorCodes, profile::Profile,
-        related_accounts::RelatedAccounts,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            email::Email, native_error_codes::NativeErrorCodes,
+            profile::Profile, related_accounts::RelatedAccounts,
+        },
+        entities::{GuestUserDeletion, ProfileCaching},
     },
-    entities::GuestUserDeletion,
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -27,6 +30,7 @@ pub async fn revoke_user_guest_to_subscription_account(
     guest_role_id: Uuid,
     email: String,
     guest_user_deletion_repo: Box<&dyn GuestUserDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<(Uuid, Uuid)>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -60,7 +64,22 @@ pub async fn revoke_user_guest_to_subscription_account(
     // ? Uninvite guest
     // ? -----------------------------------------------------------------------
 
-    guest_user_deletion_repo
-        .delete(guest_role_id, account_id, email)
-        .await
+    let response = guest_user_deletion_repo
+        .delete(guest_role_id, account_id, email.to_owned())
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    //
+    // Emails failing to parse discard all cached profiles.
+    //
+    invalidate_cached_profiles(
+        Email::from_string(email).ok(),
+        profile_caching_repo,
+    )
+    .await;
+
+    Ok(response)
 }
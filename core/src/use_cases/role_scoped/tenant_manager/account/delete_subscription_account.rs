@@ -1,5 +1,10 @@
-use crate::domain::{
-    actors::SystemActor, dtos::profile::Profile, entities::AccountDeletion,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::profile::Profile,
+        entities::{AccountDeletion, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -10,13 +15,14 @@ use uuid::Uuid;
 #[tracing::instrument(
     name = "delete_subscription_account",
     fields(profile_id = %profile.acc_id),
-    skip(account_deletion_repo)
+    skip(account_deletion_repo, profile_caching_repo)
 )]
 pub async fn delete_subscription_account(
     profile: Profile,
     tenant_id: Uuid,
     account_id: Uuid,
     account_deletion_repo: Box<&dyn AccountDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check the user permissions
@@ -33,7 +39,15 @@ pub async fn delete_subscription_account(
     // ? Delete account
     // ? -----------------------------------------------------------------------
 
-    account_deletion_repo
+    let response = account_deletion_repo
         .delete(account_id, related_accounts)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
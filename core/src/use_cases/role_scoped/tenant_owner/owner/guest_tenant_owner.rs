@@ -1,6 +1,11 @@
-use crate::domain::{
-    dtos::{email::Email, profile::Profile, user::Provider},
-    entities::{TenantOwnerConnection, TenantUpdating, UserFetching},
+use crate::{
+    domain::{
+        dtos::{email::Email, profile::Profile, user::Provider},
+        entities::{
+            ProfileCaching, TenantOwnerConnection, TenantUpdating, UserFetching,
+        },
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -16,6 +21,7 @@ use uuid::Uuid;
         profile, owner_email,
         owner_fetching_repo,
         tenant_updating_repo,
+        profile_caching_repo,
     )
 )]
 pub async fn guest_tenant_owner(
@@ -24,6 +30,7 @@ pub async fn guest_tenant_owner(
     tenant_id: Uuid,
     owner_fetching_repo: Box<&dyn UserFetching>,
     tenant_updating_repo: Box<&dyn TenantUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<CreateResponseKind<TenantOwnerConnection>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile is the owner of the tenant
@@ -53,13 +60,18 @@ pub async fn guest_tenant_owner(
     // ? -----------------------------------------------------------------------
 
     if let Some(id) = user.id {
-        tenant_updating_repo
+        let response = tenant_updating_repo
             .register_owner(
                 tenant_id,
                 id,
                 format!("account-id:{}", profile.acc_id.to_string()),
             )
-            .await
+            .await?;
+
+        invalidate_cached_profiles(Some(user.email), profile_caching_repo)
+            .await;
+
+        Ok(response)
     } else {
         return use_case_err(
             "Unable to guest user to tenant. Used ID is invalid.".to_string(),
@@ -1,6 +1,9 @@
-use crate::domain::{
-    dtos::{email::Email, profile::Profile},
-    entities::{TenantDeletion, TenantFetching},
+use crate::{
+    domain::{
+        dtos::{email::Email, profile::Profile},
+        entities::{ProfileCaching, TenantDeletion, TenantFetching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -13,7 +16,13 @@ use uuid::Uuid;
 #[tracing::instrument(
     name = "revoke_tenant_owner", 
     fields(profile_id = %profile.acc_id),
-    skip(profile, owner_email, tenant_fetching_repo, tenant_deletion_repo)
+    skip(
+        profile,
+        owner_email,
+        tenant_fetching_repo,
+        tenant_deletion_repo,
+        profile_caching_repo
+    )
 )]
 pub async fn revoke_tenant_owner(
     profile: Profile,
@@ -21,6 +30,7 @@ pub async fn revoke_tenant_owner(
     tenant_id: Uuid,
     tenant_fetching_repo: Box<&dyn TenantFetching>,
     tenant_deletion_repo: Box<&dyn TenantDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile is the owner of the tenant
@@ -84,7 +94,15 @@ pub async fn revoke_tenant_owner(
     // ? Register the owner
     // ? -----------------------------------------------------------------------
 
-    tenant_deletion_repo
-        .delete_owner(tenant_id, None, Some(owner_email))
-        .await
+    let response = tenant_deletion_repo
+        .delete_owner(tenant_id, None, Some(owner_email.to_owned()))
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(Some(owner_email), profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,6 +1,9 @@
-use crate::domain::{
-    dtos::{profile::Profile, tenant::Tenant},
-    entities::TenantUpdating,
+use crate::{
+    domain::{
+        dtos::{profile::Profile, tenant::Tenant},
+        entities::{ProfileCaching, TenantUpdating},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -19,6 +22,7 @@ pub async fn update_tenant_name_and_description(
     tenant_name: Option<String>,
     tenant_description: Option<String>,
     tenant_updating_repo: Box<&dyn TenantUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile is the owner of the tenant
@@ -30,7 +34,15 @@ pub async fn update_tenant_name_and_description(
     // ? Update tenant
     // ? -----------------------------------------------------------------------
 
-    tenant_updating_repo
+    let response = tenant_updating_repo
         .update_name_and_description(tenant_id, tenant_name, tenant_description)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
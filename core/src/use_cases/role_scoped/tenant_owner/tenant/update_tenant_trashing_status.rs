@@ -1,9 +1,12 @@
-use crate::domain::{
-    dtos::{
-        profile::Profile,
-        tenant::{Tenant, TenantStatus},
+use crate::{
+    domain::{
+        dtos::{
+            profile::Profile,
+            tenant::{Tenant, TenantStatus},
+        },
+        entities::{ProfileCaching, TenantUpdating},
     },
-    entities::TenantUpdating,
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use chrono::Local;
@@ -22,6 +25,7 @@ pub async fn update_tenant_trashing_status(
     tenant_id: Uuid,
     trashed: bool,
     tenant_updating_repo: Box<&dyn TenantUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Tenant>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the profile is the owner of the tenant
@@ -33,7 +37,7 @@ pub async fn update_tenant_trashing_status(
     // ? Update tenant
     // ? -----------------------------------------------------------------------
 
-    tenant_updating_repo
+    let response = tenant_updating_repo
         .update_tenant_status(
             tenant_id,
             TenantStatus::Trashed {
@@ -42,5 +46,13 @@ pub async fn update_tenant_trashing_status(
                 by: profile.profile_string(),
             },
         )
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,12 +1,15 @@
 use super::try_to_reach_desired_status::try_to_reach_desired_status;
-use crate::domain::{
-    actors::SystemActor,
-    dtos::{
-        account::{Account, VerboseStatus},
-        account_type::AccountType,
-        profile::Profile,
+use crate::{
+    domain::{
+        actors::SystemActor,
+        dtos::{
+            account::{Account, VerboseStatus},
+            account_type::AccountType,
+            profile::Profile,
+        },
+        entities::{AccountFetching, AccountUpdating, ProfileCaching},
     },
-    entities::{AccountFetching, AccountUpdating},
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -27,6 +30,7 @@ pub async fn change_account_approval_status(
     is_approved: bool,
     account_fetching_repo: Box<&dyn AccountFetching>,
     account_updating_repo: Box<&dyn AccountUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check permissions
@@ -88,5 +92,13 @@ on accounts with more privileges than himself.",
     )
     .await?;
 
-    account_updating_repo.update(updated_account).await
+    let response = account_updating_repo.update(updated_account).await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -8,12 +8,13 @@ use crate::{
         },
         entities::{
             AccountRegistration, GuestRoleFetching, GuestUserRegistration,
-            MessageSending,
+            MessageSending, ProfileCaching,
         },
     },
     models::AccountLifeCycle,
     use_cases::support::{
-        get_or_create_role_related_account, send_email_notification,
+        get_or_create_role_related_account, invalidate_cached_profiles,
+        send_email_notification,
     },
 };
 
@@ -40,6 +41,7 @@ pub async fn guest_to_default_account(
     guest_role_fetching_repo: Box<&dyn GuestRoleFetching>,
     message_sending_repo: Box<&dyn MessageSending>,
     guest_user_registration_repo: Box<&dyn GuestUserRegistration>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<(), MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check permissions
@@ -123,6 +125,13 @@ pub async fn guest_to_default_account(
         }
     };
 
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(Some(email.to_owned()), profile_caching_repo)
+        .await;
+
     // ? -----------------------------------------------------------------------
     // ? Notify guest user
     // ? -----------------------------------------------------------------------
@@ -1,10 +1,12 @@
 use crate::domain::{
     dtos::{
         email::Email,
-        profile::{LicensedResources, Profile, TenantsOwnership},
+        profile::{
+            LicensedResources, Profile, ProfileCacheKey, TenantsOwnership,
+        },
         route_type::PermissionedRoles,
     },
-    entities::{LicensedResourcesFetching, ProfileFetching},
+    entities::{LicensedResourcesFetching, ProfileCaching, ProfileFetching},
 };
 
 use futures::future;
@@ -13,6 +15,7 @@ use mycelium_base::{
     utils::errors::MappedErrors,
 };
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -24,6 +27,11 @@ pub enum ProfileResponse {
 }
 
 /// Fetch the user profile from email address.
+///
+/// Registered users profiles are cached by email and by the filters. Cache
+/// errors are logged and the profile is fetched from the database. Profiles
+/// are not cached when the cache is invalidated during the database reads.
+///
 #[tracing::instrument(name = "fetch_profile_from_email", skip_all)]
 pub async fn fetch_profile_from_email(
     email: Email,
@@ -33,7 +41,44 @@ pub async fn fetch_profile_from_email(
     permissioned_roles: Option<PermissionedRoles>,
     profile_fetching_repo: Box<&dyn ProfileFetching>,
     licensed_resources_fetching_repo: Box<&dyn LicensedResourcesFetching>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<ProfileResponse, MappedErrors> {
+    // ? -----------------------------------------------------------------------
+    // ? Try to get the profile from cache
+    // ? -----------------------------------------------------------------------
+
+    let cache_key = ProfileCacheKey {
+        email: email.to_owned(),
+        was_verified,
+        tenant,
+        roles: roles.to_owned(),
+        permissioned_roles: permissioned_roles.to_owned(),
+    };
+
+    match profile_caching_repo.get(cache_key.to_owned()).await {
+        Ok(FetchResponseKind::Found(profile)) => {
+            return Ok(ProfileResponse::RegisteredUser(profile))
+        }
+        Ok(FetchResponseKind::NotFound(_)) => (),
+        Err(err) => warn!("Unable to get the profile from cache: {err}"),
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Collect the cache version
+    //
+    // The version is collected before the database reads, then invalidations
+    // made during the reads prevent caching an outdated profile.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let cache_version = match profile_caching_repo.version().await {
+        Ok(version) => Some(version),
+        Err(err) => {
+            warn!("Unable to get the profile cache version: {err}");
+            None
+        }
+    };
+
     // ? -----------------------------------------------------------------------
     // ? Fetch the profile and guest from database
     // ? -----------------------------------------------------------------------
@@ -92,6 +137,19 @@ pub async fn fetch_profile_from_email(
         ),
     };
 
+    // ? -----------------------------------------------------------------------
+    // ? Cache the profile
+    // ? -----------------------------------------------------------------------
+
+    if let Some(version) = cache_version {
+        if let Err(err) = profile_caching_repo
+            .set(cache_key, profile.to_owned(), version)
+            .await
+        {
+            warn!("Unable to cache the profile: {err}");
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Return a positive response
     // ? -----------------------------------------------------------------------
@@ -1,10 +1,13 @@
-use crate::domain::{
-    dtos::{
-        native_error_codes::NativeErrorCodes,
-        profile::{Owner, Profile},
-        tenant::Tenant,
+use crate::{
+    domain::{
+        dtos::{
+            native_error_codes::NativeErrorCodes,
+            profile::{Owner, Profile},
+            tenant::Tenant,
+        },
+        entities::{ProfileCaching, TenantRegistration, UserFetching},
     },
-    entities::{TenantRegistration, UserFetching},
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -20,7 +23,12 @@ use uuid::Uuid;
         profile_id = %profile.acc_id,
         owners = ?profile.owners.iter().map(|o| o.email.to_owned()).collect::<Vec<_>>(),
     ),
-    skip(profile, user_fetching_repo, tenant_registration_repo)
+    skip(
+        profile,
+        user_fetching_repo,
+        tenant_registration_repo,
+        profile_caching_repo
+    )
 )]
 pub async fn create_tenant(
     profile: Profile,
@@ -29,6 +37,7 @@ pub async fn create_tenant(
     tenant_owner_id: Uuid,
     user_fetching_repo: Box<&dyn UserFetching>,
     tenant_registration_repo: Box<&dyn TenantRegistration>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<CreateResponseKind<Tenant>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -56,6 +65,8 @@ pub async fn create_tenant(
     // ? Initialize tenant object
     // ? -----------------------------------------------------------------------
 
+    let owner_email = user.email.to_owned();
+
     let tenant = Tenant::new_with_owners(
         tenant_name,
         tenant_description,
@@ -66,7 +77,15 @@ pub async fn create_tenant(
     // ? Register tenant
     // ? -----------------------------------------------------------------------
 
-    tenant_registration_repo
+    let response = tenant_registration_repo
         .create(tenant, format!("account-id:{}", profile.acc_id.to_string()))
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles of the owner
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(Some(owner_email), profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,4 +1,10 @@
-use crate::domain::{dtos::profile::Profile, entities::TenantDeletion};
+use crate::{
+    domain::{
+        dtos::profile::Profile,
+        entities::{ProfileCaching, TenantDeletion},
+    },
+    use_cases::support::invalidate_cached_profiles,
+};
 
 use mycelium_base::{
     entities::DeletionResponseKind, utils::errors::MappedErrors,
@@ -11,11 +17,12 @@ use uuid::Uuid;
         profile_id = %profile.acc_id,
         owners = ?profile.owners.iter().map(|o| o.email.to_owned()).collect::<Vec<_>>(),
     ),
-    skip(profile, tenant_deletion_repo))]
+    skip(profile, tenant_deletion_repo, profile_caching_repo))]
 pub async fn delete_tenant(
     profile: Profile,
     tenant_id: Uuid,
     tenant_deletion_repo: Box<&dyn TenantDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check the user permissions
@@ -27,5 +34,13 @@ pub async fn delete_tenant(
     // ? Delete tenant
     // ? -----------------------------------------------------------------------
 
-    tenant_deletion_repo.delete(tenant_id).await
+    let response = tenant_deletion_repo.delete(tenant_id).await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
@@ -1,4 +1,10 @@
-use crate::domain::{dtos::profile::Profile, entities::TenantDeletion};
+use crate::{
+    domain::{
+        dtos::profile::Profile,
+        entities::{ProfileCaching, TenantDeletion},
+    },
+    use_cases::support::invalidate_cached_profiles,
+};
 
 use mycelium_base::{
     entities::DeletionResponseKind, utils::errors::MappedErrors,
@@ -11,12 +17,13 @@ use uuid::Uuid;
         profile_id = %profile.acc_id,
         owners = ?profile.owners.iter().map(|o| o.email.to_owned()).collect::<Vec<_>>(),
     ),
-    skip(profile, tenant_deletion_repo))]
+    skip(profile, tenant_deletion_repo, profile_caching_repo))]
 pub async fn exclude_tenant_owner(
     profile: Profile,
     tenant_id: Uuid,
     owner_id: Uuid,
     tenant_deletion_repo: Box<&dyn TenantDeletion>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<DeletionResponseKind<Uuid>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check the user permissions
@@ -28,7 +35,15 @@ pub async fn exclude_tenant_owner(
     // ? Delete owner
     // ? -----------------------------------------------------------------------
 
-    tenant_deletion_repo
+    let response = tenant_deletion_repo
         .delete_owner(tenant_id, Some(owner_id), None)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
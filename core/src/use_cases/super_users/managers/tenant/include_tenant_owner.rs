@@ -1,6 +1,9 @@
-use crate::domain::{
-    dtos::profile::Profile,
-    entities::{TenantOwnerConnection, TenantUpdating},
+use crate::{
+    domain::{
+        dtos::profile::Profile,
+        entities::{ProfileCaching, TenantOwnerConnection, TenantUpdating},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -14,12 +17,13 @@ use uuid::Uuid;
         profile_id = %profile.acc_id,
         owners = ?profile.owners.iter().map(|o| o.email.to_owned()).collect::<Vec<_>>(),
     ),
-    skip(profile, tenant_updating_repo))]
+    skip(profile, tenant_updating_repo, profile_caching_repo))]
 pub async fn include_tenant_owner(
     profile: Profile,
     tenant_id: Uuid,
     owner_id: Uuid,
     tenant_updating_repo: Box<&dyn TenantUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<CreateResponseKind<TenantOwnerConnection>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check the user permissions
@@ -31,11 +35,19 @@ pub async fn include_tenant_owner(
     // ? Delete owner
     // ? -----------------------------------------------------------------------
 
-    tenant_updating_repo
+    let response = tenant_updating_repo
         .register_owner(
             tenant_id,
             owner_id,
             format!("account-id:{}", profile.acc_id.to_string()),
         )
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
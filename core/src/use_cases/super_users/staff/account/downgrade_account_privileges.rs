@@ -1,6 +1,9 @@
-use crate::domain::{
-    dtos::{account::Account, account_type::AccountType, profile::Profile},
-    entities::AccountUpdating,
+use crate::{
+    domain::{
+        dtos::{account::Account, account_type::AccountType, profile::Profile},
+        entities::{AccountUpdating, ProfileCaching},
+    },
+    use_cases::support::invalidate_cached_profiles,
 };
 
 use mycelium_base::{
@@ -19,6 +22,7 @@ pub async fn downgrade_account_privileges(
     account_id: Uuid,
     target_account_type: AccountType,
     account_updating_repo: Box<&dyn AccountUpdating>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
 ) -> Result<UpdatingResponseKind<Account>, MappedErrors> {
     // ? -----------------------------------------------------------------------
     // ? Check if the current account has sufficient privileges
@@ -48,7 +52,15 @@ pub async fn downgrade_account_privileges(
     // ? Update and persist account name
     // ? -----------------------------------------------------------------------
 
-    account_updating_repo
+    let response = account_updating_repo
         .update_account_type(account_id, target_account_type)
-        .await
+        .await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Discard the cached profiles
+    // ? -----------------------------------------------------------------------
+
+    invalidate_cached_profiles(None, profile_caching_repo).await;
+
+    Ok(response)
 }
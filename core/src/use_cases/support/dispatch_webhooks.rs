@@ -8,13 +8,24 @@ use crate::{
             },
         },
         entities::WebHookFetching,
+        utils::trace_context_headers,
     },
     models::AccountLifeCycle,
+    settings::WEBHOOK_DISPATCHES,
+    use_cases::gateway::services::fetch_oauth2_token,
 };
 
 use futures_util::future::join_all;
-use mycelium_base::entities::FetchManyResponseKind;
-use reqwest::Client;
+use mycelium_base::{
+    entities::FetchManyResponseKind,
+    utils::errors::{execution_err, MappedErrors},
+};
+use openssl::pkey::PKey;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Certificate, Client, Identity,
+};
+use std::str::FromStr;
 use tracing::error;
 
 #[tracing::instrument(name = "dispatch_webhooks", skip_all)]
@@ -80,19 +91,56 @@ pub(crate) async fn dispatch_webhooks<
     // ? Build requests to the webhooks
     //
     // Request bodies contains the account object as a JSON. It should be parsed
-    // by upstream urls.
+    // by upstream urls. Requests include the W3C trace context of the current
+    // span, connecting the webhook spans to the triggering request trace.
     //
     // ? -----------------------------------------------------------------------
 
     let client = Client::new();
 
+    let trace_headers = trace_context_headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            Some((
+                HeaderName::from_str(name).ok()?,
+                HeaderValue::from_str(value).ok()?,
+            ))
+        })
+        .collect::<HeaderMap>();
+
     let bodies: Vec<_> = hooks
         .iter()
         .map(|hook| async {
             //
-            // Create a base request to the webhook url
+            // Decrypt the hook secret if it exists
             //
-            let base_request = client.clone();
+            let secret = match &hook.get_secret() {
+                Some(secret) => {
+                    match secret.decrypt_me(config.to_owned()).await {
+                        Ok(secret) => Some(secret),
+                        Err(err) => {
+                            panic!("Error on decrypting secret: {:?}", err);
+                        }
+                    }
+                }
+                None => None,
+            };
+            //
+            // Create a base request to the webhook url. Hooks authenticated by
+            // client certificates use a dedicated client.
+            //
+            let base_request = match &secret {
+                Some(HttpSecret::ClientCertificate {
+                    cert_pem,
+                    key_pem,
+                    ca_bundle_pem,
+                }) => build_client_with_certificate(
+                    cert_pem,
+                    key_pem,
+                    ca_bundle_pem.as_deref(),
+                )?,
+                _ => client.clone(),
+            };
             //
             // Build the request based on the method
             //
@@ -108,46 +156,59 @@ pub(crate) async fn dispatch_webhooks<
             //
             // Attach the secret to the request if it exists
             //
-            (match &hook.get_secret() {
-                Some(secret) => {
-                    let decrypted_secret =
-                        match secret.decrypt_me(config.to_owned()).await {
-                            Ok(secret) => secret,
-                            Err(err) => {
-                                panic!("Error on decrypting secret: {:?}", err);
-                            }
+            Ok::<_, MappedErrors>(
+                (match secret {
+                    Some(HttpSecret::AuthorizationHeader {
+                        name,
+                        prefix,
+                        token,
+                    }) => {
+                        let credential_key = name
+                            .to_owned()
+                            .unwrap_or("Authorization".to_string());
+
+                        let credential_value = if let Some(prefix) = prefix {
+                            format!("{} {}", prefix, token)
+                        } else {
+                            token.to_owned()
                         };
 
-                    match decrypted_secret {
-                        HttpSecret::AuthorizationHeader {
-                            name,
-                            prefix,
-                            token,
-                        } => {
-                            let credential_key = name
-                                .to_owned()
-                                .unwrap_or("Authorization".to_string());
-
-                            let credential_value = if let Some(prefix) = prefix
-                            {
-                                format!("{} {}", prefix, token)
-                            } else {
-                                token.to_owned()
-                            };
-
-                            base_request
-                                .header(credential_key, credential_value)
-                        }
-                        HttpSecret::QueryParameter { name, token } => {
-                            base_request
-                                .query(&[(name.to_owned(), token.to_owned())])
-                        }
+                        base_request.header(credential_key, credential_value)
                     }
-                }
-                None => base_request,
-            })
-            .json(&payload_body)
-            .send()
+                    Some(HttpSecret::QueryParameter { name, token }) => {
+                        base_request
+                            .query(&[(name.to_owned(), token.to_owned())])
+                    }
+                    Some(HttpSecret::BasicAuth { username, password }) => {
+                        base_request.basic_auth(username, Some(password))
+                    }
+                    Some(HttpSecret::CustomHeader { name, token }) => {
+                        base_request.header(name, token)
+                    }
+                    Some(HttpSecret::OAuth2ClientCredentials {
+                        token_url,
+                        client_id,
+                        client_secret,
+                        scope,
+                        audience,
+                    }) => base_request.bearer_auth(
+                        fetch_oauth2_token(
+                            &token_url,
+                            &client_id,
+                            &client_secret,
+                            scope.as_deref(),
+                            audience.as_deref(),
+                        )
+                        .await?,
+                    ),
+                    Some(HttpSecret::ClientCertificate { .. }) | None => {
+                        base_request
+                    }
+                })
+                .headers(trace_headers.to_owned())
+                .json(&payload_body)
+                .send(),
+            )
         })
         .collect();
 
@@ -155,16 +216,42 @@ pub(crate) async fn dispatch_webhooks<
     // ? Propagate responses
     //
     // Propagation responses are collected and returned as a response. Users can
-    // check if the propagation was successful.
+    // check if the propagation was successful. The outcome of each dispatch is
+    // counted at the `myc_webhook_dispatches_total` metric.
     //
     // ? -----------------------------------------------------------------------
 
+    let trigger_label = trigger.to_string();
+
+    let count_dispatch = |outcome: &str| {
+        WEBHOOK_DISPATCHES
+            .with_label_values(&[trigger_label.as_str(), outcome])
+            .inc();
+    };
+
     let mut responses = Vec::<HookResponse>::new();
     for hook_res in join_all(bodies).await {
+        let hook_res = match hook_res {
+            Ok(hook_res) => hook_res,
+            Err(err) => {
+                error!("Error on build webhook request: {:?}", err);
+                count_dispatch("build_error");
+
+                responses.push(HookResponse {
+                    url: "".to_string(),
+                    status: 500,
+                    body: Some("Error on build webhook request".to_string()),
+                });
+
+                continue;
+            }
+        };
+
         let hook_res = match hook_res.await {
             Ok(res) => res,
             Err(err) => {
                 error!("Error on connect to webhook: {:?}", err);
+                count_dispatch("connection_error");
 
                 responses.push(HookResponse {
                     url: "".to_string(),
@@ -176,6 +263,11 @@ pub(crate) async fn dispatch_webhooks<
             }
         };
 
+        count_dispatch(match hook_res.status().is_success() {
+            true => "success",
+            false => "rejected",
+        });
+
         let url = hook_res.url();
         let scheme = url.scheme();
         let host = url.host_str().unwrap_or("");
@@ -200,3 +292,65 @@ pub(crate) async fn dispatch_webhooks<
 
     webhook_response
 }
+
+/// Build a client authenticating with the given client certificate
+///
+/// The private key is converted to PKCS#8, then PKCS#1 (`BEGIN RSA PRIVATE
+/// KEY`) and SEC1 (`BEGIN EC PRIVATE KEY`) keys are accepted. The CA bundle
+/// certificates, if present, are trusted in addition to the system ones.
+///
+fn build_client_with_certificate(
+    cert_pem: &str,
+    key_pem: &str,
+    ca_bundle_pem: Option<&str>,
+) -> Result<Client, MappedErrors> {
+    let key_pem = match PKey::private_key_from_pem(key_pem.as_bytes())
+        .and_then(|key| key.private_key_to_pem_pkcs8())
+    {
+        Ok(key_pem) => key_pem,
+        Err(err) => {
+            return execution_err(format!(
+                "Invalid webhook client certificate key: {err}"
+            ))
+            .as_error()
+        }
+    };
+
+    let identity = match Identity::from_pkcs8_pem(cert_pem.as_bytes(), &key_pem)
+    {
+        Ok(identity) => identity,
+        Err(err) => {
+            return execution_err(format!(
+                "Invalid webhook client certificate: {err}"
+            ))
+            .as_error()
+        }
+    };
+
+    let mut builder = Client::builder().identity(identity);
+
+    if let Some(ca_bundle_pem) = ca_bundle_pem {
+        let certificates =
+            match Certificate::from_pem_bundle(ca_bundle_pem.as_bytes()) {
+                Ok(certificates) => certificates,
+                Err(err) => {
+                    return execution_err(format!(
+                        "Invalid webhook CA bundle: {err}"
+                    ))
+                    .as_error()
+                }
+            };
+
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    match builder.build() {
+        Ok(client) => Ok(client),
+        Err(err) => {
+            execution_err(format!("Unable to build webhook client: {err}"))
+                .as_error()
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::{dtos::email::Email, entities::ProfileCaching};
+
+use tracing::warn;
+
+/// Discard the cached profiles affected by a change
+///
+/// Without an email, all cached profiles are discarded. It should be used when
+/// the affected profiles are not known, like changes on accounts and roles
+/// shared by several users.
+///
+/// Failures are logged instead of failing the change. The cached entries then
+/// expire by their time to live.
+#[tracing::instrument(name = "invalidate_cached_profiles", skip_all)]
+pub(crate) async fn invalidate_cached_profiles(
+    email: Option<Email>,
+    profile_caching_repo: Box<&dyn ProfileCaching>,
+) {
+    let response = match email {
+        Some(email) => profile_caching_repo.invalidate_email(email).await,
+        None => profile_caching_repo.invalidate_all().await,
+    };
+
+    if let Err(err) = response {
+        warn!("Unable to invalidate the cached profiles: {err}");
+    }
+}
@@ -1,7 +1,9 @@
 mod dispatch_webhooks;
 mod get_or_create_role_related_account;
+mod invalidate_cached_profiles;
 mod send_email_notification;
 
 pub(crate) use dispatch_webhooks::*;
 pub(crate) use get_or_create_role_related_account::*;
+pub(crate) use invalidate_cached_profiles::*;
 pub(crate) use send_email_notification::*;
@@ -1,11 +1,13 @@
 use crate::{
-    middleware::fetch_profile_from_token, responses::GatewayError,
-    settings::DEFAULT_PROFILE_KEY, Profile,
+    functions::verify_profile,
+    responses::GatewayError,
+    settings::{DEFAULT_PROFILE_KEY, DEFAULT_REQUEST_ID_KEY, PROFILE_JWKS},
+    Profile,
 };
 
 use actix_web::{dev::Payload, FromRequest, HttpRequest};
 use futures::Future;
-use log::warn;
+use log::{error, warn};
 use myc_core::domain::dtos::{
     account::VerboseStatus,
     profile::{LicensedResources, Owner, TenantsOwnership},
@@ -19,6 +21,11 @@ use uuid::Uuid;
 /// A Gateway Profile Data is used to extract the Profile from requests
 /// delivered to the gateway downstream services.
 ///
+/// The profile injected by the gateway is a compact JWS, verified offline
+/// against the keys informed by the `PROFILE_JWKS` environment variable.
+/// Missing, expired and forged profiles are rejected. Without valid keys, all
+/// profiles are rejected.
+///
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GatewayProfileData {
@@ -78,59 +85,64 @@ impl FromRequest for GatewayProfileData {
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
         //
-        // Try to extract profile from header
+        // Only profiles signed by the gateway are accepted
         //
-        match req.headers().get(DEFAULT_PROFILE_KEY) {
-            Some(res) => {
-                let unwrapped_response = match str::from_utf8(res.as_bytes()) {
-                    Ok(res) => res,
-                    Err(err) => {
-                        warn!("Unable to check user identity due: {}", err);
-
-                        return Box::pin(async move {
-                            Err(GatewayError::Unauthorized(
-                                "Unable to check user identity. Please contact administrators".to_string(),
-                            ))
-                        });
-                    }
-                };
+        let profile = match req.headers().get(DEFAULT_PROFILE_KEY) {
+            Some(res) => match str::from_utf8(res.as_bytes()) {
+                Ok(res) => res,
+                Err(err) => {
+                    warn!("Unable to check user identity due: {}", err);
 
-                match serde_json::from_str::<Self>(unwrapped_response) {
-                    Err(error) => {
-                        return Box::pin(async move {
-                            Err(GatewayError::Unauthorized(format!(
-                                "Unable to check user identity due: {error}",
-                            )))
-                        })
-                    }
-                    Ok(res) => return Box::pin(async move { Ok(res) }),
+                    return Box::pin(async move {
+                        Err(GatewayError::Unauthorized(
+                            "Unable to check user identity. Please contact administrators".to_string(),
+                        ))
+                    });
                 }
+            },
+            None => {
+                return Box::pin(async move {
+                    Err(GatewayError::Unauthorized(
+                        "Unable to check user identity: missing profile"
+                            .to_string(),
+                    ))
+                })
             }
-            None => (),
         };
-        //
-        // Try to extract authorization token from header and try to get profile
-        // from profile service.
-        //
-        match req.headers().get("Authorization") {
-            Some(res) => {
-                let token = res.to_str().unwrap().to_string();
+
+        let jwks = match PROFILE_JWKS.as_ref() {
+            Ok(jwks) => jwks,
+            Err(err) => {
+                error!("Unable to verify the profile: {err}");
 
                 return Box::pin(async move {
-                    fetch_profile_from_token(token).await
+                    Err(GatewayError::InternalServerError(
+                        "Unable to check user identity: the profile verification keys are not configured".to_string(),
+                    ))
                 });
             }
-            None => (),
         };
-        //
-        // Return a default forbidden response if the user identity could not be
-        // checked.
-        //
-        Box::pin(async move {
-            Err(GatewayError::Unauthorized(
-                "Unable to check user identity. Please contact administrators"
-                    .to_string(),
-            ))
-        })
+
+        let request_id = req
+            .headers()
+            .get(DEFAULT_REQUEST_ID_KEY)
+            .and_then(|value| value.to_str().ok());
+
+        let result = match verify_profile(profile, jwks, request_id) {
+            Err(err) => Err(err),
+            Ok(claims) => match claims.payload.decode() {
+                Err(err) => {
+                    warn!("Unable to decode the profile: {err}");
+
+                    Err(GatewayError::Unauthorized(
+                        "Unable to check user identity: invalid profile"
+                            .to_string(),
+                    ))
+                }
+                Ok(profile) => Ok(Self::from_profile(profile)),
+            },
+        };
+
+        Box::pin(async move { result })
     }
 }
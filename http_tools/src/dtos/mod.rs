@@ -1,2 +1,3 @@
 pub mod claims;
 pub mod gateway_profile_data;
+pub mod profile_claims;
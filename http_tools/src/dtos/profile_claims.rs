@@ -0,0 +1,151 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use myc_core::domain::dtos::{
+    profile::Profile, profile_projection::ProfileEncoding,
+};
+use mycelium_base::utils::errors::{dto_err, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// The size limit of the decompressed profiles in bytes
+///
+/// Protects downstream services from profiles expanding to unbounded sizes.
+///
+const MAX_DECOMPRESSED_PROFILE_SIZE: u64 = 1024 * 1024;
+
+/// The profile included in the signed profile claims
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProfilePayload {
+    /// The profile as JSON
+    Profile(Profile),
+
+    /// The profile JSON compressed with gzip and encoded as base64url
+    CompressedProfile(String),
+}
+
+impl ProfilePayload {
+    /// Build the payload of a profile with the route encoding
+    pub fn encode(
+        profile: Profile,
+        encoding: ProfileEncoding,
+    ) -> Result<Self, MappedErrors> {
+        match encoding {
+            ProfileEncoding::Json => Ok(Self::Profile(profile)),
+            ProfileEncoding::Gzip => {
+                let json = match serde_json::to_vec(&profile) {
+                    Ok(json) => json,
+                    Err(err) => {
+                        return dto_err(format!(
+                            "Unable to serialize the profile: {err}"
+                        ))
+                        .as_error()
+                    }
+                };
+
+                let mut encoder =
+                    GzEncoder::new(Vec::new(), Compression::default());
+
+                match encoder.write_all(&json).and_then(|_| encoder.finish()) {
+                    Ok(compressed) => Ok(Self::CompressedProfile(
+                        URL_SAFE_NO_PAD.encode(compressed),
+                    )),
+                    Err(err) => dto_err(format!(
+                        "Unable to compress the profile: {err}"
+                    ))
+                    .as_error(),
+                }
+            }
+        }
+    }
+
+    /// Get the profile, decompressing it if needed
+    pub fn decode(&self) -> Result<Profile, MappedErrors> {
+        let compressed = match self {
+            Self::Profile(profile) => return Ok(profile.to_owned()),
+            Self::CompressedProfile(compressed) => compressed,
+        };
+
+        let compressed = match URL_SAFE_NO_PAD.decode(compressed) {
+            Ok(compressed) => compressed,
+            Err(err) => {
+                return dto_err(format!("Invalid compressed profile: {err}"))
+                    .as_error()
+            }
+        };
+
+        let mut json = Vec::new();
+
+        if let Err(err) = GzDecoder::new(compressed.as_slice())
+            .take(MAX_DECOMPRESSED_PROFILE_SIZE)
+            .read_to_end(&mut json)
+        {
+            return dto_err(format!("Unable to decompress the profile: {err}"))
+                .as_error();
+        }
+
+        match serde_json::from_slice::<Profile>(&json) {
+            Ok(profile) => Ok(profile),
+            Err(err) => {
+                dto_err(format!("Invalid compressed profile: {err}")).as_error()
+            }
+        }
+    }
+}
+
+/// The claims of a signed profile
+///
+/// The subject is the account id. The request id binds the profile to the
+/// request forwarded by the gateway. The profile is included as JSON or
+/// compressed, as declared by the route.
+///
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileClaims {
+    pub iss: String,
+    pub sub: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub request_id: Option<String>,
+
+    #[serde(flatten)]
+    pub payload: ProfilePayload,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_compressed_profiles_are_decoded() {
+        let profile = Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            None,
+            None,
+            None,
+        );
+
+        let payload =
+            ProfilePayload::encode(profile.to_owned(), ProfileEncoding::Gzip)
+                .unwrap();
+
+        assert!(matches!(payload, ProfilePayload::CompressedProfile(_)));
+        assert_eq!(payload.decode().unwrap(), profile);
+        assert!(ProfilePayload::CompressedProfile("invalid".to_string())
+            .decode()
+            .is_err());
+    }
+}
@@ -1,5 +1,11 @@
 mod decode_jwt;
 mod encode_jwt;
+mod parse_bearer;
+mod sign_profile;
+mod verify_profile;
 
 pub use decode_jwt::*;
 pub use encode_jwt::*;
+pub use parse_bearer::*;
+pub use sign_profile::*;
+pub use verify_profile::*;
@@ -0,0 +1,22 @@
+use actix_web::{
+    error::ParseError,
+    http::header::{HeaderMap, AUTHORIZATION},
+};
+use actix_web_httpauth::headers::authorization::{
+    Authorization, Bearer, Scheme,
+};
+
+/// Parse the bearer token from the request headers
+///
+/// Works as `Authorization::<Bearer>::parse`, without depending on the actix
+/// request. Then, requests received out of the actix server (like the gRPC
+/// ones) are authenticated by the same rules.
+///
+pub fn parse_bearer_from_headers(
+    headers: &HeaderMap,
+) -> Result<Authorization<Bearer>, ParseError> {
+    let header = headers.get(AUTHORIZATION).ok_or(ParseError::Header)?;
+    let scheme = Bearer::parse(header).map_err(|_| ParseError::Header)?;
+
+    Ok(Authorization::from(scheme))
+}
@@ -0,0 +1,196 @@
+use crate::{
+    dtos::profile_claims::{ProfileClaims, ProfilePayload},
+    models::profile_signing_config::ProfileSigningConfig,
+    settings::PROFILE_ISSUER,
+};
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{
+    encode,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+    },
+    Algorithm, EncodingKey, Header,
+};
+use myc_core::domain::dtos::{
+    profile::Profile, profile_projection::ProfileEncoding,
+};
+use mycelium_base::utils::errors::{creation_err, MappedErrors};
+use ring::signature::{Ed25519KeyPair, KeyPair};
+
+/// The default time to live of the signed profiles in seconds
+const DEFAULT_PROFILE_TTL_IN_SECS: i64 = 60;
+
+/// A profile signing key
+///
+/// Keys are Ed25519 key pairs. The public key is published at the JWKS
+/// endpoint, identified by the key id.
+///
+#[derive(Clone)]
+pub struct ProfileSigningKey {
+    id: String,
+    encoding_key: EncodingKey,
+    public_key: Vec<u8>,
+}
+
+impl ProfileSigningKey {
+    /// Load a key from a PKCS#8 DER document
+    pub fn from_der(id: String, der: &[u8]) -> Result<Self, MappedErrors> {
+        let key_pair = match Ed25519KeyPair::from_pkcs8_maybe_unchecked(der) {
+            Ok(key_pair) => key_pair,
+            Err(err) => {
+                return creation_err(format!(
+                    "Invalid Ed25519 private key of the profile signing key {id}: {err}"
+                ))
+                .as_error()
+            }
+        };
+
+        Ok(Self {
+            encoding_key: EncodingKey::from_ed_der(der),
+            public_key: key_pair.public_key().as_ref().to_vec(),
+            id,
+        })
+    }
+
+    /// Load a key from a PKCS#8 PEM document
+    pub fn from_pem(id: String, pem: &str) -> Result<Self, MappedErrors> {
+        let body = pem
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.starts_with("-----"))
+            .collect::<String>();
+
+        match STANDARD.decode(body) {
+            Ok(der) => Self::from_der(id, &der),
+            Err(err) => creation_err(format!(
+                "Invalid PEM of the profile signing key {id}: {err}"
+            ))
+            .as_error(),
+        }
+    }
+
+    /// The public key as JWK
+    pub fn to_jwk(&self) -> Jwk {
+        Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                algorithm: Some(Algorithm::EdDSA),
+                key_id: Some(self.id.to_owned()),
+                ..Default::default()
+            },
+            algorithm: AlgorithmParameters::OctetKeyPair(
+                OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(&self.public_key),
+                },
+            ),
+        }
+    }
+}
+
+/// The profile signer
+///
+/// Profiles are signed by the first key. All keys are published at the JWKS
+/// endpoint.
+///
+#[derive(Clone)]
+pub struct ProfileSigner {
+    keys: Vec<ProfileSigningKey>,
+    ttl: Duration,
+}
+
+impl ProfileSigner {
+    pub fn new(
+        keys: Vec<ProfileSigningKey>,
+        ttl_in_secs: i64,
+    ) -> Result<Self, MappedErrors> {
+        if keys.is_empty() {
+            return creation_err(
+                "At least one profile signing key is required",
+            )
+            .as_error();
+        }
+
+        if ttl_in_secs <= 0 {
+            return creation_err(
+                "The time to live of the signed profiles should be positive",
+            )
+            .as_error();
+        }
+
+        Ok(Self {
+            keys,
+            ttl: Duration::seconds(ttl_in_secs),
+        })
+    }
+
+    /// Build the signer from the profile signing settings
+    ///
+    /// Private keys are resolved from the configured secrets.
+    ///
+    pub async fn from_config(
+        config: ProfileSigningConfig,
+    ) -> Result<Self, MappedErrors> {
+        let mut keys = vec![];
+
+        for key in config.keys {
+            let pem = key.private_key.async_get_or_error().await?;
+
+            keys.push(ProfileSigningKey::from_pem(key.id, &pem)?);
+        }
+
+        Self::new(
+            keys,
+            config.ttl_in_secs.unwrap_or(DEFAULT_PROFILE_TTL_IN_SECS),
+        )
+    }
+
+    /// Sign a profile as a compact JWS
+    ///
+    /// The profile is included as JSON or compressed, following the informed
+    /// encoding.
+    ///
+    pub fn sign(
+        &self,
+        profile: Profile,
+        request_id: Option<String>,
+        encoding: ProfileEncoding,
+    ) -> Result<String, MappedErrors> {
+        let key = &self.keys[0];
+        let issued_at = Utc::now();
+
+        let claims = ProfileClaims {
+            iss: PROFILE_ISSUER.to_string(),
+            sub: profile.acc_id.to_string(),
+            iat: issued_at.timestamp(),
+            exp: (issued_at + self.ttl).timestamp(),
+            request_id,
+            payload: ProfilePayload::encode(profile, encoding)?,
+        };
+
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(key.id.to_owned());
+
+        match encode(&header, &claims, &key.encoding_key) {
+            Ok(token) => Ok(token),
+            Err(err) => {
+                creation_err(format!("Unable to sign the profile: {err}"))
+                    .as_error()
+            }
+        }
+    }
+
+    /// The public keys as JWKS
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self.keys.iter().map(|key| key.to_jwk()).collect(),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    dtos::profile_claims::ProfileClaims, responses::GatewayError,
+    settings::PROFILE_ISSUER,
+};
+
+use jsonwebtoken::{
+    decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation,
+};
+use log::warn;
+
+/// The clock skew tolerated when checking the expiration of the profiles
+const PROFILE_LEEWAY_IN_SECS: u64 = 5;
+
+/// Verify a profile signed by the gateway
+///
+/// The signature is checked offline against the published keys, identified by
+/// the `kid` of the token header. Profiles bound to a request id are only
+/// accepted with the same request id, preventing profiles from being replayed
+/// in other requests, including requests without the request id.
+///
+pub fn verify_profile(
+    token: &str,
+    jwks: &JwkSet,
+    request_id: Option<&str>,
+) -> Result<ProfileClaims, GatewayError> {
+    let unauthorized = |reason: &str| {
+        GatewayError::Unauthorized(format!(
+            "Unable to check user identity: {reason}"
+        ))
+    };
+
+    let key_id = match decode_header(token) {
+        Ok(header) => match header.kid {
+            Some(key_id) => key_id,
+            None => return Err(unauthorized("missing key id")),
+        },
+        Err(err) => {
+            warn!("Invalid profile header: {err}");
+            return Err(unauthorized("invalid profile"));
+        }
+    };
+
+    let decoding_key = match jwks.find(&key_id).map(DecodingKey::from_jwk) {
+        Some(Ok(key)) => key,
+        Some(Err(err)) => {
+            warn!("Invalid profile verification key {key_id}: {err}");
+            return Err(unauthorized("invalid key"));
+        }
+        None => return Err(unauthorized("unknown key")),
+    };
+
+    let mut validation = Validation::new(Algorithm::EdDSA);
+    validation.leeway = PROFILE_LEEWAY_IN_SECS;
+    validation.set_issuer(&[PROFILE_ISSUER]);
+
+    let claims =
+        match decode::<ProfileClaims>(token, &decoding_key, &validation) {
+            Ok(token_data) => token_data.claims,
+            Err(err) => {
+                warn!("Unable to verify the profile: {err}");
+                return Err(unauthorized("invalid or expired profile"));
+            }
+        };
+
+    match (claims.request_id.as_deref(), request_id) {
+        (Some(_), None) => {
+            return Err(unauthorized("missing request id"));
+        }
+        (bound_id, Some(request_id)) if bound_id != Some(request_id) => {
+            return Err(unauthorized("profile issued to another request"));
+        }
+        _ => (),
+    }
+
+    Ok(claims)
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dtos::profile_claims::ProfilePayload,
+        functions::{ProfileSigner, ProfileSigningKey},
+    };
+
+    use chrono::Utc;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use myc_core::domain::dtos::{
+        profile::Profile, profile_projection::ProfileEncoding,
+    };
+    use ring::{rand::SystemRandom, signature::Ed25519KeyPair};
+    use uuid::Uuid;
+
+    fn generate_der() -> Vec<u8> {
+        Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .unwrap()
+            .as_ref()
+            .to_vec()
+    }
+
+    fn build_signer(id: &str, der: &[u8]) -> ProfileSigner {
+        ProfileSigner::new(
+            vec![ProfileSigningKey::from_der(id.to_string(), der).unwrap()],
+            60,
+        )
+        .unwrap()
+    }
+
+    fn build_profile() -> Profile {
+        Profile::new(
+            vec![],
+            Uuid::new_v4(),
+            false,
+            false,
+            false,
+            true,
+            true,
+            true,
+            false,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_verify_profile_accepts_signed_profiles() {
+        let signer = build_signer("key-1", &generate_der());
+        let profile = build_profile();
+
+        for encoding in [ProfileEncoding::Json, ProfileEncoding::Gzip] {
+            let token = signer
+                .sign(
+                    profile.to_owned(),
+                    Some("request-1".to_string()),
+                    encoding,
+                )
+                .unwrap();
+
+            let claims =
+                verify_profile(&token, &signer.jwks(), Some("request-1"))
+                    .unwrap();
+
+            assert_eq!(claims.payload.decode().unwrap(), profile);
+            assert_eq!(claims.sub, profile.acc_id.to_string());
+        }
+    }
+
+    #[test]
+    fn test_verify_profile_rejects_forged_profiles() {
+        let signer = build_signer("key-1", &generate_der());
+        let forger = build_signer("key-1", &generate_der());
+
+        let token = forger
+            .sign(build_profile(), None, ProfileEncoding::Json)
+            .unwrap();
+
+        assert!(verify_profile(&token, &signer.jwks(), None).is_err());
+        assert!(verify_profile("not-a-token", &signer.jwks(), None).is_err());
+    }
+
+    #[test]
+    fn test_verify_profile_rejects_other_requests_profiles() {
+        let signer = build_signer("key-1", &generate_der());
+
+        let token = signer
+            .sign(
+                build_profile(),
+                Some("request-1".to_string()),
+                ProfileEncoding::Json,
+            )
+            .unwrap();
+
+        assert!(
+            verify_profile(&token, &signer.jwks(), Some("request-2")).is_err()
+        );
+        assert!(verify_profile(&token, &signer.jwks(), None).is_err());
+    }
+
+    #[test]
+    fn test_verify_profile_rejects_expired_profiles() {
+        let der = generate_der();
+        let signer = build_signer("key-1", &der);
+        let profile = build_profile();
+        let issued_at = Utc::now().timestamp() - 120;
+
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some("key-1".to_string());
+
+        let token = encode(
+            &header,
+            &ProfileClaims {
+                iss: PROFILE_ISSUER.to_string(),
+                sub: profile.acc_id.to_string(),
+                iat: issued_at,
+                exp: issued_at + 60,
+                request_id: None,
+                payload: ProfilePayload::Profile(profile),
+            },
+            &EncodingKey::from_ed_der(&der),
+        )
+        .unwrap();
+
+        assert!(verify_profile(&token, &signer.jwks(), None).is_err());
+    }
+}
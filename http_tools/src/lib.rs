@@ -1,6 +1,5 @@
 pub mod dtos;
 pub mod functions;
-pub mod middleware;
 pub mod models;
 pub mod providers;
 pub mod responses;
@@ -1,3 +0,0 @@
-mod fetch_profile_from_header;
-
-pub(crate) use fetch_profile_from_header::*;
@@ -1,2 +1,3 @@
 pub mod auth_config;
 pub mod internal_auth_config;
+pub mod profile_signing_config;
@@ -0,0 +1,41 @@
+use myc_config::secret_resolver::SecretResolver;
+use serde::{Deserialize, Serialize};
+
+/// A profile signing key
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSigningKeyConfig {
+    /// The key id
+    ///
+    /// The id is published as the `kid` of the key and included in the header
+    /// of the signed profiles.
+    ///
+    pub id: String,
+
+    /// The Ed25519 private key in the PKCS#8 PEM format
+    pub private_key: SecretResolver<String>,
+}
+
+/// The profile signing settings
+///
+/// Profiles injected into the downstream requests are signed as compact JWS
+/// tokens, allowing downstream services to verify them with the published
+/// keys.
+///
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSigningConfig {
+    /// The signing keys
+    ///
+    /// Profiles are signed by the first key. All keys are published, allowing
+    /// downstream services to verify the profiles signed before a key
+    /// rotation.
+    ///
+    pub keys: Vec<ProfileSigningKeyConfig>,
+
+    /// The time to live of the signed profiles in seconds
+    ///
+    /// Default to 60 seconds.
+    ///
+    pub ttl_in_secs: Option<i64>,
+}
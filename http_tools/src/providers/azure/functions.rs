@@ -1,7 +1,9 @@
 use super::{config::AzureOauthConfig, models::MsGraphDecode};
-use crate::providers::shared::check_token_online;
+use crate::{
+    functions::parse_bearer_from_headers, providers::shared::check_token_online,
+};
 
-use actix_web::{http::header::Header, HttpRequest};
+use actix_web::{http::header::HeaderMap, HttpRequest};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
 use myc_core::domain::dtos::email::Email;
 use mycelium_base::utils::errors::{execution_err, MappedErrors};
@@ -16,7 +18,16 @@ use oauth2::{
 pub async fn check_credentials(
     req: HttpRequest,
 ) -> Result<Email, MappedErrors> {
-    let auth = match Authorization::<Bearer>::parse(&req) {
+    check_credentials_from_headers(req.headers()).await
+}
+
+/// Try to collect the user email from the request headers.
+///
+/// Used by the requests received out of the actix server.
+pub async fn check_credentials_from_headers(
+    headers: &HeaderMap,
+) -> Result<Email, MappedErrors> {
+    let auth = match parse_bearer_from_headers(headers) {
         Err(err) => {
             return execution_err(format!("Invalid client request: {err}"))
                 .as_error();
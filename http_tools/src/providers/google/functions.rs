@@ -3,11 +3,12 @@ use super::{
     models::{GoogleDecode, GoogleUserResult, OAuthResponse},
 };
 use crate::{
-    models::auth_config::AuthConfig, providers::shared::check_token_online,
+    functions::parse_bearer_from_headers, models::auth_config::AuthConfig,
+    providers::shared::check_token_online,
 };
 
 use actix_web::web;
-use actix_web::{http::header::Header, HttpRequest};
+use actix_web::{http::header::HeaderMap, HttpRequest};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
 use jsonwebtoken::{decode, DecodingKey, Validation};
 use log::debug;
@@ -25,7 +26,17 @@ pub async fn check_credentials(
     req: HttpRequest,
     config: GoogleOauthConfig,
 ) -> Result<Email, MappedErrors> {
-    let auth = match Authorization::<Bearer>::parse(&req) {
+    check_credentials_from_headers(req.headers(), config).await
+}
+
+/// Try to collect the user email from the request headers.
+///
+/// Used by the requests received out of the actix server.
+pub async fn check_credentials_from_headers(
+    headers: &HeaderMap,
+    config: GoogleOauthConfig,
+) -> Result<Email, MappedErrors> {
+    let auth = match parse_bearer_from_headers(headers) {
         Err(err) => {
             return execution_err(format!("Invalid client request: {err}"))
                 .as_error();
@@ -3,12 +3,18 @@ mod shared;
 mod azure;
 pub use azure::{
     config as azure_config, endpoints as azure_endpoints,
-    functions::check_credentials as az_check_credentials,
+    functions::{
+        check_credentials as az_check_credentials,
+        check_credentials_from_headers as az_check_credentials_from_headers,
+    },
 };
 
 mod google;
 pub use google::{
     config as google_config, endpoints as google_endpoints,
-    functions::check_credentials as gc_check_credentials,
+    functions::{
+        check_credentials as gc_check_credentials,
+        check_credentials_from_headers as gc_check_credentials_from_headers,
+    },
     models as google_models,
 };
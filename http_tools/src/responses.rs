@@ -1,6 +1,11 @@
+use crate::settings::{GRPC_CONTENT_TYPE, GRPC_MESSAGE_KEY, GRPC_STATUS_KEY};
+
 use actix_web::{
     error,
-    http::{header::ContentType, StatusCode},
+    http::{
+        header::{ContentType, HeaderMap, CONTENT_TYPE},
+        StatusCode,
+    },
     HttpResponse,
 };
 use derive_more::Display;
@@ -37,11 +42,90 @@ pub enum GatewayError {
     #[display(fmt = "MethodNotAllowed")]
     MethodNotAllowed(String),
 
+    #[display(fmt = "TooManyRequests")]
+    TooManyRequests(String),
+
     // ? -----------------------------------------------------------------------
     // ? Server errors (5xx)
     // ? -----------------------------------------------------------------------
     #[display(fmt = "InternalServerError")]
     InternalServerError(String),
+
+    #[display(fmt = "BadGateway")]
+    BadGateway(String),
+
+    #[display(fmt = "ServiceUnavailable")]
+    ServiceUnavailable(String),
+
+    #[display(fmt = "GatewayTimeout")]
+    GatewayTimeout(String),
+}
+
+impl GatewayError {
+    /// The error message
+    pub fn message(&self) -> &str {
+        match self {
+            GatewayError::BadRequest(msg) => msg,
+            GatewayError::Forbidden(msg) => msg,
+            GatewayError::Unauthorized(msg) => msg,
+            GatewayError::MethodNotAllowed(msg) => msg,
+            GatewayError::TooManyRequests(msg) => msg,
+            GatewayError::InternalServerError(msg) => msg,
+            GatewayError::BadGateway(msg) => msg,
+            GatewayError::ServiceUnavailable(msg) => msg,
+            GatewayError::GatewayTimeout(msg) => msg,
+        }
+    }
+
+    /// The gRPC status code equivalent to the error
+    ///
+    /// See the mapping between HTTP and gRPC status codes at
+    /// https://github.com/grpc/grpc/blob/master/doc/statuscodes.md
+    ///
+    pub fn grpc_status(&self) -> u16 {
+        match self {
+            GatewayError::BadRequest(_) => 3,
+            GatewayError::GatewayTimeout(_) => 4,
+            GatewayError::Forbidden(_) => 7,
+            GatewayError::TooManyRequests(_) => 8,
+            GatewayError::MethodNotAllowed(_) => 12,
+            GatewayError::InternalServerError(_) => 13,
+            GatewayError::BadGateway(_) => 14,
+            GatewayError::ServiceUnavailable(_) => 14,
+            GatewayError::Unauthorized(_) => 16,
+        }
+    }
+
+    /// The error message as a `grpc-message` value
+    pub fn grpc_message(&self) -> String {
+        encode_grpc_message(self.message())
+    }
+
+    /// Build the error response expected by gRPC clients
+    ///
+    /// gRPC errors are sent as trailers-only responses: the HTTP status is
+    /// always 200 and the status is informed by the `grpc-status` and
+    /// `grpc-message` headers.
+    ///
+    pub fn grpc_error_response(&self) -> HttpResponse {
+        HttpResponse::Ok()
+            .insert_header((CONTENT_TYPE, GRPC_CONTENT_TYPE))
+            .insert_header((GRPC_STATUS_KEY, self.grpc_status().to_string()))
+            .insert_header((GRPC_MESSAGE_KEY, self.grpc_message()))
+            .finish()
+    }
+
+    /// Build the error response in the format expected by the client
+    ///
+    /// Requests sent by gRPC clients receive gRPC errors. Other requests
+    /// receive the default JSON errors.
+    ///
+    pub fn response_for(&self, headers: &HeaderMap) -> HttpResponse {
+        match is_grpc_request(headers) {
+            true => self.grpc_error_response(),
+            false => error::ResponseError::error_response(self),
+        }
+    }
 }
 
 impl error::ResponseError for GatewayError {
@@ -51,13 +135,7 @@ impl error::ResponseError for GatewayError {
             .json(HttpJsonResponse {
                 msg: self.to_string(),
                 status: self.status_code().as_u16(),
-                message: match self {
-                    GatewayError::BadRequest(msg) => msg.to_owned(),
-                    GatewayError::Forbidden(msg) => msg.to_owned(),
-                    GatewayError::Unauthorized(msg) => msg.to_owned(),
-                    GatewayError::MethodNotAllowed(msg) => msg.to_owned(),
-                    GatewayError::InternalServerError(msg) => msg.to_owned(),
-                },
+                message: self.message().to_owned(),
             })
     }
 
@@ -69,9 +147,102 @@ impl error::ResponseError for GatewayError {
             GatewayError::MethodNotAllowed { .. } => {
                 StatusCode::METHOD_NOT_ALLOWED
             }
+            GatewayError::TooManyRequests { .. } => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
             GatewayError::InternalServerError { .. } => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
+            GatewayError::BadGateway { .. } => StatusCode::BAD_GATEWAY,
+            GatewayError::ServiceUnavailable { .. } => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            GatewayError::GatewayTimeout { .. } => StatusCode::GATEWAY_TIMEOUT,
         }
     }
 }
+
+/// Check if the request was sent by a gRPC client
+///
+/// gRPC requests are identified by the `application/grpc` content type,
+/// including its subtypes like `application/grpc+proto`.
+///
+pub fn is_grpc_request(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(is_grpc_content_type)
+}
+
+/// Check if a content type is the gRPC one
+///
+/// The `application/grpc-web` content types are not accepted, since gRPC-Web
+/// clients do not speak the gRPC over HTTP/2 protocol.
+///
+pub fn is_grpc_content_type(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+
+    content_type == GRPC_CONTENT_TYPE
+        || content_type.starts_with(&format!("{GRPC_CONTENT_TYPE}+"))
+        || content_type.starts_with(&format!("{GRPC_CONTENT_TYPE};"))
+}
+
+/// Percent-encode a `grpc-message` value
+///
+/// Bytes outside the printable ASCII range and the percent sign itself should
+/// be encoded, as defined by the gRPC over HTTP/2 protocol.
+///
+fn encode_grpc_message(message: &str) -> String {
+    message
+        .bytes()
+        .map(|byte| match byte {
+            b' '..=b'~' if byte != b'%' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+// ? ---------------------------------------------------------------------------
+// ? TESTS
+// ? ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::header::HeaderValue;
+
+    #[test]
+    fn test_grpc_error_response_works() {
+        let response =
+            GatewayError::Unauthorized(String::from("Invalid token: 100%"))
+                .grpc_error_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(GRPC_STATUS_KEY).unwrap(), "16");
+        assert_eq!(
+            response.headers().get(GRPC_MESSAGE_KEY).unwrap(),
+            "Invalid token: 100%25"
+        );
+    }
+
+    #[test]
+    fn test_is_grpc_request_works() {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/grpc+proto"),
+        );
+        assert!(is_grpc_request(&headers));
+
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/grpc-web"),
+        );
+        assert!(!is_grpc_request(&headers));
+
+        headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        assert!(!is_grpc_request(&headers));
+    }
+}
@@ -1,3 +1,4 @@
+use jsonwebtoken::jwk::JwkSet;
 use lazy_static::lazy_static;
 use reqwest::Client;
 use std::env::var_os;
@@ -13,6 +14,13 @@ use std::env::var_os;
 ///
 pub const DEFAULT_PROFILE_KEY: &str = "x-mycelium-profile";
 
+/// Profile issuer
+///
+/// This is the issuer of the profiles signed by the gateway and injected into
+/// the downstream requests.
+///
+pub const PROFILE_ISSUER: &str = "mycelium";
+
 /// Default scope key
 ///
 /// The scope key should be used to inject the scope present on the connection
@@ -56,6 +64,74 @@ pub const DEFAULT_TENANT_ID_KEY: &str = "x-mycelium-tenant-id";
 ///
 pub const FORWARD_FOR_KEY: &str = "x-forwarded-for";
 
+/// Forwarded protocol header key
+///
+/// This is the key used to inform the gateway downstream services about the
+/// protocol used by the client.
+///
+pub const FORWARDED_PROTO_KEY: &str = "x-forwarded-proto";
+
+/// Forwarded host header key
+///
+/// This is the key used to inform the gateway downstream services about the
+/// host requested by the client.
+///
+pub const FORWARDED_HOST_KEY: &str = "x-forwarded-host";
+
+/// Standard forwarded header key
+///
+/// This is the RFC 7239 key used to inform the gateway downstream services
+/// about the full forwarding chain of the request.
+///
+pub const FORWARDED_KEY: &str = "forwarded";
+
+/// gRPC content type
+///
+/// This is the content type used by gRPC clients, then used to identify the
+/// requests expecting gRPC errors.
+///
+pub const GRPC_CONTENT_TYPE: &str = "application/grpc";
+
+/// gRPC status header key
+///
+/// This is the key used to inform the gRPC status code of the responses.
+///
+pub const GRPC_STATUS_KEY: &str = "grpc-status";
+
+/// gRPC message header key
+///
+/// This is the key used to inform the gRPC error message of the responses.
+///
+pub const GRPC_MESSAGE_KEY: &str = "grpc-message";
+
+/// Rate limit limit key
+///
+/// This is the key used to inform the client about the number of requests
+/// accepted by window of the route rate limit policy.
+///
+pub const RATE_LIMIT_LIMIT_KEY: &str = "ratelimit-limit";
+
+/// Rate limit remaining key
+///
+/// This is the key used to inform the client about the number of requests
+/// still accepted in the current window.
+///
+pub const RATE_LIMIT_REMAINING_KEY: &str = "ratelimit-remaining";
+
+/// Rate limit reset key
+///
+/// This is the key used to inform the client about the number of seconds
+/// until the quota is fully restored.
+///
+pub const RATE_LIMIT_RESET_KEY: &str = "ratelimit-reset";
+
+/// Rate limit policy key
+///
+/// This is the key used to inform the client about the route rate limit policy,
+/// as `<limit>;w=<window in seconds>`.
+///
+pub const RATE_LIMIT_POLICY_KEY: &str = "ratelimit-policy";
+
 /// Default forwarding keys
 ///
 /// Such keys are used to map the headers that should be removed from the
@@ -88,10 +164,19 @@ pub(super) async fn get_client() -> Client {
 
 lazy_static! {
 
+    /// The keys used to verify the profiles injected by the gateway
+    ///
+    /// The value should be the JWKS document published by the gateway at the
+    /// `/.well-known/jwks.json` path. Missing and invalid documents are kept
+    /// as errors, then reported while extracting the profiles.
+    ///
     #[derive(Debug)]
-    pub(crate) static ref PROFILE_FETCHING_URL: String =
-        match var_os("PROFILE_FETCHING_URL") {
-            Some(path) => path.into_string().unwrap(),
-            None => panic!("PROFILE_FETCHING_URL not configured."),
+    pub(crate) static ref PROFILE_JWKS: Result<JwkSet, String> =
+        match var_os("PROFILE_JWKS").map(|jwks| jwks.into_string()) {
+            None => Err("PROFILE_JWKS not configured".to_string()),
+            Some(Err(_)) => Err("PROFILE_JWKS is not valid unicode".to_string()),
+            Some(Ok(jwks)) => serde_json::from_str(&jwks).map_err(|err| {
+                format!("PROFILE_JWKS is not a valid JWKS: {err}")
+            }),
         };
 }
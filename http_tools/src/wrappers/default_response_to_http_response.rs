@@ -192,6 +192,8 @@ pub fn handle_mapped_error(err: MappedErrors) -> HttpResponse {
         (MYC00021, HttpResponse::BadRequest()),
         (MYC00022, HttpResponse::BadRequest()),
         (MYC00023, HttpResponse::BadRequest()),
+        (MYC00024, HttpResponse::BadRequest()),
+        (MYC00025, HttpResponse::BadRequest()),
     ];
 
     for (code, mut response) in error_maps {
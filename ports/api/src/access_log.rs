@@ -0,0 +1,339 @@
+use crate::{
+    metrics::RouteMetricLabels,
+    models::api_config::{AccessLogConfig, AccessLogFormat, AccessLogTarget},
+};
+
+use actix_web::{
+    body::{BodySize, MessageBody},
+    http::header::CONTENT_LENGTH,
+    web, HttpMessage, HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Local};
+use hyper::http::request::Parts;
+use myc_core::domain::dtos::route_type::RouteType;
+use myc_http_tools::settings::DEFAULT_REQUEST_ID_KEY;
+use rand::Rng;
+use serde::Serialize;
+use std::{io::Write, net::IpAddr, path::PathBuf, time::Duration};
+use tracing::warn;
+use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
+use uuid::Uuid;
+
+/// The request details recorded at the access log
+///
+/// Details are collected while the request is handled and stored at the
+/// request extensions, then written once the response is built.
+///
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AccessLogDetails {
+    client_ip: Option<IpAddr>,
+    route_type: Option<&'static str>,
+    account_id: Option<Uuid>,
+    upstream_latency: Option<Duration>,
+}
+
+impl AccessLogDetails {
+    /// Update the details of a request
+    fn update(req: &HttpRequest, update: impl FnOnce(&mut Self)) {
+        let mut extensions = req.extensions_mut();
+
+        match extensions.get_mut::<Self>() {
+            Some(details) => update(details),
+            None => {
+                let mut details = Self::default();
+                update(&mut details);
+                extensions.insert(details);
+            }
+        }
+    }
+
+    pub(crate) fn record_route_type(req: &HttpRequest, route_type: &RouteType) {
+        Self::update(req, |details| details.set_route_type(route_type));
+    }
+
+    pub(crate) fn record_client_ip(req: &HttpRequest, client_ip: IpAddr) {
+        Self::update(req, |details| details.set_client_ip(client_ip));
+    }
+
+    pub(crate) fn record_account_id(req: &HttpRequest, account_id: Uuid) {
+        Self::update(req, |details| details.set_account_id(account_id));
+    }
+
+    pub(crate) fn record_upstream_latency(
+        req: &HttpRequest,
+        latency: Duration,
+    ) {
+        Self::update(req, |details| details.set_upstream_latency(latency));
+    }
+
+    //
+    // Setters used by the requests handled out of the actix server, like the
+    // gRPC calls, which keep the details by themselves.
+    //
+
+    pub(crate) fn set_route_type(&mut self, route_type: &RouteType) {
+        self.route_type = Some(route_type.name());
+    }
+
+    pub(crate) fn set_client_ip(&mut self, client_ip: IpAddr) {
+        self.client_ip = Some(client_ip);
+    }
+
+    pub(crate) fn set_account_id(&mut self, account_id: Uuid) {
+        self.account_id = Some(account_id);
+    }
+
+    pub(crate) fn set_upstream_latency(&mut self, latency: Duration) {
+        self.upstream_latency = Some(latency);
+    }
+}
+
+/// An access log entry
+///
+/// The account is identified by its id. Emails and other personal data are
+/// never recorded. Paths are recorded without the query string, which may
+/// include secrets.
+///
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccessLogEntry {
+    timestamp: DateTime<Local>,
+    request_id: Option<String>,
+    client_ip: Option<String>,
+    method: String,
+    path: String,
+    route_id: Option<String>,
+    service: Option<String>,
+    route_type: Option<&'static str>,
+    account_id: Option<Uuid>,
+    status: u16,
+    bytes: Option<u64>,
+    upstream_latency_ms: Option<u128>,
+    total_latency_ms: u128,
+}
+
+impl AccessLogEntry {
+    /// Format the entry in the common log format
+    ///
+    /// The account id replaces the authenticated user. The remaining fields are
+    /// appended as `key=value` pairs.
+    ///
+    fn to_common_log(&self) -> String {
+        let optional = |value: Option<String>| value.unwrap_or("-".to_string());
+
+        format!(
+            "{client_ip} - {account_id} [{timestamp}] \"{method} {path}\" {status} {bytes} request_id={request_id} route_id={route_id} service={service} route_type={route_type} upstream_ms={upstream_latency} total_ms={total_latency}",
+            client_ip = optional(self.client_ip.to_owned()),
+            account_id = optional(self.account_id.map(|id| id.to_string())),
+            timestamp = self.timestamp.format("%d/%b/%Y:%H:%M:%S %z"),
+            method = self.method,
+            path = self.path,
+            status = self.status,
+            bytes = optional(self.bytes.map(|bytes| bytes.to_string())),
+            request_id = optional(self.request_id.to_owned()),
+            route_id = optional(self.route_id.to_owned()),
+            service = optional(self.service.to_owned()),
+            route_type = self.route_type.unwrap_or("-"),
+            upstream_latency = optional(
+                self.upstream_latency_ms.map(|latency| latency.to_string())
+            ),
+            total_latency = self.total_latency_ms,
+        )
+    }
+}
+
+/// The access log writer
+///
+/// Entries are written by a background worker, then requests never wait for
+/// the target.
+///
+#[derive(Clone)]
+pub(crate) struct AccessLogger {
+    writer: NonBlocking,
+    format: AccessLogFormat,
+    public_routes_percentage: u8,
+}
+
+impl AccessLogger {
+    /// Build the access log writer
+    ///
+    /// The returned guard flushes the pending entries when dropped. It should
+    /// live while the server is running.
+    ///
+    pub(crate) fn new(
+        config: AccessLogConfig,
+    ) -> std::io::Result<(Self, WorkerGuard)> {
+        let (writer, guard) = match config.target {
+            AccessLogTarget::Stdout => {
+                tracing_appender::non_blocking(std::io::stdout())
+            }
+            AccessLogTarget::File { path } => {
+                let mut log_file = PathBuf::from(path);
+
+                log_file.set_extension(match config.format {
+                    AccessLogFormat::Jsonl => "jsonl",
+                    AccessLogFormat::Common => "log",
+                });
+
+                let (parent_dir, file_name) =
+                    match (log_file.parent(), log_file.file_name()) {
+                        (Some(parent_dir), Some(file_name)) => {
+                            (parent_dir.to_owned(), file_name.to_owned())
+                        }
+                        _ => {
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::InvalidInput,
+                                "Invalid access log file path",
+                            ))
+                        }
+                    };
+
+                tracing_appender::non_blocking(
+                    tracing_appender::rolling::never(parent_dir, file_name),
+                )
+            }
+        };
+
+        Ok((
+            Self {
+                writer,
+                format: config.format,
+                public_routes_percentage: config
+                    .public_routes_percentage
+                    .unwrap_or(100)
+                    .min(100),
+            },
+            guard,
+        ))
+    }
+
+    /// Write an entry
+    ///
+    /// Successful requests to public routes are sampled by the public routes
+    /// percentage.
+    ///
+    fn write(&self, entry: &AccessLogEntry) {
+        if entry.route_type == Some(RouteType::Public.name())
+            && entry.status < 400
+            && rand::thread_rng().gen_range(0..100)
+                >= self.public_routes_percentage
+        {
+            return;
+        }
+
+        let mut line = match self.format {
+            AccessLogFormat::Common => entry.to_common_log(),
+            AccessLogFormat::Jsonl => match serde_json::to_string(entry) {
+                Ok(line) => line,
+                Err(err) => {
+                    warn!("Unable to serialize the access log entry: {err}");
+                    return;
+                }
+            },
+        };
+
+        line.push('\n');
+
+        if let Err(err) = self.writer.clone().write_all(line.as_bytes()) {
+            warn!("Unable to write the access log entry: {err}");
+        }
+    }
+}
+
+/// Write the access log entry of a request
+///
+/// Successful requests to public routes are sampled by the public routes
+/// percentage. Requests to the administration API are identified by the
+/// matched route pattern. Without the access log settings, nothing is written.
+///
+pub(crate) fn write_access_log<B: MessageBody>(
+    req: &HttpRequest,
+    response: &HttpResponse<B>,
+    latency: Duration,
+) {
+    let logger = match req.app_data::<web::Data<AccessLogger>>() {
+        None => return,
+        Some(logger) => logger,
+    };
+
+    let details = req
+        .extensions()
+        .get::<AccessLogDetails>()
+        .cloned()
+        .unwrap_or_default();
+
+    let labels = req.extensions().get::<RouteMetricLabels>().cloned();
+
+    let bytes = match response.body().size() {
+        BodySize::Sized(size) => Some(size),
+        _ => response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok()),
+    };
+
+    logger.write(&AccessLogEntry {
+        timestamp: Local::now(),
+        request_id: req
+            .headers()
+            .get(DEFAULT_REQUEST_ID_KEY)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string()),
+        client_ip: details
+            .client_ip
+            .or(req.peer_addr().map(|addr| addr.ip()))
+            .map(|ip| ip.to_string()),
+        method: req.method().to_string(),
+        path: req.path().to_string(),
+        route_id: labels
+            .as_ref()
+            .map(|labels| labels.route.to_owned())
+            .or(req.match_pattern()),
+        service: labels.map(|labels| labels.service),
+        route_type: details.route_type,
+        account_id: details.account_id,
+        status: response.status().as_u16(),
+        bytes,
+        upstream_latency_ms: details
+            .upstream_latency
+            .map(|latency| latency.as_millis()),
+        total_latency_ms: latency.as_millis(),
+    });
+}
+
+/// Write the access log entry of a gRPC call
+///
+/// gRPC calls are served out of the API server, then the call details are
+/// passed explicitly instead of collected from the request extensions. The
+/// recorded status is the one counted at the gateway metrics, since the gRPC
+/// status is sent at the trailers after the entry is written. Response bodies
+/// are streamed, then their size is not recorded.
+///
+pub(crate) fn write_grpc_access_log(
+    logger: &AccessLogger,
+    parts: &Parts,
+    request_id: Option<&str>,
+    details: &AccessLogDetails,
+    labels: Option<&RouteMetricLabels>,
+    status: u16,
+    latency: Duration,
+) {
+    logger.write(&AccessLogEntry {
+        timestamp: Local::now(),
+        request_id: request_id.map(|value| value.to_string()),
+        client_ip: details.client_ip.map(|ip| ip.to_string()),
+        method: parts.method.to_string(),
+        path: parts.uri.path().to_string(),
+        route_id: labels.map(|labels| labels.route.to_owned()),
+        service: labels.map(|labels| labels.service.to_owned()),
+        route_type: details.route_type,
+        account_id: details.account_id,
+        status,
+        bytes: None,
+        upstream_latency_ms: details
+            .upstream_latency
+            .map(|latency| latency.as_millis()),
+        total_latency_ms: latency.as_millis(),
+    });
+}
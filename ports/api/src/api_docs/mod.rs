@@ -2,9 +2,11 @@ use crate::endpoints::{index, manager, role_scoped, service, staff};
 use crate::modifiers::security::MyceliumSecurity;
 
 use myc_core::domain::dtos::{
-    account, account_type, email, error_code, guest_role, guest_user, profile,
-    tag, tenant, user, webhook, route, service as service_dtos, 
-    http_secret
+    account, account_type, circuit_breaker, email, error_code, guest_role, guest_user, profile,
+    header_policy,
+    tag, tenant, user, webhook, route, path_rewrite, service as service_dtos, 
+    http_secret, health_check, upstream, rate_limit, retry, timeout,
+    traffic_split, request_mirror, cors, profile_projection
 };
 use myc_http_tools::providers::{azure_endpoints, google_endpoints};
 use myc_http_tools::{utils::HttpJsonResponse, SystemActor};
@@ -22,8 +24,10 @@ use manager::guest_role_endpoints as Managers__Guest_Role;
 use manager::tenant_endpoints as Managers__Tenants;
 use manager::account_endpoints as Managers__Accounts;
 use role_scoped::account_manager::guest_endpoints as Account_Manager__Guest;
+use role_scoped::gateway_manager::circuit_breaker_endpoints as GatewayManager__Circuit_Breaker;
 use role_scoped::gateway_manager::route_endpoints as GatewayManager__Route;
 use role_scoped::gateway_manager::service_endpoints as GatewayManager__Service;
+use role_scoped::gateway_manager::service_health_endpoints as GatewayManager__Service_Health;
 use role_scoped::beginners::account_endpoints as Beginners__Account;
 use role_scoped::beginners::meta_endpoints as Beginners__Meta;
 use role_scoped::beginners::profile_endpoints as Beginners__Profile;
@@ -265,6 +269,10 @@ struct BeginnersUserApiDoc;
     ),
     paths(
         GatewayManager__Route::list_routes_url,
+        GatewayManager__Route::reload_routes_url,
+        GatewayManager__Route::register_route_url,
+        GatewayManager__Route::update_route_url,
+        GatewayManager__Route::delete_route_url,
     ),
     security(("Bearer" = []))
 )]
@@ -280,6 +288,11 @@ struct GatewayManagerRouteApiDoc;
     ),
     paths(
         GatewayManager__Service::list_services_url,
+        GatewayManager__Service::register_service_url,
+        GatewayManager__Service::update_service_url,
+        GatewayManager__Service::delete_service_url,
+        GatewayManager__Service_Health::list_services_health_url,
+        GatewayManager__Circuit_Breaker::list_circuit_breakers_url,
     ),
     security(("Bearer" = []))
 )]
@@ -649,20 +662,52 @@ struct UsersManagerAccountApiDoc;
             account::Account,
             account::VerboseStatus,
             account_type::AccountType,
+            circuit_breaker::CircuitBreaker,
+            circuit_breaker::CircuitBreakerConfig,
+            circuit_breaker::CircuitState,
+            cors::CorsPolicy,
             email::Email,
             error_code::ErrorCode,
             guest_role::GuestRole,
             guest_role::Permission,
+            header_policy::HeaderPassThrough,
+            header_policy::HeaderPolicy,
+            header_policy::HeaderRenameRule,
+            header_policy::HeaderTransformation,
+            header_policy::HeaderValueRule,
+            health_check::HealthCheckConfig,
+            health_check::HealthCheckFailure,
+            health_check::HealthStatus,
+            health_check::ServiceHealth,
             http_secret::HttpSecret, 
+            path_rewrite::PathRewriteRule,
             profile::Owner,
             profile::LicensedResource,
             profile::Profile,
+            profile_projection::ProfileEncoding,
+            profile_projection::ProfileField,
+            profile_projection::ProfileProjection,
+            rate_limit::RateLimitAlgorithm,
+            rate_limit::RateLimitKey,
+            rate_limit::RateLimitPolicy,
+            request_mirror::RequestMirror,
+            retry::BackoffStrategy,
+            retry::RetryBackoff,
+            retry::RetryPolicy,
             service_dtos::Service, 
+            service_dtos::ServiceSecret,
             route::Route, 
             tag::Tag,
             tenant::Tenant,
             tenant::TenantMetaKey,
             tenant::TenantStatus,
+            timeout::UpstreamTimeouts,
+            traffic_split::BackendOverride,
+            traffic_split::StickyKey,
+            traffic_split::TrafficSplit,
+            traffic_split::WeightedBackend,
+            upstream::LoadBalancingStrategy,
+            upstream::UpstreamTarget,
             user::User,
             webhook::WebHook,
             webhook::WebHookTrigger,
@@ -698,8 +743,15 @@ struct UsersManagerAccountApiDoc;
             //
             // GATEWAY MANAGER
             //
+            role_scoped::gateway_manager::circuit_breaker_endpoints::ListCircuitBreakersParams,
             role_scoped::gateway_manager::route_endpoints::ListRoutesByServiceParams,
+            role_scoped::gateway_manager::route_endpoints::ReloadRoutesResponse,
+            role_scoped::gateway_manager::route_endpoints::RegisterRouteBody,
+            role_scoped::gateway_manager::route_endpoints::UpdateRouteBody,
             role_scoped::gateway_manager::service_endpoints::ListServicesParams,
+            role_scoped::gateway_manager::service_endpoints::RegisterServiceBody,
+            role_scoped::gateway_manager::service_endpoints::UpdateServiceBody,
+            role_scoped::gateway_manager::service_health_endpoints::ListServicesHealthParams,
 
             //
             // GUEST MANAGER
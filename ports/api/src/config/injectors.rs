@@ -8,7 +8,9 @@ use crate::modules::{
     GuestUserDeletionModule, GuestUserFetchingModule,
     GuestUserOnAccountUpdatingModule, GuestUserRegistrationModule,
     LicensedResourcesFetchingModule, MessageSendingQueueModule,
-    ProfileFetchingModule, RoutesFetchingModule, TenantDeletionModule,
+    ProfileCachingModule, ProfileFetchingModule, RateLimitCountingModule,
+    RoutesDatabaseFetchingModule, RoutesDeletionModule, RoutesFetchingModule,
+    RoutesRegistrationModule, RoutesUpdatingModule, TenantDeletionModule,
     TenantFetchingModule, TenantRegistrationModule, TenantTagDeletionModule,
     TenantTagRegistrationModule, TenantTagUpdatingModule, TenantUpdatingModule,
     TokenFetchingModule, TokenInvalidationModule, TokenRegistrationModule,
@@ -18,11 +20,18 @@ use crate::modules::{
 };
 
 use actix_web::web;
+use myc_core::{
+    domain::entities::ProfileCaching,
+    models::{ProfileCacheBackend, ProfileCacheConfig},
+};
 use myc_mem_db::repositories::{
+    ProfileCachingMemDbRepo, ProfileCachingMemDbRepoParameters,
     RoutesFetchingMemDbRepo, RoutesFetchingMemDbRepoParameters,
 };
 use myc_notifier::repositories::{
     MessageSendingQueueRepository, MessageSendingQueueRepositoryParameters,
+    ProfileCachingRedisRepository, RateLimitCountingRedisRepository,
+    RateLimitCountingRedisRepositoryParameters,
 };
 use myc_prisma::repositories::{
     AccountDeletionSqlDbRepository, AccountDeletionSqlDbRepositoryParameters,
@@ -63,9 +72,13 @@ use myc_prisma::repositories::{
     LicensedResourcesFetchingSqlDbRepository,
     LicensedResourcesFetchingSqlDbRepositoryParameters,
     ProfileFetchingSqlDbRepository, ProfileFetchingSqlDbRepositoryParameters,
-    TenantDeletionSqlDbRepository, TenantDeletionSqlDbRepositoryParameters,
-    TenantFetchingSqlDbRepository, TenantFetchingSqlDbRepositoryParameters,
-    TenantRegistrationSqlDbRepository,
+    RoutesDeletionSqlDbRepository, RoutesDeletionSqlDbRepositoryParameters,
+    RoutesFetchingSqlDbRepository, RoutesFetchingSqlDbRepositoryParameters,
+    RoutesRegistrationSqlDbRepository,
+    RoutesRegistrationSqlDbRepositoryParameters, RoutesUpdatingSqlDbRepository,
+    RoutesUpdatingSqlDbRepositoryParameters, TenantDeletionSqlDbRepository,
+    TenantDeletionSqlDbRepositoryParameters, TenantFetchingSqlDbRepository,
+    TenantFetchingSqlDbRepositoryParameters, TenantRegistrationSqlDbRepository,
     TenantRegistrationSqlDbRepositoryParameters,
     TenantTagDeletionSqlDbRepository,
     TenantTagDeletionSqlDbRepositoryParameters,
@@ -90,6 +103,57 @@ use myc_prisma::repositories::{
 };
 use std::sync::Arc;
 
+/// Build the profile caching module
+///
+/// Profiles are cached in memory unless the Redis store is configured. Without
+/// the profile cache settings, profiles are not cached.
+pub fn build_profile_caching_module(
+    config: Option<ProfileCacheConfig>,
+) -> ProfileCachingModule {
+    let builder = ProfileCachingModule::builder();
+
+    match config {
+        Some(ProfileCacheConfig {
+            backend: ProfileCacheBackend::Redis,
+            ttl_in_secs,
+        }) => builder
+            .with_component_override::<dyn ProfileCaching>(Box::new(
+                ProfileCachingRedisRepository {
+                    ttl_in_secs: Some(ttl_in_secs),
+                },
+            ))
+            .build(),
+        config => builder
+            .with_component_parameters::<ProfileCachingMemDbRepo>(
+                ProfileCachingMemDbRepoParameters {
+                    ttl_in_secs: config.map(|config| config.ttl_in_secs),
+                },
+            )
+            .build(),
+    }
+}
+
+/// Build the gateway routes fetching module
+///
+/// Routes are fetched from the in-memory routes index.
+///
+pub fn build_routes_fetching_module() -> RoutesFetchingModule {
+    RoutesFetchingModule::builder()
+        .with_component_parameters::<RoutesFetchingMemDbRepo>(
+            RoutesFetchingMemDbRepoParameters {},
+        )
+        .build()
+}
+
+/// Build the gateway rate limit counting module
+pub fn build_rate_limit_counting_module() -> RateLimitCountingModule {
+    RateLimitCountingModule::builder()
+        .with_component_parameters::<RateLimitCountingRedisRepository>(
+            RateLimitCountingRedisRepositoryParameters {},
+        )
+        .build()
+}
+
 /// Configure injection modules.
 pub fn configure(config: &mut web::ServiceConfig) {
     config
@@ -204,6 +268,10 @@ pub fn configure(config: &mut web::ServiceConfig) {
                 .build(),
         ))
         // ? -------------------------------------------------------------------
+        // ? Rate limit
+        // ? -------------------------------------------------------------------
+        .app_data(Arc::new(build_rate_limit_counting_module()))
+        // ? -------------------------------------------------------------------
         // ? User
         // ? -------------------------------------------------------------------
         .app_data(Arc::new(
@@ -242,10 +310,32 @@ pub fn configure(config: &mut web::ServiceConfig) {
         // ? -------------------------------------------------------------------
         // ? Routes
         // ? -------------------------------------------------------------------
+        .app_data(Arc::new(build_routes_fetching_module()))
+        .app_data(Arc::new(
+            RoutesDatabaseFetchingModule::builder()
+                .with_component_parameters::<RoutesFetchingSqlDbRepository>(
+                    RoutesFetchingSqlDbRepositoryParameters {},
+                )
+                .build(),
+        ))
+        .app_data(Arc::new(
+            RoutesRegistrationModule::builder()
+                .with_component_parameters::<RoutesRegistrationSqlDbRepository>(
+                    RoutesRegistrationSqlDbRepositoryParameters {},
+                )
+                .build(),
+        ))
+        .app_data(Arc::new(
+            RoutesUpdatingModule::builder()
+                .with_component_parameters::<RoutesUpdatingSqlDbRepository>(
+                    RoutesUpdatingSqlDbRepositoryParameters {},
+                )
+                .build(),
+        ))
         .app_data(Arc::new(
-            RoutesFetchingModule::builder()
-                .with_component_parameters::<RoutesFetchingMemDbRepo>(
-                    RoutesFetchingMemDbRepoParameters {},
+            RoutesDeletionModule::builder()
+                .with_component_parameters::<RoutesDeletionSqlDbRepository>(
+                    RoutesDeletionSqlDbRepositoryParameters {},
                 )
                 .build(),
         ))
@@ -0,0 +1,27 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use myc_http_tools::functions::ProfileSigner;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config.service(jwks_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Provide the public keys of the profile signer.
+///
+/// Downstream services verify the profiles injected by the gateway with the
+/// published keys. Keys are identified by the `kid` of the signed profiles.
+#[get("/.well-known/jwks.json")]
+pub async fn jwks_url(
+    profile_signer: web::Data<ProfileSigner>,
+) -> impl Responder {
+    HttpResponse::Ok()
+        .insert_header(("Cache-Control", "public, max-age=300"))
+        .json(profile_signer.jwks())
+}
@@ -0,0 +1,70 @@
+use crate::metrics::record_circuit_breaker_states;
+
+use actix_web::{
+    get, http::header::Header, web, HttpRequest, HttpResponse, Responder,
+};
+use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
+use constant_time_eq::constant_time_eq;
+use prometheus::{Encoder, TextEncoder};
+use tracing::warn;
+
+/// The bearer token required to scrape the metrics
+///
+/// Without a token, the metrics endpoint is open. It should only happen when
+/// metrics are served by a dedicated port.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct MetricsToken(pub Option<String>);
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config.service(metrics_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// Provide the metrics in the Prometheus exposition format.
+///
+/// Metrics include the gateway and administration API requests, the upstream
+/// errors, the circuit state of the downstream services, the profile fetching
+/// and secrets resolution latencies, the email queue depth and the webhook
+/// dispatches.
+#[get("/metrics")]
+pub async fn metrics_url(
+    req: HttpRequest,
+    token: web::Data<MetricsToken>,
+) -> impl Responder {
+    if let Some(token) = &token.0 {
+        let authorized = match Authorization::<Bearer>::parse(&req) {
+            Ok(auth) => constant_time_eq(
+                auth.into_scheme().token().as_bytes(),
+                token.as_bytes(),
+            ),
+            Err(_) => false,
+        };
+
+        if !authorized {
+            return HttpResponse::Unauthorized().finish();
+        }
+    }
+
+    record_circuit_breaker_states().await;
+
+    let encoder = TextEncoder::new();
+    let mut buffer = vec![];
+
+    if let Err(err) = encoder.encode(&prometheus::gather(), &mut buffer) {
+        warn!("Unable to encode the metrics: {err}");
+
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type(encoder.format_type())
+        .body(buffer)
+}
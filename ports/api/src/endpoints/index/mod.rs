@@ -1 +1,3 @@
 pub(crate) mod heath_check_endpoints;
+pub(crate) mod jwks_endpoints;
+pub(crate) mod metrics_endpoints;
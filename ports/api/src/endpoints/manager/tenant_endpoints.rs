@@ -2,8 +2,8 @@ use crate::{
     dtos::MyceliumProfileData,
     endpoints::shared::PaginationParams,
     modules::{
-        TenantDeletionModule, TenantFetchingModule, TenantRegistrationModule,
-        TenantUpdatingModule, UserFetchingModule,
+        ProfileCachingModule, TenantDeletionModule, TenantFetchingModule,
+        TenantRegistrationModule, TenantUpdatingModule, UserFetchingModule,
     },
 };
 
@@ -12,8 +12,9 @@ use myc_core::{
     domain::{
         dtos::tenant::{Tenant, TenantMetaKey},
         entities::{
-            TenantDeletion, TenantFetching, TenantOwnerConnection,
-            TenantRegistration, TenantUpdating, UserFetching,
+            ProfileCaching, TenantDeletion, TenantFetching,
+            TenantOwnerConnection, TenantRegistration, TenantUpdating,
+            UserFetching,
         },
     },
     use_cases::super_users::managers::{
@@ -123,6 +124,7 @@ pub async fn create_tenant_url(
         TenantRegistrationModule,
         dyn TenantRegistration,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match create_tenant(
         profile.to_profile(),
@@ -131,6 +133,7 @@ pub async fn create_tenant_url(
         body.owner_id,
         Box::new(&*user_fetching_repo),
         Box::new(&*tenant_registration_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -240,11 +243,13 @@ pub async fn delete_tenant_url(
     profile: MyceliumProfileData,
     path: web::Path<Uuid>,
     tenant_deletion_repo: Inject<TenantDeletionModule, dyn TenantDeletion>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match delete_tenant(
         profile.to_profile(),
         path.into_inner(),
         Box::from(&*tenant_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -291,6 +296,7 @@ pub async fn include_tenant_owner_url(
     path: web::Path<(Uuid, Uuid)>,
     profile: MyceliumProfileData,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (tenant_id, owner_id) = path.into_inner();
 
@@ -299,6 +305,7 @@ pub async fn include_tenant_owner_url(
         tenant_id,
         owner_id,
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -344,6 +351,7 @@ pub async fn exclude_tenant_owner_url(
     path: web::Path<(Uuid, Uuid)>,
     profile: MyceliumProfileData,
     tenant_deletion_repo: Inject<TenantDeletionModule, dyn TenantDeletion>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (tenant_id, owner_id) = path.into_inner();
 
@@ -352,6 +360,7 @@ pub async fn exclude_tenant_owner_url(
         tenant_id,
         owner_id,
         Box::new(&*tenant_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -3,15 +3,16 @@ use crate::{
     middleware::check_credentials_with_multi_identity_provider,
     modules::{
         AccountRegistrationModule, AccountUpdatingModule,
-        MessageSendingQueueModule, UserFetchingModule, WebHookFetchingModule,
+        MessageSendingQueueModule, ProfileCachingModule, UserFetchingModule,
+        WebHookFetchingModule,
     },
 };
 
 use actix_web::{patch, post, web, HttpRequest, HttpResponse, Responder};
 use myc_core::{
     domain::entities::{
-        AccountRegistration, AccountUpdating, MessageSending, UserFetching,
-        WebHookFetching,
+        AccountRegistration, AccountUpdating, MessageSending, ProfileCaching,
+        UserFetching, WebHookFetching,
     },
     models::AccountLifeCycle,
     use_cases::role_scoped::beginner::account::{
@@ -190,6 +191,7 @@ pub async fn update_own_account_name_url(
     body: web::Json<UpdateOwnAccountNameAccountBody>,
     profile: MyceliumProfileData,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let profile = profile.to_profile();
 
@@ -212,6 +214,7 @@ pub async fn update_own_account_name_url(
         profile,
         body.name.to_owned(),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -1,10 +1,11 @@
 use crate::{
-    dtos::MyceliumProfileData, modules::GuestUserOnAccountUpdatingModule,
+    dtos::MyceliumProfileData,
+    modules::{GuestUserOnAccountUpdatingModule, ProfileCachingModule},
 };
 
 use actix_web::{post, web, Responder};
 use myc_core::{
-    domain::entities::GuestUserOnAccountUpdating,
+    domain::entities::{GuestUserOnAccountUpdating, ProfileCaching},
     use_cases::role_scoped::beginner::guest_user::accept_invitation,
 };
 use myc_http_tools::{
@@ -78,6 +79,7 @@ pub async fn accept_invitation_url(
         GuestUserOnAccountUpdatingModule,
         dyn GuestUserOnAccountUpdating,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (account_id, guest_role_name, permission) = query.into_inner();
 
@@ -87,6 +89,7 @@ pub async fn accept_invitation_url(
         guest_role_name,
         Permission::from_i32(permission.into()),
         Box::new(&*guest_user_on_account_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -5,9 +5,9 @@ use crate::{
         parse_issuer_from_request,
     },
     modules::{
-        MessageSendingQueueModule, TokenInvalidationModule,
-        TokenRegistrationModule, UserDeletionModule, UserFetchingModule,
-        UserRegistrationModule, UserUpdatingModule,
+        MessageSendingQueueModule, ProfileCachingModule,
+        TokenInvalidationModule, TokenRegistrationModule, UserDeletionModule,
+        UserFetchingModule, UserRegistrationModule, UserUpdatingModule,
     },
 };
 
@@ -18,8 +18,9 @@ use myc_core::{
         actors::SystemActor,
         dtos::user::{Provider, Totp, User},
         entities::{
-            MessageSending, TokenInvalidation, TokenRegistration, UserDeletion,
-            UserFetching, UserRegistration, UserUpdating,
+            MessageSending, ProfileCaching, TokenInvalidation,
+            TokenRegistration, UserDeletion, UserFetching, UserRegistration,
+            UserUpdating,
         },
     },
     models::AccountLifeCycle,
@@ -418,6 +419,7 @@ pub async fn check_user_token_url(
         TokenInvalidationModule,
         dyn TokenInvalidation,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let email = match Email::from_string(body.email.to_owned()) {
         Err(err) => {
@@ -437,6 +439,7 @@ pub async fn check_user_token_url(
         Box::new(&*user_fetching_repo),
         Box::new(&*user_updating_repo),
         Box::new(&*token_invalidation_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -1,2 +1,27 @@
+pub(crate) mod circuit_breaker_endpoints;
 pub(crate) mod route_endpoints;
 pub(crate) mod service_endpoints;
+pub(crate) mod service_health_endpoints;
+
+use crate::models::api_config::{ApiConfig, RoutesSource};
+
+use actix_web::HttpResponse;
+use myc_http_tools::utils::HttpJsonResponse;
+
+/// Reject changes to read-only routes
+///
+/// Routes loaded from the routes file could only be changed by editing the
+/// file. Returns a method not allowed response in such case.
+///
+pub(crate) fn check_routes_are_writable(
+    api_config: &ApiConfig,
+) -> Option<HttpResponse> {
+    match api_config.routes_source.to_owned().unwrap_or_default() {
+        RoutesSource::Database => None,
+        RoutesSource::Yaml => Some(HttpResponse::MethodNotAllowed().json(
+            HttpJsonResponse::new_message(
+                "Routes are loaded from the routes file and are read-only. Set the routes source to database to manage routes through the API.",
+            ),
+        )),
+    }
+}
@@ -1,17 +1,48 @@
-use crate::{dtos::MyceliumProfileData, modules::RoutesFetchingModule};
+use super::check_routes_are_writable;
+use crate::{
+    dtos::MyceliumProfileData,
+    models::api_config::{ApiConfig, RoutesSource},
+    modules::{
+        RoutesDatabaseFetchingModule, RoutesDeletionModule,
+        RoutesFetchingModule, RoutesRegistrationModule, RoutesUpdatingModule,
+    },
+};
 
-use actix_web::{get, web, Responder};
+use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use myc_core::{
-    domain::{dtos::route::Route, entities::RoutesFetching},
-    use_cases::role_scoped::gateway_manager::route::list_routes,
+    domain::{
+        dtos::{
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            http::{HttpMethod, Protocol},
+            path_rewrite::PathRewriteRule,
+            profile_projection::ProfileProjection,
+            rate_limit::RateLimitPolicy,
+            request_mirror::RequestMirror,
+            retry::RetryPolicy,
+            route::Route,
+            route_type::RouteType,
+            timeout::UpstreamTimeouts,
+            traffic_split::TrafficSplit,
+        },
+        entities::{
+            RoutesDeletion, RoutesFetching, RoutesRegistration, RoutesUpdating,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::role_scoped::gateway_manager::route::{
+        delete_route, list_routes, register_route, reload_routes,
+        reload_routes_from_database, update_route,
+    },
 };
 use myc_http_tools::{
     utils::HttpJsonResponse,
     wrappers::default_response_to_http_response::{
-        fetch_many_response_kind, handle_mapped_error,
+        create_response_kind, delete_response_kind, fetch_many_response_kind,
+        handle_mapped_error, updating_response_kind,
     },
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use shaku_actix::Inject;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
@@ -21,7 +52,12 @@ use uuid::Uuid;
 // ? ---------------------------------------------------------------------------
 
 pub fn configure(config: &mut web::ServiceConfig) {
-    config.service(list_routes_url);
+    config
+        .service(list_routes_url)
+        .service(reload_routes_url)
+        .service(register_route_url)
+        .service(update_route_url)
+        .service(delete_route_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -36,6 +72,58 @@ pub struct ListRoutesByServiceParams {
     include_service_details: Option<bool>,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterRouteBody {
+    service_id: Uuid,
+    group: RouteType,
+    methods: Vec<HttpMethod>,
+    path: String,
+    protocol: Protocol,
+    allowed_sources: Option<Vec<String>>,
+    secret_name: Option<String>,
+    accept_insecure_routing: Option<bool>,
+    rate_limit: Option<RateLimitPolicy>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    accept_non_idempotent_retries: Option<bool>,
+    rewrite: Option<Vec<PathRewriteRule>>,
+    header_policy: Option<HeaderPolicy>,
+    traffic_split: Option<TrafficSplit>,
+    mirror: Option<RequestMirror>,
+    cors: Option<CorsPolicy>,
+    profile_projection: Option<ProfileProjection>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRouteBody {
+    group: Option<RouteType>,
+    methods: Option<Vec<HttpMethod>>,
+    path: Option<String>,
+    protocol: Option<Protocol>,
+    allowed_sources: Option<Vec<String>>,
+    secret_name: Option<String>,
+    accept_insecure_routing: Option<bool>,
+    rate_limit: Option<RateLimitPolicy>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    accept_non_idempotent_retries: Option<bool>,
+    rewrite: Option<Vec<PathRewriteRule>>,
+    header_policy: Option<HeaderPolicy>,
+    traffic_split: Option<TrafficSplit>,
+    mirror: Option<RequestMirror>,
+    cors: Option<CorsPolicy>,
+    profile_projection: Option<ProfileProjection>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReloadRoutesResponse {
+    /// The number of loaded routes
+    routes: usize,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 // ? ---------------------------------------------------------------------------
@@ -96,3 +184,333 @@ pub async fn list_routes_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Reload routes
+///
+/// This function is restricted to the GatewayManager users. Reload the routes
+/// from the routes source without restarting the gateway. Case the routes are
+/// invalid, the current routes remain active and a bad request response is
+/// returned.
+///
+#[utoipa::path(
+    post,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid routes.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 200,
+            description = "Routes reloaded.",
+            body = ReloadRoutesResponse,
+        ),
+    ),
+)]
+#[post("/reload")]
+pub async fn reload_routes_url(
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+) -> impl Responder {
+    let response = match api_config.routes_source.to_owned().unwrap_or_default()
+    {
+        RoutesSource::Yaml => {
+            reload_routes(profile.to_profile(), api_config.routes.to_owned())
+                .await
+        }
+        RoutesSource::Database => {
+            reload_routes_from_database(
+                profile.to_profile(),
+                life_cycle_settings.get_ref().to_owned(),
+                Box::new(&*routes_fetching_repo),
+            )
+            .await
+        }
+    };
+
+    match response {
+        Ok(routes) => HttpResponse::Ok().json(ReloadRoutesResponse { routes }),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Register a route
+///
+/// This function is restricted to the GatewayManager users. Routes of the
+/// same service sharing the same path should accept different methods. Only
+/// available when routes are stored in the database.
+///
+#[utoipa::path(
+    post,
+    request_body = RegisterRouteBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid route or service not found.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 201,
+            description = "Route created.",
+            body = Route,
+        ),
+        (
+            status = 200,
+            description = "Route already exists.",
+            body = Route,
+        ),
+    ),
+)]
+#[post("")]
+pub async fn register_route_url(
+    body: web::Json<RegisterRouteBody>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_registration_repo: Inject<
+        RoutesRegistrationModule,
+        dyn RoutesRegistration,
+    >,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match register_route(
+        profile.to_profile(),
+        body.service_id.to_owned(),
+        body.group.to_owned(),
+        body.methods.to_owned(),
+        body.path.to_owned(),
+        body.protocol.to_owned(),
+        body.allowed_sources.to_owned(),
+        body.secret_name.to_owned(),
+        body.accept_insecure_routing.to_owned(),
+        body.rate_limit.to_owned(),
+        body.timeouts.to_owned(),
+        body.retry.to_owned(),
+        body.accept_non_idempotent_retries.to_owned(),
+        body.rewrite.to_owned(),
+        body.header_policy.to_owned(),
+        body.traffic_split.to_owned(),
+        body.mirror.to_owned(),
+        body.cors.to_owned(),
+        body.profile_projection.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_registration_repo),
+    )
+    .await
+    {
+        Ok(res) => create_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Update a route
+///
+/// This function is restricted to the GatewayManager users. Only the informed
+/// fields are updated. Only available when routes are stored in the database.
+///
+#[utoipa::path(
+    patch,
+    params(
+        ("route_id" = Uuid, Path, description = "The route primary key."),
+    ),
+    request_body = UpdateRouteBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid route or route not found.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Route updated.",
+            body = Route,
+        ),
+    ),
+)]
+#[patch("/{route_id}")]
+pub async fn update_route_url(
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateRouteBody>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_updating_repo: Inject<RoutesUpdatingModule, dyn RoutesUpdating>,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match update_route(
+        profile.to_profile(),
+        path.to_owned(),
+        body.group.to_owned(),
+        body.methods.to_owned(),
+        body.path.to_owned(),
+        body.protocol.to_owned(),
+        body.allowed_sources.to_owned(),
+        body.secret_name.to_owned(),
+        body.accept_insecure_routing.to_owned(),
+        body.rate_limit.to_owned(),
+        body.timeouts.to_owned(),
+        body.retry.to_owned(),
+        body.accept_non_idempotent_retries.to_owned(),
+        body.rewrite.to_owned(),
+        body.header_policy.to_owned(),
+        body.traffic_split.to_owned(),
+        body.mirror.to_owned(),
+        body.cors.to_owned(),
+        body.profile_projection.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_updating_repo),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Delete a route
+///
+/// This function is restricted to the GatewayManager users. Only available
+/// when routes are stored in the database.
+///
+#[utoipa::path(
+    delete,
+    params(
+        ("route_id" = Uuid, Path, description = "The route primary key."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Route not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Route deleted.",
+        ),
+    ),
+)]
+#[delete("/{route_id}")]
+pub async fn delete_route_url(
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_deletion_repo: Inject<RoutesDeletionModule, dyn RoutesDeletion>,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match delete_route(
+        profile.to_profile(),
+        path.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_deletion_repo),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -1,14 +1,40 @@
-use crate::{dtos::MyceliumProfileData, modules::RoutesFetchingModule};
+use super::check_routes_are_writable;
+use crate::{
+    dtos::MyceliumProfileData,
+    models::api_config::ApiConfig,
+    modules::{
+        RoutesDatabaseFetchingModule, RoutesDeletionModule,
+        RoutesFetchingModule, RoutesRegistrationModule, RoutesUpdatingModule,
+    },
+};
 
-use actix_web::{get, web, Responder};
+use actix_web::{delete, get, patch, post, web, Responder};
 use myc_core::{
-    domain::{dtos::service::Service, entities::RoutesFetching},
-    use_cases::role_scoped::gateway_manager::service::list_services,
+    domain::{
+        dtos::{
+            circuit_breaker::CircuitBreakerConfig,
+            cors::CorsPolicy,
+            header_policy::HeaderPolicy,
+            health_check::HealthCheckConfig,
+            retry::RetryPolicy,
+            service::{Service, ServiceSecret},
+            timeout::UpstreamTimeouts,
+            upstream::{LoadBalancingStrategy, UpstreamTarget},
+        },
+        entities::{
+            RoutesDeletion, RoutesFetching, RoutesRegistration, RoutesUpdating,
+        },
+    },
+    models::AccountLifeCycle,
+    use_cases::role_scoped::gateway_manager::service::{
+        delete_service, list_services, register_service, update_service,
+    },
 };
 use myc_http_tools::{
     utils::HttpJsonResponse,
     wrappers::default_response_to_http_response::{
-        fetch_many_response_kind, handle_mapped_error,
+        create_response_kind, delete_response_kind, fetch_many_response_kind,
+        handle_mapped_error, updating_response_kind,
     },
 };
 use serde::Deserialize;
@@ -21,7 +47,11 @@ use uuid::Uuid;
 // ? ---------------------------------------------------------------------------
 
 pub fn configure(config: &mut web::ServiceConfig) {
-    config.service(list_services_url);
+    config
+        .service(list_services_url)
+        .service(register_service_url)
+        .service(update_service_url)
+        .service(delete_service_url);
 }
 
 // ? ---------------------------------------------------------------------------
@@ -35,6 +65,38 @@ pub struct ListServicesParams {
     name: Option<String>,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterServiceBody {
+    name: String,
+    host: String,
+    targets: Option<Vec<UpstreamTarget>>,
+    load_balancing: Option<LoadBalancingStrategy>,
+    health_check: Option<HealthCheckConfig>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    header_policy: Option<HeaderPolicy>,
+    cors: Option<CorsPolicy>,
+    secrets: Option<Vec<ServiceSecret>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateServiceBody {
+    name: Option<String>,
+    host: Option<String>,
+    targets: Option<Vec<UpstreamTarget>>,
+    load_balancing: Option<LoadBalancingStrategy>,
+    health_check: Option<HealthCheckConfig>,
+    timeouts: Option<UpstreamTimeouts>,
+    retry: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    header_policy: Option<HeaderPolicy>,
+    cors: Option<CorsPolicy>,
+    secrets: Option<Vec<ServiceSecret>>,
+}
+
 // ? ---------------------------------------------------------------------------
 // ? Define API paths
 // ? ---------------------------------------------------------------------------
@@ -94,3 +156,251 @@ pub async fn list_services_url(
         Err(err) => handle_mapped_error(err),
     }
 }
+
+/// Register a service
+///
+/// This function is restricted to the GatewayManager users. Only available
+/// when routes are stored in the database.
+///
+#[utoipa::path(
+    post,
+    request_body = RegisterServiceBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid service.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 201,
+            description = "Service created.",
+            body = Service,
+        ),
+        (
+            status = 200,
+            description = "Service already exists.",
+            body = Service,
+        ),
+    ),
+)]
+#[post("")]
+pub async fn register_service_url(
+    body: web::Json<RegisterServiceBody>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_registration_repo: Inject<
+        RoutesRegistrationModule,
+        dyn RoutesRegistration,
+    >,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match register_service(
+        profile.to_profile(),
+        body.name.to_owned(),
+        body.host.to_owned(),
+        body.targets.to_owned(),
+        body.load_balancing.to_owned(),
+        body.health_check.to_owned(),
+        body.timeouts.to_owned(),
+        body.retry.to_owned(),
+        body.circuit_breaker.to_owned(),
+        body.header_policy.to_owned(),
+        body.cors.to_owned(),
+        body.secrets.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_registration_repo),
+    )
+    .await
+    {
+        Ok(res) => create_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Update a service
+///
+/// This function is restricted to the GatewayManager users. Only the informed
+/// fields are updated. Only available when routes are stored in the database.
+///
+#[utoipa::path(
+    patch,
+    params(
+        ("service_id" = Uuid, Path, description = "The service primary key."),
+    ),
+    request_body = UpdateServiceBody,
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Invalid service or service not found.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 202,
+            description = "Service updated.",
+            body = Service,
+        ),
+    ),
+)]
+#[patch("/{service_id}")]
+pub async fn update_service_url(
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateServiceBody>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_updating_repo: Inject<RoutesUpdatingModule, dyn RoutesUpdating>,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match update_service(
+        profile.to_profile(),
+        path.to_owned(),
+        body.name.to_owned(),
+        body.host.to_owned(),
+        body.targets.to_owned(),
+        body.load_balancing.to_owned(),
+        body.health_check.to_owned(),
+        body.timeouts.to_owned(),
+        body.retry.to_owned(),
+        body.circuit_breaker.to_owned(),
+        body.header_policy.to_owned(),
+        body.cors.to_owned(),
+        body.secrets.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_updating_repo),
+    )
+    .await
+    {
+        Ok(res) => updating_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
+
+/// Delete a service
+///
+/// This function is restricted to the GatewayManager users. The service routes
+/// are deleted together with the service. Only available when routes are
+/// stored in the database.
+///
+#[utoipa::path(
+    delete,
+    params(
+        ("service_id" = Uuid, Path, description = "The service primary key."),
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 405,
+            description = "Routes are read-only.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 400,
+            description = "Service not deleted.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Service deleted.",
+        ),
+    ),
+)]
+#[delete("/{service_id}")]
+pub async fn delete_service_url(
+    path: web::Path<Uuid>,
+    profile: MyceliumProfileData,
+    api_config: web::Data<ApiConfig>,
+    life_cycle_settings: web::Data<AccountLifeCycle>,
+    routes_fetching_repo: Inject<
+        RoutesDatabaseFetchingModule,
+        dyn RoutesFetching,
+    >,
+    routes_deletion_repo: Inject<RoutesDeletionModule, dyn RoutesDeletion>,
+) -> impl Responder {
+    if let Some(response) = check_routes_are_writable(&api_config) {
+        return response;
+    }
+
+    match delete_service(
+        profile.to_profile(),
+        path.to_owned(),
+        life_cycle_settings.get_ref().to_owned(),
+        Box::new(&*routes_fetching_repo),
+        Box::new(&*routes_deletion_repo),
+    )
+    .await
+    {
+        Ok(res) => delete_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -0,0 +1,94 @@
+use crate::dtos::MyceliumProfileData;
+
+use actix_web::{get, web, Responder};
+use myc_core::{
+    domain::dtos::health_check::ServiceHealth,
+    use_cases::role_scoped::gateway_manager::service::list_services_health,
+};
+use myc_http_tools::{
+    utils::HttpJsonResponse,
+    wrappers::default_response_to_http_response::{
+        fetch_many_response_kind, handle_mapped_error,
+    },
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+// ? ---------------------------------------------------------------------------
+// ? Configure application
+// ? ---------------------------------------------------------------------------
+
+pub fn configure(config: &mut web::ServiceConfig) {
+    config.service(list_services_health_url);
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API structs
+// ? ---------------------------------------------------------------------------
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ListServicesHealthParams {
+    id: Option<Uuid>,
+    name: Option<String>,
+}
+
+// ? ---------------------------------------------------------------------------
+// ? Define API paths
+// ? ---------------------------------------------------------------------------
+
+/// List services health
+///
+/// This function is restricted to the GatewayManager users. List the current
+/// health status of the downstream services and the last health check failure
+/// of each one.
+///
+#[utoipa::path(
+    get,
+    params(
+        ListServicesHealthParams,
+    ),
+    responses(
+        (
+            status = 500,
+            description = "Unknown internal server error.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 403,
+            description = "Forbidden.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 401,
+            description = "Unauthorized.",
+            body = HttpJsonResponse,
+        ),
+        (
+            status = 204,
+            description = "Not found.",
+        ),
+        (
+            status = 200,
+            description = "Fetching success.",
+            body = [ServiceHealth],
+        ),
+    ),
+)]
+#[get("/health")]
+pub async fn list_services_health_url(
+    query: web::Query<ListServicesHealthParams>,
+    profile: MyceliumProfileData,
+) -> impl Responder {
+    match list_services_health(
+        profile.to_profile(),
+        query.id.to_owned(),
+        query.name.to_owned(),
+    )
+    .await
+    {
+        Ok(res) => fetch_many_response_kind(res),
+        Err(err) => handle_mapped_error(err),
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     modules::{
         GuestRoleDeletionModule, GuestRoleFetchingModule,
         GuestRoleRegistrationModule, GuestRoleUpdatingModule,
+        ProfileCachingModule,
     },
 };
 
@@ -12,7 +13,7 @@ use myc_core::{
         dtos::guest_role::{GuestRole, Permission},
         entities::{
             GuestRoleDeletion, GuestRoleFetching, GuestRoleRegistration,
-            GuestRoleUpdating,
+            GuestRoleUpdating, ProfileCaching,
         },
     },
     use_cases::role_scoped::guest_manager::guest_role::{
@@ -230,11 +231,13 @@ pub async fn delete_guest_role_url(
     path: web::Path<Uuid>,
     profile: MyceliumProfileData,
     role_deletion_repo: Inject<GuestRoleDeletionModule, dyn GuestRoleDeletion>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match delete_guest_role(
         profile.to_profile(),
         path.to_owned(),
         Box::new(&*role_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -287,6 +290,7 @@ pub async fn update_guest_role_name_and_description_url(
     profile: MyceliumProfileData,
     role_fetching_repo: Inject<GuestRoleFetchingModule, dyn GuestRoleFetching>,
     role_updating_repo: Inject<GuestRoleUpdatingModule, dyn GuestRoleUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_guest_role_name_and_description(
         profile.to_profile(),
@@ -295,6 +299,7 @@ pub async fn update_guest_role_name_and_description_url(
         path.to_owned(),
         Box::new(&*role_fetching_repo),
         Box::new(&*role_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -347,6 +352,7 @@ pub async fn update_guest_role_permissions_url(
     profile: MyceliumProfileData,
     role_fetching_repo: Inject<GuestRoleFetchingModule, dyn GuestRoleFetching>,
     role_updating_repo: Inject<GuestRoleUpdatingModule, dyn GuestRoleUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_guest_role_permission(
         profile.to_profile(),
@@ -354,6 +360,7 @@ pub async fn update_guest_role_permissions_url(
         body.permission.to_owned(),
         Box::new(&*role_fetching_repo),
         Box::new(&*role_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -412,6 +419,7 @@ pub async fn insert_role_child_url(
         GuestRoleUpdatingModule,
         dyn GuestRoleUpdating,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (guest_role_id, child_id) = path.into_inner();
 
@@ -421,6 +429,7 @@ pub async fn insert_role_child_url(
         child_id,
         Box::new(&*guest_role_fetching_repo),
         Box::new(&*guest_role_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -475,6 +484,7 @@ pub async fn remove_role_child_url(
         GuestRoleUpdatingModule,
         dyn GuestRoleUpdating,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (guest_role_id, child_id) = path.into_inner();
 
@@ -483,6 +493,7 @@ pub async fn remove_role_child_url(
         guest_role_id,
         child_id,
         Box::new(&*guest_role_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -20,8 +20,10 @@ use beginners::{
     user_endpoints as no_role_user_endpoints,
 };
 use gateway_manager::{
+    circuit_breaker_endpoints as gateway_manager_circuit_breaker_endpoints,
     route_endpoints as gateway_manager_route_endpoints,
     service_endpoints as gateway_manager_service_endpoints,
+    service_health_endpoints as gateway_manager_service_health_endpoints,
 };
 use guest_manager::{
     guest_role_endpoints as guest_manager_guest_role_endpoints,
@@ -117,6 +119,12 @@ pub(crate) fn configure(config: &mut web::ServiceConfig) {
             )
             .service(
                 web::scope(&format!("/{}", UrlGroup::Services))
+                    .configure(
+                        gateway_manager_service_health_endpoints::configure,
+                    )
+                    .configure(
+                        gateway_manager_circuit_breaker_endpoints::configure,
+                    )
                     .configure(gateway_manager_service_endpoints::configure),
             ),
         )
@@ -3,7 +3,7 @@ use crate::{
     endpoints::shared::PaginationParams,
     modules::{
         AccountFetchingModule, AccountRegistrationModule,
-        AccountUpdatingModule, WebHookFetchingModule,
+        AccountUpdatingModule, ProfileCachingModule, WebHookFetchingModule,
     },
 };
 
@@ -14,7 +14,7 @@ use myc_core::{
         dtos::{account::VerboseStatus, account_type::AccountType},
         entities::{
             AccountFetching, AccountRegistration, AccountUpdating,
-            WebHookFetching,
+            ProfileCaching, WebHookFetching,
         },
     },
     models::AccountLifeCycle,
@@ -443,6 +443,7 @@ pub async fn update_account_name_and_flags_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let account_id = path.into_inner();
 
@@ -457,6 +458,7 @@ pub async fn update_account_name_and_flags_url(
         body.is_default.to_owned(),
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
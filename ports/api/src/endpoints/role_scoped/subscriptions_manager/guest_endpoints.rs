@@ -4,7 +4,7 @@ use crate::{
         AccountFetchingModule, GuestRoleFetchingModule,
         GuestUserDeletionModule, GuestUserFetchingModule,
         GuestUserRegistrationModule, LicensedResourcesFetchingModule,
-        MessageSendingQueueModule,
+        MessageSendingQueueModule, ProfileCachingModule,
     },
 };
 
@@ -17,7 +17,7 @@ use myc_core::{
         entities::{
             AccountFetching, GuestRoleFetching, GuestUserDeletion,
             GuestUserFetching, GuestUserRegistration,
-            LicensedResourcesFetching, MessageSending,
+            LicensedResourcesFetching, MessageSending, ProfileCaching,
         },
     },
     models::AccountLifeCycle,
@@ -225,6 +225,7 @@ pub async fn guest_user_url(
         dyn GuestUserRegistration,
     >,
     message_sending_repo: Inject<MessageSendingQueueModule, dyn MessageSending>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (account_id, role_id) = path.to_owned();
 
@@ -247,6 +248,7 @@ pub async fn guest_user_url(
         Box::new(&*guest_role_fetching_repo),
         Box::new(&*guest_registration_repo),
         Box::new(&*message_sending_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -305,6 +307,7 @@ pub async fn uninvite_guest_url(
         GuestUserDeletionModule,
         dyn GuestUserDeletion,
     >,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let (account_id, role_id) = path.to_owned();
 
@@ -315,6 +318,7 @@ pub async fn uninvite_guest_url(
         role_id,
         info.email.to_owned(),
         Box::new(&*guest_user_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -1,11 +1,11 @@
 use crate::{
     dtos::{MyceliumProfileData, TenantData},
-    modules::AccountDeletionModule,
+    modules::{AccountDeletionModule, ProfileCachingModule},
 };
 
 use actix_web::{delete, web, Responder};
 use myc_core::{
-    domain::entities::AccountDeletion,
+    domain::entities::{AccountDeletion, ProfileCaching},
     use_cases::role_scoped::tenant_manager::delete_subscription_account,
 };
 use myc_http_tools::{
@@ -73,6 +73,7 @@ pub async fn delete_subscription_account_url(
     path: web::Path<Uuid>,
     profile: MyceliumProfileData,
     account_deletion_repo: Inject<AccountDeletionModule, dyn AccountDeletion>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let account_id = path.into_inner();
 
@@ -81,6 +82,7 @@ pub async fn delete_subscription_account_url(
         tenant.tenant_id().to_owned(),
         account_id,
         Box::new(&*account_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -1,16 +1,16 @@
 use crate::{
     dtos::{MyceliumProfileData, TenantData},
     modules::{
-        TenantDeletionModule, TenantFetchingModule, TenantUpdatingModule,
-        UserFetchingModule,
+        ProfileCachingModule, TenantDeletionModule, TenantFetchingModule,
+        TenantUpdatingModule, UserFetchingModule,
     },
 };
 
 use actix_web::{delete, post, web, HttpResponse, Responder};
 use myc_core::{
     domain::entities::{
-        TenantDeletion, TenantFetching, TenantOwnerConnection, TenantUpdating,
-        UserFetching,
+        ProfileCaching, TenantDeletion, TenantFetching, TenantOwnerConnection,
+        TenantUpdating, UserFetching,
     },
     use_cases::role_scoped::tenant_owner::{
         guest_tenant_owner, revoke_tenant_owner,
@@ -97,6 +97,7 @@ pub async fn guest_tenant_owner_url(
     profile: MyceliumProfileData,
     owner_fetching_repo: Inject<UserFetchingModule, dyn UserFetching>,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let email = match Email::from_string(body.email.to_owned()) {
         Ok(email) => email,
@@ -112,6 +113,7 @@ pub async fn guest_tenant_owner_url(
         tenant.tenant_id().to_owned(),
         Box::new(&*owner_fetching_repo),
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -165,6 +167,7 @@ pub async fn revoke_tenant_owner_url(
     profile: MyceliumProfileData,
     tenant_fetching_repo: Inject<TenantFetchingModule, dyn TenantFetching>,
     tenant_deletion_repo: Inject<TenantDeletionModule, dyn TenantDeletion>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let email = match Email::from_string(body.email.to_owned()) {
         Ok(email) => email,
@@ -180,6 +183,7 @@ pub async fn revoke_tenant_owner_url(
         tenant.tenant_id().to_owned(),
         Box::new(&*tenant_fetching_repo),
         Box::new(&*tenant_deletion_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
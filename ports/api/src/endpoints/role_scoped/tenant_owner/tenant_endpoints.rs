@@ -1,8 +1,11 @@
-use crate::{dtos::MyceliumProfileData, modules::TenantUpdatingModule};
+use crate::{
+    dtos::MyceliumProfileData,
+    modules::{ProfileCachingModule, TenantUpdatingModule},
+};
 
 use actix_web::{patch, web, Responder};
 use myc_core::{
-    domain::entities::TenantUpdating,
+    domain::entities::{ProfileCaching, TenantUpdating},
     use_cases::role_scoped::tenant_owner::{
         update_tenant_archiving_status, update_tenant_name_and_description,
         update_tenant_trashing_status, update_tenant_verifying_status,
@@ -104,6 +107,7 @@ pub async fn update_tenant_name_and_description_url(
     body: web::Json<UpdateTenantNameAndDescriptionBody>,
     profile: MyceliumProfileData,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_tenant_name_and_description(
         profile.to_profile(),
@@ -111,6 +115,7 @@ pub async fn update_tenant_name_and_description_url(
         body.name.to_owned(),
         body.description.to_owned(),
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -159,12 +164,14 @@ pub async fn update_tenant_archiving_status_url(
     body: web::Json<UpdateTenantArchivingBody>,
     profile: MyceliumProfileData,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_tenant_archiving_status(
         profile.to_profile(),
         path.into_inner(),
         body.archived.to_owned(),
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -213,12 +220,14 @@ pub async fn update_tenant_trashing_status_url(
     body: web::Json<UpdateTenantTrashingBody>,
     profile: MyceliumProfileData,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_tenant_trashing_status(
         profile.to_profile(),
         path.into_inner(),
         body.trashed.to_owned(),
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -267,12 +276,14 @@ pub async fn update_tenant_verifying_status_url(
     body: web::Json<UpdateTenantVerifyingBody>,
     profile: MyceliumProfileData,
     tenant_updating_repo: Inject<TenantUpdatingModule, dyn TenantUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match update_tenant_verifying_status(
         profile.to_profile(),
         path.into_inner(),
         body.verified.to_owned(),
         Box::new(&*tenant_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
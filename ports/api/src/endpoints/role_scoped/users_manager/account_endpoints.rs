@@ -1,11 +1,13 @@
 use crate::{
     dtos::MyceliumProfileData,
-    modules::{AccountFetchingModule, AccountUpdatingModule},
+    modules::{
+        AccountFetchingModule, AccountUpdatingModule, ProfileCachingModule,
+    },
 };
 
 use actix_web::{patch, web, Responder};
 use myc_core::{
-    domain::entities::{AccountFetching, AccountUpdating},
+    domain::entities::{AccountFetching, AccountUpdating, ProfileCaching},
     use_cases::role_scoped::users_manager::account::{
         change_account_activation_status, change_account_approval_status,
         change_account_archival_status,
@@ -85,6 +87,7 @@ pub async fn approve_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_approval_status(
         profile.to_profile(),
@@ -92,6 +95,7 @@ pub async fn approve_account_url(
         true,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -143,6 +147,7 @@ pub async fn disapprove_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_approval_status(
         profile.to_profile(),
@@ -150,6 +155,7 @@ pub async fn disapprove_account_url(
         false,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -201,6 +207,7 @@ pub async fn activate_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_activation_status(
         profile.to_profile(),
@@ -208,6 +215,7 @@ pub async fn activate_account_url(
         true,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -259,6 +267,7 @@ pub async fn deactivate_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_activation_status(
         profile.to_profile(),
@@ -266,6 +275,7 @@ pub async fn deactivate_account_url(
         false,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -316,6 +326,7 @@ pub async fn archive_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_archival_status(
         profile.to_profile(),
@@ -323,6 +334,7 @@ pub async fn archive_account_url(
         true,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -373,6 +385,7 @@ pub async fn unarchive_account_url(
     profile: MyceliumProfileData,
     account_fetching_repo: Inject<AccountFetchingModule, dyn AccountFetching>,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match change_account_archival_status(
         profile.to_profile(),
@@ -380,6 +393,7 @@ pub async fn unarchive_account_url(
         false,
         Box::new(&*account_fetching_repo),
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
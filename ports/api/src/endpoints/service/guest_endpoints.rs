@@ -3,6 +3,7 @@ use crate::{
     modules::{
         AccountRegistrationModule, GuestRoleFetchingModule,
         GuestUserRegistrationModule, MessageSendingQueueModule,
+        ProfileCachingModule,
     },
 };
 
@@ -12,7 +13,7 @@ use myc_core::{
         dtos::{account::Account, user::User},
         entities::{
             AccountRegistration, GuestRoleFetching, GuestUserRegistration,
-            MessageSending,
+            MessageSending, ProfileCaching,
         },
     },
     models::AccountLifeCycle,
@@ -123,6 +124,7 @@ pub async fn guest_to_default_account_url(
         dyn GuestUserRegistration,
     >,
     message_sending_repo: Inject<MessageSendingQueueModule, dyn MessageSending>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     let role_id = path.to_owned();
 
@@ -159,6 +161,7 @@ pub async fn guest_to_default_account_url(
         Box::new(&*guest_role_fetching_repo),
         Box::new(&*message_sending_repo),
         Box::new(&*guest_registration_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -1,8 +1,14 @@
-use crate::{dtos::MyceliumProfileData, modules::AccountUpdatingModule};
+use crate::{
+    dtos::MyceliumProfileData,
+    modules::{AccountUpdatingModule, ProfileCachingModule},
+};
 
 use actix_web::{patch, web, Responder};
 use myc_core::{
-    domain::{dtos::account_type::AccountType, entities::AccountUpdating},
+    domain::{
+        dtos::account_type::AccountType,
+        entities::{AccountUpdating, ProfileCaching},
+    },
     use_cases::super_users::staff::account::{
         downgrade_account_privileges, upgrade_account_privileges,
     },
@@ -111,6 +117,7 @@ pub async fn upgrade_account_privileges_url(
     body: web::Json<UpgradeAccountPrivilegesBody>,
     profile: MyceliumProfileData,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match upgrade_account_privileges(
         profile.to_profile(),
@@ -120,6 +127,7 @@ pub async fn upgrade_account_privileges_url(
             UpgradeTargetAccountType::Staff => AccountType::Staff,
         },
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
@@ -171,6 +179,7 @@ pub async fn downgrade_account_privileges_url(
     body: web::Json<DowngradeAccountPrivilegesBody>,
     profile: MyceliumProfileData,
     account_updating_repo: Inject<AccountUpdatingModule, dyn AccountUpdating>,
+    profile_caching_repo: Inject<ProfileCachingModule, dyn ProfileCaching>,
 ) -> impl Responder {
     match downgrade_account_privileges(
         profile.to_profile(),
@@ -180,6 +189,7 @@ pub async fn downgrade_account_privileges_url(
             DowngradeTargetAccountType::User => AccountType::User,
         },
         Box::new(&*account_updating_repo),
+        Box::new(&*profile_caching_repo),
     )
     .await
     {
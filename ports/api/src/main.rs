@@ -1,7 +1,9 @@
+mod access_log;
 mod api_docs;
 mod config;
 mod dtos;
 mod endpoints;
+mod metrics;
 mod middleware;
 mod models;
 mod modifiers;
@@ -10,6 +12,7 @@ mod otel;
 mod router;
 mod settings;
 
+use access_log::{write_access_log, AccessLogger};
 use actix_cors::Cors;
 use actix_web::{
     dev::Service,
@@ -18,11 +21,17 @@ use actix_web::{
 };
 use actix_web_opentelemetry::RequestTracing;
 use api_docs::ApiDoc;
-use awc::{error::HeaderValue, Client};
-use config::injectors::configure as configure_injection_modules;
+use awc::Client;
+use config::injectors::{
+    build_profile_caching_module, build_rate_limit_counting_module,
+    build_routes_fetching_module, configure as configure_injection_modules,
+};
 use core::panic;
 use endpoints::{
-    index::heath_check_endpoints,
+    index::{
+        heath_check_endpoints, jwks_endpoints,
+        metrics_endpoints::{self, MetricsToken},
+    },
     manager::{
         account_endpoints as manager_account_endpoints,
         guest_role_endpoints as manager_guest_role_endpoints,
@@ -37,24 +46,42 @@ use endpoints::{
     shared::insert_role_header,
     staff::account_endpoints as staff_account_endpoints,
 };
+use metrics::record_admin_request;
 use models::{
-    api_config::{LogFormat, LoggingTarget},
+    api_config::{
+        GrpcConfig, LogFormat, LoggingTarget, MetricsConfig, RoutesSource,
+    },
     config_handler::ConfigHandler,
 };
 use myc_config::{
     init_vault_config_from_file, optional_config::OptionalConfig,
 };
-use myc_core::{domain::dtos::http::Protocol, settings::init_in_memory_routes};
+use myc_core::{
+    domain::dtos::http::Protocol,
+    settings::{init_in_memory_routes, ROUTES},
+    use_cases::gateway::{
+        routes::{
+            refresh_allowed_hostnames, reload_config_from_database,
+            reload_config_from_yaml, seed_routes_from_yaml,
+        },
+        services::check_services_health,
+    },
+};
 use myc_http_tools::{
+    functions::ProfileSigner,
     providers::{azure_endpoints, google_endpoints},
     settings::DEFAULT_REQUEST_ID_KEY,
 };
+use myc_mem_db::repositories::RoutesFetchingMemDbRepo;
 use myc_notifier::{
     executor::consume_messages,
     repositories::MessageSendingSmtpRepository,
     settings::{init_queue_config_from_file, init_smtp_config_from_file},
 };
-use myc_prisma::repositories::connector::generate_prisma_client_of_thread;
+use myc_prisma::repositories::{
+    connector::generate_prisma_client_of_thread, RoutesFetchingSqlDbRepository,
+    RoutesRegistrationSqlDbRepository,
+};
 use oauth2::http::HeaderName;
 use openssl::{
     pkey::PKey,
@@ -63,24 +90,35 @@ use openssl::{
 };
 use opentelemetry::trace::TracerProvider;
 use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
 use otel::{metadata_from_headers, parse_otlp_headers_from_env};
 use reqwest::header::{
     ACCEPT, ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_METHODS,
     ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_LENGTH, CONTENT_TYPE,
 };
-use router::route_request;
-use settings::{ADMIN_API_SCOPE, GATEWAY_API_SCOPE, SUPER_USER_API_SCOPE};
+use router::{
+    ensure_request_id, route_request, serve_grpc_gateway, GrpcGateway,
+};
+use settings::{
+    ADMIN_API_SCOPE, DEFAULT_ALLOWED_SOURCES_RESOLVE_INTERVAL,
+    DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_ROUTES_WATCH_INTERVAL,
+    GATEWAY_API_SCOPE, SUPER_USER_API_SCOPE,
+};
 use std::{
-    path::PathBuf, process::id as process_id, str::FromStr, time::Duration,
+    path::PathBuf,
+    process::id as process_id,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
 };
-use tracing::{info, trace};
+use tokio::{fs::metadata, net::TcpListener};
+use tracing::{error, info, trace, warn};
 use tracing_actix_web::TracingLogger;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::{fmt, EnvFilter};
 use utoipa::OpenApi;
 use utoipa_redoc::{FileConfig, Redoc, Servable};
 use utoipa_swagger_ui::{oauth, Config, SwaggerUi};
-use uuid::Uuid;
 
 // ? ---------------------------------------------------------------------------
 // ? API fire elements
@@ -220,6 +258,14 @@ pub async fn main() -> std::io::Result<()> {
         .expect("Failed to install OpenTelemetry tracer")
         .tracer(name);
 
+        //
+        // The W3C trace context is extracted from the client requests and
+        // propagated to the upstream services and webhooks
+        //
+        opentelemetry::global::set_text_map_propagator(
+            TraceContextPropagator::new(),
+        );
+
         let telemetry_layer =
             tracing_opentelemetry::layer().with_tracer(tracer);
 
@@ -252,6 +298,25 @@ pub async fn main() -> std::io::Result<()> {
         };
     };
 
+    // ? -----------------------------------------------------------------------
+    // ? Configure the access log
+    //
+    // The access log records the gateway and administration API requests at
+    // its own target, separated from the application log.
+    //
+    // ? -----------------------------------------------------------------------
+    let (access_logger, _access_log_guard) =
+        match api_config.access_log.to_owned() {
+            None => (None, None),
+            Some(access_log_config) => {
+                info!("Initializing the access log");
+
+                let (logger, guard) = AccessLogger::new(access_log_config)?;
+
+                (Some(logger), Some(guard))
+            }
+        };
+
     // ? -----------------------------------------------------------------------
     // ? Routes should be used on API gateway
     //
@@ -260,8 +325,12 @@ pub async fn main() -> std::io::Result<()> {
     // memory and the gateway should know the routes during their execution.
     //
     // ? -----------------------------------------------------------------------
-    info!("Initializing routes");
-    init_in_memory_routes(Some(config.api.routes.clone())).await;
+    let routes_source = api_config.routes_source.to_owned().unwrap_or_default();
+
+    if routes_source == RoutesSource::Yaml {
+        info!("Initializing routes");
+        init_in_memory_routes(Some(config.api.routes.clone())).await;
+    }
 
     // ? -----------------------------------------------------------------------
     // ? Initialize vault configuration
@@ -308,6 +377,38 @@ pub async fn main() -> std::io::Result<()> {
 
     generate_prisma_client_of_thread(process_id()).await;
 
+    // ? -----------------------------------------------------------------------
+    // ? Load routes from the database
+    //
+    // When routes are stored in the database, the routes file is used as seed
+    // if the database contains no services. Then the stored routes are loaded
+    // into memory.
+    //
+    // ? -----------------------------------------------------------------------
+    if routes_source == RoutesSource::Database {
+        info!("Initializing routes from the database");
+
+        if let Err(err) = seed_routes_from_yaml(
+            config.api.routes.to_owned(),
+            config.core.account_life_cycle.to_owned(),
+            Box::new(&RoutesFetchingSqlDbRepository {}),
+            Box::new(&RoutesRegistrationSqlDbRepository {}),
+        )
+        .await
+        {
+            warn!("Routes file not used as seed: {err}");
+        }
+
+        if let Err(err) = reload_config_from_database(
+            config.core.account_life_cycle.to_owned(),
+            Box::new(&RoutesFetchingSqlDbRepository {}),
+        )
+        .await
+        {
+            panic!("Error on load routes from the database: {err}");
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Fire the scheduler
     // ? -----------------------------------------------------------------------
@@ -370,6 +471,297 @@ pub async fn main() -> std::io::Result<()> {
         }
     });
 
+    // ? -----------------------------------------------------------------------
+    // ? Fire the downstream services health checker
+    //
+    // Services with a health check configuration are periodically checked. The
+    // gateway router uses the resulting health state to reject requests to
+    // unhealthy services without waiting for the gateway timeout. An interval
+    // of zero disables the health checker.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let health_check_interval = api_config
+        .health_check_interval
+        .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL);
+
+    if health_check_interval > 0 {
+        info!("Fire downstream services health checker");
+
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(Duration::from_secs(
+                health_check_interval,
+            ));
+
+            loop {
+                interval.tick().await;
+
+                if let Err(err) = check_services_health(Box::new(
+                    &RoutesFetchingMemDbRepo {},
+                ))
+                .await
+                {
+                    warn!("Error on check services health: {err}");
+                }
+            }
+        });
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Fire the allowed sources resolver
+    //
+    // Hostnames declared as route allowed sources are resolved when the routes
+    // are loaded. They are periodically resolved again, then address changes
+    // are applied without reloading the routes. An interval of zero disables
+    // the resolver.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let allowed_sources_resolve_interval = api_config
+        .allowed_sources_resolve_interval
+        .unwrap_or(DEFAULT_ALLOWED_SOURCES_RESOLVE_INTERVAL);
+
+    if allowed_sources_resolve_interval > 0 {
+        info!("Fire allowed sources resolver");
+
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(Duration::from_secs(
+                allowed_sources_resolve_interval,
+            ));
+
+            //
+            // The first tick completes immediately, and the hostnames were
+            // just resolved by the routes loading.
+            //
+            interval.tick().await;
+
+            loop {
+                interval.tick().await;
+                refresh_allowed_hostnames().await;
+            }
+        });
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Fire the routes watcher
+    //
+    // The routes file is reloaded when its modification time changes. When
+    // routes are stored in the database, they are periodically synced, then
+    // changes performed by other gateway instances are applied. Invalid routes
+    // are rejected and the current routes remain active.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let routes_watch_interval = api_config
+        .routes_watch_interval
+        .unwrap_or(DEFAULT_ROUTES_WATCH_INTERVAL);
+
+    if routes_watch_interval > 0 && routes_source == RoutesSource::Database {
+        info!("Fire routes database watcher");
+
+        let life_cycle_settings = config.core.account_life_cycle.to_owned();
+
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(Duration::from_secs(
+                routes_watch_interval,
+            ));
+
+            loop {
+                interval.tick().await;
+
+                if let Err(err) = reload_config_from_database(
+                    life_cycle_settings.to_owned(),
+                    Box::new(&RoutesFetchingSqlDbRepository {}),
+                )
+                .await
+                {
+                    error!("Error on sync routes: {err}");
+                }
+            }
+        });
+    } else if routes_watch_interval > 0 {
+        info!("Fire routes file watcher");
+
+        let routes_file = api_config.routes.to_owned();
+
+        actix_rt::spawn(async move {
+            let mut interval = actix_rt::time::interval(Duration::from_secs(
+                routes_watch_interval,
+            ));
+
+            let mut last_modified = metadata(&routes_file)
+                .await
+                .and_then(|meta| meta.modified())
+                .ok();
+
+            loop {
+                interval.tick().await;
+
+                let modified = metadata(&routes_file)
+                    .await
+                    .and_then(|meta| meta.modified())
+                    .ok();
+
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+
+                last_modified = modified;
+
+                info!("Routes file changed. Reloading routes");
+
+                if let Err(err) =
+                    reload_config_from_yaml(routes_file.to_owned()).await
+                {
+                    error!("Error on reload routes: {err}");
+                }
+            }
+        });
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Configure the Prometheus metrics exposition
+    //
+    // Metrics are served by a dedicated port or by the API server. Metrics
+    // served by the API server should be protected by a bearer token. The token
+    // is resolved after the vault configuration, allowing it to be stored in
+    // the vault.
+    //
+    // ? -----------------------------------------------------------------------
+    let metrics_token = match api_config.metrics.to_owned() {
+        None => None,
+        Some(MetricsConfig {
+            service_port: None,
+            token: None,
+        }) => panic!(
+            "Metrics should be served by a dedicated port or protected by a token"
+        ),
+        Some(MetricsConfig { token: None, .. }) => None,
+        Some(MetricsConfig {
+            token: Some(token), ..
+        }) => match token.async_get_or_error().await {
+            Ok(token) => Some(token),
+            Err(err) => panic!("Error on get metrics token: {err}"),
+        },
+    };
+
+    if let Some(MetricsConfig {
+        service_port: Some(port),
+        ..
+    }) = api_config.metrics.to_owned()
+    {
+        info!("Fire the metrics server on port {port}");
+
+        let metrics_token = metrics_token.to_owned();
+
+        let metrics_server = HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::new(MetricsToken(
+                    metrics_token.to_owned(),
+                )))
+                .configure(metrics_endpoints::configure)
+        })
+        .workers(1)
+        .bind((api_config.service_ip.to_owned(), port))?
+        .run();
+
+        actix_rt::spawn(metrics_server);
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Configure the profile signer
+    //
+    // Profiles injected into the downstream requests are signed, allowing
+    // downstream services to verify them offline. The private keys are
+    // resolved after the vault configuration, allowing them to be stored in
+    // the vault.
+    //
+    // Without the signer, the startup fails when the loaded routes inject
+    // profiles. Routes injecting profiles loaded later are rejected.
+    //
+    // ? -----------------------------------------------------------------------
+    info!("Configuring the profile signer");
+
+    let profile_signer = match api_config.profile_signing.to_owned() {
+        None => {
+            let injecting_routes = ROUTES
+                .lock()
+                .await
+                .iter()
+                .filter(|route| route.group.injects_profile())
+                .map(|route| route.path.to_owned())
+                .collect::<Vec<_>>();
+
+            if !injecting_routes.is_empty() {
+                panic!(
+                    "Profile signing not configured. It is required by the routes injecting profiles: {}",
+                    injecting_routes.join(", ")
+                );
+            }
+
+            warn!(
+                "Profile signing not configured. Requests to routes injecting profiles will be rejected"
+            );
+
+            None
+        }
+        Some(config) => match ProfileSigner::from_config(config).await {
+            Ok(signer) => Some(signer),
+            Err(err) => panic!("Error on configure the profile signer: {err}"),
+        },
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Configure the gRPC listener
+    //
+    // gRPC routes are served by a dedicated HTTP/2 listener, since the API
+    // server does not relay the HTTP/2 trailers carrying the gRPC status. The
+    // listener shares the routes, the rate limit counters, the profile cache,
+    // the profile signer and the access log with the API server.
+    //
+    // ? -----------------------------------------------------------------------
+    match api_config.grpc.to_owned() {
+        None => {
+            if ROUTES
+                .lock()
+                .await
+                .iter()
+                .any(|route| route.protocol == Protocol::Grpc)
+            {
+                warn!(
+                    "gRPC listener not configured. Requests to gRPC routes will not be served"
+                );
+            }
+        }
+        Some(GrpcConfig { service_port }) => {
+            info!("Fire the gRPC listener on port {service_port}");
+
+            let listener = TcpListener::bind((
+                api_config.service_ip.to_owned(),
+                service_port,
+            ))
+            .await?;
+
+            let gateway = GrpcGateway {
+                api_config: api_config.to_owned(),
+                auth_config: config.auth.to_owned(),
+                profile_signer: profile_signer.to_owned(),
+                routes_fetching_module: Arc::new(
+                    build_routes_fetching_module(),
+                ),
+                rate_limit_counting_module: Arc::new(
+                    build_rate_limit_counting_module(),
+                ),
+                profile_caching_module: Arc::new(build_profile_caching_module(
+                    config.core.profile_cache.to_owned(),
+                )),
+                access_logger: access_logger.to_owned(),
+            };
+
+            actix_rt::spawn(serve_grpc_gateway(listener, gateway));
+        }
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Configure the server
     // ? -----------------------------------------------------------------------
@@ -380,26 +772,36 @@ pub async fn main() -> std::io::Result<()> {
         let auth_config = config.auth.clone();
         let token_config = config.core.account_life_cycle.clone();
 
-        let cors = Cors::default()
-            .allowed_origin_fn(move |origin, _| {
-                local_api_config
-                    .allowed_origins
-                    .contains(&origin.to_str().unwrap_or("").to_string())
-            })
-            .expose_headers(vec![
-                ACCESS_CONTROL_ALLOW_CREDENTIALS,
-                ACCESS_CONTROL_ALLOW_METHODS,
-                ACCESS_CONTROL_ALLOW_ORIGIN,
-                CONTENT_LENGTH,
-                CONTENT_TYPE,
-                ACCEPT,
-                HeaderName::from_str(DEFAULT_REQUEST_ID_KEY).unwrap(),
-            ])
-            .allow_any_header()
-            .allow_any_method()
-            .max_age(3600);
-
-        trace!("Configured Cors: {:?}", cors);
+        //
+        // The global CORS settings are applied to the health and the
+        // administration endpoints. Gateway routes apply their own policies,
+        // using the global settings as default.
+        //
+        let allowed_origins = local_api_config.allowed_origins.to_owned();
+
+        let build_cors = move || {
+            let allowed_origins = allowed_origins.to_owned();
+
+            Cors::default()
+                .allowed_origin_fn(move |origin, _| {
+                    allowed_origins
+                        .contains(&origin.to_str().unwrap_or("").to_string())
+                })
+                .expose_headers(vec![
+                    ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                    ACCESS_CONTROL_ALLOW_METHODS,
+                    ACCESS_CONTROL_ALLOW_ORIGIN,
+                    CONTENT_LENGTH,
+                    CONTENT_TYPE,
+                    ACCEPT,
+                    HeaderName::from_str(DEFAULT_REQUEST_ID_KEY).unwrap(),
+                ])
+                .allow_any_header()
+                .allow_any_method()
+                .max_age(3600)
+        };
+
+        trace!("Configured Cors: {:?}", build_cors());
 
         // ? -------------------------------------------------------------------
         // ? Configure base application
@@ -420,8 +822,51 @@ pub async fn main() -> std::io::Result<()> {
                     format!("/{}", endpoints::shared::UrlScope::Health)
                         .as_str(),
                 )
+                .wrap(build_cors())
                 .configure(heath_check_endpoints::configure),
-            );
+            )
+            //
+            // Profile signing keys
+            //
+            // The public keys of the profile signer are published to the
+            // downstream services.
+            //
+            .configure(|config| {
+                if let Some(signer) = &profile_signer {
+                    config
+                        .app_data(web::Data::new(signer.to_owned()))
+                        .configure(jwks_endpoints::configure);
+                }
+            });
+
+        //
+        // Access log
+        //
+        // The access logger is shared by the gateway and the administration
+        // API requests.
+        //
+        let app = match access_logger.to_owned() {
+            Some(logger) => app.app_data(web::Data::new(logger)),
+            None => app,
+        };
+
+        //
+        // Metrics
+        //
+        // Metrics are served by the API server only when no dedicated port is
+        // configured.
+        //
+        let app = match &local_api_config.metrics {
+            Some(MetricsConfig {
+                service_port: None,
+                ..
+            }) => app
+                .app_data(web::Data::new(MetricsToken(
+                    metrics_token.to_owned(),
+                )))
+                .configure(metrics_endpoints::configure),
+            _ => app,
+        };
 
         // ? -------------------------------------------------------------------
         // ? Configure base mycelium scope
@@ -584,17 +1029,14 @@ pub async fn main() -> std::io::Result<()> {
             // ? ---------------------------------------------------------------
             .wrap(NormalizePath::new(TrailingSlash::MergeOnly))
             // ? ---------------------------------------------------------------
-            // ? Configure CORS policies
-            // ? ---------------------------------------------------------------
-            .wrap(cors)
-            // ? ---------------------------------------------------------------
             // ? Configure Log elements
             // ? ---------------------------------------------------------------
             // These wrap create the basic log elements and exclude the health
-            // check route.
+            // check and the metrics routes.
             .wrap(
                 Logger::default()
                     .exclude_regex("/health/*")
+                    .exclude_regex("/metrics")
                     .exclude_regex("/doc/swagger/*")
                     .exclude_regex("/doc/redoc/*"),
             )
@@ -602,10 +1044,37 @@ pub async fn main() -> std::io::Result<()> {
             // ? Configure Injection modules
             // ? ---------------------------------------------------------------
             .configure(configure_injection_modules)
+            .app_data(Arc::new(build_profile_caching_module(
+                config.core.profile_cache.to_owned(),
+            )))
             // ? ---------------------------------------------------------------
             // ? Configure mycelium routes
+            //
+            // Requests are counted by route pattern at the administration API
+            // metrics and recorded at the access log.
             // ? ---------------------------------------------------------------
-            .service(mycelium_scope)
+            .service(mycelium_scope.wrap(build_cors()).wrap_fn(|req, srv| {
+                let started_at = Instant::now();
+                let response = srv.call(req);
+
+                async move {
+                    let response = response.await?;
+
+                    record_admin_request(
+                        response.request(),
+                        response.status(),
+                        started_at.elapsed(),
+                    );
+
+                    write_access_log(
+                        response.request(),
+                        response.response(),
+                        started_at.elapsed(),
+                    );
+
+                    Ok(response)
+                }
+            }))
             // ? ---------------------------------------------------------------
             // ? Configure API documentation
             // ? ---------------------------------------------------------------
@@ -641,17 +1110,11 @@ pub async fn main() -> std::io::Result<()> {
             .service(
                 web::scope(&format!("/{}", GATEWAY_API_SCOPE))
                     //
-                    // Inject a request ID to downstream services
+                    // Inject a request ID to downstream services, keeping the
+                    // ones sent by clients
                     //
                     .wrap_fn(|mut req, srv| {
-                        req.headers_mut().insert(
-                            HeaderName::from_str(DEFAULT_REQUEST_ID_KEY)
-                                .unwrap(),
-                            HeaderValue::from_str(
-                                Uuid::new_v4().to_string().as_str(),
-                            )
-                            .unwrap(),
-                        );
+                        ensure_request_id(req.headers_mut());
 
                         srv.call(req)
                     })
@@ -0,0 +1,215 @@
+use actix_web::{http::StatusCode, HttpMessage, HttpRequest};
+use awc::error::{ConnectError, SendRequestError};
+use lazy_static::lazy_static;
+use myc_core::{
+    domain::dtos::route::Route,
+    use_cases::gateway::services::collect_circuit_breakers,
+};
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec,
+    HistogramVec, IntCounterVec, IntGaugeVec,
+};
+use std::time::Duration;
+
+// ? ---------------------------------------------------------------------------
+// ? Prometheus metrics
+//
+// Metrics are registered at the default Prometheus registry, shared with the
+// core and adapters metrics, and exposed by the metrics endpoint.
+// ? ---------------------------------------------------------------------------
+
+lazy_static! {
+    static ref GATEWAY_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "myc_gateway_requests_total",
+        "The gateway requests by service, route, method and status",
+        &["service", "route", "method", "status"]
+    )
+    .expect("Unable to register the gateway requests metrics");
+    static ref GATEWAY_REQUEST_DURATION: HistogramVec =
+        register_histogram_vec!(
+        "myc_gateway_request_duration_seconds",
+        "The time to answer the gateway requests, up to the response headers",
+        &["service", "route", "method", "status"]
+    )
+        .expect("Unable to register the gateway latency metrics");
+    static ref GATEWAY_UPSTREAM_ERRORS: IntCounterVec =
+        register_int_counter_vec!(
+            "myc_gateway_upstream_errors_total",
+            "The errors on sending requests to the upstream services by class",
+            &["service", "class"]
+        )
+        .expect("Unable to register the upstream errors metrics");
+    static ref CIRCUIT_BREAKER_STATE: IntGaugeVec = register_int_gauge_vec!(
+        "myc_gateway_circuit_breaker_state",
+        "The circuit state of the downstream services (0: closed, 1: half-open, 2: open)",
+        &["service"]
+    )
+    .expect("Unable to register the circuit breaker metrics");
+    static ref ADMIN_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "myc_admin_requests_total",
+        "The administration API requests by route, method and status",
+        &["route", "method", "status"]
+    )
+    .expect("Unable to register the admin requests metrics");
+    static ref ADMIN_REQUEST_DURATION: HistogramVec = register_histogram_vec!(
+        "myc_admin_request_duration_seconds",
+        "The time to answer the administration API requests",
+        &["route", "method", "status"]
+    )
+    .expect("Unable to register the admin latency metrics");
+    static ref PROFILE_FETCH_DURATION: HistogramVec = register_histogram_vec!(
+        "myc_profile_fetch_duration_seconds",
+        "The time to fetch the profiles of the authenticated users",
+        &["outcome"]
+    )
+    .expect("Unable to register the profile fetch metrics");
+}
+
+/// The labels identifying the route of a gateway request
+///
+/// The labels are stored at the request extensions while forwarding, then
+/// collected once the response is built.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct RouteMetricLabels {
+    pub service: String,
+    pub route: String,
+}
+
+impl RouteMetricLabels {
+    pub(crate) fn new(service: &str, route: &Route) -> Self {
+        Self {
+            service: service.to_owned(),
+            route: route
+                .id
+                .map(|id| id.to_string())
+                .unwrap_or(route.path.to_owned()),
+        }
+    }
+}
+
+/// Record a gateway request
+///
+/// Requests not matching any route are labelled as `unmatched`.
+///
+pub(crate) fn record_gateway_request(
+    req: &HttpRequest,
+    status: StatusCode,
+    latency: Duration,
+) {
+    let labels = req.extensions().get::<RouteMetricLabels>().cloned();
+
+    observe_gateway_request(
+        labels.as_ref(),
+        req.method().as_str(),
+        status.as_str(),
+        latency,
+    );
+}
+
+/// Record a gRPC call served by the gRPC listener
+///
+/// gRPC calls are counted with the gateway requests, using the `POST` method.
+/// Calls not matching any route are labelled as `unmatched`.
+///
+pub(crate) fn record_grpc_request(
+    labels: Option<&RouteMetricLabels>,
+    status: u16,
+    latency: Duration,
+) {
+    observe_gateway_request(labels, "POST", &status.to_string(), latency);
+}
+
+fn observe_gateway_request(
+    labels: Option<&RouteMetricLabels>,
+    method: &str,
+    status: &str,
+    latency: Duration,
+) {
+    let (service, route) = match labels {
+        None => ("unmatched", "unmatched"),
+        Some(labels) => (labels.service.as_str(), labels.route.as_str()),
+    };
+
+    let values = [service, route, method, status];
+
+    GATEWAY_REQUESTS.with_label_values(&values).inc();
+    GATEWAY_REQUEST_DURATION
+        .with_label_values(&values)
+        .observe(latency.as_secs_f64());
+}
+
+/// Record an error on sending a request to an upstream service
+pub(crate) fn record_upstream_error(service: &str, err: &SendRequestError) {
+    let class = match err {
+        SendRequestError::Connect(
+            ConnectError::SslIsNotSupported | ConnectError::SslError(_),
+        ) => "tls",
+        SendRequestError::Connect(
+            ConnectError::Resolver(_)
+            | ConnectError::NoRecords
+            | ConnectError::Unresolved,
+        ) => "dns",
+        SendRequestError::Connect(ConnectError::Timeout) => "connect_timeout",
+        SendRequestError::Connect(_) => "connect",
+        SendRequestError::Timeout => "timeout",
+        SendRequestError::Url(_) => "url",
+        _ => "send",
+    };
+
+    record_upstream_error_class(service, class);
+}
+
+/// Record an error on sending a request to an upstream service by class
+///
+/// Used by the listeners which do not send requests through the gateway
+/// client, like the gRPC listener.
+///
+pub(crate) fn record_upstream_error_class(service: &str, class: &str) {
+    GATEWAY_UPSTREAM_ERRORS
+        .with_label_values(&[service, class])
+        .inc();
+}
+
+/// Record the circuit state of the downstream services
+///
+/// States are collected when the metrics are scraped. Services removed from
+/// the routes are no longer exported.
+///
+pub(crate) async fn record_circuit_breaker_states() {
+    let circuit_breakers = collect_circuit_breakers().await;
+
+    CIRCUIT_BREAKER_STATE.reset();
+
+    for breaker in circuit_breakers {
+        CIRCUIT_BREAKER_STATE
+            .with_label_values(&[breaker.service_name.as_str()])
+            .set(breaker.state.as_metric_value() as i64);
+    }
+}
+
+/// Record an administration API request
+///
+/// Requests are labelled by the matched route pattern, avoiding a label value
+/// for each resource id.
+///
+pub(crate) fn record_admin_request(
+    req: &HttpRequest,
+    status: StatusCode,
+    latency: Duration,
+) {
+    let route = req.match_pattern().unwrap_or("unmatched".to_string());
+    let values = [route.as_str(), req.method().as_str(), status.as_str()];
+
+    ADMIN_REQUESTS.with_label_values(&values).inc();
+    ADMIN_REQUEST_DURATION
+        .with_label_values(&values)
+        .observe(latency.as_secs_f64());
+}
+
+/// Record the time to fetch the profile of an authenticated user
+pub(crate) fn record_profile_fetch(outcome: &str, latency: Duration) {
+    PROFILE_FETCH_DURATION
+        .with_label_values(&[outcome])
+        .observe(latency.as_secs_f64());
+}
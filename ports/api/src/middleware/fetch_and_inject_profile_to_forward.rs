@@ -1,37 +1,119 @@
-use super::fetch_profile_from_request;
+use super::fetch_profile_from_headers;
+use crate::dtos::MyceliumProfileData;
 
-use actix_web::HttpRequest;
-use awc::ClientRequest;
-use myc_core::domain::dtos::route_type::PermissionedRoles;
-use myc_http_tools::{responses::GatewayError, settings::DEFAULT_PROFILE_KEY};
+use actix_web::http::header::HeaderMap;
+use myc_core::domain::{
+    dtos::{
+        profile::Profile, profile_projection::ProfileProjection, route::Route,
+        route_type::PermissionedRoles,
+    },
+    entities::ProfileCaching,
+};
+use myc_http_tools::{
+    functions::ProfileSigner,
+    models::auth_config::AuthConfig,
+    responses::GatewayError,
+    settings::{DEFAULT_PROFILE_KEY, DEFAULT_REQUEST_ID_KEY},
+};
 use reqwest::header::{HeaderName, HeaderValue};
-use std::str::FromStr;
-use tracing::warn;
-use uuid::Uuid;
+use tracing::{error, warn};
 
-/// Fetch profile from email and inject on client request
+/// The settings used to fetch and sign the injected profiles
+///
+/// Requests received by the actix server collect them from the application
+/// data. gRPC calls collect them from the gRPC listener state.
+///
+pub(crate) struct ProfileInjectionContext<'a> {
+    pub auth_config: &'a AuthConfig,
+    pub profile_caching_repo: &'a dyn ProfileCaching,
+    pub profile_signer: Option<&'a ProfileSigner>,
+}
+
+/// Fetch profile from email and inject on the forwarded headers
 ///
 /// Try to extract profile from email (these extracted from the bearer token)
 /// and, then find the profile from the email and inject profile into the
-/// forward request.
+/// forwarded headers.
 ///
 /// These use-case is usual over middleware or routers parts of the application.
+///
+/// The injected profile is signed as a compact JWS by the application profile
+/// signer. The route profile projection defines the injected profile parts,
+/// the profile encoding and the profile size limit. The profile is returned
+/// to be used by the router without verifying the signed profile.
+///
 #[tracing::instrument(name = "fetch_and_inject_profile_to_forward", skip_all)]
-pub async fn fetch_and_inject_profile_to_forward(
-    req: HttpRequest,
-    mut forwarded_req: ClientRequest,
-    tenant: Option<Uuid>,
+pub(crate) async fn fetch_and_inject_profile_to_forward(
+    headers: &HeaderMap,
+    forwarded_headers: &mut HeaderMap,
+    context: &ProfileInjectionContext<'_>,
     roles: Option<Vec<String>>,
     permissioned_roles: Option<PermissionedRoles>,
-) -> Result<ClientRequest, GatewayError> {
-    let profile = fetch_profile_from_request(
-        req,
-        tenant,
+    route: &Route,
+) -> Result<Profile, GatewayError> {
+    let profile = fetch_profile_from_headers(
+        headers,
+        context.auth_config,
+        context.profile_caching_repo,
+        None,
         roles.to_owned(),
         permissioned_roles.to_owned(),
     )
     .await?;
 
+    let profile_signer = match context.profile_signer {
+        Some(signer) => signer,
+        None => {
+            error!("Unable to inject the profile: profile signing is not configured");
+
+            return Err(GatewayError::InternalServerError(
+                "Profile signing is not configured. Please contact the system administrator.".to_string(),
+            ));
+        }
+    };
+
+    let request_id = headers
+        .get(DEFAULT_REQUEST_ID_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    let signed_profile = sign_profile_to_forward(
+        &profile,
+        profile_signer,
+        request_id,
+        roles,
+        permissioned_roles,
+        &route
+            .id
+            .map(|id| id.to_string())
+            .unwrap_or(route.path.to_owned()),
+        route.profile_projection.to_owned(),
+    )?;
+
+    forwarded_headers
+        .insert(HeaderName::from_static(DEFAULT_PROFILE_KEY), signed_profile);
+
+    Ok(profile.to_profile())
+}
+
+/// Check and sign the profile injected into the forwarded request
+///
+/// Profiles without licensed resources are rejected by routes protected by
+/// roles, unless they belong to managers or staffs.
+///
+/// The profile is signed by the gateway, allowing downstream services to
+/// verify it offline. The request id binds the profile to the forwarded
+/// request. The signed profile is returned as the profile header value.
+///
+fn sign_profile_to_forward(
+    profile: &MyceliumProfileData,
+    profile_signer: &ProfileSigner,
+    request_id: Option<String>,
+    roles: Option<Vec<String>>,
+    permissioned_roles: Option<PermissionedRoles>,
+    route_id: &str,
+    profile_projection: Option<ProfileProjection>,
+) -> Result<HeaderValue, GatewayError> {
     //
     // Permissioned roles have priority over the roles. Them, it should be
     // evaluated first.
@@ -58,20 +140,60 @@ pub async fn fetch_and_inject_profile_to_forward(
         }
     }
 
-    forwarded_req.headers_mut().insert(
-        HeaderName::from_str(DEFAULT_PROFILE_KEY).unwrap(),
-        match HeaderValue::from_str(
-            &serde_json::to_string(&profile.to_profile()).unwrap(),
-        ) {
-            Err(err) => {
-                warn!("err: {:?}", err.to_string());
-                return Err(GatewayError::InternalServerError(format!(
-                    "{err}"
-                )));
-            }
-            Ok(res) => res,
-        },
-    );
-
-    Ok(forwarded_req)
+    //
+    // Only the profile parts declared by the route are injected. Licensed
+    // resources are limited to the route roles.
+    //
+    let projection = profile_projection.unwrap_or_default();
+
+    let route_roles = roles.or(permissioned_roles.map(|permissioned_roles| {
+        permissioned_roles
+            .into_iter()
+            .map(|(role, _)| role)
+            .collect::<Vec<String>>()
+    }));
+
+    let signed_profile = match profile_signer.sign(
+        projection.project(profile.to_profile(), route_roles),
+        request_id,
+        projection.encoding(),
+    ) {
+        Ok(signed_profile) => signed_profile,
+        Err(err) => {
+            warn!("Unable to sign the profile: {err}");
+
+            return Err(GatewayError::InternalServerError(
+                "Unexpected error on sign the profile. Please contact the system administrator.".to_string(),
+            ));
+        }
+    };
+
+    //
+    // Upstream proxies could reject large headers. Then, profiles larger than
+    // the route limit are rejected before being forwarded. The limit is a
+    // route configuration, then the request fails as a bad gateway instead of
+    // an internal error.
+    //
+    if signed_profile.len() > projection.max_size() {
+        error!(
+            "The profile of account {} has {} bytes, exceeding the profile size limit of route {route_id} ({} bytes)",
+            profile.acc_id,
+            signed_profile.len(),
+            projection.max_size(),
+        );
+
+        return Err(GatewayError::BadGateway(format!(
+            "The profile ({} bytes) exceeds the profile size limit of the route ({} bytes). The route profile projection should include less profile parts or use the gzip encoding.",
+            signed_profile.len(),
+            projection.max_size(),
+        )));
+    }
+
+    match HeaderValue::from_str(&signed_profile) {
+        Err(err) => {
+            warn!("err: {:?}", err.to_string());
+            Err(GatewayError::InternalServerError(format!("{err}")))
+        }
+        Ok(res) => Ok(res),
+    }
 }
@@ -1,32 +1,57 @@
-use super::fetch_role_scoped_connection_string_from_request;
+use super::fetch_role_scoped_connection_string_from_headers;
 
-use actix_web::HttpRequest;
-use awc::ClientRequest;
+use actix_web::http::header::HeaderMap;
 use myc_core::domain::dtos::{
     native_error_codes::NativeErrorCodes, route_type::PermissionedRoles,
     token::RoleScopedConnectionString,
 };
 use myc_http_tools::{responses::GatewayError, settings::DEFAULT_SCOPE_KEY};
 use reqwest::header::{HeaderName, HeaderValue};
-use std::str::FromStr;
 use tracing::error;
 
+/// Check the role scoped connection string and inject it on the forwarded
+/// headers
+///
+/// Used by the requests received by the actix server and by the gRPC calls.
+///
 #[tracing::instrument(
     name = "fetch_and_inject_role_scoped_connection_string_to_forward",
     skip_all
 )]
-pub async fn fetch_and_inject_role_scoped_connection_string_to_forward(
-    req: HttpRequest,
-    mut forwarded_req: ClientRequest,
+pub(crate) async fn fetch_and_inject_role_scoped_connection_string_to_forward(
+    headers: &HeaderMap,
+    forwarded_headers: &mut HeaderMap,
     roles: Option<Vec<String>>,
     permissioned_roles: Option<PermissionedRoles>,
-) -> Result<ClientRequest, GatewayError> {
+) -> Result<(), GatewayError> {
+    let scope = fetch_role_scoped_connection_string_to_forward(
+        headers,
+        roles,
+        permissioned_roles,
+    )
+    .await?;
+
+    forwarded_headers.insert(HeaderName::from_static(DEFAULT_SCOPE_KEY), scope);
+
+    Ok(())
+}
+
+/// Check the role scoped connection string of the request headers
+///
+/// Returns the serialized connection string scope, injected into the
+/// forwarded request as the scope header.
+///
+async fn fetch_role_scoped_connection_string_to_forward(
+    headers: &HeaderMap,
+    roles: Option<Vec<String>>,
+    permissioned_roles: Option<PermissionedRoles>,
+) -> Result<HeaderValue, GatewayError> {
     // ? -----------------------------------------------------------------------
     // ? Extract the role scoped connection string
     // ? -----------------------------------------------------------------------
 
     let connection_string: RoleScopedConnectionString =
-        fetch_role_scoped_connection_string_from_request(req)
+        fetch_role_scoped_connection_string_from_headers(headers)
             .await?
             .connection_string()
             .to_owned();
@@ -74,23 +99,16 @@ pub async fn fetch_and_inject_role_scoped_connection_string_to_forward(
     }
 
     // ? -----------------------------------------------------------------------
-    // ? Inject the serialized connection string into the forwarded request
+    // ? Serialize the connection string scope
     // ? -----------------------------------------------------------------------
 
-    forwarded_req.headers_mut().insert(
-        HeaderName::from_str(DEFAULT_SCOPE_KEY).unwrap(),
-        match HeaderValue::from_str(
-            &serde_json::to_string(&connection_string.scope).unwrap(),
-        ) {
-            Err(err) => {
-                error!("err: {:?}", err.to_string());
-                return Err(GatewayError::InternalServerError(format!(
-                    "{err}"
-                )));
-            }
-            Ok(res) => res,
-        },
-    );
-
-    Ok(forwarded_req)
+    match HeaderValue::from_str(
+        &serde_json::to_string(&connection_string.scope).unwrap(),
+    ) {
+        Err(err) => {
+            error!("err: {:?}", err.to_string());
+            Err(GatewayError::InternalServerError(format!("{err}")))
+        }
+        Ok(res) => Ok(res),
+    }
 }
@@ -1,32 +1,41 @@
-use crate::dtos::MyceliumProfileData;
+use crate::{
+    access_log::AccessLogDetails, dtos::MyceliumProfileData,
+    metrics::record_profile_fetch, modules::ProfileCachingModule,
+};
 
-use actix_web::{error::ParseError, http::header::Header, web, HttpRequest};
-use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
+use actix_web::{error::ParseError, http::header::HeaderMap, web, HttpRequest};
 use jsonwebtoken::errors::ErrorKind;
 use jwt::{Header as JwtHeader, RegisteredClaims, Token};
 use myc_config::optional_config::OptionalConfig;
 use myc_core::{
-    domain::dtos::{email::Email, route_type::PermissionedRoles},
+    domain::{
+        dtos::{email::Email, route_type::PermissionedRoles},
+        entities::ProfileCaching,
+    },
     use_cases::service::profile::{fetch_profile_from_email, ProfileResponse},
 };
 use myc_http_tools::{
-    functions::decode_jwt_hs512,
-    models::{
-        auth_config::AuthConfig, internal_auth_config::InternalOauthConfig,
+    functions::{decode_jwt_hs512, parse_bearer_from_headers},
+    models::auth_config::AuthConfig,
+    providers::{
+        az_check_credentials_from_headers, gc_check_credentials_from_headers,
     },
-    providers::{az_check_credentials, gc_check_credentials},
     responses::GatewayError,
 };
 use myc_prisma::repositories::{
     LicensedResourcesFetchingSqlDbRepository, ProfileFetchingSqlDbRepository,
 };
+use shaku::HasComponent;
+use std::{sync::Arc, time::Instant};
 use tracing::{trace, warn};
 use uuid::Uuid;
 
 /// Try to populate profile to request header
 ///
 /// This function is auxiliary of the MyceliumProfileData struct used to extract
-/// the Mycelium Profile from the request on mycelium native APIs.
+/// the Mycelium Profile from the request on mycelium native APIs. Profiles are
+/// read from the profile cache when enabled. The time to fetch the profile is
+/// recorded at the profile fetching metrics.
 #[tracing::instrument(name = "fetch_profile_from_request", skip_all)]
 pub(crate) async fn fetch_profile_from_request(
     req: HttpRequest,
@@ -34,8 +43,42 @@ pub(crate) async fn fetch_profile_from_request(
     roles: Option<Vec<String>>,
     permissioned_roles: Option<PermissionedRoles>,
 ) -> Result<MyceliumProfileData, GatewayError> {
-    let email =
-        check_credentials_with_multi_identity_provider(req.clone()).await?;
+    let auth_config = get_auth_config(&req)?;
+    let profile_caching_module = get_profile_caching_module(&req)?;
+
+    let profile_caching_repo: &dyn ProfileCaching =
+        profile_caching_module.resolve_ref();
+
+    let profile = fetch_profile_from_headers(
+        req.headers(),
+        &auth_config,
+        profile_caching_repo,
+        tenant,
+        roles,
+        permissioned_roles,
+    )
+    .await?;
+
+    AccessLogDetails::record_account_id(&req, profile.acc_id);
+
+    Ok(profile)
+}
+
+/// Try to fetch the profile of the request credentials
+///
+/// Works as `fetch_profile_from_request`, collecting the credentials from the
+/// request headers. Used by the requests received out of the actix server,
+/// like the gRPC calls.
+#[tracing::instrument(name = "fetch_profile_from_headers", skip_all)]
+pub(crate) async fn fetch_profile_from_headers(
+    headers: &HeaderMap,
+    auth_config: &AuthConfig,
+    profile_caching_repo: &dyn ProfileCaching,
+    tenant: Option<Uuid>,
+    roles: Option<Vec<String>>,
+    permissioned_roles: Option<PermissionedRoles>,
+) -> Result<MyceliumProfileData, GatewayError> {
+    let email = check_credentials_from_headers(headers, auth_config).await?;
 
     if email.is_none() {
         return Err(GatewayError::Unauthorized(format!(
@@ -47,7 +90,9 @@ pub(crate) async fn fetch_profile_from_request(
         trace!("Email: {:?}", email.redacted_email());
     };
 
-    let profile = match fetch_profile_from_email(
+    let started_at = Instant::now();
+
+    let profile_response = fetch_profile_from_email(
         email.to_owned().unwrap(),
         None,
         tenant,
@@ -55,9 +100,20 @@ pub(crate) async fn fetch_profile_from_request(
         permissioned_roles,
         Box::new(&ProfileFetchingSqlDbRepository {}),
         Box::new(&LicensedResourcesFetchingSqlDbRepository {}),
+        Box::new(profile_caching_repo),
     )
-    .await
-    {
+    .await;
+
+    record_profile_fetch(
+        match &profile_response {
+            Err(_) => "error",
+            Ok(ProfileResponse::UnregisteredUser(_)) => "unregistered",
+            Ok(ProfileResponse::RegisteredUser(_)) => "registered",
+        },
+        started_at.elapsed(),
+    );
+
+    let profile = match profile_response {
         Err(err) => {
             warn!("Unexpected error on fetch profile from email: {err}");
 
@@ -91,10 +147,24 @@ pub(crate) async fn fetch_profile_from_request(
 pub async fn check_credentials_with_multi_identity_provider(
     req: HttpRequest,
 ) -> Result<Option<Email>, GatewayError> {
-    let issuer = parse_issuer_from_request(req.clone()).await?;
+    let auth_config = get_auth_config(&req)?;
+
+    check_credentials_from_headers(req.headers(), &auth_config).await
+}
+
+/// Check the request credentials from the request headers
+///
+/// Works as `check_credentials_with_multi_identity_provider`, without
+/// depending on the actix request.
+pub(crate) async fn check_credentials_from_headers(
+    headers: &HeaderMap,
+    auth_config: &AuthConfig,
+) -> Result<Option<Email>, GatewayError> {
+    let issuer = parse_issuer_from_headers(headers).await?;
     trace!("Issuer: {:?}", issuer);
 
-    discover_provider(issuer.to_owned().to_lowercase(), req).await
+    discover_provider(issuer.to_owned().to_lowercase(), headers, auth_config)
+        .await
 }
 
 /// Parse issuer from request
@@ -104,7 +174,14 @@ pub async fn check_credentials_with_multi_identity_provider(
 pub async fn parse_issuer_from_request(
     req: HttpRequest,
 ) -> Result<String, GatewayError> {
-    let auth = match Authorization::<Bearer>::parse(&req) {
+    parse_issuer_from_headers(req.headers()).await
+}
+
+/// Parse issuer from the request headers
+async fn parse_issuer_from_headers(
+    headers: &HeaderMap,
+) -> Result<String, GatewayError> {
+    let auth = match parse_bearer_from_headers(headers) {
         Err(err) => {
             return Err(GatewayError::Unauthorized(format!(
                 "Unexpected error on get bearer from request: {err}"
@@ -142,32 +219,21 @@ pub async fn parse_issuer_from_request(
 #[tracing::instrument(name = "discover_provider", skip_all)]
 async fn discover_provider(
     auth_provider: String,
-    req: HttpRequest,
+    headers: &HeaderMap,
+    auth_config: &AuthConfig,
 ) -> Result<Option<Email>, GatewayError> {
     let provider = if auth_provider.contains("sts.windows.net")
         || auth_provider.contains("azure-ad")
     {
         trace!("Checking credentials with Azure AD");
-        az_check_credentials(req).await
+        az_check_credentials_from_headers(headers).await
     } else if auth_provider.contains("google") {
         trace!("Checking credentials with Google OAuth2");
         //
-        // Try to extract authentication configurations from HTTP request.
-        //
-        let req_auth_config = req.app_data::<web::Data<AuthConfig>>();
-        //
-        // If Google OAuth2 config if not available the returns a Unauthorized.
-        //
-        if let None = req_auth_config {
-            return Err(GatewayError::Unauthorized(format!(
-                "Unable to extract Google auth config from request."
-            )));
-        }
-        //
         // If Google OAuth2 config if not available the returns a Unauthorized
         // response.
         //
-        let config = match req_auth_config.unwrap().google.clone() {
+        let config = match auth_config.google.clone() {
             OptionalConfig::Disabled => {
                 warn!(
                     "Users trying to request and the Google OAuth2 is disabled."
@@ -182,19 +248,16 @@ async fn discover_provider(
         //
         // Check if credentials are valid.
         //
-        gc_check_credentials(req, config).await
+        gc_check_credentials_from_headers(headers, config).await
     } else if auth_provider.contains("mycelium") {
         trace!("Checking credentials with Mycelium Auth");
         //
-        // Extract the internal OAuth2 configuration from the HTTP request. If
-        // the configuration is not available returns a InternalServerError
-        // response.
+        // Extract the internal OAuth2 configuration. If the configuration is
+        // not available returns a InternalServerError response.
         //
-        let req_auth_config = match req
-            .app_data::<web::Data<InternalOauthConfig>>()
-        {
-            Some(config) => config.jwt_secret.to_owned(),
-            None => {
+        let req_auth_config = match &auth_config.internal {
+            OptionalConfig::Enabled(config) => config.jwt_secret.to_owned(),
+            OptionalConfig::Disabled => {
                 return Err(GatewayError::InternalServerError(format!(
                         "Unexpected error on validate internal auth config. Please contact the system administrator."
                     )));
@@ -216,7 +279,7 @@ async fn discover_provider(
         // Extract the bearer from the request. If the bearer is not available
         // returns a Unauthorized response.
         //
-        let auth = match Authorization::<Bearer>::parse(&req) {
+        let auth = match parse_bearer_from_headers(headers) {
             Err(err) => match err {
                 ParseError::Header => {
                     return Err(GatewayError::Unauthorized(format!(
@@ -282,3 +345,27 @@ async fn discover_provider(
         }
     }
 }
+
+/// Get the authentication settings registered at the application
+pub(crate) fn get_auth_config(
+    req: &HttpRequest,
+) -> Result<web::Data<AuthConfig>, GatewayError> {
+    match req.app_data::<web::Data<AuthConfig>>() {
+        Some(config) => Ok(config.to_owned()),
+        None => Err(GatewayError::InternalServerError(
+            "Unexpected error on get the auth config. Please contact the system administrator.".to_string(),
+        )),
+    }
+}
+
+/// Get the profile caching module registered at the application
+pub(crate) fn get_profile_caching_module(
+    req: &HttpRequest,
+) -> Result<Arc<ProfileCachingModule>, GatewayError> {
+    match req.app_data::<Arc<ProfileCachingModule>>() {
+        Some(module) => Ok(module.to_owned()),
+        None => Err(GatewayError::InternalServerError(
+            "Unexpected error on get the profile cache. Please contact the system administrator.".to_string(),
+        )),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::dtos::MyceliumRoleScopedConnectionStringData;
 
-use actix_web::HttpRequest;
+use actix_web::{http::header::HeaderMap, HttpRequest};
 use myc_core::domain::{
     dtos::token::{MultiTypeMeta, RoleWithPermissionsScope},
     entities::TokenFetching,
@@ -18,6 +18,15 @@ use tracing::error;
 )]
 pub async fn fetch_role_scoped_connection_string_from_request(
     req: HttpRequest,
+) -> Result<MyceliumRoleScopedConnectionStringData, GatewayError> {
+    fetch_role_scoped_connection_string_from_headers(req.headers()).await
+}
+
+/// Fetch the role scoped connection string from the request headers
+///
+/// Used by the requests received out of the actix server, like the gRPC calls.
+pub(crate) async fn fetch_role_scoped_connection_string_from_headers(
+    headers: &HeaderMap,
 ) -> Result<MyceliumRoleScopedConnectionStringData, GatewayError> {
     // ? -----------------------------------------------------------------------
     // ? Fetch connection string from request header
@@ -28,7 +37,7 @@ pub async fn fetch_role_scoped_connection_string_from_request(
     // ? -----------------------------------------------------------------------
 
     let connection_string_header =
-        match req.headers().get(DEFAULT_CONNECTION_STRING_KEY) {
+        match headers.get(DEFAULT_CONNECTION_STRING_KEY) {
             Some(value) => value,
             None => {
                 return Err(GatewayError::Unauthorized(
@@ -1,8 +1,20 @@
+use crate::settings::DEFAULT_MAX_BUFFERED_BODY_SIZE;
+
+use actix_web::http::header::{
+    ACCEPT, ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_LENGTH, CONTENT_TYPE,
+};
 use myc_config::{
     load_config_from_file, optional_config::OptionalConfig,
     secret_resolver::SecretResolver,
 };
-use myc_core::domain::dtos::http::Protocol;
+use myc_core::domain::dtos::{
+    cors::CorsPolicy, forwarded::TrustedProxies, http::Protocol,
+};
+use myc_http_tools::{
+    models::profile_signing_config::ProfileSigningConfig,
+    settings::DEFAULT_REQUEST_ID_KEY,
+};
 use mycelium_base::utils::errors::{creation_err, MappedErrors};
 use serde::Deserialize;
 use std::path::PathBuf;
@@ -51,6 +63,103 @@ pub struct LoggingConfig {
     pub target: Option<LoggingTarget>,
 }
 
+/// The format of the access log entries
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessLogFormat {
+    /// One JSON object per line
+    Jsonl,
+
+    /// The common log format
+    ///
+    /// The gateway fields missing from the common log format are appended as
+    /// `key=value` pairs.
+    ///
+    Common,
+}
+
+/// The target of the access log entries
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessLogTarget {
+    Stdout,
+    File { path: String },
+}
+
+/// The access log settings
+///
+/// The access log records the gateway and administration API requests,
+/// separated from the application log.
+///
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessLogConfig {
+    pub format: AccessLogFormat,
+    pub target: AccessLogTarget,
+
+    /// The percentage of the public routes requests recorded
+    ///
+    /// Requests answered with error status codes are always recorded. Default
+    /// to `100`.
+    ///
+    pub public_routes_percentage: Option<u8>,
+}
+
+/// The Prometheus metrics exposition settings
+///
+/// Metrics should be served by a dedicated port or protected by a bearer
+/// token.
+///
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsConfig {
+    /// The port of the dedicated metrics server
+    ///
+    /// The server is bound to the service IP. Without it, metrics are served
+    /// by the API server at the `/metrics` path and the token is required.
+    ///
+    pub service_port: Option<u16>,
+
+    /// The bearer token required to scrape the metrics
+    pub token: Option<SecretResolver<String>>,
+}
+
+/// The gRPC listener settings
+///
+/// gRPC routes are served by a dedicated listener, accepting gRPC calls over
+/// HTTP/2 without TLS (h2c).
+///
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcConfig {
+    /// The port of the gRPC listener
+    ///
+    /// The listener is bound to the service IP.
+    ///
+    pub service_port: u16,
+}
+
+/// The source of the gateway routes
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RoutesSource {
+    /// Routes are loaded from the routes file
+    ///
+    /// The routes file is read-only. Gateway managers could not register,
+    /// update or delete routes through the API.
+    ///
+    #[default]
+    Yaml,
+
+    /// Routes are stored in the database
+    ///
+    /// Routes could be managed through the API and shared by several gateway
+    /// instances. The routes file is used as seed when the database contains
+    /// no services.
+    ///
+    Database,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiConfig {
@@ -59,9 +168,63 @@ pub struct ApiConfig {
     pub allowed_origins: Vec<String>,
     pub service_workers: i32,
     pub gateway_timeout: u64,
+    pub health_check_interval: Option<u64>,
+
+    /// The interval in seconds between the allowed source hostnames
+    /// resolutions
+    ///
+    /// Hostnames declared as route allowed sources are resolved when the
+    /// routes are loaded, then resolved again at this interval. Set to `0` to
+    /// resolve them only when the routes are loaded. Default to `300`.
+    ///
+    pub allowed_sources_resolve_interval: Option<u64>,
+
+    /// The size limit of the request bodies buffered by the gateway in bytes
+    ///
+    /// Request bodies are buffered to be sent again to the failover targets
+    /// and retries. Larger bodies are streamed to the first target, without
+    /// failover and retries. Default to `1048576`.
+    ///
+    pub max_buffered_body_size_in_bytes: Option<usize>,
     pub logging: LoggingConfig,
+
+    /// The access log settings
+    ///
+    /// If empty, requests are not recorded at the access log.
+    ///
+    pub access_log: Option<AccessLogConfig>,
+
+    /// The Prometheus metrics exposition settings
+    ///
+    /// If empty, metrics are not exposed.
+    ///
+    pub metrics: Option<MetricsConfig>,
+
+    /// The gRPC listener settings
+    ///
+    /// If empty, the gRPC routes are not served.
+    ///
+    pub grpc: Option<GrpcConfig>,
+
+    /// The profile signing settings
+    ///
+    /// Profiles injected into the downstream requests are signed by the
+    /// configured keys, which are published at the `/.well-known/jwks.json`
+    /// path. If empty, requests to routes injecting profiles are rejected.
+    ///
+    pub profile_signing: Option<ProfileSigningConfig>,
     pub routes: String,
+    pub routes_source: Option<RoutesSource>,
+    pub routes_watch_interval: Option<u64>,
     pub tls: OptionalConfig<TlsConfig>,
+
+    /// The proxies trusted to declare the forwarding headers
+    ///
+    /// IP addresses and CIDR ranges of the proxies in front of the gateway,
+    /// like ingress controllers. Default to no trusted proxies.
+    ///
+    #[serde(default)]
+    pub trusted_proxies: TrustedProxies,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -87,4 +250,38 @@ impl ApiConfig {
             Err(err) => Err(err),
         }
     }
+
+    pub fn max_buffered_body_size(&self) -> usize {
+        self.max_buffered_body_size_in_bytes
+            .unwrap_or(DEFAULT_MAX_BUFFERED_BODY_SIZE)
+    }
+
+    /// The CORS policy of the gateway routes without their own policies
+    ///
+    /// The policy mirrors the CORS settings of the administration API, built
+    /// from the allowed origins.
+    ///
+    pub fn default_cors_policy(&self) -> CorsPolicy {
+        CorsPolicy {
+            allowed_origins: self.allowed_origins.to_owned(),
+            allowed_methods: None,
+            allowed_headers: None,
+            expose_headers: Some(
+                [
+                    ACCESS_CONTROL_ALLOW_CREDENTIALS.as_str(),
+                    ACCESS_CONTROL_ALLOW_METHODS.as_str(),
+                    ACCESS_CONTROL_ALLOW_ORIGIN.as_str(),
+                    CONTENT_LENGTH.as_str(),
+                    CONTENT_TYPE.as_str(),
+                    ACCEPT.as_str(),
+                    DEFAULT_REQUEST_ID_KEY,
+                ]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            ),
+            allow_credentials: None,
+            max_age_in_secs: None,
+        }
+    }
 }
@@ -6,6 +6,7 @@ mod guest_user;
 mod licensed_resource;
 mod message;
 mod profile;
+mod rate_limit;
 //mod role;
 mod route;
 mod tenant;
@@ -22,6 +23,7 @@ pub use guest_user::*;
 pub use licensed_resource::*;
 pub use message::*;
 pub use profile::*;
+pub use rate_limit::*;
 //pub use role::*;
 pub use route::*;
 pub use tenant::*;
@@ -1,3 +1,4 @@
+use myc_mem_db::repositories::ProfileCachingMemDbRepo;
 use myc_prisma::repositories::ProfileFetchingSqlDbRepository;
 use shaku::module;
 
@@ -7,3 +8,10 @@ module! {
         providers = []
     }
 }
+
+module! {
+    pub ProfileCachingModule {
+        components = [ProfileCachingMemDbRepo],
+        providers = []
+    }
+}
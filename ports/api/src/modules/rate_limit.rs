@@ -0,0 +1,9 @@
+use myc_notifier::repositories::RateLimitCountingRedisRepository;
+use shaku::module;
+
+module! {
+    pub RateLimitCountingModule {
+        components = [RateLimitCountingRedisRepository],
+        providers = []
+    }
+}
@@ -1,4 +1,8 @@
 use myc_mem_db::repositories::RoutesFetchingMemDbRepo;
+use myc_prisma::repositories::{
+    RoutesDeletionSqlDbRepository, RoutesFetchingSqlDbRepository,
+    RoutesRegistrationSqlDbRepository, RoutesUpdatingSqlDbRepository,
+};
 use shaku::module;
 
 module! {
@@ -7,3 +11,31 @@ module! {
         providers = []
     }
 }
+
+module! {
+    pub RoutesDatabaseFetchingModule {
+        components = [RoutesFetchingSqlDbRepository],
+        providers = []
+    }
+}
+
+module! {
+    pub RoutesRegistrationModule {
+        components = [RoutesRegistrationSqlDbRepository],
+        providers = []
+    }
+}
+
+module! {
+    pub RoutesUpdatingModule {
+        components = [RoutesUpdatingSqlDbRepository],
+        providers = []
+    }
+}
+
+module! {
+    pub RoutesDeletionModule {
+        components = [RoutesDeletionSqlDbRepository],
+        providers = []
+    }
+}
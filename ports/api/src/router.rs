@@ -1,39 +1,106 @@
-use super::middleware::fetch_and_inject_profile_to_forward;
+mod grpc;
+mod mirror;
+mod pipeline;
+mod websocket;
+
 use crate::{
-    middleware::fetch_and_inject_role_scoped_connection_string_to_forward,
-    models::api_config::ApiConfig, modules::RoutesFetchingModule,
-    settings::GATEWAY_API_SCOPE,
+    access_log::{write_access_log, AccessLogDetails},
+    metrics::{
+        record_gateway_request, record_upstream_error, RouteMetricLabels,
+    },
+    middleware::{
+        get_auth_config, get_profile_caching_module, ProfileInjectionContext,
+    },
+    models::api_config::ApiConfig,
+    modules::{RateLimitCountingModule, RoutesFetchingModule},
+    settings::{GATEWAY_API_SCOPE, MAX_REQUEST_ID_LENGTH},
 };
 
-use actix_web::{http::uri::PathAndQuery, web, HttpRequest, HttpResponse};
+use actix_web::{
+    error::PayloadError,
+    http::{
+        header::{
+            HeaderMap, HeaderName, HeaderValue, ACCESS_CONTROL_REQUEST_METHOD,
+            CONTENT_LENGTH, HOST, ORIGIN, RETRY_AFTER,
+        },
+        uri::{Authority, PathAndQuery},
+        Method, Uri,
+    },
+    web, HttpMessage, HttpRequest, HttpResponse, ResponseError,
+};
 use awc::{
     error::{ConnectError, SendRequestError},
-    Client,
+    Client, ClientRequest, Connector,
 };
+use futures::{stream, StreamExt};
+use mirror::{dispatch_mirror_request, PrimaryResult};
 use myc_config::optional_config::OptionalConfig;
 use myc_core::{
     domain::{
         dtos::{
-            http::{HttpMethod, Protocol},
+            cors::CorsPolicy,
+            forwarded::ForwardedChain,
+            http::HttpMethod,
             http_secret::HttpSecret,
-            route_type::RouteType,
+            profile::Profile,
+            rate_limit::{RateLimitDecision, RateLimitKey, RateLimitPolicy},
+            route::Route,
+            service::Service,
+            token::{ConnectionStringBean, RoleWithPermissionsScope},
+            traffic_split::StickyKey,
+            upstream::UpstreamTarget,
+        },
+        entities::{ProfileCaching, RateLimitCounting, RoutesFetching},
+    },
+    use_cases::gateway::{
+        routes::{check_rate_limit, match_forward_address},
+        services::{
+            register_circuit_breaker_result, select_upstream_targets,
+            start_upstream_request,
         },
-        entities::RoutesFetching,
     },
-    use_cases::gateway::routes::match_forward_address,
 };
 use myc_http_tools::{
+    functions::ProfileSigner,
     responses::GatewayError,
     settings::{
-        DEFAULT_PROFILE_KEY, DEFAULT_REQUEST_ID_KEY, FORWARDING_KEYS,
-        FORWARD_FOR_KEY,
+        DEFAULT_CONNECTION_STRING_KEY, DEFAULT_REQUEST_ID_KEY,
+        RATE_LIMIT_LIMIT_KEY, RATE_LIMIT_POLICY_KEY, RATE_LIMIT_REMAINING_KEY,
+        RATE_LIMIT_RESET_KEY,
     },
 };
-use mycelium_base::{dtos::Parent, entities::FetchResponseKind};
+use mycelium_base::{
+    dtos::Parent,
+    entities::{FetchManyResponseKind, FetchResponseKind},
+};
+use openssl::{
+    error::ErrorStack,
+    pkey::PKey,
+    ssl::{SslConnector, SslMethod},
+    x509::X509,
+};
+use pipeline::{
+    acquire_service_circuit, check_allowed_source, enforce_account_rate_limit,
+    enforce_request_rate_limit, filter_response_headers,
+    inject_caller_identity, inject_secret_header, prepare_forwarded_headers,
+    select_healthy_targets, solve_route_secret,
+};
+use shaku::HasComponent;
 use shaku_actix::Inject;
-use std::{str::FromStr, time::Duration};
+use std::{
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    net::IpAddr,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 use tracing::{error, trace, warn};
 use url::Url;
+use uuid::Uuid;
+use websocket::proxy_websocket;
+
+pub(crate) use grpc::{serve_grpc_gateway, GrpcGateway};
 
 /// Forward request to the client service.
 ///
@@ -41,14 +108,32 @@ use url::Url;
 /// service name exists and the current user has enough permissions to perform
 /// the desired action.
 ///
-/// TODO: This forwarded implementation is incomplete as it only handles the
-/// TODO: unofficial X-Forwarded-For header but not the official Forwarded
-/// TODO: one.
+/// The forwarding headers (`Forwarded` and `X-Forwarded-*`) sent to the
+/// downstream services extend the chain declared by the trusted proxies.
+///
+/// Routes splitting the traffic between backend services record the selected
+/// backend at the `myc.backend` field of the request span.
+///
+/// Cross-origin requests follow the CORS policy of the route, or the global
+/// CORS settings when the route and its service declare no policy.
+///
+/// Requests are counted at the gateway metrics, labelled by the service which
+/// received the request and the matched route, and recorded at the access log.
+///
+/// The request id is sent to the upstream services and back to the client.
+/// The W3C trace context of the request span is sent to the upstream services,
+/// connecting their spans to the gateway trace.
+///
+/// Requests sent by gRPC clients have the gateway errors answered as gRPC
+/// status codes. gRPC routes are served by the gRPC listener.
 ///
 #[tracing::instrument(
     name = "route_request", 
     skip_all,
-    fields(myc.requestId = tracing::field::Empty)
+    fields(
+        myc.requestId = tracing::field::Empty,
+        myc.backend = tracing::field::Empty
+    )
 )]
 pub(crate) async fn route_request(
     req: HttpRequest,
@@ -57,22 +142,106 @@ pub(crate) async fn route_request(
     api_config: web::Data<ApiConfig>,
     timeout: web::Data<u64>,
     routing_fetching_repo: Inject<RoutesFetchingModule, dyn RoutesFetching>,
+    rate_limit_counting_repo: Inject<
+        RateLimitCountingModule,
+        dyn RateLimitCounting,
+    >,
 ) -> Result<HttpResponse, GatewayError> {
-    let replace_path = &format!("/{}", GATEWAY_API_SCOPE);
+    let client_req = req.clone();
+    let cors_config = api_config.clone();
+    let started_at = Instant::now();
+
+    let response = forward_request(
+        req,
+        payload,
+        client,
+        api_config,
+        timeout,
+        routing_fetching_repo,
+        rate_limit_counting_repo,
+    )
+    .await;
+
+    record_gateway_request(
+        &client_req,
+        match &response {
+            Ok(response) => response.status(),
+            Err(err) => err.status_code(),
+        },
+        started_at.elapsed(),
+    );
+
+    let mut response = match response {
+        Ok(response) => response,
+        Err(err) => err.response_for(client_req.headers()),
+    };
+
+    //
+    // The request id is sent back to the client, including the gateway errors,
+    // allowing clients to correlate their requests with the gateway traces.
+    //
+    if let Some(request_id) = client_req.headers().get(DEFAULT_REQUEST_ID_KEY) {
+        response.headers_mut().insert(
+            HeaderName::from_static(DEFAULT_REQUEST_ID_KEY),
+            request_id.to_owned(),
+        );
+    }
+
+    write_access_log(&client_req, &response, started_at.elapsed());
+
+    //
+    // Responses to cross-origin requests receive the headers of the route CORS
+    // policy, including the gateway errors. Preflight requests are answered
+    // while forwarding.
+    //
+    let origin = match client_req
+        .headers()
+        .get(ORIGIN)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(origin) if !is_preflight_request(&client_req) => {
+            origin.to_string()
+        }
+        _ => return Ok(response),
+    };
+
+    let policy = client_req
+        .extensions()
+        .get::<CorsPolicy>()
+        .cloned()
+        .unwrap_or_else(|| cors_config.default_cors_policy());
+
+    policy.apply_response_headers(&origin, response.headers_mut());
+
+    Ok(response)
+}
+
+/// Authorize the request and stream it to the upstream targets
+async fn forward_request(
+    req: HttpRequest,
+    mut payload: web::Payload,
+    client: web::Data<Client>,
+    api_config: web::Data<ApiConfig>,
+    timeout: web::Data<u64>,
+    routing_fetching_repo: Inject<RoutesFetchingModule, dyn RoutesFetching>,
+    rate_limit_counting_repo: Inject<
+        RateLimitCountingModule,
+        dyn RateLimitCounting,
+    >,
+) -> Result<HttpResponse, GatewayError> {
+    let gateway_scope = &format!("/{}", GATEWAY_API_SCOPE);
 
     // ? -----------------------------------------------------------------------
     // ? Set the request id to the current span
     // ? -----------------------------------------------------------------------
 
-    let request_id =
-        if let Some(request_id) = req.headers().get(DEFAULT_REQUEST_ID_KEY) {
-            tracing::Span::current()
-                .record("myc.requestId", &Some(request_id.to_str().unwrap()));
-
-            Some(request_id.to_owned())
-        } else {
-            None
-        };
+    if let Some(request_id) = req
+        .headers()
+        .get(DEFAULT_REQUEST_ID_KEY)
+        .and_then(|request_id| request_id.to_str().ok())
+    {
+        tracing::Span::current().record("myc.requestId", request_id);
+    }
 
     // ? -----------------------------------------------------------------------
     // ? Try to match the forward address
@@ -84,13 +253,17 @@ pub(crate) async fn route_request(
 
     trace!("Discovering route for request");
 
-    let request_path = match PathAndQuery::from_str(
-        &req.uri()
-            .path()
-            .to_string()
-            .as_str()
-            .replace(replace_path, ""),
-    ) {
+    //
+    // Only the leading gateway scope is removed, then path segments matching
+    // the scope name are preserved.
+    //
+    let gateway_path = req.uri().path();
+    let gateway_path = match gateway_path.strip_prefix(gateway_scope.as_str()) {
+        Some(path) if path.is_empty() || path.starts_with('/') => path,
+        _ => gateway_path,
+    };
+
+    let request_path = match PathAndQuery::from_str(gateway_path) {
         Err(err) => {
             warn!("{:?}", err);
             return Err(GatewayError::BadRequest(String::from(
@@ -100,8 +273,23 @@ pub(crate) async fn route_request(
         Ok(res) => res,
     };
 
+    //
+    // Preflight requests are matched by the method of the actual request.
+    //
+    let is_preflight = is_preflight_request(&req);
+
+    let request_method = match is_preflight {
+        true => req
+            .headers()
+            .get(ACCESS_CONTROL_REQUEST_METHOD)
+            .and_then(|value| Method::from_bytes(value.as_bytes()).ok())
+            .unwrap_or(req.method().to_owned()),
+        false => req.method().to_owned(),
+    };
+
     let route = match match_forward_address(
         request_path.to_owned(),
+        HttpMethod::from_reqwest_method(request_method),
         Box::new(&*routing_fetching_repo),
     )
     .await
@@ -135,6 +323,60 @@ pub(crate) async fn route_request(
         },
     };
 
+    // ? -----------------------------------------------------------------------
+    // ? Answer the CORS preflight requests
+    //
+    // The route policy replaces the service one. Without both, the global CORS
+    // settings are used. The policy is stored at the request extensions, then
+    // applied to the response of the actual requests.
+    //
+    // ? -----------------------------------------------------------------------
+
+    if let Some(policy) = route.cors_policy() {
+        req.extensions_mut().insert(policy.to_owned());
+    }
+
+    if let Parent::Record(service) = &route.service {
+        req.extensions_mut()
+            .insert(RouteMetricLabels::new(&service.name, &route));
+    }
+
+    AccessLogDetails::record_route_type(&req, &route.group);
+
+    if is_preflight {
+        let policy = route
+            .cors_policy()
+            .cloned()
+            .unwrap_or_else(|| api_config.default_cors_policy());
+
+        let origin = req
+            .headers()
+            .get(ORIGIN)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        return match policy.preflight_headers(origin, req.headers()) {
+            None => {
+                trace!("Preflight request from {origin} rejected");
+
+                Err(GatewayError::Forbidden(String::from(
+                    "CORS request not allowed for this route",
+                )))
+            }
+            Some(headers) => {
+                let mut response = HttpResponse::Ok().finish();
+
+                for (name, value) in headers.iter() {
+                    response
+                        .headers_mut()
+                        .append(name.to_owned(), value.to_owned());
+                }
+
+                Ok(response)
+            }
+        };
+    }
+
     // ? -----------------------------------------------------------------------
     // ? Check if the method is allowed
     // ? -----------------------------------------------------------------------
@@ -160,6 +402,76 @@ pub(crate) async fn route_request(
         },
     }
 
+    // ? -----------------------------------------------------------------------
+    // ? Check if the request source is allowed
+    //
+    // The check is performed before the profile lookup to avoid unnecessary
+    // database queries.
+    //
+    // ? -----------------------------------------------------------------------
+
+    //
+    // The source is the real client address, resolved from the forwarding
+    // headers sent by the trusted proxies.
+    //
+    let forwarded_chain = ForwardedChain::new(
+        req.headers(),
+        req.peer_addr().map(|addr| addr.ip()),
+        match api_config.tls {
+            OptionalConfig::Enabled(_) => "https",
+            OptionalConfig::Disabled => "http",
+        },
+        req.headers()
+            .get(HOST)
+            .and_then(|host| host.to_str().ok())
+            .map(|host| host.to_string())
+            .or(req.uri().authority().map(|authority| authority.to_string())),
+        &api_config.trusted_proxies,
+    );
+
+    let source_ip = forwarded_chain.client_ip;
+
+    if let Some(client_ip) = source_ip {
+        AccessLogDetails::record_client_ip(&req, client_ip);
+    }
+
+    check_allowed_source(&route, source_ip)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the route rate limit by the request attributes
+    // ? -----------------------------------------------------------------------
+
+    let rate_limit = match enforce_request_rate_limit(
+        &route,
+        source_ip,
+        req.headers(),
+        Box::new(&*rate_limit_counting_repo),
+    )
+    .await
+    {
+        Err(response) => return Ok(response),
+        Ok(rate_limit) => rate_limit,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Select the upstream targets
+    // ? -----------------------------------------------------------------------
+
+    trace!("Selecting upstream targets");
+
+    let service = match route.service {
+        Parent::Record(ref service) => service,
+        Parent::Id(_) => {
+            error!("Service not found");
+
+            return Err(GatewayError::InternalServerError(String::from(
+                "Service not found",
+            )));
+        }
+    };
+
+    let mut targets = select_healthy_targets(service).await?;
+
     // ? -----------------------------------------------------------------------
     // ? Build the downstream URL address
     //
@@ -169,7 +481,7 @@ pub(crate) async fn route_request(
 
     trace!("Building downstream URL");
 
-    let registered_uri = match route.build_uri().await {
+    let registered_uri = match route.build_target_uri(&targets[0]).await {
         Err(err) => {
             warn!("{:?}", err);
             return Err(GatewayError::InternalServerError(format!("{err}")));
@@ -182,261 +494,173 @@ pub(crate) async fn route_request(
                 )));
             }
             Ok(mut url) => {
-                let service = match route.service {
-                    Parent::Record(ref service) => service,
-                    Parent::Id(_) => {
-                        error!("Service not found");
-
+                //
+                // The upstream path is built from the request path without the
+                // service name, applying the route rewrite rules.
+                //
+                match route.build_upstream_path(request_path.path()) {
+                    Err(err) => {
+                        warn!("{:?}", err);
                         return Err(GatewayError::InternalServerError(
-                            String::from("Service not found"),
+                            String::from("Invalid upstream path"),
                         ));
                     }
+                    Ok(path) => url.set_path(&path),
                 };
 
-                let name = service.name.to_owned();
-
-                url.set_path(
-                    req.uri()
-                        .path()
-                        .replace(replace_path, "")
-                        .replace(format!("/{name}").as_str(), "")
-                        .as_str(),
-                );
-
                 url.set_query(req.uri().query());
                 url
             }
         },
     };
 
-    let forwarded_req = client
+    //
+    // Timeouts declared by the route override the ones declared by the
+    // service. Without them, the gateway timeout is used.
+    //
+    let response_timeout = route
+        .response_timeout_in_secs()
+        .unwrap_or(*timeout.get_ref());
+
+    let mut client = match route.connect_timeout_in_secs() {
+        None => client.get_ref().to_owned(),
+        Some(connect_timeout) => {
+            get_client_with_connect_timeout(connect_timeout)
+        }
+    };
+
+    let mut forwarded_req = client
         .request_from(registered_uri.as_str(), req.head())
         .no_decompress()
-        .timeout(Duration::from_secs(*timeout.into_inner()));
+        .timeout(Duration::from_secs(response_timeout));
 
-    let mut forwarded_req = match req.head().peer_addr {
-        Some(addr) => forwarded_req
-            .insert_header((FORWARD_FOR_KEY, format!("{}", addr.ip()))),
-        None => forwarded_req,
-    };
+    prepare_forwarded_headers(
+        &route,
+        forwarded_req.headers_mut(),
+        &forwarded_chain,
+    )?;
 
     // ? -----------------------------------------------------------------------
     // ? Check authentication and get permissions
+    // ? -----------------------------------------------------------------------
+
+    let auth_config = get_auth_config(&req)?;
+    let profile_caching_module = get_profile_caching_module(&req)?;
+    let profile_caching_repo: &dyn ProfileCaching =
+        profile_caching_module.resolve_ref();
+    let profile_signer = req.app_data::<web::Data<ProfileSigner>>().cloned();
+
+    let profile = inject_caller_identity(
+        &route,
+        req.headers(),
+        forwarded_req.headers_mut(),
+        &ProfileInjectionContext {
+            auth_config: &auth_config,
+            profile_caching_repo,
+            profile_signer: profile_signer.as_deref(),
+        },
+    )
+    .await?;
+
+    if let Some(profile) = &profile {
+        AccessLogDetails::record_account_id(&req, profile.acc_id);
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Select the traffic split backend
     //
-    // Protected routes (RouteType::Protected) should include valid information
-    // of the user email. This step try to collect this information and fetch
-    // the user profile. Case email is valid but the user is not registered on
-    // the system, it returns a Forbidden response. Case the user was previously
-    // registered, then include the profile-pack into the header response to be
-    // collected by client service.
+    // The selection is performed after the authentication, allowing clients to
+    // be kept at the same backend by account and testers to be sent to a
+    // specific backend by role. The selected backend replaces the upstream
+    // targets of the route service. The remaining route and service settings,
+    // including secrets and header policies, are kept.
     //
     // ? -----------------------------------------------------------------------
 
-    trace!("Checking authentication and permissions");
+    let backend = select_split_backend(
+        &route,
+        service,
+        profile.to_owned(),
+        req.headers(),
+        Box::new(&*routing_fetching_repo),
+    )
+    .await;
 
-    match route.group.to_owned() {
-        //
-        // Public routes do not need any authentication or profile injection.
-        //
-        RouteType::Public => {
-            trace!("Route(Public): {path}", path = route.path);
+    if let Some(backend) = &backend {
+        trace!("Routing request to backend {}", backend.name);
 
-            ()
-        }
-        //
-        // Protected routes should include the full qualified user profile into
-        // the header
-        //
-        RouteType::Protected => {
-            trace!("Route(Protected): {path}", path = route.path);
-            //
-            // Try to populate profile from the request
-            //
-            forwarded_req = fetch_and_inject_profile_to_forward(
-                req,
-                forwarded_req,
-                None,
-                None,
-                None,
-            )
-            .await?;
-        }
-        //
-        // Protected routes should include the user profile filtered by roles
-        // into the header
-        //
-        RouteType::ProtectedByRoles { roles } => {
-            trace!("Route(ProtectedByRoles): {path}", path = route.path);
-            //
-            // Try to populate profile from the request filtering licensed
-            // resources by roles
-            //
-            forwarded_req = fetch_and_inject_profile_to_forward(
-                req,
-                forwarded_req,
-                None,
-                Some(roles),
-                None,
-            )
-            .await?;
-        }
-        //
-        // Protected routes should include the user profile filtered by roles
-        // and permissions into the header
-        //
-        RouteType::ProtectedByPermissionedRoles { permissioned_roles } => {
-            trace!(
-                "Route(ProtectedByPermissionedRoles): {path}",
-                path = route.path
-            );
-            //
-            // Try to populate profile from the request filtering licensed
-            // resources by roles and permissions
-            //
-            forwarded_req = fetch_and_inject_profile_to_forward(
-                req,
-                forwarded_req,
-                None,
-                None,
-                Some(permissioned_roles),
-            )
-            .await?;
-        }
-        //
-        // Protected routes by service token should include the users role which
-        // the service token is associated
-        //
-        RouteType::ProtectedByServiceTokenWithRole { roles } => {
-            trace!(
-                "Route(ProtectedByServiceTokenWithRole): {path}",
-                path = route.path
-            );
-            //
-            // Try to populate profile from the request filtering licensed
-            // resources by roles and permissions
-            //
-            forwarded_req =
-                fetch_and_inject_role_scoped_connection_string_to_forward(
-                    req,
-                    forwarded_req,
-                    Some(roles),
-                    None,
-                )
-                .await?;
-        }
-        //
-        // Protected routes by service token should include the users role which
-        // the service token is associated
-        //
-        RouteType::ProtectedByServiceTokenWithPermissionedRoles {
-            permissioned_roles,
-        } => {
-            trace!(
-                "Route(ProtectedByServiceTokenWithPermissionedRoles): {path}",
-                path = route.path
-            );
-            //
-            // Try to populate profile from the request filtering licensed
-            // resources by roles and permissions
-            //
-            forwarded_req =
-                fetch_and_inject_role_scoped_connection_string_to_forward(
-                    req,
-                    forwarded_req,
-                    None,
-                    Some(permissioned_roles),
-                )
-                .await?;
-        }
+        targets = select_healthy_targets(backend).await?;
+
+        forwarded_req = build_target_request(
+            &client,
+            &forwarded_req,
+            &targets[0],
+            response_timeout,
+        )?;
     }
 
+    let service = backend.as_ref().unwrap_or(service);
+
+    req.extensions_mut()
+        .insert(RouteMetricLabels::new(&service.name, &route));
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the route rate limit by the account
+    // ? -----------------------------------------------------------------------
+
+    let rate_limit = match enforce_account_rate_limit(
+        &route,
+        forwarded_chain.client_ip,
+        req.headers(),
+        profile.as_ref().map(|profile| profile.acc_id),
+        rate_limit,
+        Box::new(&*rate_limit_counting_repo),
+    )
+    .await
+    {
+        Err(response) => return Ok(response),
+        Ok(rate_limit) => rate_limit,
+    };
+
+    //
+    // Mirror requests are copied before injecting the route secrets, then the
+    // mirror service never receives the route credentials, including the
+    // client certificate identity.
+    //
+    let mirror_base = match &route.mirror {
+        None => None,
+        Some(_) => Some((
+            client.to_owned(),
+            build_target_request(
+                &client,
+                &forwarded_req,
+                &targets[0],
+                response_timeout,
+            )?,
+        )),
+    };
+
     // ? -----------------------------------------------------------------------
     // ? Build the downstream url if the address has match.
     //
     // Submit the request and stream the response to the requester.
     // ? -----------------------------------------------------------------------
 
-    trace!("Injecting downstream secret into request");
-
-    let route_secret = match route.solve_secret().await {
-        Err(err) => {
-            warn!("{:?}", err);
-            return Err(GatewayError::InternalServerError(format!("{err}")));
-        }
-        Ok(res) => res,
-    };
+    let route_secret = solve_route_secret(
+        &route,
+        matches!(api_config.tls, OptionalConfig::Enabled(_)),
+    )
+    .await?;
 
     let mut route_key = None;
 
     if let Some(secret) = route_secret {
-        let accept_insecure_routing =
-            route.accept_insecure_routing.unwrap_or(false);
-
-        //
-        // Check if the service supports TLS
-        //
-        if let OptionalConfig::Disabled = api_config.tls {
-            if !accept_insecure_routing {
-                error!("Secrets are only allowed for HTTPS routes");
-
-                return Err(GatewayError::InternalServerError(
-                    "Unexpected error on route request".to_string(),
-                ));
-            }
-        }
-
-        //
-        // Check if the route supports HTTPS
-        //
-        if ![Protocol::Https].contains(&route.protocol) {
-            if !accept_insecure_routing {
-                error!(
-                    "Secrets are only allowed for HTTPS routes: {path}",
-                    path = route.path
-                );
-
-                return Err(GatewayError::InternalServerError(
-                    "Unexpected error on route request".to_string(),
-                ));
-            }
-        }
+        route_key =
+            inject_secret_header(&secret, forwarded_req.headers_mut()).await?;
 
         match secret {
             //
-            // Insert the authorization key into the header
-            //
-            HttpSecret::AuthorizationHeader {
-                name,
-                prefix,
-                token,
-            } => {
-                //
-                // Build the bearer token
-                //
-                let mut bearer_token = prefix.unwrap_or("Bearer".to_string());
-                bearer_token.push_str(format!(" {}", token).as_str());
-                let bearer_name = name.unwrap_or("Authorization".to_string());
-                route_key = Some(bearer_name.to_owned());
-
-                //
-                // Remove any previous Authorization header that may exist
-                //
-                forwarded_req.headers_mut().remove(bearer_name.to_owned());
-                forwarded_req
-                    .headers_mut()
-                    .remove(bearer_name.to_lowercase().to_owned());
-                forwarded_req
-                    .headers_mut()
-                    .remove(bearer_name.to_uppercase().to_owned());
-
-                //
-                // Insert the new Authorization header
-                //
-                forwarded_req =
-                    forwarded_req.insert_header((bearer_name, bearer_token));
-            }
-            //
-            // Insert the query parameter into the header
+            // Insert the query parameter into the request
             //
             HttpSecret::QueryParameter { name, token } => {
                 forwarded_req =
@@ -450,6 +674,36 @@ pub(crate) async fn route_request(
                         Ok(res) => res,
                     };
             }
+            //
+            // Authenticate the gateway at the upstream using mutual TLS
+            //
+            // The request is rebuilt using a client with the certificate
+            // identity. Failover targets, retries and WebSocket connections use
+            // the same client.
+            //
+            HttpSecret::ClientCertificate {
+                cert_pem,
+                key_pem,
+                ca_bundle_pem,
+            } => {
+                client = get_client_with_certificate(
+                    route.connect_timeout_in_secs(),
+                    &cert_pem,
+                    &key_pem,
+                    ca_bundle_pem.as_deref(),
+                )?;
+
+                forwarded_req = build_target_request(
+                    &client,
+                    &forwarded_req,
+                    &targets[0],
+                    response_timeout,
+                )?;
+            }
+            //
+            // The remaining secrets were injected as headers
+            //
+            _ => (),
         }
     };
 
@@ -461,7 +715,302 @@ pub(crate) async fn route_request(
 
     trace!("Forwarding request to service");
 
-    let binding_response = match forwarded_req.send_stream(payload).await {
+    //
+    // Retries are only accepted for idempotent methods, unless the route
+    // explicitly accepts non-idempotent retries.
+    //
+    let retry_policy = route
+        .retry_policy(HttpMethod::from_reqwest_method(req.method().to_owned()))
+        .filter(|policy| policy.max_attempts > 1);
+
+    //
+    // Sampled requests are sent to the mirror service too. Requests declaring
+    // bodies larger than the mirror limit are not mirrored, avoiding to buffer
+    // them.
+    //
+    let mut mirror = None;
+
+    if let Some(route_mirror) = &route.mirror {
+        let declared_length = req
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or_default();
+
+        if !route.protocol.is_websocket()
+            && declared_length <= route_mirror.max_body_size()
+            && route_mirror.sample()
+        {
+            if let Some(mirror_service) = fetch_service_by_name(
+                &route_mirror.service,
+                Box::new(&*routing_fetching_repo),
+            )
+            .await
+            {
+                match select_upstream_targets(&mirror_service).await.first() {
+                    None => warn!(
+                        "Mirror {} has no healthy targets",
+                        mirror_service.name
+                    ),
+                    Some(target) => {
+                        mirror = Some((
+                            mirror_service.name.to_owned(),
+                            target.to_owned(),
+                            route_mirror.max_body_size(),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    //
+    // Requests to services with multiple upstream targets or retry policies
+    // have the body buffered up to the gateway limit, allowing it to be sent
+    // again to the failover targets and retries. Mirrored requests have the
+    // body buffered up to the mirror limit. Larger bodies are streamed to the
+    // first target, without failover, retries and mirroring. WebSocket
+    // connections are never buffered.
+    //
+    let is_replayable = targets.len() > 1 || retry_policy.is_some();
+
+    let buffer_size = [
+        is_replayable.then(|| api_config.max_buffered_body_size()),
+        mirror.as_ref().map(|(_, _, max_body_size)| *max_body_size),
+    ]
+    .into_iter()
+    .flatten()
+    .max();
+
+    let mut body_prefix = None;
+
+    let buffered_body = match buffer_size {
+        Some(max_size) if !route.protocol.is_websocket() => {
+            match read_request_body(&mut payload, max_size).await? {
+                (body, true) => Some(body),
+                (body, false) => {
+                    trace!(
+                        "Request body exceeds {max_size} bytes. Streaming it without failover, retries and mirroring"
+                    );
+
+                    body_prefix = Some(body);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let mirror = mirror.filter(|(_, _, max_body_size)| {
+        buffered_body
+            .as_ref()
+            .is_some_and(|body| body.len() <= *max_body_size)
+    });
+
+    //
+    // WebSocket routes upgrade the connection instead of streaming the
+    // request. Retries do not apply to them.
+    //
+    if route.protocol.is_websocket() {
+        return proxy_websocket(
+            &req,
+            payload,
+            &client,
+            &forwarded_req,
+            &targets,
+            service,
+            response_timeout,
+            route.idle_timeout_in_secs(),
+        )
+        .await;
+    }
+
+    //
+    // The target addresses are built before accepting the request at the
+    // service circuit, then the gateway errors never hold a circuit trial.
+    //
+    let target_uris = targets
+        .iter()
+        .map(|target| build_target_uri(forwarded_req.get_uri(), target))
+        .collect::<Result<Vec<Uri>, GatewayError>>()?;
+
+    //
+    // Requests dropped before the circuit result, like on client
+    // disconnections, release their trial.
+    //
+    let circuit_permit =
+        match acquire_service_circuit(service, req.headers()).await {
+            Err(response) => return Ok(response),
+            Ok(permit) => permit,
+        };
+
+    //
+    // The mirror request is dispatched before the primary one, then slow
+    // mirrors do not delay the client response.
+    //
+    let mirror_sender = match (mirror, mirror_base, &buffered_body) {
+        (
+            Some((mirror_service, target, _)),
+            Some((mirror_client, mirror_req)),
+            Some(body),
+        ) => {
+            trace!("Mirroring request to {mirror_service}");
+
+            build_target_request(
+                &mirror_client,
+                &mirror_req,
+                &target,
+                response_timeout,
+            )
+            .ok()
+            .map(|mirror_req| {
+                dispatch_mirror_request(
+                    mirror_req,
+                    body.to_owned(),
+                    mirror_service,
+                    target,
+                )
+            })
+        }
+        _ => None,
+    };
+
+    let started_at = Instant::now();
+
+    let send_result = match buffered_body {
+        None => {
+            let host = &targets[0].host;
+
+            let _upstream_request =
+                start_upstream_request(&service.name, host).await;
+
+            match body_prefix {
+                None => forwarded_req.send_stream(payload).await,
+                Some(prefix) => {
+                    forwarded_req
+                        .send_stream(
+                            stream::once(async move {
+                                Ok::<_, PayloadError>(prefix)
+                            })
+                            .chain(payload),
+                        )
+                        .await
+                }
+            }
+        }
+        Some(body) => {
+            let mut index = 0;
+            let mut failovers = 0;
+            let mut attempt = 1;
+
+            loop {
+                let target = &targets[index % targets.len()];
+
+                let target_req = build_request_to_uri(
+                    &client,
+                    &forwarded_req,
+                    target_uris[index % targets.len()].to_owned(),
+                    response_timeout,
+                );
+
+                let upstream_request =
+                    start_upstream_request(&service.name, &target.host).await;
+                let result = target_req.send_body(body.to_owned()).await;
+                drop(upstream_request);
+
+                index += 1;
+
+                match result {
+                    //
+                    // Connection errors fail over to the next target
+                    //
+                    Err(SendRequestError::Connect(err))
+                        if failovers + 1 < targets.len() =>
+                    {
+                        warn!(
+                            "Error on connect to {host}: {err}. Failing over to {next}",
+                            host = target.host,
+                            next = targets[index % targets.len()].host
+                        );
+
+                        failovers += 1;
+                    }
+                    //
+                    // Once the failover targets are exhausted, retryable
+                    // results are sent again until the policy attempts are
+                    // exhausted. Timeouts are never retried.
+                    //
+                    result => {
+                        let policy = match &retry_policy {
+                            Some(policy) if attempt < policy.max_attempts => {
+                                policy
+                            }
+                            _ => break result,
+                        };
+
+                        let should_retry = match &result {
+                            Err(SendRequestError::Connect(_)) => {
+                                policy.retry_on_connect_errors()
+                            }
+                            Ok(res) => {
+                                policy.retry_on_status(res.status().as_u16())
+                            }
+                            Err(_) => false,
+                        };
+
+                        if !should_retry {
+                            break result;
+                        }
+
+                        let delay = policy.delay_before_retry(attempt);
+
+                        warn!(
+                            "Retrying request to service {service} ({attempt}/{max_attempts}) in {delay}ms",
+                            service = service.name,
+                            attempt = attempt + 1,
+                            max_attempts = policy.max_attempts,
+                            delay = delay.as_millis()
+                        );
+
+                        actix_rt::time::sleep(delay).await;
+
+                        attempt += 1;
+                        failovers = 0;
+                    }
+                }
+            }
+        }
+    };
+
+    let upstream_latency = started_at.elapsed();
+
+    AccessLogDetails::record_upstream_latency(&req, upstream_latency);
+
+    if let Some(sender) = mirror_sender {
+        let _ = sender.send(PrimaryResult {
+            status: send_result.as_ref().ok().map(|res| res.status()),
+            latency: upstream_latency,
+        });
+    }
+
+    if let Err(err) = &send_result {
+        record_upstream_error(&service.name, err);
+    }
+
+    register_circuit_breaker_result(
+        circuit_permit,
+        match &send_result {
+            Err(err) => Some(format!("Error on route request: {err}")),
+            Ok(res) if res.status().is_server_error() => {
+                Some(format!("Unexpected status code: {}", res.status()))
+            }
+            Ok(_) => None,
+        },
+    )
+    .await;
+
+    let binding_response = match send_result {
         Err(err) => match err {
             SendRequestError::Connect(e) => {
                 match e {
@@ -488,6 +1037,13 @@ pub(crate) async fn route_request(
                     "Unexpected error on route request",
                 )));
             }
+            SendRequestError::Timeout => {
+                warn!("Timeout on route request to service {}", service.name);
+
+                return Err(GatewayError::GatewayTimeout(String::from(
+                    "Service did not respond in time",
+                )));
+            }
             SendRequestError::Url(e) => {
                 warn!("Error on route/url to service: {e}");
 
@@ -506,66 +1062,529 @@ pub(crate) async fn route_request(
         Ok(res) => res,
     };
 
-    let mut client_response = HttpResponse::build(binding_response.status());
+    // ! Remove the gateway internal headers
+    //
+    // The upstream headers are passed through keeping multiple values of the
+    // same header, like `Set-Cookie`. Then the internal headers are removed.
+    //
+    let upstream_headers = binding_response.headers().to_owned();
+
+    let mut client_response = HttpResponse::build(binding_response.status())
+        .streaming(binding_response);
 
-    if let Some(request_id) = request_id {
-        client_response
-            .insert_header((DEFAULT_REQUEST_ID_KEY, request_id.to_owned()));
+    let response_headers = client_response.headers_mut();
+
+    for (header_name, header_value) in upstream_headers.iter() {
+        response_headers
+            .append(header_name.to_owned(), header_value.to_owned());
     }
 
-    // ! Remove `Connection` as peer and forward service name
-    //
-    // https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Connection#Directives
-    //
-    // Both headers contain sensitive information about the system internals.
-    // Thus, be careful on edit this section.
+    filter_response_headers(&route, route_key, &rate_limit, response_headers)?;
 
-    //
-    // Start the headers with the route key if exists
-    //
-    let mut headers = if let Some(key) = route_key {
-        [key].to_vec().into_iter().collect::<Vec<String>>()
-    } else {
-        vec![]
+    trace!("Route request completed");
+
+    Ok(client_response)
+}
+
+/// Get a client using the given connect timeout
+///
+/// Clients are not thread-safe, then they are cached by worker thread and
+/// connect timeout, reusing their connection pools between requests.
+///
+fn get_client_with_connect_timeout(connect_timeout: u64) -> Client {
+    thread_local! {
+        static CLIENTS: RefCell<HashMap<u64, Client>> =
+            RefCell::new(HashMap::new());
+    }
+
+    CLIENTS.with(|clients| {
+        clients
+            .borrow_mut()
+            .entry(connect_timeout)
+            .or_insert_with(|| {
+                Client::builder()
+                    .connector(
+                        Connector::new()
+                            .timeout(Duration::from_secs(connect_timeout)),
+                    )
+                    .finish()
+            })
+            .to_owned()
+    })
+}
+
+/// Get a client authenticating with the given client certificate
+///
+/// Clients are cached by worker thread, connect timeout and certificate. The
+/// CA bundle certificates, if present, are trusted in addition to the system
+/// ones to verify the upstream certificates.
+///
+fn get_client_with_certificate(
+    connect_timeout: Option<u64>,
+    cert_pem: &str,
+    key_pem: &str,
+    ca_bundle_pem: Option<&str>,
+) -> Result<Client, GatewayError> {
+    thread_local! {
+        static CLIENTS: RefCell<HashMap<u64, Client>> =
+            RefCell::new(HashMap::new());
+    }
+
+    let mut hasher = DefaultHasher::new();
+    (connect_timeout, cert_pem, key_pem, ca_bundle_pem).hash(&mut hasher);
+    let client_key = hasher.finish();
+
+    if let Some(client) =
+        CLIENTS.with(|clients| clients.borrow().get(&client_key).cloned())
+    {
+        return Ok(client);
+    }
+
+    let ssl_connector =
+        match build_ssl_connector(cert_pem, key_pem, ca_bundle_pem) {
+            Ok(ssl_connector) => ssl_connector,
+            Err(err) => {
+                warn!("Invalid client certificate: {err}");
+
+                return Err(GatewayError::InternalServerError(String::from(
+                    "Unexpected error on route request",
+                )));
+            }
+        };
+
+    let connector = Connector::new().openssl(ssl_connector);
+
+    let client = Client::builder()
+        .connector(match connect_timeout {
+            None => connector,
+            Some(timeout) => connector.timeout(Duration::from_secs(timeout)),
+        })
+        .finish();
+
+    CLIENTS.with(|clients| {
+        clients.borrow_mut().insert(client_key, client.to_owned())
+    });
+
+    Ok(client)
+}
+
+/// Build a TLS connector with the given client certificate
+///
+/// The certificate PEM could include intermediate certificates after the
+/// client certificate.
+///
+fn build_ssl_connector(
+    cert_pem: &str,
+    key_pem: &str,
+    ca_bundle_pem: Option<&str>,
+) -> Result<SslConnector, ErrorStack> {
+    let mut builder = SslConnector::builder(SslMethod::tls())?;
+
+    let mut chain = X509::stack_from_pem(cert_pem.as_bytes())?.into_iter();
+
+    if let Some(certificate) = chain.next() {
+        builder.set_certificate(&certificate)?;
+    }
+
+    for certificate in chain {
+        builder.add_extra_chain_cert(certificate)?;
+    }
+
+    builder
+        .set_private_key(&PKey::private_key_from_pem(key_pem.as_bytes())?)?;
+    builder.check_private_key()?;
+
+    if let Some(ca_bundle_pem) = ca_bundle_pem {
+        for certificate in X509::stack_from_pem(ca_bundle_pem.as_bytes())? {
+            builder.cert_store_mut().add_cert(certificate)?;
+        }
+    }
+
+    builder.set_alpn_protos(b"\x02h2\x08http/1.1")?;
+
+    Ok(builder.build())
+}
+
+/// Build a copy of the forwarded request pointing to an upstream target
+///
+/// Sent requests could not be reused, then a copy is built for each failover
+/// and retry attempt.
+///
+fn build_target_request(
+    client: &Client,
+    forwarded_req: &ClientRequest,
+    target: &UpstreamTarget,
+    timeout: u64,
+) -> Result<ClientRequest, GatewayError> {
+    let uri = build_target_uri(forwarded_req.get_uri(), target)?;
+
+    Ok(build_request_to_uri(client, forwarded_req, uri, timeout))
+}
+
+/// Build a copy of the forwarded request pointing to an upstream uri
+fn build_request_to_uri(
+    client: &Client,
+    forwarded_req: &ClientRequest,
+    uri: Uri,
+    timeout: u64,
+) -> ClientRequest {
+    let mut target_req = client
+        .request(forwarded_req.get_method().to_owned(), uri)
+        .version(forwarded_req.get_version().to_owned())
+        .no_decompress()
+        .timeout(Duration::from_secs(timeout));
+
+    *target_req.headers_mut() = forwarded_req.headers().to_owned();
+
+    target_req
+}
+
+/// Build a copy of the forwarded uri pointing to an upstream target
+fn build_target_uri(
+    uri: &Uri,
+    target: &UpstreamTarget,
+) -> Result<Uri, GatewayError> {
+    let mut uri_parts = uri.to_owned().into_parts();
+
+    uri_parts.authority = match Authority::from_str(
+        target.host.split("/").next().unwrap_or_default(),
+    ) {
+        Err(err) => {
+            warn!("Invalid upstream target {}: {err}", target.host);
+
+            return Err(GatewayError::InternalServerError(String::from(
+                "Invalid upstream target",
+            )));
+        }
+        Ok(authority) => Some(authority),
     };
 
-    //
-    // Append the standard forwarding keys
-    //
-    headers.append(
-        &mut FORWARDING_KEYS
-            .to_vec()
-            .iter()
-            .map(|s| s.to_string())
-            .collect(),
+    match Uri::from_parts(uri_parts) {
+        Err(err) => {
+            warn!("Invalid upstream target {}: {err}", target.host);
+
+            Err(GatewayError::InternalServerError(String::from(
+                "Invalid upstream target",
+            )))
+        }
+        Ok(uri) => Ok(uri),
+    }
+}
+
+/// Build the response to requests rejected by the service circuit
+///
+/// The `Retry-After` header informs the time until the circuit accepts
+/// requests again.
+///
+fn build_circuit_open_response(
+    service: &Service,
+    retry_after: u64,
+    headers: &HeaderMap,
+) -> HttpResponse {
+    trace!(
+        "Request rejected by the circuit breaker of {}",
+        service.name
     );
 
-    //
-    // Append the default profile and forward for keys
-    //
-    headers.append(&mut vec![
-        FORWARD_FOR_KEY.to_string(),
-        DEFAULT_PROFILE_KEY.to_string(),
-    ]);
+    let mut response = GatewayError::ServiceUnavailable(format!(
+        "Service {} is currently unavailable",
+        service.name
+    ))
+    .response_for(headers);
 
-    //
-    // Filter the headers of the response before send it to the client
-    //
-    for (header_name, header_value) in
-        binding_response.headers().iter().filter(|(h, _)| {
-            headers
-                .to_owned()
+    if let Ok(value) = HeaderValue::from_str(&retry_after.to_string()) {
+        response.headers_mut().insert(RETRY_AFTER, value);
+    }
+
+    response
+}
+
+/// Count the request at the route rate limit
+///
+/// Returns the policy and the decision to be exposed at the response headers.
+/// Rejected requests receive a 429 response, including the time to wait
+/// before retrying.
+///
+async fn enforce_rate_limit(
+    route: &Route,
+    policy: RateLimitPolicy,
+    client_ip: Option<IpAddr>,
+    headers: &HeaderMap,
+    account_id: Option<Uuid>,
+    rate_limit_counting_repo: Box<&dyn RateLimitCounting>,
+) -> Result<Option<(RateLimitPolicy, RateLimitDecision)>, HttpResponse> {
+    let decision = match check_rate_limit(
+        route,
+        build_rate_limit_key(policy.key(), client_ip, headers, account_id),
+        rate_limit_counting_repo,
+    )
+    .await
+    {
+        None => return Ok(None),
+        Some(decision) => decision,
+    };
+
+    if !decision.allowed {
+        trace!(
+            "Request rejected by the rate limit of route {route_id}",
+            route_id = route
+                .id
+                .map(|id| id.to_string())
+                .unwrap_or(route.path.to_owned())
+        );
+
+        let mut response = GatewayError::TooManyRequests(String::from(
+            "Rate limit exceeded for this route",
+        ))
+        .response_for(headers);
+
+        insert_rate_limit_headers(response.headers_mut(), &policy, &decision);
+
+        return Err(response);
+    }
+
+    Ok(Some((policy, decision)))
+}
+
+/// Build the key used to count the requests of a rate limited route
+///
+/// Requests without the attribute expected by the policy key are counted by
+/// the real client IP. Connection string signatures are hashed, avoiding to store
+/// them in the counters store.
+///
+fn build_rate_limit_key(
+    key: RateLimitKey,
+    client_ip: Option<IpAddr>,
+    headers: &HeaderMap,
+    account_id: Option<Uuid>,
+) -> String {
+    let client_ip = format!(
+        "ip:{}",
+        client_ip
+            .map(|ip| ip.to_string())
+            .unwrap_or("unknown".to_string())
+    );
+
+    match key {
+        RateLimitKey::ClientIp => client_ip,
+        RateLimitKey::AccountId => account_id
+            .map(|account_id| format!("account:{account_id}"))
+            .unwrap_or(client_ip),
+        RateLimitKey::ConnectionString => headers
+            .get(DEFAULT_CONNECTION_STRING_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| {
+                RoleWithPermissionsScope::try_from(value.to_string()).ok()
+            })
+            .and_then(|scope| {
+                scope.get_scope_beans().into_iter().find_map(
+                    |bean| match bean {
+                        ConnectionStringBean::SIG(signature) => Some(signature),
+                        _ => None,
+                    },
+                )
+            })
+            .map(|signature| {
+                format!(
+                    "connection-string:{}",
+                    Uuid::new_v3(&Uuid::NAMESPACE_OID, signature.as_bytes())
+                )
+            })
+            .unwrap_or(client_ip),
+    }
+}
+
+/// Ensure the gateway request includes a request id
+///
+/// Ids sent by clients are kept, allowing them to correlate the gateway
+/// traces with their own logs. Missing ids, and ids which are too long or
+/// include characters other than letters, digits, `-`, `_`, `.` and `:`, are
+/// replaced by a new UUID.
+///
+pub(crate) fn ensure_request_id(headers: &mut HeaderMap) {
+    let is_valid = headers
+        .get(DEFAULT_REQUEST_ID_KEY)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|request_id| {
+            !request_id.is_empty()
+                && request_id.len() <= MAX_REQUEST_ID_LENGTH
+                && request_id.chars().all(|char| {
+                    char.is_ascii_alphanumeric() || "-_.:".contains(char)
+                })
+        });
+
+    if !is_valid {
+        if let Ok(request_id) =
+            HeaderValue::from_str(Uuid::new_v4().to_string().as_str())
+        {
+            headers.insert(
+                HeaderName::from_static(DEFAULT_REQUEST_ID_KEY),
+                request_id,
+            );
+        }
+    }
+}
+
+/// Check if the request is a CORS preflight request
+fn is_preflight_request(req: &HttpRequest) -> bool {
+    req.method() == Method::OPTIONS
+        && req.headers().contains_key(ORIGIN)
+        && req.headers().contains_key(ACCESS_CONTROL_REQUEST_METHOD)
+}
+
+/// Read the request body
+///
+/// Reading stops once the body exceeds the size limit. The returned flag
+/// indicates if the whole body was read.
+///
+async fn read_request_body(
+    payload: &mut web::Payload,
+    max_size: usize,
+) -> Result<(web::Bytes, bool), GatewayError> {
+    let mut body = web::BytesMut::new();
+
+    while let Some(chunk) = payload.next().await {
+        match chunk {
+            Err(err) => {
+                warn!("Error on read request body: {err}");
+
+                return Err(GatewayError::BadRequest(String::from(
+                    "Invalid request body",
+                )));
+            }
+            Ok(chunk) => body.extend_from_slice(&chunk),
+        }
+
+        if body.len() > max_size {
+            return Ok((body.freeze(), false));
+        }
+    }
+
+    Ok((body.freeze(), true))
+}
+
+/// Fetch a service by name
+///
+/// Services are resolved among the services of the loaded routes. Used to
+/// resolve the traffic split backends and the request mirrors.
+///
+async fn fetch_service_by_name(
+    name: &str,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Option<Service> {
+    let service = match routes_fetching_repo
+        .list_services(None, Some(name.to_string()))
+        .await
+    {
+        Ok(FetchManyResponseKind::Found(services)) => {
+            services.into_iter().next()
+        }
+        Ok(FetchManyResponseKind::FoundPaginated(paginated)) => {
+            paginated.records.into_iter().next()
+        }
+        Ok(FetchManyResponseKind::NotFound) => None,
+        Err(err) => {
+            warn!("Unable to fetch service {name}: {err}");
+
+            None
+        }
+    };
+
+    if service.is_none() {
+        warn!("Service {name} not found");
+    }
+
+    service
+}
+
+/// Select the traffic split backend of the route
+///
+/// Returns the backend replacing the route service. Requests kept at the route
+/// service, including the ones selecting backends which could not be found,
+/// return `None`.
+///
+async fn select_split_backend(
+    route: &Route,
+    service: &Service,
+    profile: Option<Profile>,
+    headers: &HeaderMap,
+    routes_fetching_repo: Box<&dyn RoutesFetching>,
+) -> Option<Service> {
+    let split = route.traffic_split.as_ref()?;
+
+    let sticky_value = match &split.sticky {
+        None => None,
+        Some(StickyKey::AccountId) => {
+            profile.as_ref().map(|profile| profile.acc_id.to_string())
+        }
+        Some(StickyKey::Header(name)) => headers
+            .get(name.as_str())
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string()),
+    };
+
+    let roles = profile
+        .and_then(|profile| profile.licensed_resources)
+        .map(|licenses| {
+            licenses
+                .to_licenses_vector()
                 .into_iter()
-                .map(|h| h.to_lowercase())
+                .map(|license| license.role)
                 .collect::<Vec<String>>()
-                .contains(&h.to_owned().to_string().to_lowercase())
         })
-    {
-        client_response
-            .insert_header((header_name.clone(), header_value.clone()));
+        .unwrap_or_default();
+
+    match split.select(sticky_value.as_deref(), &roles, headers) {
+        None => None,
+        Some(name) => {
+            tracing::Span::current().record("myc.backend", name);
+
+            match name == service.name {
+                true => None,
+                false => {
+                    let backend =
+                        fetch_service_by_name(name, routes_fetching_repo).await;
+
+                    if backend.is_none() {
+                        warn!(
+                            "Backend {name} not found. Routing request to {}",
+                            service.name
+                        );
+                    }
+
+                    backend
+                }
+            }
+        }
     }
+}
 
-    trace!("Route request completed");
+/// Insert the rate limit headers into a response
+///
+/// The `Retry-After` header is included only for rejected requests.
+///
+fn insert_rate_limit_headers(
+    headers: &mut HeaderMap,
+    policy: &RateLimitPolicy,
+    decision: &RateLimitDecision,
+) {
+    let mut values = vec![
+        (RATE_LIMIT_LIMIT_KEY, decision.limit.to_string()),
+        (RATE_LIMIT_REMAINING_KEY, decision.remaining.to_string()),
+        (RATE_LIMIT_RESET_KEY, decision.reset_in_secs().to_string()),
+        (RATE_LIMIT_POLICY_KEY, policy.to_header_value()),
+    ]
+    .into_iter()
+    .map(|(name, value)| (HeaderName::from_static(name), value))
+    .collect::<Vec<(HeaderName, String)>>();
 
-    Ok(client_response.streaming(binding_response))
+    if let Some(retry_after) = decision.retry_after_in_secs() {
+        values.push((RETRY_AFTER, retry_after.to_string()));
+    }
+
+    for (name, value) in values {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            headers.insert(name, value);
+        }
+    }
 }
@@ -0,0 +1,883 @@
+use super::{
+    ensure_request_id,
+    pipeline::{
+        acquire_service_circuit, check_allowed_source,
+        enforce_account_rate_limit, enforce_request_rate_limit,
+        filter_response_headers, inject_caller_identity, inject_secret_header,
+        prepare_forwarded_headers, select_healthy_targets, solve_route_secret,
+    },
+    select_split_backend,
+};
+use crate::{
+    access_log::{write_grpc_access_log, AccessLogDetails, AccessLogger},
+    metrics::{
+        record_grpc_request, record_upstream_error_class, RouteMetricLabels,
+    },
+    middleware::ProfileInjectionContext,
+    models::api_config::ApiConfig,
+    modules::{
+        ProfileCachingModule, RateLimitCountingModule, RoutesFetchingModule,
+    },
+};
+
+use actix_web::{
+    http::{
+        header::{
+            HeaderMap, HeaderName, HeaderValue, CONNECTION, HOST, TE,
+            TRANSFER_ENCODING, UPGRADE,
+        },
+        uri::PathAndQuery,
+    },
+    HttpResponse, ResponseError,
+};
+use http_body_util::{Either, Empty};
+use hyper::{
+    body::{Bytes, Incoming},
+    server::conn::http2,
+    service::service_fn,
+    Method, Request, Response, StatusCode, Uri, Version,
+};
+use hyper_util::{
+    client::legacy::{connect::HttpConnector, Client},
+    rt::{TokioExecutor, TokioIo},
+};
+use myc_core::{
+    domain::{
+        dtos::{forwarded::ForwardedChain, route::Route},
+        entities::{ProfileCaching, RateLimitCounting, RoutesFetching},
+    },
+    use_cases::gateway::{
+        routes::match_grpc_address,
+        services::{register_circuit_breaker_result, start_upstream_request},
+    },
+};
+use myc_http_tools::{
+    functions::ProfileSigner,
+    models::auth_config::AuthConfig,
+    responses::{is_grpc_request, GatewayError},
+    settings::DEFAULT_REQUEST_ID_KEY,
+};
+use mycelium_base::{dtos::Parent, entities::FetchResponseKind};
+use shaku::HasComponent;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    convert::Infallible,
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::net::TcpListener;
+use tracing::{error, trace, warn, Instrument};
+
+/// The body of the responses sent by the gRPC listener
+///
+/// Upstream bodies are relayed as received, including their trailers. Gateway
+/// errors are sent as trailers-only responses, without body.
+///
+type GrpcBody = Either<Incoming, Empty<Bytes>>;
+
+/// The client sending the gRPC calls to the upstream targets
+type GrpcClient = Client<HttpConnector, Incoming>;
+
+/// The state shared by the gRPC listener connections
+///
+/// The listener is served outside the API server, then the settings and the
+/// injection modules used by the gateway routes are passed explicitly.
+///
+pub(crate) struct GrpcGateway {
+    pub api_config: ApiConfig,
+    pub auth_config: AuthConfig,
+    pub profile_signer: Option<ProfileSigner>,
+    pub routes_fetching_module: Arc<RoutesFetchingModule>,
+    pub rate_limit_counting_module: Arc<RateLimitCountingModule>,
+    pub profile_caching_module: Arc<ProfileCachingModule>,
+    pub access_logger: Option<AccessLogger>,
+}
+
+/// The details of a gRPC call collected while it is routed
+///
+/// Recorded at the gateway metrics and at the access log once the response is
+/// built.
+///
+#[derive(Default)]
+struct GrpcCallDetails {
+    labels: Option<RouteMetricLabels>,
+    access_log: AccessLogDetails,
+}
+
+/// Executor spawning the HTTP/2 tasks at the current thread
+///
+/// The gateway use cases are not `Send`, then the connections and streams of
+/// the gRPC listener are driven by the actix runtime of the current thread.
+///
+#[derive(Clone, Copy)]
+struct LocalExecutor;
+
+impl<F> hyper::rt::Executor<F> for LocalExecutor
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    fn execute(&self, future: F) {
+        actix_rt::spawn(future);
+    }
+}
+
+/// Serve the gRPC routes
+///
+/// gRPC calls are accepted over HTTP/2 without TLS (h2c) and proxied to the
+/// upstream targets of the matched routes. The HTTP/2 frames are streamed in
+/// both directions and the upstream trailers, which carry the call status,
+/// are relayed to the client.
+///
+/// Calls are authorized as the gateway requests: the allowed sources, the rate
+/// limits, the route groups, the traffic splits, the route secrets and the
+/// circuit breakers are applied. The profile and the connection string are
+/// injected as gRPC metadata. Denied calls are answered with the gRPC status
+/// equivalent to the gateway error.
+///
+/// Calls are counted at the gateway metrics and recorded at the access log.
+///
+pub(crate) async fn serve_grpc_gateway(
+    listener: TcpListener,
+    gateway: GrpcGateway,
+) {
+    let gateway = Rc::new(gateway);
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(err) => {
+                warn!("Error on accept gRPC connection: {err}");
+
+                continue;
+            }
+        };
+
+        let gateway = gateway.clone();
+
+        actix_rt::spawn(async move {
+            let service = service_fn(move |req| {
+                let gateway = gateway.clone();
+
+                async move {
+                    Ok::<_, Infallible>(
+                        route_grpc_request(req, peer, &gateway).await,
+                    )
+                }
+            });
+
+            if let Err(err) = http2::Builder::new(LocalExecutor)
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                trace!("gRPC connection from {peer} closed: {err}");
+            }
+        });
+    }
+}
+
+/// Route a gRPC call to the upstream targets
+///
+/// The request id is sent to the upstream services and back to the client,
+/// including the gateway errors. The call is recorded at the access log as the
+/// gateway requests.
+///
+async fn route_grpc_request(
+    req: Request<Incoming>,
+    peer: SocketAddr,
+    gateway: &GrpcGateway,
+) -> Response<GrpcBody> {
+    let started_at = Instant::now();
+
+    let (parts, body) = req.into_parts();
+    let mut headers = to_gateway_headers(&parts.headers);
+
+    ensure_request_id(&mut headers);
+
+    let span = tracing::info_span!(
+        "route_grpc_request",
+        myc.requestId = tracing::field::Empty,
+        myc.backend = tracing::field::Empty
+    );
+
+    let mut details = GrpcCallDetails::default();
+
+    let result = forward_grpc_request(
+        parts.method.to_owned(),
+        parts.uri.to_owned(),
+        headers.to_owned(),
+        body,
+        peer.ip(),
+        gateway,
+        &mut details,
+    )
+    .instrument(span)
+    .await;
+
+    let (status, mut response) = match result {
+        Ok(response) => (response.status().as_u16(), response),
+        Err(err) => (
+            err.status_code().as_u16(),
+            to_grpc_response(err.grpc_error_response()),
+        ),
+    };
+
+    record_grpc_request(details.labels.as_ref(), status, started_at.elapsed());
+
+    let request_id = headers.get(DEFAULT_REQUEST_ID_KEY);
+
+    if let Some(request_id) = request_id {
+        if let Ok(value) =
+            hyper::header::HeaderValue::from_bytes(request_id.as_bytes())
+        {
+            response.headers_mut().insert(DEFAULT_REQUEST_ID_KEY, value);
+        }
+    }
+
+    if let Some(logger) = &gateway.access_logger {
+        write_grpc_access_log(
+            logger,
+            &parts,
+            request_id.and_then(|value| value.to_str().ok()),
+            &details.access_log,
+            details.labels.as_ref(),
+            status,
+            started_at.elapsed(),
+        );
+    }
+
+    response
+}
+
+/// Authorize the gRPC call and stream it to the upstream targets
+async fn forward_grpc_request(
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Incoming,
+    peer_ip: IpAddr,
+    gateway: &GrpcGateway,
+    details: &mut GrpcCallDetails,
+) -> Result<Response<GrpcBody>, GatewayError> {
+    if let Some(request_id) = headers
+        .get(DEFAULT_REQUEST_ID_KEY)
+        .and_then(|request_id| request_id.to_str().ok())
+    {
+        tracing::Span::current().record("myc.requestId", request_id);
+    }
+
+    let routes_fetching_repo: &dyn RoutesFetching =
+        gateway.routes_fetching_module.resolve_ref();
+
+    let rate_limit_counting_repo: &dyn RateLimitCounting =
+        gateway.rate_limit_counting_module.resolve_ref();
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the request is a gRPC call
+    //
+    // gRPC calls are always sent with the POST method and the gRPC content
+    // type.
+    //
+    // ? -----------------------------------------------------------------------
+
+    if method != Method::POST {
+        return Err(GatewayError::MethodNotAllowed(String::from(
+            "gRPC calls should use the POST method",
+        )));
+    }
+
+    if !is_grpc_request(&headers) {
+        return Err(GatewayError::BadRequest(String::from(
+            "Only gRPC calls are accepted by this listener",
+        )));
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Try to match the gRPC route
+    //
+    // gRPC calls are matched by the full call path, since gRPC clients do not
+    // include the service name.
+    //
+    // ? -----------------------------------------------------------------------
+
+    trace!("Discovering gRPC route for request");
+
+    let request_path = match PathAndQuery::from_str(uri.path()) {
+        Err(err) => {
+            warn!("{:?}", err);
+
+            return Err(GatewayError::BadRequest(String::from(
+                "Invalid request path",
+            )));
+        }
+        Ok(res) => res,
+    };
+
+    let route = match match_grpc_address(
+        request_path.to_owned(),
+        Box::new(routes_fetching_repo),
+    )
+    .await
+    {
+        Err(err) => {
+            warn!("{:?}", err);
+
+            return Err(GatewayError::InternalServerError(String::from(
+                "Invalid client service",
+            )));
+        }
+        Ok(FetchResponseKind::Found(route)) => route,
+        Ok(_) => {
+            return Err(GatewayError::BadRequest(String::from(
+                "Request path does not match any service",
+            )))
+        }
+    };
+
+    let service = match route.service {
+        Parent::Record(ref service) => service,
+        Parent::Id(_) => {
+            error!("Service not found");
+
+            return Err(GatewayError::InternalServerError(String::from(
+                "Service not found",
+            )));
+        }
+    };
+
+    details.labels = Some(RouteMetricLabels::new(&service.name, &route));
+    details.access_log.set_route_type(&route.group);
+
+    trace!(
+        "[ {request_path} ]: {service} -> {path}",
+        request_path = request_path.path(),
+        service = service.name,
+        path = route.path
+    );
+
+    // ? -----------------------------------------------------------------------
+    // ? Check if the request source is allowed
+    //
+    // The source is the real client address, resolved from the forwarding
+    // headers sent by the trusted proxies.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let forwarded_chain = ForwardedChain::new(
+        &headers,
+        Some(peer_ip),
+        "http",
+        uri.authority()
+            .map(|authority| authority.to_string())
+            .or(headers
+                .get(HOST)
+                .and_then(|host| host.to_str().ok())
+                .map(|host| host.to_string())),
+        &gateway.api_config.trusted_proxies,
+    );
+
+    let source_ip = forwarded_chain.client_ip;
+
+    if let Some(client_ip) = source_ip {
+        details.access_log.set_client_ip(client_ip);
+    }
+
+    check_allowed_source(&route, source_ip)?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the route rate limit by the request attributes
+    // ? -----------------------------------------------------------------------
+
+    let rate_limit = match enforce_request_rate_limit(
+        &route,
+        source_ip,
+        &headers,
+        Box::new(rate_limit_counting_repo),
+    )
+    .await
+    {
+        Err(response) => return Ok(to_grpc_response(response)),
+        Ok(rate_limit) => rate_limit,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Build the forwarded metadata
+    // ? -----------------------------------------------------------------------
+
+    let mut forwarded_headers = headers.to_owned();
+
+    prepare_forwarded_headers(
+        &route,
+        &mut forwarded_headers,
+        &forwarded_chain,
+    )?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Check authentication and get permissions
+    //
+    // The profile and the connection string are injected as gRPC metadata,
+    // using the same keys of the gateway requests.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let profile_caching_repo: &dyn ProfileCaching =
+        gateway.profile_caching_module.resolve_ref();
+
+    let profile = inject_caller_identity(
+        &route,
+        &headers,
+        &mut forwarded_headers,
+        &ProfileInjectionContext {
+            auth_config: &gateway.auth_config,
+            profile_caching_repo,
+            profile_signer: gateway.profile_signer.as_ref(),
+        },
+    )
+    .await?;
+
+    if let Some(profile) = &profile {
+        details.access_log.set_account_id(profile.acc_id);
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Select the traffic split backend and the upstream targets
+    // ? -----------------------------------------------------------------------
+
+    let backend = select_split_backend(
+        &route,
+        service,
+        profile.to_owned(),
+        &headers,
+        Box::new(routes_fetching_repo),
+    )
+    .await;
+
+    let service = backend.as_ref().unwrap_or(service);
+
+    details.labels = Some(RouteMetricLabels::new(&service.name, &route));
+
+    let targets = select_healthy_targets(service).await?;
+    let target = &targets[0];
+
+    // ? -----------------------------------------------------------------------
+    // ? Check the route rate limit by the account
+    // ? -----------------------------------------------------------------------
+
+    let rate_limit = match enforce_account_rate_limit(
+        &route,
+        source_ip,
+        &headers,
+        profile.as_ref().map(|profile| profile.acc_id),
+        rate_limit,
+        Box::new(rate_limit_counting_repo),
+    )
+    .await
+    {
+        Err(response) => return Ok(to_grpc_response(response)),
+        Ok(rate_limit) => rate_limit,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Inject the route secret
+    //
+    // gRPC upstreams are reached without TLS, then secrets are only sent to
+    // routes accepting insecure routing. Only the secrets sent as headers are
+    // supported.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let route_key = inject_route_secret(&route, &mut forwarded_headers).await?;
+
+    // ? -----------------------------------------------------------------------
+    // ? Send the call to the upstream
+    //
+    // The call is sent to the first selected target, without failover and
+    // retries, since the request body is streamed. Traffic split backends
+    // replace the targets of the route service.
+    //
+    // ? -----------------------------------------------------------------------
+
+    trace!("Forwarding gRPC call to service");
+
+    let upstream_uri = match Uri::from_str(&format!(
+        "http://{authority}{path}",
+        authority = target.host.split("/").next().unwrap_or_default(),
+        path = uri.path()
+    )) {
+        Err(err) => {
+            warn!("Invalid upstream target {}: {err}", target.host);
+
+            return Err(GatewayError::InternalServerError(String::from(
+                "Invalid upstream target",
+            )));
+        }
+        Ok(uri) => uri,
+    };
+
+    let mut upstream_req = Request::new(body);
+
+    *upstream_req.method_mut() = Method::POST;
+    *upstream_req.uri_mut() = upstream_uri;
+    *upstream_req.version_mut() = Version::HTTP_2;
+    *upstream_req.headers_mut() = to_grpc_headers(&forwarded_headers);
+
+    let response_timeout = route
+        .response_timeout_in_secs()
+        .unwrap_or(gateway.api_config.gateway_timeout);
+
+    let client = get_grpc_client(route.connect_timeout_in_secs());
+
+    let circuit_permit = match acquire_service_circuit(service, &headers).await
+    {
+        Err(response) => return Ok(to_grpc_response(response)),
+        Ok(permit) => permit,
+    };
+
+    let upstream_request =
+        start_upstream_request(&service.name, &target.host).await;
+
+    let upstream_started_at = Instant::now();
+
+    let send_result = actix_rt::time::timeout(
+        Duration::from_secs(response_timeout),
+        client.request(upstream_req),
+    )
+    .await;
+
+    details
+        .access_log
+        .set_upstream_latency(upstream_started_at.elapsed());
+
+    drop(upstream_request);
+
+    let failure = match &send_result {
+        Err(_) => Some(String::from("Timeout on route request")),
+        Ok(Err(err)) => Some(format!("Error on route request: {err}")),
+        Ok(Ok(res)) if res.status().is_server_error() => {
+            Some(format!("Unexpected status code: {}", res.status()))
+        }
+        Ok(Ok(_)) => None,
+    };
+
+    register_circuit_breaker_result(circuit_permit, failure).await;
+
+    let upstream_response = match send_result {
+        Err(_) => {
+            record_upstream_error_class(&service.name, "timeout");
+
+            warn!("Timeout on route request to service {}", service.name);
+
+            return Err(GatewayError::GatewayTimeout(String::from(
+                "Service did not respond in time",
+            )));
+        }
+        Ok(Err(err)) => {
+            record_upstream_error_class(
+                &service.name,
+                match err.is_connect() {
+                    true => "connect",
+                    false => "send",
+                },
+            );
+
+            warn!("Error on route/stream to service: {err}");
+
+            return Err(GatewayError::BadGateway(String::from(
+                "Unexpected error on route request",
+            )));
+        }
+        Ok(Ok(res)) => res,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Relay the upstream response
+    //
+    // The gateway internal headers are removed from the response headers. The
+    // body and the trailers are relayed as received.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let (parts, body) = upstream_response.into_parts();
+
+    let mut response_headers = to_gateway_headers(&parts.headers);
+
+    filter_response_headers(
+        &route,
+        route_key,
+        &rate_limit,
+        &mut response_headers,
+    )?;
+
+    let mut response = Response::new(Either::Left(body));
+
+    *response.status_mut() = parts.status;
+    *response.headers_mut() = to_grpc_headers(&response_headers);
+
+    trace!("gRPC call completed");
+
+    Ok(response)
+}
+
+/// Inject the route secret into the forwarded metadata
+///
+/// Returns the name of the header which contains the secret, removed from the
+/// upstream response. Query parameters and client certificates are not
+/// supported by gRPC routes.
+///
+async fn inject_route_secret(
+    route: &Route,
+    forwarded_headers: &mut HeaderMap,
+) -> Result<Option<String>, GatewayError> {
+    let secret = match solve_route_secret(route, false).await? {
+        None => return Ok(None),
+        Some(secret) => secret,
+    };
+
+    match inject_secret_header(&secret, forwarded_headers).await? {
+        Some(name) => Ok(Some(name)),
+        None => {
+            error!(
+                "Query parameter and client certificate secrets are not supported by gRPC routes: {path}",
+                path = route.path
+            );
+
+            Err(GatewayError::InternalServerError(
+                "Unexpected error on route request".to_string(),
+            ))
+        }
+    }
+}
+
+/// Get a gRPC client using the given connect timeout
+///
+/// Clients are cached by worker thread and connect timeout, reusing their
+/// HTTP/2 connections between calls.
+///
+fn get_grpc_client(connect_timeout: Option<u64>) -> GrpcClient {
+    thread_local! {
+        static CLIENTS: RefCell<HashMap<Option<u64>, GrpcClient>> =
+            RefCell::new(HashMap::new());
+    }
+
+    CLIENTS.with(|clients| {
+        clients
+            .borrow_mut()
+            .entry(connect_timeout)
+            .or_insert_with(|| {
+                let mut connector = HttpConnector::new();
+
+                connector.set_connect_timeout(
+                    connect_timeout.map(Duration::from_secs),
+                );
+
+                Client::builder(TokioExecutor::new())
+                    .http2_only(true)
+                    .build(connector)
+            })
+            .to_owned()
+    })
+}
+
+/// Convert the HTTP/2 headers to the gateway headers
+///
+/// The gateway use cases are built over the actix headers.
+///
+fn to_gateway_headers(headers: &hyper::HeaderMap) -> HeaderMap {
+    let mut gateway_headers = HeaderMap::new();
+
+    for (name, value) in headers.iter() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_str().as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            gateway_headers.append(name, value);
+        }
+    }
+
+    gateway_headers
+}
+
+/// Convert the gateway headers to HTTP/2 headers
+///
+/// Connection specific headers are not allowed by HTTP/2, then they are
+/// discarded. The `TE` header is only kept with the `trailers` value, which is
+/// required by the gRPC calls. The host is sent as the request authority.
+///
+fn to_grpc_headers(headers: &HeaderMap) -> hyper::HeaderMap {
+    let mut grpc_headers = hyper::HeaderMap::new();
+
+    for (name, value) in headers.iter() {
+        if [HOST, CONNECTION, TRANSFER_ENCODING, UPGRADE].contains(name)
+            || ["keep-alive", "proxy-connection"].contains(&name.as_str())
+            || (*name == TE && value != "trailers")
+        {
+            continue;
+        }
+
+        if let (Ok(name), Ok(value)) = (
+            hyper::header::HeaderName::from_bytes(name.as_str().as_bytes()),
+            hyper::header::HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            grpc_headers.append(name, value);
+        }
+    }
+
+    grpc_headers
+}
+
+/// Convert a gateway response without body to a gRPC response
+///
+/// Used to send the gateway errors, which are trailers-only responses.
+///
+fn to_grpc_response(response: HttpResponse) -> Response<GrpcBody> {
+    let mut grpc_response = Response::new(Either::Right(Empty::new()));
+
+    *grpc_response.status_mut() =
+        StatusCode::from_u16(response.status().as_u16())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    *grpc_response.headers_mut() = to_grpc_headers(response.headers());
+
+    grpc_response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use myc_config::secret_resolver::SecretResolver;
+    use myc_core::domain::dtos::{
+        http::{HttpMethod, Protocol},
+        http_secret::HttpSecret,
+        route_type::RouteType,
+        service::{Service, ServiceSecret},
+    };
+
+    fn build_route(secret: HttpSecret) -> Route {
+        let service = Service::new(
+            None,
+            "grpc-service".to_string(),
+            "localhost:50052".to_string(),
+            None,
+            None,
+            None,
+            vec![],
+            Some(vec![ServiceSecret::new(
+                "grpc-secret".to_string(),
+                SecretResolver::Value(secret),
+            )]),
+        );
+
+        Route::new(
+            None,
+            service,
+            RouteType::Public,
+            vec![HttpMethod::Post],
+            "/users.v1.UserService/*".to_string(),
+            Protocol::Grpc,
+            None,
+            Some("grpc-secret".to_string()),
+            Some(true),
+        )
+    }
+
+    #[test]
+    fn test_to_grpc_headers_drops_connection_headers() {
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in [
+            ("host", "localhost:50051"),
+            ("connection", "keep-alive"),
+            ("keep-alive", "timeout=5"),
+            ("proxy-connection", "keep-alive"),
+            ("transfer-encoding", "chunked"),
+            ("upgrade", "h2c"),
+            ("te", "trailers"),
+            ("content-type", "application/grpc"),
+        ] {
+            headers.append(
+                HeaderName::from_static(name),
+                HeaderValue::from_static(value),
+            );
+        }
+
+        let grpc_headers = to_grpc_headers(&headers);
+
+        for name in [
+            "host",
+            "connection",
+            "keep-alive",
+            "proxy-connection",
+            "transfer-encoding",
+            "upgrade",
+        ] {
+            assert!(!grpc_headers.contains_key(name), "{name} was kept");
+        }
+
+        assert_eq!(grpc_headers.get("te").unwrap(), "trailers");
+        assert_eq!(
+            grpc_headers.get("content-type").unwrap(),
+            "application/grpc"
+        );
+    }
+
+    #[test]
+    fn test_to_grpc_headers_drops_te_without_trailers() {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(TE, HeaderValue::from_static("gzip"));
+
+        assert!(!to_grpc_headers(&headers).contains_key("te"));
+    }
+
+    #[tokio::test]
+    async fn test_inject_route_secret_rejects_query_and_certificate_secrets() {
+        for secret in [
+            HttpSecret::QueryParameter {
+                name: "token".to_string(),
+                token: "secret-token".to_string(),
+            },
+            HttpSecret::ClientCertificate {
+                cert_pem: "cert".to_string(),
+                key_pem: "key".to_string(),
+                ca_bundle_pem: None,
+            },
+        ] {
+            let mut forwarded_headers = HeaderMap::new();
+
+            let result = inject_route_secret(
+                &build_route(secret),
+                &mut forwarded_headers,
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Err(GatewayError::InternalServerError(_))
+            ));
+            assert!(forwarded_headers.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inject_route_secret_inserts_header_secrets() {
+        let mut forwarded_headers = HeaderMap::new();
+
+        let route_key = inject_route_secret(
+            &build_route(HttpSecret::CustomHeader {
+                name: "x-api-key".to_string(),
+                token: "secret-token".to_string(),
+            }),
+            &mut forwarded_headers,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(route_key, Some("x-api-key".to_string()));
+        assert_eq!(forwarded_headers.get("x-api-key").unwrap(), "secret-token");
+    }
+}
@@ -0,0 +1,88 @@
+use actix_web::{http::StatusCode, web::Bytes};
+use awc::ClientRequest;
+use futures::channel::oneshot::{self, Sender};
+use myc_core::{
+    domain::dtos::upstream::UpstreamTarget,
+    use_cases::gateway::services::start_upstream_request,
+};
+use std::time::{Duration, Instant};
+use tracing::{info, trace, warn, Instrument};
+
+/// The result of the primary request compared with the mirror one
+pub(super) struct PrimaryResult {
+    pub status: Option<StatusCode>,
+    pub latency: Duration,
+}
+
+/// Send a copy of the request to the mirror service
+///
+/// The request is sent in the background and the mirror response is
+/// discarded. Once both responses are received, the status and latency
+/// differences are logged. The returned sender should receive the result of
+/// the primary request.
+///
+pub(super) fn dispatch_mirror_request(
+    mirror_req: ClientRequest,
+    body: Bytes,
+    mirror_service: String,
+    target: UpstreamTarget,
+) -> Sender<PrimaryResult> {
+    let (sender, receiver) = oneshot::channel::<PrimaryResult>();
+
+    actix_rt::spawn(
+        async move {
+            let upstream_request =
+                start_upstream_request(&mirror_service, &target.host).await;
+
+            let started_at = Instant::now();
+            let result = mirror_req.send_body(body).await;
+            let latency = started_at.elapsed();
+
+            drop(upstream_request);
+
+            let status = match result {
+                Ok(response) => Some(response.status()),
+                Err(err) => {
+                    warn!("Error on mirror request to {mirror_service}: {err}");
+
+                    None
+                }
+            };
+
+            //
+            // Primary requests dropped before finishing are not compared
+            //
+            let primary = match receiver.await {
+                Ok(primary) => primary,
+                Err(_) => {
+                    trace!("Primary request dropped before the mirror one");
+                    return;
+                }
+            };
+
+            let latency_diff =
+                latency.as_millis() as i64 - primary.latency.as_millis() as i64;
+
+            let format_status = |status: Option<StatusCode>| {
+                status
+                    .map(|status| status.as_u16().to_string())
+                    .unwrap_or("error".to_string())
+            };
+
+            match status == primary.status {
+                true => info!(
+                    "Mirror {mirror_service} answered as the primary: status {status}, latency difference {latency_diff}ms",
+                    status = format_status(status),
+                ),
+                false => warn!(
+                    "Mirror {mirror_service} answered differently from the primary: status {status} (primary {primary_status}), latency difference {latency_diff}ms",
+                    status = format_status(status),
+                    primary_status = format_status(primary.status),
+                ),
+            }
+        }
+        .instrument(tracing::Span::current()),
+    );
+
+    sender
+}
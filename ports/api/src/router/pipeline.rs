@@ -0,0 +1,571 @@
+use super::{
+    build_circuit_open_response, enforce_rate_limit, insert_rate_limit_headers,
+};
+use crate::middleware::{
+    fetch_and_inject_profile_to_forward,
+    fetch_and_inject_role_scoped_connection_string_to_forward,
+    ProfileInjectionContext,
+};
+
+use actix_web::{
+    http::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    HttpResponse,
+};
+use myc_core::{
+    domain::{
+        dtos::{
+            forwarded::ForwardedChain,
+            http::Protocol,
+            http_secret::{encode_basic_credentials, HttpSecret},
+            profile::Profile,
+            rate_limit::{RateLimitDecision, RateLimitKey, RateLimitPolicy},
+            route::Route,
+            route_type::RouteType,
+            service::Service,
+            upstream::UpstreamTarget,
+        },
+        entities::RateLimitCounting,
+        utils::trace_context_headers,
+    },
+    settings::RESOLVED_HOSTNAMES,
+    use_cases::gateway::services::{
+        acquire_circuit_breaker, fetch_oauth2_token, select_upstream_targets,
+        CircuitBreakerPermit,
+    },
+};
+use myc_http_tools::{
+    responses::GatewayError,
+    settings::{
+        DEFAULT_PROFILE_KEY, FORWARDED_HOST_KEY, FORWARDED_KEY,
+        FORWARDED_PROTO_KEY, FORWARDING_KEYS, FORWARD_FOR_KEY,
+    },
+};
+use std::{net::IpAddr, str::FromStr};
+use tracing::{error, trace, warn};
+use uuid::Uuid;
+
+/// The rate limit policy and decision exposed at the response headers
+pub(super) type RateLimitState = Option<(RateLimitPolicy, RateLimitDecision)>;
+
+/// Check if the request source is allowed by the route
+///
+/// Routes with allowed sources should only be reached by requests from the
+/// declared IPs, CIDR ranges or hostnames.
+///
+pub(super) fn check_allowed_source(
+    route: &Route,
+    source_ip: Option<IpAddr>,
+) -> Result<(), GatewayError> {
+    trace!("Checking if source is allowed");
+
+    match route.allow_source(source_ip, &RESOLVED_HOSTNAMES.load()) {
+        Err(err) => {
+            warn!("{:?}", err);
+
+            Err(GatewayError::InternalServerError(String::from(
+                "Invalid route source restrictions",
+            )))
+        }
+        Ok(false) => {
+            trace!(
+                "Source {source} rejected by the allowedSources rule of route {route_id}: {rule}",
+                source = source_ip
+                    .map(|ip| ip.to_string())
+                    .unwrap_or("unknown".to_string()),
+                route_id = route
+                    .id
+                    .map(|id| id.to_string())
+                    .unwrap_or(route.path.to_owned()),
+                rule = route
+                    .allowed_sources
+                    .to_owned()
+                    .unwrap_or_default()
+                    .join(", ")
+            );
+
+            Err(GatewayError::Forbidden(String::from(
+                "Request source is not allowed for this route",
+            )))
+        }
+        Ok(true) => Ok(()),
+    }
+}
+
+/// Check the route rate limit by the request attributes
+///
+/// Policies counted by the client IP or by the connection string do not
+/// depend on the profile, then exceeding requests are rejected before the
+/// profile lookup to avoid unnecessary database queries. Policies counted by
+/// the account are checked by `enforce_account_rate_limit`.
+///
+pub(super) async fn enforce_request_rate_limit(
+    route: &Route,
+    client_ip: Option<IpAddr>,
+    headers: &HeaderMap,
+    rate_limit_counting_repo: Box<&dyn RateLimitCounting>,
+) -> Result<RateLimitState, HttpResponse> {
+    match route
+        .rate_limit
+        .to_owned()
+        .filter(|policy| policy.key() != RateLimitKey::AccountId)
+    {
+        None => Ok(None),
+        Some(policy) => {
+            trace!("Checking rate limit");
+
+            enforce_rate_limit(
+                route,
+                policy,
+                client_ip,
+                headers,
+                None,
+                rate_limit_counting_repo,
+            )
+            .await
+        }
+    }
+}
+
+/// Check the route rate limit by the account
+///
+/// The check is performed after the authentication, allowing requests to be
+/// counted by the authenticated account. Routes without a policy counted by
+/// the account keep the decision of the request rate limit.
+///
+pub(super) async fn enforce_account_rate_limit(
+    route: &Route,
+    client_ip: Option<IpAddr>,
+    headers: &HeaderMap,
+    account_id: Option<Uuid>,
+    rate_limit: RateLimitState,
+    rate_limit_counting_repo: Box<&dyn RateLimitCounting>,
+) -> Result<RateLimitState, HttpResponse> {
+    match route
+        .rate_limit
+        .to_owned()
+        .filter(|policy| policy.key() == RateLimitKey::AccountId)
+    {
+        None => Ok(rate_limit),
+        Some(policy) => {
+            trace!("Checking rate limit");
+
+            enforce_rate_limit(
+                route,
+                policy,
+                client_ip,
+                headers,
+                account_id,
+                rate_limit_counting_repo,
+            )
+            .await
+        }
+    }
+}
+
+/// Build the headers forwarded to the upstream
+///
+/// The service and route header transformations are applied before injecting
+/// the gateway headers. Thus, policies could not replace them. The forwarding
+/// headers sent by the client are replaced by the resolved forwarding chain,
+/// and the trace context by the one of the request span. Profiles sent by the
+/// client are discarded, since only the gateway injects them.
+///
+pub(super) fn prepare_forwarded_headers(
+    route: &Route,
+    forwarded_headers: &mut HeaderMap,
+    forwarded_chain: &ForwardedChain,
+) -> Result<(), GatewayError> {
+    if let Err(err) = route.apply_request_header_policy(forwarded_headers) {
+        warn!("Unable to apply the request header policy: {err}");
+
+        return Err(GatewayError::InternalServerError(String::from(
+            "Unexpected error on route request",
+        )));
+    }
+
+    for key in [
+        FORWARD_FOR_KEY,
+        FORWARDED_KEY,
+        FORWARDED_PROTO_KEY,
+        FORWARDED_HOST_KEY,
+        DEFAULT_PROFILE_KEY,
+    ] {
+        forwarded_headers.remove(key);
+    }
+
+    let mut gateway_headers = vec![(
+        FORWARDED_PROTO_KEY.to_string(),
+        forwarded_chain.proto.to_owned(),
+    )];
+
+    if let Some(forward_for) = forwarded_chain.x_forwarded_for() {
+        gateway_headers.push((FORWARD_FOR_KEY.to_string(), forward_for));
+    }
+
+    if let Some(forwarded) = forwarded_chain.forwarded() {
+        gateway_headers.push((FORWARDED_KEY.to_string(), forwarded));
+    }
+
+    if let Some(host) = forwarded_chain.host.to_owned() {
+        gateway_headers.push((FORWARDED_HOST_KEY.to_string(), host));
+    }
+
+    gateway_headers.extend(trace_context_headers());
+
+    for (name, value) in gateway_headers {
+        insert_header(forwarded_headers, &name, &value)?;
+    }
+
+    Ok(())
+}
+
+/// Check the route group and inject the caller identity
+///
+/// Protected routes (RouteType::Protected) should include valid information
+/// of the user email. This step try to collect this information and fetch the
+/// user profile. Case email is valid but the user is not registered on the
+/// system, it returns a Forbidden response. Case the user was previously
+/// registered, then the signed profile is injected into the forwarded headers
+/// to be collected by client service. Routes protected by service tokens
+/// receive the connection string scope instead.
+///
+/// Returns the profile of the caller, used by the traffic split and by the
+/// account rate limit.
+///
+pub(super) async fn inject_caller_identity(
+    route: &Route,
+    headers: &HeaderMap,
+    forwarded_headers: &mut HeaderMap,
+    context: &ProfileInjectionContext<'_>,
+) -> Result<Option<Profile>, GatewayError> {
+    trace!("Checking authentication and permissions");
+
+    match route.group.to_owned() {
+        //
+        // Public routes do not need any authentication or profile injection.
+        //
+        RouteType::Public => {
+            trace!("Route(Public): {path}", path = route.path);
+
+            Ok(None)
+        }
+        //
+        // Protected routes should include the full qualified user profile into
+        // the header
+        //
+        RouteType::Protected => {
+            trace!("Route(Protected): {path}", path = route.path);
+
+            fetch_and_inject_profile_to_forward(
+                headers,
+                forwarded_headers,
+                context,
+                None,
+                None,
+                route,
+            )
+            .await
+            .map(Some)
+        }
+        //
+        // Protected routes should include the user profile filtered by roles
+        // into the header
+        //
+        RouteType::ProtectedByRoles { roles } => {
+            trace!("Route(ProtectedByRoles): {path}", path = route.path);
+
+            fetch_and_inject_profile_to_forward(
+                headers,
+                forwarded_headers,
+                context,
+                Some(roles),
+                None,
+                route,
+            )
+            .await
+            .map(Some)
+        }
+        //
+        // Protected routes should include the user profile filtered by roles
+        // and permissions into the header
+        //
+        RouteType::ProtectedByPermissionedRoles { permissioned_roles } => {
+            trace!(
+                "Route(ProtectedByPermissionedRoles): {path}",
+                path = route.path
+            );
+
+            fetch_and_inject_profile_to_forward(
+                headers,
+                forwarded_headers,
+                context,
+                None,
+                Some(permissioned_roles),
+                route,
+            )
+            .await
+            .map(Some)
+        }
+        //
+        // Protected routes by service token should include the users role which
+        // the service token is associated
+        //
+        RouteType::ProtectedByServiceTokenWithRole { roles } => {
+            trace!(
+                "Route(ProtectedByServiceTokenWithRole): {path}",
+                path = route.path
+            );
+
+            fetch_and_inject_role_scoped_connection_string_to_forward(
+                headers,
+                forwarded_headers,
+                Some(roles),
+                None,
+            )
+            .await
+            .map(|_| None)
+        }
+        //
+        // Protected routes by service token should include the users role which
+        // the service token is associated
+        //
+        RouteType::ProtectedByServiceTokenWithPermissionedRoles {
+            permissioned_roles,
+        } => {
+            trace!(
+                "Route(ProtectedByServiceTokenWithPermissionedRoles): {path}",
+                path = route.path
+            );
+
+            fetch_and_inject_role_scoped_connection_string_to_forward(
+                headers,
+                forwarded_headers,
+                None,
+                Some(permissioned_roles),
+            )
+            .await
+            .map(|_| None)
+        }
+    }
+}
+
+/// Select the healthy upstream targets of a service
+///
+/// Targets marked as unhealthy by the health checker are skipped. Services
+/// without healthy targets are rejected immediately instead of waiting for
+/// the gateway timeout.
+///
+pub(super) async fn select_healthy_targets(
+    service: &Service,
+) -> Result<Vec<UpstreamTarget>, GatewayError> {
+    let targets = select_upstream_targets(service).await;
+
+    if targets.is_empty() {
+        warn!("Service {} has no healthy targets", service.name);
+
+        return Err(GatewayError::ServiceUnavailable(format!(
+            "Service {} is currently unavailable",
+            service.name
+        )));
+    }
+
+    Ok(targets)
+}
+
+/// Solve the secret of the route
+///
+/// Secrets are only sent to HTTPS routes served with TLS, unless the route
+/// accepts insecure routing.
+///
+pub(super) async fn solve_route_secret(
+    route: &Route,
+    tls_enabled: bool,
+) -> Result<Option<HttpSecret>, GatewayError> {
+    trace!("Injecting downstream secret into request");
+
+    let secret = match route.solve_secret().await {
+        Err(err) => {
+            warn!("{:?}", err);
+            return Err(GatewayError::InternalServerError(format!("{err}")));
+        }
+        Ok(None) => return Ok(None),
+        Ok(Some(secret)) => secret,
+    };
+
+    if !route.accept_insecure_routing.unwrap_or(false)
+        && !(tls_enabled
+            && [Protocol::Https, Protocol::Wss].contains(&route.protocol))
+    {
+        error!(
+            "Secrets are only allowed for HTTPS routes: {path}",
+            path = route.path
+        );
+
+        return Err(GatewayError::InternalServerError(
+            "Unexpected error on route request".to_string(),
+        ));
+    }
+
+    Ok(Some(secret))
+}
+
+/// Inject the route secrets sent as headers
+///
+/// Returns the name of the header which contains the secret, removed from the
+/// upstream response. Query parameters and client certificates are not sent
+/// as headers, then they are left to the caller and `None` is returned.
+///
+pub(super) async fn inject_secret_header(
+    secret: &HttpSecret,
+    forwarded_headers: &mut HeaderMap,
+) -> Result<Option<String>, GatewayError> {
+    let (name, value) = match secret.to_owned() {
+        //
+        // Insert the authorization key into the header
+        //
+        HttpSecret::AuthorizationHeader {
+            name,
+            prefix,
+            token,
+        } => (
+            name.unwrap_or(AUTHORIZATION.to_string()),
+            format!("{} {token}", prefix.unwrap_or("Bearer".to_string())),
+        ),
+        //
+        // Insert the basic credentials into the header
+        //
+        HttpSecret::BasicAuth { username, password } => (
+            AUTHORIZATION.to_string(),
+            encode_basic_credentials(&username, &password),
+        ),
+        //
+        // Insert the token into the custom header
+        //
+        HttpSecret::CustomHeader { name, token } => (name, token),
+        //
+        // Insert the OAuth2 access token into the header
+        //
+        HttpSecret::OAuth2ClientCredentials {
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+            audience,
+        } => match fetch_oauth2_token(
+            &token_url,
+            &client_id,
+            &client_secret,
+            scope.as_deref(),
+            audience.as_deref(),
+        )
+        .await
+        {
+            Err(err) => {
+                warn!("Unable to fetch the route access token: {err}");
+
+                return Err(GatewayError::InternalServerError(String::from(
+                    "Unexpected error on route request",
+                )));
+            }
+            Ok(token) => (AUTHORIZATION.to_string(), format!("Bearer {token}")),
+        },
+        HttpSecret::QueryParameter { .. }
+        | HttpSecret::ClientCertificate { .. } => return Ok(None),
+    };
+
+    //
+    // The inserted header replaces any previous value sent by the client
+    //
+    insert_header(forwarded_headers, &name, &value)?;
+
+    Ok(Some(name))
+}
+
+/// Accept the request at the service circuit
+///
+/// Services with an open circuit are rejected without waiting for the upstream
+/// timeout. The check should be performed just before sending the request,
+/// then half-open circuits only count trials with a registered result.
+///
+pub(super) async fn acquire_service_circuit(
+    service: &Service,
+    headers: &HeaderMap,
+) -> Result<CircuitBreakerPermit, HttpResponse> {
+    acquire_circuit_breaker(service)
+        .await
+        .map_err(|retry_after| {
+            build_circuit_open_response(service, retry_after, headers)
+        })
+}
+
+/// Filter the headers of the upstream response
+///
+/// The gateway internal headers are removed before applying the service and
+/// route header transformations and inserting the rate limit headers.
+///
+/// The route secret, the forwarding and the profile headers contain sensitive
+/// information about the system internals. Thus, be careful on edit this
+/// function.
+///
+pub(super) fn filter_response_headers(
+    route: &Route,
+    route_key: Option<String>,
+    rate_limit: &RateLimitState,
+    response_headers: &mut HeaderMap,
+) -> Result<(), GatewayError> {
+    let internal_headers = route_key
+        .into_iter()
+        .chain(FORWARDING_KEYS.iter().map(|key| key.to_string()))
+        .chain(
+            [
+                FORWARD_FOR_KEY,
+                FORWARDED_KEY,
+                FORWARDED_PROTO_KEY,
+                FORWARDED_HOST_KEY,
+                DEFAULT_PROFILE_KEY,
+            ]
+            .map(|key| key.to_string()),
+        )
+        .map(|key| key.to_lowercase())
+        .collect::<Vec<String>>();
+
+    for key in internal_headers {
+        response_headers.remove(key.as_str());
+    }
+
+    if let Err(err) = route.apply_response_header_policy(response_headers) {
+        warn!("Unable to apply the response header policy: {err}");
+
+        return Err(GatewayError::InternalServerError(String::from(
+            "Unexpected error on route response",
+        )));
+    }
+
+    if let Some((policy, decision)) = rate_limit {
+        insert_rate_limit_headers(response_headers, policy, decision);
+    }
+
+    Ok(())
+}
+
+/// Insert a header into the forwarded headers
+fn insert_header(
+    headers: &mut HeaderMap,
+    name: &str,
+    value: &str,
+) -> Result<(), GatewayError> {
+    match (HeaderName::from_str(name), HeaderValue::from_str(value)) {
+        (Ok(name), Ok(value)) => {
+            headers.insert(name, value);
+
+            Ok(())
+        }
+        _ => {
+            warn!("Invalid header {name}");
+
+            Err(GatewayError::InternalServerError(String::from(
+                "Unexpected error on route request",
+            )))
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use super::{build_circuit_open_response, build_target_uri};
+
+use actix_web::{
+    http::header::{
+        HeaderName, SEC_WEBSOCKET_EXTENSIONS, SEC_WEBSOCKET_KEY,
+        SEC_WEBSOCKET_PROTOCOL, SEC_WEBSOCKET_VERSION,
+    },
+    web, HttpRequest, HttpResponse, ResponseError,
+};
+use actix_ws::{CloseCode, CloseReason, Message, MessageStream, Session};
+use awc::{
+    error::{SendRequestError, WsClientError, WsProtocolError},
+    ws::Frame,
+    Client, ClientRequest,
+};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use myc_core::{
+    domain::dtos::{service::Service, upstream::UpstreamTarget},
+    use_cases::gateway::services::{
+        acquire_circuit_breaker, register_circuit_breaker_result,
+        start_upstream_request,
+    },
+};
+use myc_http_tools::{responses::GatewayError, settings::FORWARDING_KEYS};
+use std::time::Duration;
+use tracing::{trace, warn};
+
+/// Proxy a WebSocket connection to the service upstream targets
+///
+/// The client handshake is validated and the target addresses are built before
+/// accepting the connection at the service circuit. Then the upstream handshake
+/// is performed, failing over to the next targets on connection errors.
+/// Finally, the client connection is upgraded and the frames are relayed in
+/// both directions until one of the sides closes the connection or it remains
+/// idle for longer than the idle timeout.
+///
+pub(super) async fn proxy_websocket(
+    req: &HttpRequest,
+    payload: web::Payload,
+    client: &Client,
+    forwarded_req: &ClientRequest,
+    targets: &[UpstreamTarget],
+    service: &Service,
+    handshake_timeout: u64,
+    idle_timeout: u64,
+) -> Result<HttpResponse, GatewayError> {
+    //
+    // Validate the client handshake before reaching the upstream
+    //
+    let (mut response, session, messages) = match actix_ws::handle(req, payload)
+    {
+        Ok(handshake) => handshake,
+        Err(err) => {
+            warn!("Invalid WebSocket handshake: {err}");
+
+            return Ok(err.error_response());
+        }
+    };
+
+    let target_uris = targets
+        .iter()
+        .map(|target| build_target_uri(forwarded_req.get_uri(), target))
+        .collect::<Result<Vec<_>, GatewayError>>()?;
+
+    let circuit_permit = match acquire_circuit_breaker(service).await {
+        Err(retry_after) => {
+            return Ok(build_circuit_open_response(
+                service,
+                retry_after,
+                req.headers(),
+            ))
+        }
+        Ok(permit) => permit,
+    };
+
+    // ? -----------------------------------------------------------------------
+    // ? Perform the upstream handshake
+    //
+    // The handshake headers are generated by the gateway client. The remaining
+    // end-to-end headers, including the injected profile and secrets, are sent
+    // to the upstream.
+    //
+    // ? -----------------------------------------------------------------------
+
+    let mut connection = None;
+    let mut failure = None;
+
+    for (target, uri) in targets.iter().zip(target_uris) {
+        let mut ws_req = client.ws(uri);
+
+        for (name, value) in forwarded_req
+            .headers()
+            .iter()
+            .filter(|(name, _)| !is_handshake_header(name))
+        {
+            ws_req = ws_req.header(name.to_owned(), value.to_owned());
+        }
+
+        let upstream_request =
+            start_upstream_request(&service.name, &target.host).await;
+
+        let result = actix_rt::time::timeout(
+            Duration::from_secs(handshake_timeout),
+            ws_req.connect(),
+        )
+        .await;
+
+        match result {
+            Ok(Ok((upstream_response, framed))) => {
+                connection = Some((
+                    target.host.to_owned(),
+                    upstream_response,
+                    framed,
+                    upstream_request,
+                ));
+
+                break;
+            }
+            Ok(Err(err)) => {
+                warn!(
+                    "Error on WebSocket handshake with {}: {err}",
+                    target.host
+                );
+
+                //
+                // Connection errors fail over to the next target
+                //
+                if let WsClientError::SendRequest(SendRequestError::Connect(
+                    _,
+                )) = err
+                {
+                    failure = Some(GatewayError::ServiceUnavailable(format!(
+                        "Service {} is currently unavailable",
+                        service.name
+                    )));
+
+                    continue;
+                }
+
+                //
+                // Upstream rejections are propagated to the client
+                //
+                if let WsClientError::InvalidResponseStatus(status) = err {
+                    register_circuit_breaker_result(
+                        circuit_permit,
+                        match status.is_server_error() {
+                            true => Some(format!(
+                                "Unexpected status code: {status}"
+                            )),
+                            false => None,
+                        },
+                    )
+                    .await;
+
+                    return Ok(HttpResponse::build(status).finish());
+                }
+
+                failure = Some(GatewayError::InternalServerError(
+                    String::from("Unexpected error on route request"),
+                ));
+
+                break;
+            }
+            Err(_) => {
+                warn!("WebSocket handshake with {} timed out", target.host);
+
+                failure = Some(GatewayError::GatewayTimeout(String::from(
+                    "Service did not respond in time",
+                )));
+
+                break;
+            }
+        }
+    }
+
+    let (host, upstream_response, framed, upstream_request) = match connection {
+        Some(connection) => connection,
+        None => {
+            register_circuit_breaker_result(
+                circuit_permit,
+                Some(String::from("Error on WebSocket handshake")),
+            )
+            .await;
+
+            return Err(failure.unwrap_or(GatewayError::InternalServerError(
+                String::from("Unexpected error on route request"),
+            )));
+        }
+    };
+
+    register_circuit_breaker_result(circuit_permit, None).await;
+
+    //
+    // The sub-protocol selected by the upstream is informed to the client
+    //
+    if let Some(protocol) =
+        upstream_response.headers().get(SEC_WEBSOCKET_PROTOCOL)
+    {
+        response
+            .headers_mut()
+            .insert(SEC_WEBSOCKET_PROTOCOL, protocol.to_owned());
+    }
+
+    // ? -----------------------------------------------------------------------
+    // ? Relay the frames
+    //
+    // The upstream connection is counted as an active request of the target
+    // until the relay finishes.
+    //
+    // ? -----------------------------------------------------------------------
+
+    actix_rt::spawn(async move {
+        relay_frames(
+            session,
+            messages,
+            framed,
+            Duration::from_secs(idle_timeout),
+        )
+        .await;
+
+        drop(upstream_request);
+
+        trace!("WebSocket connection with {host} closed");
+    });
+
+    Ok(response)
+}
+
+/// Relay the frames between the client and the upstream connections
+///
+/// Close frames are propagated to the other side. Connections without frames
+/// during the idle timeout are closed on both sides.
+///
+async fn relay_frames<U>(
+    mut session: Session,
+    mut messages: MessageStream,
+    upstream: U,
+    idle_timeout: Duration,
+) where
+    U: Stream<Item = Result<Frame, WsProtocolError>>
+        + Sink<Message, Error = WsProtocolError>,
+{
+    let (mut upstream_sink, mut upstream_frames) = upstream.split();
+
+    let close_reason = loop {
+        tokio::select! {
+            //
+            // Client to upstream
+            //
+            message = messages.next() => match message {
+                Some(Ok(Message::Close(reason))) => {
+                    let _ = upstream_sink
+                        .send(Message::Close(reason.to_owned()))
+                        .await;
+
+                    break reason;
+                }
+                Some(Ok(Message::Nop)) => (),
+                Some(Ok(message)) => {
+                    if let Err(err) = upstream_sink.send(message).await {
+                        warn!(
+                            "Error on send WebSocket frame to upstream: {err}"
+                        );
+
+                        break Some(CloseReason::from(CloseCode::Error));
+                    }
+                }
+                Some(Err(err)) => {
+                    warn!("Invalid WebSocket frame from client: {err}");
+
+                    let reason = Some(CloseReason::from(CloseCode::Protocol));
+                    let _ = upstream_sink
+                        .send(Message::Close(reason.to_owned()))
+                        .await;
+
+                    break reason;
+                }
+                None => {
+                    let _ = upstream_sink.send(Message::Close(None)).await;
+
+                    break None;
+                }
+            },
+            //
+            // Upstream to client
+            //
+            frame = upstream_frames.next() => {
+                let result = match frame {
+                    Some(Ok(Frame::Text(text))) => {
+                        session
+                            .text(String::from_utf8_lossy(&text).into_owned())
+                            .await
+                    }
+                    Some(Ok(Frame::Binary(bytes))) => {
+                        session.binary(bytes).await
+                    }
+                    Some(Ok(Frame::Continuation(item))) => {
+                        session.continuation(item).await
+                    }
+                    Some(Ok(Frame::Ping(bytes))) => session.ping(&bytes).await,
+                    Some(Ok(Frame::Pong(bytes))) => session.pong(&bytes).await,
+                    Some(Ok(Frame::Close(reason))) => {
+                        let _ = upstream_sink
+                            .send(Message::Close(reason.to_owned()))
+                            .await;
+
+                        break reason;
+                    }
+                    Some(Err(err)) => {
+                        warn!("Invalid WebSocket frame from upstream: {err}");
+
+                        break Some(CloseReason::from(CloseCode::Error));
+                    }
+                    None => break Some(CloseReason::from(CloseCode::Away)),
+                };
+
+                if result.is_err() {
+                    let _ = upstream_sink.send(Message::Close(None)).await;
+
+                    break None;
+                }
+            },
+            //
+            // Idle connections
+            //
+            _ = actix_rt::time::sleep(idle_timeout) => {
+                let reason = Some(CloseReason {
+                    code: CloseCode::Away,
+                    description: Some(String::from("Idle timeout")),
+                });
+
+                let _ = upstream_sink
+                    .send(Message::Close(reason.to_owned()))
+                    .await;
+
+                break reason;
+            }
+        }
+    };
+
+    let _ = session.close(close_reason).await;
+    let _ = upstream_sink.close().await;
+}
+
+/// Check if the header is generated by the WebSocket handshake or is a
+/// hop-by-hop header
+fn is_handshake_header(name: &HeaderName) -> bool {
+    [
+        SEC_WEBSOCKET_KEY,
+        SEC_WEBSOCKET_VERSION,
+        SEC_WEBSOCKET_EXTENSIONS,
+    ]
+    .contains(name)
+        || FORWARDING_KEYS
+            .iter()
+            .any(|key| key.eq_ignore_ascii_case(name.as_str()))
+}
@@ -9,3 +9,19 @@ pub const SUPER_USER_API_SCOPE: &str = "su";
 
 /// The scope used to indicate role scoped routes
 pub const ROLE_SCOPED_API_SCOPE: &str = "rs";
+
+/// The maximum length of the request ids sent by clients
+pub const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// The default size limit of the buffered request bodies in bytes
+pub const DEFAULT_MAX_BUFFERED_BODY_SIZE: usize = 1024 * 1024;
+
+/// The default interval in seconds between downstream services health checks
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+
+/// The default interval in seconds between routes file changes checks
+pub const DEFAULT_ROUTES_WATCH_INTERVAL: u64 = 10;
+
+/// The default interval in seconds between allowed source hostnames
+/// resolutions
+pub const DEFAULT_ALLOWED_SOURCES_RESOLVE_INTERVAL: u64 = 300;